@@ -126,3 +126,598 @@ unit-cup = šálek
 validation-required = Toto pole je povinné
 validation-positive = Musí být kladné číslo
 validation-date-range = Datum konce musí být po datu začátku
+
+# Layout
+language = Jazyk
+nav-planner = Plánovač
+nav-pantry = Spíž
+nav-admin = Správa
+page-not-found = Stránka nenalezena
+page-not-found-text = Hledaná stránka neexistuje.
+go-home = Zpět na úvod
+signing-in = Přihlašování…
+login-subtitle = Pro pokračování se přihlaste
+
+# Home
+home-tagline = Snadné a efektivní plánování táborového jídla
+home-camps-text = Spravujte tábory a akce včetně termínů a počtu účastníků
+home-recipes-text = Vytvářejte a spravujte recepty s výpočtem porcí
+home-ingredients-text = Uspořádejte suroviny a kategorie pro snadné plánování
+home-planner-text = Naplánujte denní jídla pro své tábory
+home-reports-text = Generujte nákupní seznamy a přehledy jídel
+home-tips = Rychlé tipy
+home-tips-text = Začněte surovinami, vytvořte recepty, založte tábor a naplánujte jídla!
+home-guide = Jak začít
+home-step-1 = Vytvořte kategorie surovin a přidejte suroviny
+home-step-2 = Sestavte knihovnu receptů s přesnými porcemi a postupy
+home-step-3 = Založte tábor s termínem, místem a výchozím počtem účastníků
+home-step-4 = Naplánujte jídla na každý den z knihovny receptů
+home-step-5 = Vygenerujte nákupní seznamy a denní přehledy pro tábor
+
+# Shared
+date = Datum
+total = Celkem
+storage = Skladování
+saving = Ukládání...
+generating = Generování...
+print = Tisk
+preview = Náhled
+accept = Přijmout
+accept-all = Přijmout vše
+reject = Zahodit
+notes-optional = Poznámky (nepovinné)
+suppliers = Dodavatelé
+duplicates = Duplicity
+cook-from-stock = Vaříme ze zásob
+search-camps = Hledat tábory...
+search-recipes = Hledat recepty...
+children-count = { $count ->
+    [one] { $count } dítě
+    [few] { $count } děti
+   *[other] { $count } dětí
+}
+teens-count = { $count ->
+    [one] { $count } teenager
+    [few] { $count } teenageři
+   *[other] { $count } teenagerů
+}
+adults-count = { $count ->
+    [one] { $count } dospělý
+    [few] { $count } dospělí
+   *[other] { $count } dospělých
+}
+attendance-summary = Účast: děti { $children }, teenageři { $teens }, dospělí { $adults }
+days-count = { $count ->
+    [one] { $count } den
+    [few] { $count } dny
+   *[other] { $count } dní
+}
+validation-required-fields = Vyplňte všechna povinná pole
+validation-children-number = Neplatný počet dětí
+validation-teens-number = Neplatný počet teenagerů
+validation-adults-number = Neplatný počet dospělých
+validation-children-negative = Počet dětí nesmí být záporný
+validation-teens-negative = Počet teenagerů nesmí být záporný
+validation-adults-negative = Počet dospělých nesmí být záporný
+error-load-camps = Nepodařilo se načíst tábory: { $error }
+error-load-camp = Nepodařilo se načíst tábor: { $error }
+error-load-recipes = Nepodařilo se načíst recepty: { $error }
+error-load-meals = Nepodařilo se načíst jídla: { $error }
+
+# Camp list
+camp-created = Tábor byl vytvořen
+camp-deleted = Tábor byl smazán
+delete-camp = Smazat tábor
+archive-camp = Archivovat tábor
+camp-archived = Tábor byl archivován, lze ho obnovit z koše
+no-camps = Zatím žádné tábory
+no-camps-text = Začněte vytvořením prvního tábora
+camp-notes-placeholder = Další poznámky k táboru
+plan-meals = Plánovat jídla
+calendar-feed = Kalendář
+calendar-feed-title = Odebírat jídelníček v kalendáři
+calendar-subscribe-prompt = Přidejte tuto adresu do svého kalendáře:
+open-plan-check = Otevřít kontrolu plánu
+plan-looks-fine = Plán je v pořádku
+plan-warnings = { $count } upozornění
+error-create-camp = Nepodařilo se vytvořit tábor: { $error }
+error-delete-camp = Nepodařilo se smazat tábor: { $error }
+error-archive-camp = Nepodařilo se archivovat tábor: { $error }
+error-calendar-link = Nepodařilo se získat odkaz na kalendář: { $error }
+error-check-plans = Nepodařilo se zkontrolovat plán: { $error }
+
+# Planner
+meal-type = Jídlo
+add-meal = Přidat jídlo
+edit-meal = Upravit jídlo
+update-meal = Uložit jídlo
+delete-meal = Smazat jídlo
+delete-meal-confirm = Opravdu chcete smazat toto jídlo? Tuto akci nelze vrátit.
+meal-added = Jídlo bylo přidáno
+meal-updated = Jídlo bylo upraveno
+meal-deleted = Jídlo bylo smazáno
+no-camp-selected = Není vybrán tábor
+select-camp-to-plan = Pro plánování jídel vyberte tábor
+go-to-camps = Přejít na tábory
+view-label = Zobrazení:
+view-single-day = Jeden den
+view-all-days = Všechny dny
+previous-day = Předchozí den
+next-day = Další den
+camp-day = { $day }. den
+camp-day-of = { $day }. den z { $days }
+attendance-optional = Účast (nepovinné)
+no-meals-for-date = Na tento den nejsou naplánována žádná jídla. Přidejte je tlačítkem „Přidat jídlo“.
+no-meals-for-day = Na tento den nejsou naplánována žádná jídla
+no-meals-planned-yet = Zatím nejsou naplánována žádná jídla. Jídla přidáte v zobrazení „Jeden den“.
+plan-check-button = Kontrola
+generate-button = Generovat
+error-save-meal = Nepodařilo se uložit jídlo: { $error }
+error-delete-meal = Nepodařilo se smazat jídlo: { $error }
+
+# Plan check
+plan-check = Kontrola plánu
+check-again = Zkontrolovat znovu
+no-problems = Nenalezeny žádné problémy
+plan-warning-repeated-recipe = Opakovaný recept
+plan-warning-no-produce = Bez zeleniny a ovoce
+plan-warning-missing-attendance = Chybí účast
+plan-warning-empty-slots = Prázdná jídla
+plan-warning-empty-recipe = Recept bez surovin
+plan-no-meals = Nejsou naplánována žádná jídla
+plan-nothing-planned = Nic není naplánováno
+plan-repeated-recipe = { $recipe } se podává dvakrát po sobě
+plan-empty-recipe = { $recipe } nemá žádné suroviny
+plan-missing-attendance = { $recipe } nemá zadanou účast; použijí se výchozí hodnoty tábora
+plan-no-produce = Žádné jídlo dne neobsahuje zeleninu ani ovoce
+
+# Menu generator
+generate-menu = Generovat jídelníček
+generate-menu-help = Doplní prázdná jídla recepty se štítky daného jídla. Recepty se štítkem „hot“ se počítají jako teplá jídla, recepty se štítkem „cook-intensive“ jako náročná na vaření.
+any-recipe = Jakýkoli recept
+hot = Teplé
+no-repeat-days = Neopakovat během (dní)
+max-cost-per-day = Max. cena na osobu a den
+max-cook-intensive = Max. náročných jídel za den
+no-limit = Bez omezení
+seed = Seed
+exclude-allergens = Vynechat alergeny
+next-seed = Zkusit další seed
+another-menu = Jiný jídelníček
+cost-per-person = { $cost } na osobu
+menu-all-planned = Všechna jídla tábora jsou již naplánována
+menu-unfilled-no-recipes = Zatím nejsou žádné recepty
+menu-unfilled-no-tag = Žádný recept se štítkem { $tags }
+menu-unfilled-no-hot-tag = Žádný teplý recept se štítkem { $tags }
+menu-unfilled-allergens = Každý vhodný recept obsahuje vyloučený alergen
+menu-unfilled-repeated = { $days ->
+    [one] Každý vhodný recept se podává v posledním { $days } dni
+    [few] Každý vhodný recept se podává v posledních { $days } dnech
+   *[other] Každý vhodný recept se podává v posledních { $days } dnech
+}
+menu-unfilled-cook-intensive = Každý vhodný recept je náročný na vaření a denní limit je vyčerpán
+menu-unfilled-budget = Žádný vhodný recept se nevejde do denního rozpočtu
+calendar-menu = Jídelníček
+meals-added = Přidáno jídel: { $count }
+meals-added-on = Přidáno jídel na { $date }: { $count }
+error-generate-menu = Nepodařilo se vygenerovat jídelníček: { $error }
+error-save-meals = Nepodařilo se uložit jídla: { $error }
+error-save-meals-on = Nepodařilo se uložit jídla na { $date }: { $error }
+
+# Report generator
+report-type = Typ reportu
+report-shopping-list = Nákupní seznam
+report-meal-schedule = Jídelníček
+report-attendance-summary = Přehled účasti
+report-purchase-orders = Objednávky
+report-shopping-trips = Nákupy
+report-needed = Potřeba
+report-margin = Rezerva
+report-in-stock = Skladem
+report-to-buy = Nakoupit
+report-packs = Balení
+report-leftover = Zbyde
+report-needed-on = Potřeba dne
+report-used = Použití
+report-keeps = Trvanlivost
+report-generated = Report „{ $report }“ byl vygenerován
+error-generate-report = Nepodařilo se vygenerovat report „{ $report }“: { $error }
+select-camp-first = Vyberte tábor
+select-dates-first = Vyberte datum začátku a konce
+add-trip = Přidat nákup
+add-trip-first = Přidejte alespoň jeden nákup
+no-supplier = Bez dodavatele
+delivery-order-by = Dodání { $delivery } • objednat do { $order_by }
+needed-before-first-trip = Některé suroviny jsou potřeba před prvním nákupem:
+trip-on = Nákup { $date }
+nothing-to-buy = Není co nakupovat
+pdf-camp = Tábor: { $name }
+pdf-date-range = Od { $start } do { $end }
+
+# API tokens
+api-tokens = API tokeny
+api-tokens-help = Tokeny umožňují skriptům a botům používat REST API na /api/v1 bez přihlášení. Posílejte je v hlavičce „Authorization: Bearer <token>“.
+api-token-name = Název
+api-token-name-placeholder = např. Tabulka hospodáře
+api-token-scope = Přístup
+api-token-scope-read = Jen čtení
+api-token-scope-read-write = Čtení i zápis
+api-token-create = Vytvořit token
+api-token-created = Token byl vytvořen
+api-token-copy-now = Zkopírujte si token hned, znovu už zobrazen nebude:
+api-token-prefix = Token
+api-token-last-used = Naposledy použit
+api-token-never-used = Nikdy
+api-token-revoke = Zrušit
+api-token-revoke-confirm = Skripty používající tento token přestanou fungovat. Opravdu ho zrušit?
+api-token-revoked = Token byl zrušen
+no-api-tokens = Zatím žádné API tokeny
+error-load-api-tokens = Nepodařilo se načíst API tokeny: { $error }
+error-create-api-token = Nepodařilo se vytvořit token: { $error }
+error-revoke-api-token = Nepodařilo se zrušit token: { $error }
+
+# Errors
+error-not-found = Záznam nebyl nalezen
+error-unauthorized = Nejprve se přihlaste
+error-internal = Něco se pokazilo: { $error }
+error-duplicate = Záznam se stejným názvem už existuje
+error-reference = Na záznam odkazují jiná data
+error-category-in-use = { $count ->
+    [one] Kategorii nelze smazat: používá ji ještě { $count } surovina. Nejprve ji přeřaďte nebo smažte.
+    [few] Kategorii nelze smazat: používají ji ještě { $count } suroviny. Nejprve je přeřaďte nebo smažte.
+   *[other] Kategorii nelze smazat: používá ji ještě { $count } surovin. Nejprve je přeřaďte nebo smažte.
+}
+error-ingredient-in-use = { $count ->
+    [one] Surovinu používá { $count } recept
+    [few] Surovinu používají { $count } recepty
+   *[other] Surovinu používá { $count } receptů
+}
+error-recipe-in-use = { $count ->
+    [one] Recept je naplánován na { $count } jídlo
+    [few] Recept je naplánován na { $count } jídla
+   *[other] Recept je naplánován na { $count } jídel
+}
+error-stock-already-consumed = Zásoby na { $date } už byly spotřebovány
+error-alias-is-own-name = „{ $alias }“ už je název této suroviny
+error-alias-is-ingredient-name = „{ $alias }“ už je název suroviny „{ $name }“
+error-alias-taken = „{ $alias }“ už je alias suroviny „{ $name }“
+error-supplier-category-taken = Kategorii „{ $category }“ už dodává „{ $supplier }“
+error-supplier-ingredient-taken = Surovinu „{ $ingredient }“ už dodává „{ $supplier }“
+validation-name-required = Název nesmí být prázdný
+validation-token-name-required = Název tokenu je povinný
+validation-end-before-start = Datum konce musí být po datu začátku
+validation-invalid-date = Neplatné datum
+validation-date-outside-camp = Datum musí spadat do termínu tábora, { $start } až { $end }
+validation-meals-outside-camp = Na { $date } jsou naplánována jídla mimo nové termíny; nejprve je přesuňte nebo smažte
+validation-invalid-meal-type = Neplatný typ jídla
+validation-recipe-not-plannable = Recept neexistuje nebo je archivovaný
+validation-invalid-file = Neplatný soubor: { $error }
+validation-servings-positive = Základní počet porcí musí být větší než 0
+validation-quantity-positive = Všechna množství surovin musí být větší než 0
+validation-quantity-negative = Množství zásob nesmí být záporné
+validation-quantities-negative = Množství nesmí být záporná
+validation-multiplier-negative = Násobitele nesmí být záporné
+validation-headcount-negative = Počet lidí nesmí být záporný
+validation-headcount-empty = Zadejte, pro kolik lidí se vaří
+validation-margin-negative = Rezerva nesmí být záporná
+validation-price-negative = Cena nesmí být záporná
+validation-shelf-life-negative = Trvanlivost nesmí být záporná
+validation-storage-type-unknown = Neznámý typ skladování „{ $value }“
+validation-pack-size-positive = Velikost balení musí být větší než nula
+validation-lead-time-negative = Dodací lhůta nesmí být záporná
+validation-lead-time-number = Neplatné číslo pro dodací lhůtu
+validation-budget-negative = Denní rozpočet nesmí být záporný
+validation-alias-empty = Alias nesmí být prázdný
+validation-select-meal-slot = Vyberte alespoň jedno jídlo k doplnění
+validation-replace-with-itself = Jako náhradu vyberte jinou položku
+validation-replacement-archived = Náhrada je archivovaná, nejprve ji obnovte
+validation-merge-select-source = Vyberte alespoň jednu surovinu ke sloučení
+validation-merge-into-itself = Surovinu nelze sloučit samu se sebou
+validation-factor-positive = Převodní koeficienty musí být větší než 0
+validation-merge-no-conversion = Chybí převod z { $unit } suroviny „{ $source }“ na { $target_unit } suroviny „{ $target }“
+validation-default-category-missing = Výchozí kategorie „{ $name }“ neexistuje
+validation-resolutions-count = Očekáváno { $expected } přiřazení surovin, přijato { $got }
+validation-resolution-unknown-ingredient = Surovina vybraná pro „{ $line }“ neexistuje
+
+# Usage
+usage-still-used = Je ještě použito zde, proto nejde smazat:
+usage-replace-with = Všude nahradit za
+usage-choose-replacement = Vyberte náhradu…
+usage-replace-and-delete = Nahradit a smazat
+usage-archive-help = Nebo archivovat: zmizí ze seznamů, ale zůstane tam, kde je použito.
+usage-archive = Archivovat
+
+# Trash
+trash = Koš
+trash-help = Archivované položky se nezobrazují v seznamech ani v nabídkách. Po obnovení je lze znovu používat.
+trash-empty = Nic není archivováno
+trash-name = Název
+trash-kind = Typ
+trash-archived-on = Archivováno
+trash-camp = Tábor
+trash-category = Kategorie
+trash-ingredient = Surovina
+trash-recipe = Recept
+trash-restore = Obnovit
+trash-restored = Obnoveno
+error-load-trash = Nepodařilo se načíst koš: { $error }
+error-restore = Nepodařilo se obnovit: { $error }
+
+# Recipe editor
+new-recipe = Nový recept
+update-recipe = Uložit změny
+save-recipe = Uložit recept
+delete-recipe = Smazat recept
+recipe-in-use = Recept se používá
+recipe-created = Recept byl vytvořen!
+recipe-updated = Recept byl upraven!
+recipe-deleted = Recept byl smazán!
+recipe-replaced = Recept byl nahrazen a smazán
+recipe-archived = Recept byl archivován, lze ho obnovit z koše
+no-recipes = Zatím žádné recepty
+no-recipes-text = Začněte vytvořením prvního receptu
+import = Import
+tags = Štítky
+tags-placeholder = teplé, vegetariánské, rychlé...
+quantity-short = Mn.
+child-multiplier-short = Dítě×
+teen-multiplier-short = Teen.×
+adult-multiplier-short = Dosp.×
+search-ingredients = Hledat suroviny...
+servings-count = { $count ->
+    [one] { $count } porce
+    [few] { $count } porce
+   *[other] { $count } porcí
+}
+cost-per-serving = { $cost } / porce
+allergens = Alergeny
+download-jsonld = Stáhnout jako schema.org JSON-LD
+download-cooklang = Stáhnout jako Cooklang
+recipes-found = { $count ->
+    [one] Nalezen { $count } recept
+    [few] Nalezeny { $count } recepty
+   *[other] Nalezeno { $count } receptů
+}
+previous-page = Předchozí
+next-page = Další
+page-of = Strana { $page } z { $pages }
+error-load-ingredients = Nepodařilo se načíst suroviny: { $error }
+error-load-recipe = Nepodařilo se načíst recept: { $error }
+error-search-recipes = Nepodařilo se vyhledat recepty: { $error }
+error-create-recipe = Nepodařilo se vytvořit recept: { $error }
+error-update-recipe = Nepodařilo se upravit recept: { $error }
+error-load-usage = Nepodařilo se zjistit, kde se používá: { $error }
+error-delete = Nepodařilo se smazat: { $error }
+error-replace = Nepodařilo se nahradit: { $error }
+error-archive = Nepodařilo se archivovat: { $error }
+
+# Recipe search
+recipe-search-placeholder = Hledat recepty podle názvu, suroviny, štítku nebo postupu...
+recipe-search-filters = Filtry
+recipe-search-add-ingredient = Přidat surovinu...
+recipe-search-contains = Obsahuje
+recipe-search-without = Bez
+recipe-search-max-cost = Max. cena za porci
+recipe-search-free-of = Bez alergenu
+
+# Stock finder
+stock-leftovers-list = Seznam zbytků
+stock-shared-only = Jen společný sklad
+stock-camp = Zásoby tábora
+stock-min-coverage = Min. pokrytí %
+stock-find-recipes = Najít recepty
+stock-nothing-to-cook = Zatím není co vařit
+stock-nothing-to-cook-text = Snižte minimální pokrytí nebo doplňte zásoby
+stock-no-recipe-covered = Dostupné zásoby nepokrývají žádný recept
+stock-covered-lines = { $covered }/{ $total } surovin
+stock-missing = Chybí
+validation-select-ingredient = Vyberte surovinu
+validation-select-ingredient-unit = Vyberte surovinu a jednotku
+validation-invalid-quantity = Neplatné množství
+validation-invalid-headcount = Neplatný počet osob
+validation-leftovers-empty = Přidejte alespoň jednu zbylou surovinu
+error-find-recipes = Nepodařilo se najít recepty: { $error }
+
+# Suppliers
+add-supplier = Přidat dodavatele
+new-supplier = Nový dodavatel
+edit-supplier = Upravit dodavatele
+delete-supplier = Smazat dodavatele
+delete-supplier-confirm = Opravdu chcete smazat tohoto dodavatele?
+supplier-name = Název
+supplier-name-placeholder = např. Pekárna ve vsi
+supplier-contact = Kontakt
+supplier-contact-placeholder = Telefon nebo e-mail
+supplier-lead-time = Dodací lhůta (dny)
+supplier-delivery-days = Dny závozu (nic nevybráno = každý den)
+supplier-deliver-upfront = Dodat celou objednávku najednou (jinak vždy těsně před dnem, kdy je potřeba)
+supplier-categories = Dodávané kategorie
+supplier-add-ingredient = Přidat jednotlivou surovinu...
+supplier-ingredients = Jednotlivé suroviny (mají přednost před kategorií)
+supplier-every-day = každý den
+supplier-lead-time-days = { $count ->
+    [one] dodací lhůta { $count } den
+    [few] dodací lhůta { $count } dny
+   *[other] dodací lhůta { $count } dní
+}
+supplier-single-delivery = jedna dodávka
+supplier-ingredient-count = { $count ->
+    [one] + { $count } jednotlivá surovina
+    [few] + { $count } jednotlivé suroviny
+   *[other] + { $count } jednotlivých surovin
+}
+supplier-saved = Dodavatel byl uložen!
+supplier-deleted = Dodavatel byl smazán!
+no-suppliers = Zatím žádní dodavatelé
+no-suppliers-text = Přidejte řezníka, pekárnu a velkoobchod a rozdělte objednávky mezi ně
+error-load-suppliers = Nepodařilo se načíst dodavatele: { $error }
+error-load-categories = Nepodařilo se načíst kategorie: { $error }
+error-save-supplier = Nepodařilo se uložit dodavatele: { $error }
+error-delete-supplier = Nepodařilo se smazat dodavatele: { $error }
+
+# Data transfer
+data-transfer = Přenos dat
+export = Export
+transfer-export-help = Stáhne všechny kategorie, suroviny, recepty, tábory, jídelníčky, účast, zásoby a dodavatele.
+transfer-file = Soubor s exportem (JSON nebo YAML)
+transfer-mode = Režim
+transfer-mode-merge = Sloučit – ponechat stávající data, záznamy se stejným názvem použít znovu
+transfer-mode-replace = Nahradit – nejdřív smazat všechna stávající data plánování
+transfer-dry-run = Ověřit (zkušební běh)
+transfer-replace = Nahradit
+transfer-replace-title = Nahradit všechna data
+transfer-replace-confirm = Všechny stávající tábory, recepty, suroviny, kategorie a dodavatelé budou před importem smazáni i se zásobami a velikostmi balení. Nejdřív spusťte zkušební běh, který upozorní na data, která soubor neobnoví. Tuto akci nelze vrátit.
+transfer-dry-run-result = Výsledek zkušebního běhu
+transfer-import-result = Výsledek importu
+transfer-errors = Chyby
+transfer-warnings = Upozornění
+transfer-type = Typ
+transfer-created = Vytvořeno
+transfer-matched = Spárováno
+transfer-planned-meals = Naplánovaná jídla
+transfer-stock-items = Položky zásob
+transfer-pack-sizes = Velikosti balení
+transfer-choose-file = Nejdřív vyberte soubor s exportem
+transfer-file-invalid = Soubor není platný, nic nebylo importováno
+transfer-file-valid = Soubor je platný
+transfer-imported = Data byla importována!
+error-read-file = Nepodařilo se načíst { $name }
+error-import = Import se nezdařil: { $error }
+
+# Ingredient manager
+new-ingredient = Nová surovina
+delete-ingredient = Smazat surovinu
+ingredient-in-use = Surovina se používá
+ingredient-created = Surovina byla vytvořena!
+ingredient-updated = Surovina byla upravena!
+ingredient-deleted = Surovina byla smazána!
+ingredient-replaced = Surovina byla nahrazena a smazána
+ingredient-archived = Surovina byla archivována, lze ji obnovit z koše
+no-ingredients = Zatím žádné suroviny
+no-ingredients-text = Začněte vytvořením první suroviny
+select-category = Kategorie...
+safety-margin-percent = Rezerva %
+shelf-life-days = Vydrží (dny)
+price = Cena
+price-per-unit = Cena / jednotka
+days-short = { $count } d
+storage-fridge = Lednice
+storage-freezer = Mrazák
+storage-dry = Suché
+pack-sizes = Velikosti balení
+pack-sizes-none = Žádné – nákupní seznamy používají přesná množství
+pack-size = Velikost
+pack-label-placeholder = Popis, např. 1kg pytel
+aliases = Jiné názvy
+aliases-none = Žádné – surovinu lze najít jen podle názvu
+alias-placeholder = Jiný název, např. jak jí říká obchod
+validation-unit-required = Jednotka je povinná
+error-create-ingredient = Nepodařilo se vytvořit surovinu: { $error }
+error-update-ingredient = Nepodařilo se upravit surovinu: { $error }
+error-load-packs = Nepodařilo se načíst velikosti balení: { $error }
+error-add-pack = Nepodařilo se přidat velikost balení: { $error }
+error-delete-pack = Nepodařilo se smazat velikost balení: { $error }
+error-load-aliases = Nepodařilo se načíst jiné názvy: { $error }
+error-add-alias = Nepodařilo se přidat jiný název: { $error }
+error-delete-alias = Nepodařilo se smazat jiný název: { $error }
+
+# Category manager
+new-category = Nová kategorie
+delete-category = Smazat kategorii
+sort-order = Pořadí
+sort-order-value = Pořadí: { $order }
+category-created = Kategorie byla vytvořena!
+category-updated = Kategorie byla upravena!
+category-deleted = Kategorie byla smazána!
+category-archived = Kategorie byla archivována, obnovit ji lze z koše na stránce Správa
+no-categories = Zatím žádné kategorie
+no-categories-text = Začněte vytvořením první kategorie
+error-save-category = Nepodařilo se uložit kategorii: { $error }
+
+# Ingredient merge
+merge = Sloučit
+merge-title = Duplicitní suroviny
+merge-help = Suroviny, jejichž názvy se liší jen velikostí písmen, diakritikou nebo jedním či dvěma písmeny. Sloučení přesune všechny řádky receptů a zásoby k cílové surovině a ostatní názvy ponechá jako jiné názvy pro budoucí importy.
+merge-heading = Sloučit { $sources } do { $target }
+merge-units-convert = Všechny jednotky se převedou automaticky.
+merge-units-missing = Pro tyto jednotky není známý převod. Zadejte, kolik odpovídá jedné jednotce:
+merge-one-unit-of = 1 { $unit } suroviny { $ingredient } =
+merge-uses = { $count ->
+    [one] ({ $count } použití)
+   *[other] ({ $count } použití)
+}
+merge-enter-factor = Zadejte, kolik { $target_unit } odpovídá jedné { $unit } suroviny { $ingredient }
+merge-searching = Hledám duplicity...
+merge-none-found = Žádné pravděpodobné duplicity
+merge-target = cíl
+merge-usage = receptů: { $recipes }, položek zásob: { $stock_items }
+merge-selected = Sloučit vybrané
+merge-done = Sloučeno surovin: { $count } do { $target } (řádků receptů: { $recipe_lines }, položek zásob: { $stock_items })
+error-find-duplicates = Nepodařilo se najít duplicity: { $error }
+error-check-units = Nepodařilo se zkontrolovat jednotky: { $error }
+error-merge = Sloučení se nezdařilo: { $error }
+
+# Pantry
+add-stock = Přidat zásobu
+new-stock = Nová zásoba
+remove-stock = Odebrat zásobu
+remove-stock-confirm = Opravdu chcete odebrat tuto položku zásob?
+storeroom = Sklad
+storeroom-placeholder = např. sklep
+stock-all = Všechny zásoby (vyberte tábor pro filtrování)...
+stock-shared-storeroom = Společný sklad
+stock-consume-day = Spotřebovat naplánovaná jídla dne
+stock-deduct = Odečíst ze zásob
+stock-already-deducted = Již odečteno: { $dates }
+stock-added = Zásoba byla přidána!
+stock-updated = Zásoba byla upravena!
+stock-removed = Položka zásob byla odebrána!
+stock-nothing-consumed = Nic z toho, co bylo ten den naplánováno, nebylo skladem
+stock-consumed = { $count ->
+    [one] Ze zásob odečtena { $count } surovina
+    [few] Ze zásob odečteny { $count } suroviny
+   *[other] Ze zásob odečteno { $count } surovin
+}
+no-stock = Žádné zásoby
+no-stock-text = Přidejte zbylé základní suroviny, aby nákupní seznamy ukazovaly jen to, co je potřeba koupit
+validation-select-camp-date = Vyberte tábor a datum
+error-load-stock = Nepodařilo se načíst zásoby: { $error }
+error-load-consumed-days = Nepodařilo se načíst spotřebované dny: { $error }
+error-add-stock = Nepodařilo se přidat zásobu: { $error }
+error-update-stock = Nepodařilo se upravit zásobu: { $error }
+error-consume-stock = Nepodařilo se spotřebovat zásoby: { $error }
+
+# Recipe import
+recipe-import-title = Import receptu
+recipe-import-file = Soubor (.jsonld, .json nebo .cook)
+recipe-import-format = Formát
+recipe-import-paste = …nebo recept vložte
+recipe-import-read = Načíst recept
+recipe-import-match = Přiřadit surovinu...
+recipe-import-new-ingredient = Nová „{ $name }“
+recipe-import-skip = Vynechat
+recipe-import-help = Množství se berou jako množství pro uvedený počet porcí dospělých; nové suroviny se zařadí do kategorie „Other“.
+recipe-import-create = Vytvořit recept
+recipe-import-empty = Vyberte soubor nebo vložte recept
+recipe-imported = Recept „{ $name }“ byl importován!
+error-read-recipe = Nepodařilo se načíst recept: { $error }
+yaml-import-title = Import receptů z YAML
+yaml-import-help = Nahrajte soubory ve stejném formátu jako source_data/. Nic se nezapíše, dokud nepotvrdíte náhled.
+yaml-import-mapping = Mapování (volitelné)
+yaml-import-update-existing = Aktualizovat existující recepty a suroviny
+yaml-import-auto-create = Vytvořit neznámé suroviny
+yaml-import-preview-first = Nejdřív zobrazte náhled importu
+yaml-import-show-unchanged = Zobrazit nezměněné
+yaml-import-auto-created = Suroviny, které budou vytvořeny
+yaml-import-unit-mismatches = Nesouhlasící jednotky
+yaml-import-unit-mismatch = { $recipe }: { $ingredient } se používá v { $recipe_unit }, ale vede se v { $ingredient_unit }
+yaml-import-skipped-lines = Vynechané řádky
+yaml-import-unmapped-types = Typy surovin bez přiřazené kategorie: { $types }
+yaml-import-unknown-groups = Ignorované skupiny porcí: { $groups }
+yaml-import-left-as-is = (ponecháno beze změny)
+yaml-import-choose-file = Nejdřív vyberte soubor s recepty nebo surovinami
+yaml-import-done = Recepty byly importovány!
+import-status-new = Nové
+import-status-changed = Změněné
+import-status-conflict = Konflikt
+import-status-unchanged = Nezměněné
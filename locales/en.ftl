@@ -126,3 +126,579 @@ unit-cup = cup
 validation-required = This field is required
 validation-positive = Must be a positive number
 validation-date-range = End date must be after start date
+
+# Layout
+language = Language
+nav-planner = Planner
+nav-pantry = Pantry
+nav-admin = Admin
+page-not-found = Page not found
+page-not-found-text = The page you're looking for doesn't exist.
+go-home = Go Home
+signing-in = Signing in…
+login-subtitle = Sign in to continue
+
+# Home
+home-tagline = Organize your camp meals with ease and efficiency
+home-camps-text = Manage your scout camps and events with dates and attendance tracking
+home-recipes-text = Create and manage delicious recipes with portion calculations
+home-ingredients-text = Organize ingredients and categories for efficient planning
+home-planner-text = Plan daily meals for your camps with smart scheduling
+home-reports-text = Generate shopping lists and comprehensive meal reports
+home-tips = Quick Tips
+home-tips-text = Start with ingredients, build recipes, create camps, and plan meals!
+home-guide = Getting Started Guide
+home-step-1 = Create ingredient categories and add ingredients to your inventory
+home-step-2 = Build your recipe library with accurate portion sizes and instructions
+home-step-3 = Set up a camp with dates, location, and default attendance numbers
+home-step-4 = Plan meals for each day using your recipe library
+home-step-5 = Generate shopping lists and daily reports for your camp
+
+# Shared
+date = Date
+total = Total
+storage = Storage
+saving = Saving...
+generating = Generating...
+print = Print
+preview = Preview
+accept = Accept
+accept-all = Accept all
+reject = Reject
+notes-optional = Notes (optional)
+suppliers = Suppliers
+duplicates = Duplicates
+cook-from-stock = Cook from Stock
+search-camps = Search camps...
+search-recipes = Search recipes...
+children-count = { $count } children
+teens-count = { $count } teens
+adults-count = { $count } adults
+attendance-summary = Attendance: { $children } children, { $teens } teens, { $adults } adults
+days-count = { $count ->
+    [one] { $count } day
+   *[other] { $count } days
+}
+validation-required-fields = Please fill in all required fields
+validation-children-number = Invalid number for children
+validation-teens-number = Invalid number for teens
+validation-adults-number = Invalid number for adults
+validation-children-negative = Number of children cannot be negative
+validation-teens-negative = Number of teens cannot be negative
+validation-adults-negative = Number of adults cannot be negative
+error-load-camps = Failed to load camps: { $error }
+error-load-camp = Failed to load camp: { $error }
+error-load-recipes = Failed to load recipes: { $error }
+error-load-meals = Failed to load meals: { $error }
+
+# Camp list
+camp-created = Camp created successfully!
+camp-deleted = Camp deleted successfully!
+delete-camp = Delete Camp
+archive-camp = Archive camp
+camp-archived = Camp archived, it can be restored from the trash
+no-camps = No camps yet
+no-camps-text = Get started by creating your first camp
+camp-notes-placeholder = Any additional notes about this camp
+plan-meals = Plan Meals
+calendar-feed = Calendar feed
+calendar-feed-title = Subscribe to the menu in a calendar app
+calendar-subscribe-prompt = Subscribe to this URL in your calendar app:
+open-plan-check = Open the plan check
+plan-looks-fine = Plan looks fine
+plan-warnings = { $count ->
+    [one] { $count } warning
+   *[other] { $count } warnings
+}
+error-create-camp = Failed to create camp: { $error }
+error-delete-camp = Failed to delete camp: { $error }
+error-archive-camp = Failed to archive camp: { $error }
+error-calendar-link = Failed to get calendar link: { $error }
+error-check-plans = Failed to check the plan: { $error }
+
+# Planner
+meal-type = Meal Type
+add-meal = Add Meal
+edit-meal = Edit Meal
+update-meal = Update Meal
+delete-meal = Delete Meal
+delete-meal-confirm = Are you sure you want to delete this meal? This action cannot be undone.
+meal-added = Meal added successfully!
+meal-updated = Meal updated successfully!
+meal-deleted = Meal deleted successfully!
+no-camp-selected = No Camp Selected
+select-camp-to-plan = Please select a camp to start planning meals
+go-to-camps = Go to Camps
+view-label = View:
+view-single-day = Single Day
+view-all-days = All Days
+previous-day = Previous Day
+next-day = Next Day
+camp-day = Day { $day }
+camp-day-of = Day { $day } of { $days }
+attendance-optional = Attendance (optional)
+no-meals-for-date = No meals planned for this date. Click 'Add Meal' to plan one.
+no-meals-for-day = No meals planned for this day
+no-meals-planned-yet = No meals planned yet. Select 'Single Day' view to add meals.
+plan-check-button = Check
+generate-button = Generate
+error-save-meal = Failed to save meal: { $error }
+error-delete-meal = Failed to delete meal: { $error }
+
+# Plan check
+plan-check = Plan Check
+check-again = Check again
+no-problems = No problems found
+plan-warning-repeated-recipe = Repeated recipe
+plan-warning-no-produce = No vegetables or fruit
+plan-warning-missing-attendance = Missing attendance
+plan-warning-empty-slots = Empty slots
+plan-warning-empty-recipe = Recipe without ingredients
+plan-no-meals = No meals planned
+plan-nothing-planned = Nothing planned
+plan-repeated-recipe = { $recipe } is served twice in a row
+plan-empty-recipe = { $recipe } has no ingredients
+plan-missing-attendance = { $recipe } has no attendance; the camp defaults are used
+plan-no-produce = No meal of the day contains vegetables or fruit
+
+# Menu generator
+generate-menu = Generate Menu
+generate-menu-help = Fills empty slots with recipes carrying the slot's tags. Recipes tagged "hot" count as hot meals, recipes tagged "cook-intensive" as cook-intensive.
+any-recipe = Any recipe
+hot = Hot
+no-repeat-days = No repeat within (days)
+max-cost-per-day = Max. cost per person and day
+max-cook-intensive = Max. cook-intensive per day
+no-limit = No limit
+seed = Seed
+exclude-allergens = Exclude allergens
+next-seed = Try the next seed
+another-menu = Another menu
+cost-per-person = { $cost } per person
+menu-all-planned = Every meal slot of the camp is already planned
+menu-unfilled-no-recipes = There are no recipes yet
+menu-unfilled-no-tag = No recipe tagged { $tags }
+menu-unfilled-no-hot-tag = No hot recipe tagged { $tags }
+menu-unfilled-allergens = Every matching recipe contains an excluded allergen
+menu-unfilled-repeated = { $days ->
+    [one] Every matching recipe is served within { $days } day
+   *[other] Every matching recipe is served within { $days } days
+}
+menu-unfilled-cook-intensive = Every matching recipe is cook-intensive and the daily limit is reached
+menu-unfilled-budget = No matching recipe fits the daily budget
+calendar-menu = Menu
+meals-added = Added { $count } meal(s)
+meals-added-on = Added { $count } meal(s) on { $date }
+error-generate-menu = Failed to generate menu: { $error }
+error-save-meals = Failed to save meals: { $error }
+error-save-meals-on = Failed to save meals of { $date }: { $error }
+
+# Report generator
+report-type = Report Type
+report-shopping-list = Shopping List
+report-meal-schedule = Meal Schedule
+report-attendance-summary = Attendance Summary
+report-purchase-orders = Purchase Orders
+report-shopping-trips = Shopping Trips
+report-needed = Needed
+report-margin = Margin
+report-in-stock = In Stock
+report-to-buy = To Buy
+report-packs = Packs
+report-leftover = Leftover
+report-needed-on = Needed On
+report-used = Used
+report-keeps = Keeps
+report-generated = { $report } generated successfully!
+error-generate-report = Failed to generate { $report }: { $error }
+select-camp-first = Please select a camp
+select-dates-first = Please select start and end dates
+add-trip = Add Trip
+add-trip-first = Please add at least one shopping trip
+no-supplier = No supplier assigned
+delivery-order-by = Delivery { $delivery } • order by { $order_by }
+needed-before-first-trip = Some ingredients are needed before the first trip:
+trip-on = Trip { $date }
+nothing-to-buy = Nothing to buy
+pdf-camp = Camp: { $name }
+pdf-date-range = From { $start } to { $end }
+
+# API tokens
+api-tokens = API Tokens
+api-tokens-help = Tokens let scripts and bots use the REST API at /api/v1 without logging in. Send them as the header "Authorization: Bearer <token>".
+api-token-name = Name
+api-token-name-placeholder = e.g. Treasurer's spreadsheet
+api-token-scope = Access
+api-token-scope-read = Read only
+api-token-scope-read-write = Read and write
+api-token-create = Create Token
+api-token-created = Token created
+api-token-copy-now = Copy the token now, it will not be shown again:
+api-token-prefix = Token
+api-token-last-used = Last Used
+api-token-never-used = Never
+api-token-revoke = Revoke
+api-token-revoke-confirm = Scripts using this token will stop working. Revoke it?
+api-token-revoked = Token revoked
+no-api-tokens = No API tokens yet
+error-load-api-tokens = Failed to load API tokens: { $error }
+error-create-api-token = Failed to create the token: { $error }
+error-revoke-api-token = Failed to revoke the token: { $error }
+
+# Errors
+error-not-found = The record was not found
+error-unauthorized = Please log in first
+error-internal = Something went wrong: { $error }
+error-duplicate = A record with the same name already exists
+error-reference = The record is referenced by other data
+error-category-in-use = { $count ->
+    [one] Cannot delete category: { $count } ingredient is still using it. Please reassign or delete it first.
+   *[other] Cannot delete category: { $count } ingredients are still using it. Please reassign or delete them first.
+}
+error-ingredient-in-use = { $count ->
+    [one] The ingredient is used by { $count } recipe
+   *[other] The ingredient is used by { $count } recipes
+}
+error-recipe-in-use = { $count ->
+    [one] The recipe is planned for { $count } meal
+   *[other] The recipe is planned for { $count } meals
+}
+error-stock-already-consumed = Stock for { $date } has already been consumed
+error-alias-is-own-name = “{ $alias }” is already the ingredient's name
+error-alias-is-ingredient-name = “{ $alias }” is already the name of ingredient “{ $name }”
+error-alias-taken = “{ $alias }” is already an alias of ingredient “{ $name }”
+error-supplier-category-taken = Category “{ $category }” is already delivered by “{ $supplier }”
+error-supplier-ingredient-taken = Ingredient “{ $ingredient }” is already delivered by “{ $supplier }”
+validation-name-required = Name cannot be empty
+validation-token-name-required = Token name is required
+validation-end-before-start = End date must be after start date
+validation-invalid-date = Invalid date
+validation-date-outside-camp = The date must be within the camp, { $start } to { $end }
+validation-meals-outside-camp = Meals are planned on { $date }, outside the new dates; move or delete them first
+validation-invalid-meal-type = Invalid meal type
+validation-recipe-not-plannable = The recipe does not exist or is archived
+validation-invalid-file = Invalid file: { $error }
+validation-servings-positive = Base servings must be greater than 0
+validation-quantity-positive = All ingredient quantities must be greater than 0
+validation-quantity-negative = Stock quantity cannot be negative
+validation-quantities-negative = Quantities cannot be negative
+validation-multiplier-negative = Multipliers cannot be negative
+validation-headcount-negative = Headcount cannot be negative
+validation-headcount-empty = Enter how many people to cook for
+validation-margin-negative = Safety margin cannot be negative
+validation-price-negative = Price cannot be negative
+validation-shelf-life-negative = Shelf life cannot be negative
+validation-storage-type-unknown = Unknown storage type “{ $value }”
+validation-pack-size-positive = Pack size must be greater than zero
+validation-lead-time-negative = Lead time cannot be negative
+validation-lead-time-number = Invalid number for lead time
+validation-budget-negative = Daily budget cannot be negative
+validation-alias-empty = Alias cannot be empty
+validation-select-meal-slot = Select at least one meal slot to fill
+validation-replace-with-itself = Choose a different item as the replacement
+validation-replacement-archived = The replacement is archived, restore it first
+validation-merge-select-source = Select at least one ingredient to merge
+validation-merge-into-itself = An ingredient cannot be merged into itself
+validation-factor-positive = Conversion factors must be greater than 0
+validation-merge-no-conversion = No conversion from { $unit } of “{ $source }” to { $target_unit } of “{ $target }”
+validation-default-category-missing = Default category “{ $name }” does not exist
+validation-resolutions-count = Expected { $expected } ingredient resolutions, got { $got }
+validation-resolution-unknown-ingredient = Ingredient chosen for “{ $line }” does not exist
+
+# Usage
+usage-still-used = It is still used here, so it can't be deleted:
+usage-replace-with = Replace everywhere with
+usage-choose-replacement = Choose a replacement…
+usage-replace-and-delete = Replace and delete
+usage-archive-help = Or archive it: it disappears from the lists but stays where it is used.
+usage-archive = Archive
+
+# Trash
+trash = Trash
+trash-help = Archived items are hidden from the lists and dropdowns. Restore them to use them again.
+trash-empty = Nothing is archived
+trash-name = Name
+trash-kind = Type
+trash-archived-on = Archived
+trash-camp = Camp
+trash-category = Category
+trash-ingredient = Ingredient
+trash-recipe = Recipe
+trash-restore = Restore
+trash-restored = Restored
+error-load-trash = Failed to load the trash: { $error }
+error-restore = Failed to restore: { $error }
+
+# Recipe editor
+new-recipe = New Recipe
+update-recipe = Update Recipe
+save-recipe = Save Recipe
+delete-recipe = Delete Recipe
+recipe-in-use = Recipe in use
+recipe-created = Recipe created successfully!
+recipe-updated = Recipe updated successfully!
+recipe-deleted = Recipe deleted successfully!
+recipe-replaced = Recipe replaced and deleted
+recipe-archived = Recipe archived, it can be restored from the trash
+no-recipes = No recipes yet
+no-recipes-text = Get started by creating your first recipe
+import = Import
+tags = Tags
+tags-placeholder = hot, vegetarian, quick...
+quantity-short = Qty
+child-multiplier-short = Child×
+teen-multiplier-short = Teen×
+adult-multiplier-short = Adult×
+search-ingredients = Search ingredients...
+servings-count = { $count ->
+    [one] { $count } serving
+   *[other] { $count } servings
+}
+cost-per-serving = { $cost } / serving
+allergens = Allergens
+download-jsonld = Download as schema.org JSON-LD
+download-cooklang = Download as Cooklang
+recipes-found = { $count ->
+    [one] { $count } recipe found
+   *[other] { $count } recipes found
+}
+previous-page = Previous
+next-page = Next
+page-of = Page { $page } of { $pages }
+error-load-ingredients = Failed to load ingredients: { $error }
+error-load-recipe = Failed to load recipe: { $error }
+error-search-recipes = Failed to search recipes: { $error }
+error-create-recipe = Failed to create recipe: { $error }
+error-update-recipe = Failed to update recipe: { $error }
+error-load-usage = Failed to check where it is used: { $error }
+error-delete = Failed to delete: { $error }
+error-replace = Failed to replace: { $error }
+error-archive = Failed to archive: { $error }
+
+# Recipe search
+recipe-search-placeholder = Search recipes by name, ingredient, tag or instructions...
+recipe-search-filters = Filters
+recipe-search-add-ingredient = Add ingredient...
+recipe-search-contains = Contains
+recipe-search-without = Without
+recipe-search-max-cost = Max. cost per serving
+recipe-search-free-of = Free of
+
+# Stock finder
+stock-leftovers-list = Leftovers list
+stock-shared-only = Shared storeroom only
+stock-camp = Camp stock
+stock-min-coverage = Min. coverage %
+stock-find-recipes = Find recipes
+stock-nothing-to-cook = Nothing to cook yet
+stock-nothing-to-cook-text = Lower the minimum coverage or add more stock
+stock-no-recipe-covered = No recipe is covered by the available stock
+stock-covered-lines = { $covered }/{ $total } ingredients
+stock-missing = Missing
+validation-select-ingredient = Select an ingredient
+validation-select-ingredient-unit = Please select an ingredient and a unit
+validation-invalid-quantity = Invalid quantity
+validation-invalid-headcount = Invalid headcount
+validation-leftovers-empty = Add at least one leftover ingredient
+error-find-recipes = Failed to find recipes: { $error }
+
+# Suppliers
+add-supplier = Add Supplier
+new-supplier = New Supplier
+edit-supplier = Edit Supplier
+delete-supplier = Delete Supplier
+delete-supplier-confirm = Are you sure you want to delete this supplier?
+supplier-name = Name
+supplier-name-placeholder = e.g. Village bakery
+supplier-contact = Contact
+supplier-contact-placeholder = Phone or e-mail
+supplier-lead-time = Lead time (days)
+supplier-delivery-days = Delivery days (none selected = every day)
+supplier-deliver-upfront = Deliver the whole order at once (otherwise just before each day it is needed)
+supplier-categories = Categories delivered
+supplier-add-ingredient = Add individual ingredient...
+supplier-ingredients = Individual ingredients (override category)
+supplier-every-day = every day
+supplier-lead-time-days = { $count ->
+    [one] { $count } day lead time
+   *[other] { $count } days lead time
+}
+supplier-single-delivery = single delivery
+supplier-ingredient-count = { $count ->
+    [one] + { $count } individual ingredient
+   *[other] + { $count } individual ingredients
+}
+supplier-saved = Supplier saved successfully!
+supplier-deleted = Supplier deleted successfully!
+no-suppliers = No suppliers yet
+no-suppliers-text = Add your butcher, bakery and wholesaler to split purchase orders
+error-load-suppliers = Failed to load suppliers: { $error }
+error-load-categories = Failed to load categories: { $error }
+error-save-supplier = Failed to save supplier: { $error }
+error-delete-supplier = Failed to delete supplier: { $error }
+
+# Data transfer
+data-transfer = Data Transfer
+export = Export
+transfer-export-help = Download all categories, ingredients, recipes, camps, meal plans, attendance, stock and suppliers.
+transfer-file = Export file (JSON or YAML)
+transfer-mode = Mode
+transfer-mode-merge = Merge – keep existing data, reuse records with the same name
+transfer-mode-replace = Replace – delete all existing planning data first
+transfer-dry-run = Validate (dry run)
+transfer-replace = Replace
+transfer-replace-title = Replace All Data
+transfer-replace-confirm = All existing camps, recipes, ingredients, categories and suppliers will be deleted before the import, together with their stock and pack sizes. Run a dry run first to see warnings about data the file can't restore. This action cannot be undone.
+transfer-dry-run-result = Dry Run Result
+transfer-import-result = Import Result
+transfer-errors = Errors
+transfer-warnings = Warnings
+transfer-type = Type
+transfer-created = Created
+transfer-matched = Matched
+transfer-planned-meals = Planned meals
+transfer-stock-items = Stock items
+transfer-pack-sizes = Pack sizes
+transfer-choose-file = Please choose an export file first
+transfer-file-invalid = The file is not valid, nothing was imported
+transfer-file-valid = The file is valid
+transfer-imported = Data imported!
+error-read-file = Failed to read { $name }
+error-import = Import failed: { $error }
+
+# Ingredient manager
+new-ingredient = New Ingredient
+delete-ingredient = Delete Ingredient
+ingredient-in-use = Ingredient in use
+ingredient-created = Ingredient created successfully!
+ingredient-updated = Ingredient updated!
+ingredient-deleted = Ingredient deleted successfully!
+ingredient-replaced = Ingredient replaced and deleted
+ingredient-archived = Ingredient archived, it can be restored from the trash
+no-ingredients = No ingredients yet
+no-ingredients-text = Get started by creating your first ingredient
+select-category = Category...
+safety-margin-percent = Margin %
+shelf-life-days = Keeps (days)
+price = Price
+price-per-unit = Price / unit
+days-short = { $count } d
+storage-fridge = Fridge
+storage-freezer = Freezer
+storage-dry = Dry
+pack-sizes = Pack sizes
+pack-sizes-none = None – shopping lists use exact quantities
+pack-size = Size
+pack-label-placeholder = Label, e.g. 1 kg bag
+aliases = Aliases
+aliases-none = None – the ingredient is only found by its name
+alias-placeholder = Another name, e.g. what the shop calls it
+validation-unit-required = Unit is required
+error-create-ingredient = Failed to create ingredient: { $error }
+error-update-ingredient = Failed to update ingredient: { $error }
+error-load-packs = Failed to load pack sizes: { $error }
+error-add-pack = Failed to add pack size: { $error }
+error-delete-pack = Failed to delete pack size: { $error }
+error-load-aliases = Failed to load aliases: { $error }
+error-add-alias = Failed to add alias: { $error }
+error-delete-alias = Failed to delete alias: { $error }
+
+# Category manager
+new-category = New Category
+delete-category = Delete Category
+sort-order = Sort Order
+sort-order-value = Sort: { $order }
+category-created = Category created successfully!
+category-updated = Category updated successfully!
+category-deleted = Category deleted successfully!
+category-archived = Category archived, restore it from the trash on the Admin page
+no-categories = No categories yet
+no-categories-text = Get started by creating your first category
+error-save-category = Failed to save category: { $error }
+
+# Ingredient merge
+merge = Merge
+merge-title = Duplicate Ingredients
+merge-help = Ingredients with names that differ only in case, diacritics or a letter or two. Merging moves all recipe lines and stock to the target and keeps the other names as aliases for future imports.
+merge-heading = Merge { $sources } into { $target }
+merge-units-convert = All units convert automatically.
+merge-units-missing = These units have no known conversion. Enter what one unit amounts to:
+merge-one-unit-of = 1 { $unit } of { $ingredient } =
+merge-uses = { $count ->
+    [one] ({ $count } use)
+   *[other] ({ $count } uses)
+}
+merge-enter-factor = Enter how many { $target_unit } one { $unit } of { $ingredient } is
+merge-searching = Looking for duplicates...
+merge-none-found = No likely duplicates found
+merge-target = target
+merge-usage = { $recipes } recipes, { $stock_items } stock items
+merge-selected = Merge selected
+merge-done = Merged { $count } ingredients into { $target } ({ $recipe_lines } recipe lines, { $stock_items } stock items)
+error-find-duplicates = Failed to find duplicates: { $error }
+error-check-units = Failed to check units: { $error }
+error-merge = Merge failed: { $error }
+
+# Pantry
+add-stock = Add Stock
+new-stock = New Stock
+remove-stock = Remove Stock
+remove-stock-confirm = Are you sure you want to remove this stock item?
+storeroom = Storeroom
+storeroom-placeholder = e.g. cellar
+stock-all = All stock (select a camp to filter)...
+stock-shared-storeroom = Shared storeroom
+stock-consume-day = Consume planned meals of day
+stock-deduct = Deduct from stock
+stock-already-deducted = Already deducted: { $dates }
+stock-added = Stock added!
+stock-updated = Stock updated!
+stock-removed = Stock item removed!
+stock-nothing-consumed = Nothing planned that day was in stock
+stock-consumed = { $count ->
+    [one] Deducted { $count } ingredient from stock
+   *[other] Deducted { $count } ingredients from stock
+}
+no-stock = No stock recorded
+no-stock-text = Add leftover staples so shopping lists only show what must be bought
+validation-select-camp-date = Please select a camp and a date
+error-load-stock = Failed to load stock: { $error }
+error-load-consumed-days = Failed to load consumed days: { $error }
+error-add-stock = Failed to add stock: { $error }
+error-update-stock = Failed to update stock: { $error }
+error-consume-stock = Failed to consume stock: { $error }
+
+# Recipe import
+recipe-import-title = Import Recipe
+recipe-import-file = File (.jsonld, .json or .cook)
+recipe-import-format = Format
+recipe-import-paste = …or paste the recipe
+recipe-import-read = Read Recipe
+recipe-import-match = Match to ingredient...
+recipe-import-new-ingredient = New “{ $name }”
+recipe-import-skip = Skip
+recipe-import-help = Quantities are taken as the amount for the given servings of adults; new ingredients go to the “Other” category.
+recipe-import-create = Create Recipe
+recipe-import-empty = Please choose a file or paste a recipe
+recipe-imported = Recipe “{ $name }” imported!
+error-read-recipe = Failed to read recipe: { $error }
+yaml-import-title = Import Recipes from YAML
+yaml-import-help = Upload files in the same format as source_data/. Nothing is written until you confirm the preview.
+yaml-import-mapping = Mapping (optional)
+yaml-import-update-existing = Update existing recipes and ingredients
+yaml-import-auto-create = Create unknown ingredients
+yaml-import-preview-first = Preview the import first
+yaml-import-show-unchanged = Show unchanged
+yaml-import-auto-created = Ingredients that will be created
+yaml-import-unit-mismatches = Unit mismatches
+yaml-import-unit-mismatch = { $recipe }: { $ingredient } is used in { $recipe_unit } but kept in { $ingredient_unit }
+yaml-import-skipped-lines = Skipped lines
+yaml-import-unmapped-types = Ingredient types without a category mapping: { $types }
+yaml-import-unknown-groups = Ignored portion groups: { $groups }
+yaml-import-left-as-is = (left as is)
+yaml-import-choose-file = Please choose a recipes or ingredients file first
+yaml-import-done = Recipes imported!
+import-status-new = New
+import-status-changed = Changed
+import-status-conflict = Conflict
+import-status-unchanged = Unchanged
@@ -0,0 +1,28 @@
+-- Create inventory_items table (stock on hand, per camp or shared storeroom)
+CREATE TABLE IF NOT EXISTS inventory_items (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    ingredient_id INTEGER NOT NULL,
+    camp_id INTEGER,
+    storeroom TEXT NOT NULL DEFAULT '',
+    quantity REAL NOT NULL DEFAULT 0,
+    unit TEXT NOT NULL,
+    notes TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(id) ON DELETE CASCADE,
+    FOREIGN KEY (camp_id) REFERENCES camps(id) ON DELETE CASCADE
+);
+
+-- Track which camp days have already been deducted from stock
+CREATE TABLE IF NOT EXISTS inventory_consumptions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    camp_id INTEGER NOT NULL,
+    date DATE NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (camp_id) REFERENCES camps(id) ON DELETE CASCADE,
+    UNIQUE(camp_id, date)
+);
+
+-- Create indexes
+CREATE INDEX IF NOT EXISTS idx_inventory_items_ingredient ON inventory_items(ingredient_id);
+CREATE INDEX IF NOT EXISTS idx_inventory_items_camp ON inventory_items(camp_id);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS supplier_ingredients;
+DROP TABLE IF EXISTS supplier_categories;
+DROP TABLE IF EXISTS suppliers;
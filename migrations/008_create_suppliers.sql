@@ -0,0 +1,36 @@
+-- Create suppliers table
+CREATE TABLE IF NOT EXISTS suppliers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    contact TEXT,
+    lead_time_days INTEGER NOT NULL DEFAULT 0,
+    -- Comma-separated weekdays the supplier delivers on (e.g. 'mon,wed,fri'); empty means every day
+    delivery_days TEXT NOT NULL DEFAULT '',
+    -- Deliver the whole camp order at once instead of just before each day it is needed
+    deliver_upfront BOOLEAN NOT NULL DEFAULT 0,
+    notes TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Categories a supplier delivers
+CREATE TABLE IF NOT EXISTS supplier_categories (
+    supplier_id INTEGER NOT NULL,
+    category_id INTEGER NOT NULL,
+    PRIMARY KEY (supplier_id, category_id),
+    FOREIGN KEY (supplier_id) REFERENCES suppliers(id) ON DELETE CASCADE,
+    FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE CASCADE
+);
+
+-- Individual ingredients a supplier delivers (takes precedence over categories)
+CREATE TABLE IF NOT EXISTS supplier_ingredients (
+    supplier_id INTEGER NOT NULL,
+    ingredient_id INTEGER NOT NULL,
+    PRIMARY KEY (supplier_id, ingredient_id),
+    FOREIGN KEY (supplier_id) REFERENCES suppliers(id) ON DELETE CASCADE,
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(id) ON DELETE CASCADE
+);
+
+-- Create indexes
+CREATE INDEX IF NOT EXISTS idx_supplier_categories_category ON supplier_categories(category_id);
+CREATE INDEX IF NOT EXISTS idx_supplier_ingredients_ingredient ON supplier_ingredients(ingredient_id);
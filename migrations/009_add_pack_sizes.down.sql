@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS ingredient_packs;
+ALTER TABLE ingredients DROP COLUMN safety_margin_percent;
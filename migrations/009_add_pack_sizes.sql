@@ -0,0 +1,15 @@
+-- Extra percentage added on top of the calculated quantity when shopping
+ALTER TABLE ingredients ADD COLUMN safety_margin_percent REAL NOT NULL DEFAULT 0;
+
+-- Purchasable pack sizes per ingredient (e.g. 1 kg bag, 10 pcs egg pack)
+CREATE TABLE IF NOT EXISTS ingredient_packs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    ingredient_id INTEGER NOT NULL,
+    unit TEXT NOT NULL,
+    size REAL NOT NULL CHECK(size > 0),
+    label TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_ingredient_packs_ingredient ON ingredient_packs(ingredient_id);
@@ -0,0 +1,2 @@
+ALTER TABLE ingredients DROP COLUMN storage_type;
+ALTER TABLE ingredients DROP COLUMN shelf_life_days;
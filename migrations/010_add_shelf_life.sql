@@ -0,0 +1,5 @@
+-- How long an ingredient keeps after buying (NULL = keeps for the whole camp)
+ALTER TABLE ingredients ADD COLUMN shelf_life_days INTEGER;
+
+-- Where the ingredient is stored
+ALTER TABLE ingredients ADD COLUMN storage_type TEXT NOT NULL DEFAULT 'dry' CHECK(storage_type IN ('fridge', 'freezer', 'dry'));
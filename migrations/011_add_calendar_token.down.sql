@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_camps_calendar_token;
+ALTER TABLE camps DROP COLUMN calendar_token;
@@ -0,0 +1,4 @@
+-- Secret token that lets calendar apps subscribe to a camp's menu without logging in
+ALTER TABLE camps ADD COLUMN calendar_token TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_camps_calendar_token ON camps(calendar_token);
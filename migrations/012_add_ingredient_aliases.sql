@@ -0,0 +1,13 @@
+-- Other names of an ingredient (merged duplicates, shop names), used when matching imports
+CREATE TABLE IF NOT EXISTS ingredient_aliases (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    ingredient_id INTEGER NOT NULL,
+    alias TEXT NOT NULL,
+    -- Lowercase alias without diacritics, see models::normalize_name
+    normalized TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (ingredient_id) REFERENCES ingredients(id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_ingredient_aliases_normalized ON ingredient_aliases(normalized);
+CREATE INDEX IF NOT EXISTS idx_ingredient_aliases_ingredient ON ingredient_aliases(ingredient_id);
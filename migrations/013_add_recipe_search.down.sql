@@ -0,0 +1,16 @@
+-- The triggers and the view use the new columns, so they go first
+DROP TRIGGER IF EXISTS recipe_search_recipe_insert;
+DROP TRIGGER IF EXISTS recipe_search_recipe_update;
+DROP TRIGGER IF EXISTS recipe_search_recipe_delete;
+DROP TRIGGER IF EXISTS recipe_search_line_insert;
+DROP TRIGGER IF EXISTS recipe_search_line_update;
+DROP TRIGGER IF EXISTS recipe_search_line_delete;
+DROP TRIGGER IF EXISTS recipe_search_ingredient_rename;
+DROP TRIGGER IF EXISTS recipe_search_alias_insert;
+DROP TRIGGER IF EXISTS recipe_search_alias_delete;
+DROP TABLE IF EXISTS recipe_search;
+DROP VIEW IF EXISTS recipe_search_source;
+
+ALTER TABLE ingredients DROP COLUMN allergens;
+ALTER TABLE ingredients DROP COLUMN price_per_unit;
+ALTER TABLE recipes DROP COLUMN tags;
@@ -0,0 +1,87 @@
+-- Comma-separated recipe tags (e.g. "hot, vegetarian")
+ALTER TABLE recipes ADD COLUMN tags TEXT NOT NULL DEFAULT '';
+
+-- Price per primary unit (NULL = unknown) and comma-separated allergens
+ALTER TABLE ingredients ADD COLUMN price_per_unit REAL CHECK(price_per_unit IS NULL OR price_per_unit >= 0);
+ALTER TABLE ingredients ADD COLUMN allergens TEXT NOT NULL DEFAULT '';
+
+-- Text indexed per recipe: ingredient names together with their aliases
+CREATE VIEW IF NOT EXISTS recipe_search_source AS
+SELECT
+    r.id,
+    r.name,
+    COALESCE(r.instructions, '') AS instructions,
+    r.tags,
+    COALESCE((
+        SELECT group_concat(i.name || COALESCE(' ' || (
+            SELECT group_concat(a.alias, ' ') FROM ingredient_aliases a WHERE a.ingredient_id = i.id
+        ), ''), ' ')
+        FROM recipe_ingredients ri
+        JOIN ingredients i ON ri.ingredient_id = i.id
+        WHERE ri.recipe_id = r.id
+    ), '') AS ingredients
+FROM recipes r;
+
+-- Full-text index; remove_diacritics folds "zelí" to "zeli"
+CREATE VIRTUAL TABLE IF NOT EXISTS recipe_search USING fts5(
+    name, instructions, tags, ingredients,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO recipe_search (rowid, name, instructions, tags, ingredients)
+SELECT id, name, instructions, tags, ingredients FROM recipe_search_source;
+
+-- Keep the index in step with recipes, their lines, ingredient names and aliases
+CREATE TRIGGER IF NOT EXISTS recipe_search_recipe_insert AFTER INSERT ON recipes BEGIN
+    INSERT INTO recipe_search (rowid, name, instructions, tags, ingredients)
+    SELECT id, name, instructions, tags, ingredients FROM recipe_search_source WHERE id = NEW.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_recipe_update AFTER UPDATE ON recipes BEGIN
+    DELETE FROM recipe_search WHERE rowid = OLD.id;
+    INSERT INTO recipe_search (rowid, name, instructions, tags, ingredients)
+    SELECT id, name, instructions, tags, ingredients FROM recipe_search_source WHERE id = NEW.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_recipe_delete AFTER DELETE ON recipes BEGIN
+    DELETE FROM recipe_search WHERE rowid = OLD.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_line_insert AFTER INSERT ON recipe_ingredients BEGIN
+    DELETE FROM recipe_search WHERE rowid = NEW.recipe_id;
+    INSERT INTO recipe_search (rowid, name, instructions, tags, ingredients)
+    SELECT id, name, instructions, tags, ingredients FROM recipe_search_source WHERE id = NEW.recipe_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_line_update AFTER UPDATE OF ingredient_id, recipe_id ON recipe_ingredients BEGIN
+    DELETE FROM recipe_search WHERE rowid IN (OLD.recipe_id, NEW.recipe_id);
+    INSERT INTO recipe_search (rowid, name, instructions, tags, ingredients)
+    SELECT id, name, instructions, tags, ingredients FROM recipe_search_source WHERE id IN (OLD.recipe_id, NEW.recipe_id);
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_line_delete AFTER DELETE ON recipe_ingredients BEGIN
+    DELETE FROM recipe_search WHERE rowid = OLD.recipe_id;
+    INSERT INTO recipe_search (rowid, name, instructions, tags, ingredients)
+    SELECT id, name, instructions, tags, ingredients FROM recipe_search_source WHERE id = OLD.recipe_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_ingredient_rename AFTER UPDATE OF name ON ingredients BEGIN
+    DELETE FROM recipe_search WHERE rowid IN (SELECT recipe_id FROM recipe_ingredients WHERE ingredient_id = NEW.id);
+    INSERT INTO recipe_search (rowid, name, instructions, tags, ingredients)
+    SELECT id, name, instructions, tags, ingredients FROM recipe_search_source
+    WHERE id IN (SELECT recipe_id FROM recipe_ingredients WHERE ingredient_id = NEW.id);
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_alias_insert AFTER INSERT ON ingredient_aliases BEGIN
+    DELETE FROM recipe_search WHERE rowid IN (SELECT recipe_id FROM recipe_ingredients WHERE ingredient_id = NEW.ingredient_id);
+    INSERT INTO recipe_search (rowid, name, instructions, tags, ingredients)
+    SELECT id, name, instructions, tags, ingredients FROM recipe_search_source
+    WHERE id IN (SELECT recipe_id FROM recipe_ingredients WHERE ingredient_id = NEW.ingredient_id);
+END;
+
+CREATE TRIGGER IF NOT EXISTS recipe_search_alias_delete AFTER DELETE ON ingredient_aliases BEGIN
+    DELETE FROM recipe_search WHERE rowid IN (SELECT recipe_id FROM recipe_ingredients WHERE ingredient_id = OLD.ingredient_id);
+    INSERT INTO recipe_search (rowid, name, instructions, tags, ingredients)
+    SELECT id, name, instructions, tags, ingredients FROM recipe_search_source
+    WHERE id IN (SELECT recipe_id FROM recipe_ingredients WHERE ingredient_id = OLD.ingredient_id);
+END;
@@ -0,0 +1,14 @@
+-- Personal tokens for scripts using the REST API; only a SHA-256 hash of the secret is kept
+CREATE TABLE IF NOT EXISTS api_tokens (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    -- 'read' or 'read_write'
+    scope TEXT NOT NULL CHECK(scope IN ('read', 'read_write')),
+    token_hash TEXT NOT NULL,
+    -- First characters of the secret, shown so the owner can tell tokens apart
+    prefix TEXT NOT NULL,
+    last_used_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_api_tokens_hash ON api_tokens(token_hash);
@@ -0,0 +1,2 @@
+ALTER TABLE recipes DROP COLUMN archived_at;
+ALTER TABLE ingredients DROP COLUMN archived_at;
@@ -0,0 +1,4 @@
+-- Ingredients and recipes that are still used can be archived instead of deleted;
+-- archived ones are left out of the lists
+ALTER TABLE ingredients ADD COLUMN archived_at TIMESTAMP;
+ALTER TABLE recipes ADD COLUMN archived_at TIMESTAMP;
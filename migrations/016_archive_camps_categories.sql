@@ -0,0 +1,4 @@
+-- Past camps and unused categories can be archived too; archived rows are left
+-- out of the lists and can be restored from the trash
+ALTER TABLE camps ADD COLUMN archived_at TIMESTAMP;
+ALTER TABLE categories ADD COLUMN archived_at TIMESTAMP;
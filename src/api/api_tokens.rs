@@ -0,0 +1,82 @@
+use crate::error::AppError;
+use crate::models::{ApiToken, ApiTokenScope, CreateApiToken, NewApiToken};
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+
+/// Prefix of every secret, so a leaked token is easy to recognise
+const SECRET_PREFIX: &str = "mp_";
+
+/// Characters of the secret kept in `api_tokens.prefix`
+const SHOWN_PREFIX_LEN: usize = 10;
+
+pub async fn get_api_tokens(pool: &SqlitePool) -> Result<Vec<ApiToken>, AppError> {
+    sqlx::query_as::<_, ApiToken>(
+        "SELECT id, name, scope, prefix, last_used_at, created_at
+         FROM api_tokens
+         ORDER BY created_at DESC, id DESC"
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::from)
+}
+
+/// Create a token and return its secret, which is only stored hashed
+pub async fn create_api_token(pool: &SqlitePool, token: CreateApiToken) -> Result<NewApiToken, AppError> {
+    let name = token.name.trim();
+    if name.is_empty() {
+        return Err(AppError::validation("name", "validation-token-name-required"));
+    }
+
+    let random: String = sqlx::query_scalar("SELECT lower(hex(randomblob(32)))")
+        .fetch_one(pool)
+        .await?;
+    let secret = format!("{}{}", SECRET_PREFIX, random);
+
+    let result = sqlx::query(
+        "INSERT INTO api_tokens (name, scope, token_hash, prefix) VALUES (?, ?, ?, ?)"
+    )
+    .bind(name)
+    .bind(token.scope.as_str())
+    .bind(hash_secret(&secret))
+    .bind(&secret[..SHOWN_PREFIX_LEN])
+    .execute(pool)
+    .await?;
+
+    let token = sqlx::query_as::<_, ApiToken>(
+        "SELECT id, name, scope, prefix, last_used_at, created_at FROM api_tokens WHERE id = ?"
+    )
+    .bind(result.last_insert_rowid())
+    .fetch_one(pool)
+    .await?;
+
+    Ok(NewApiToken { token, secret })
+}
+
+pub async fn revoke_api_token(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM api_tokens WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Scope of the token with this secret, or `None` when it is unknown or revoked.
+/// Records the time of use.
+pub async fn authenticate_api_token(pool: &SqlitePool, secret: &str) -> Result<Option<ApiTokenScope>, AppError> {
+    let scope: Option<String> = sqlx::query_scalar(
+        "UPDATE api_tokens SET last_used_at = CURRENT_TIMESTAMP WHERE token_hash = ? RETURNING scope"
+    )
+    .bind(hash_secret(secret.trim()))
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(scope.and_then(|scope| scope.parse().ok()))
+}
+
+fn hash_secret(secret: &str) -> String {
+    Sha256::digest(secret.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
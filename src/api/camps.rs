@@ -1,54 +1,39 @@
-use crate::models::{Camp, CreateCamp, UpdateCamp};
+use crate::error::{AppError, FieldError};
+use crate::models::{Camp, CreateCamp, UpdateCamp, Validate};
+use chrono::NaiveDate;
 use sqlx::SqlitePool;
 
-pub async fn get_camps(pool: &SqlitePool) -> Result<Vec<Camp>, sqlx::Error> {
+/// Newest camp first; archived camps only with `include_archived`
+pub async fn get_camps(pool: &SqlitePool, include_archived: bool) -> Result<Vec<Camp>, AppError> {
     sqlx::query_as::<_, Camp>(
-        "SELECT id, name, start_date, end_date, default_children, default_teens, default_adults, notes, created_at, updated_at 
+        "SELECT id, name, start_date, end_date, default_children, default_teens, default_adults, notes, archived_at, created_at, updated_at 
          FROM camps 
+         WHERE archived_at IS NULL OR ?
          ORDER BY start_date DESC"
     )
+    .bind(include_archived)
     .fetch_all(pool)
     .await
+    .map_err(AppError::from)
 }
 
-pub async fn get_camp(pool: &SqlitePool, id: i64) -> Result<Camp, sqlx::Error> {
+pub async fn get_camp(pool: &SqlitePool, id: i64) -> Result<Camp, AppError> {
     sqlx::query_as::<_, Camp>(
-        "SELECT id, name, start_date, end_date, default_children, default_teens, default_adults, notes, created_at, updated_at 
+        "SELECT id, name, start_date, end_date, default_children, default_teens, default_adults, notes, archived_at, created_at, updated_at 
          FROM camps 
          WHERE id = ?"
     )
     .bind(id)
     .fetch_one(pool)
     .await
+    .map_err(AppError::from)
 }
 
 pub async fn create_camp(
     pool: &SqlitePool,
     camp: CreateCamp,
-) -> Result<Camp, sqlx::Error> {
-    // Validate date range
-    if camp.start_date >= camp.end_date {
-        return Err(sqlx::Error::Decode(
-            "End date must be after start date".into()
-        ));
-    }
-
-    // Validate counts are non-negative
-    if camp.default_children < 0 {
-        return Err(sqlx::Error::Decode(
-            "Number of children cannot be negative".into()
-        ));
-    }
-    if camp.default_teens < 0 {
-        return Err(sqlx::Error::Decode(
-            "Number of teens cannot be negative".into()
-        ));
-    }
-    if camp.default_adults < 0 {
-        return Err(sqlx::Error::Decode(
-            "Number of adults cannot be negative".into()
-        ));
-    }
+) -> Result<Camp, AppError> {
+    camp.validate()?;
 
     let result = sqlx::query(
         "INSERT INTO camps (name, start_date, end_date, default_children, default_teens, default_adults, notes) 
@@ -71,39 +56,47 @@ pub async fn update_camp(
     pool: &SqlitePool,
     id: i64,
     camp: UpdateCamp,
-) -> Result<Camp, sqlx::Error> {
+) -> Result<Camp, AppError> {
     let existing = get_camp(pool, id).await?;
 
-    // Determine final values
-    let final_start_date = camp.start_date.unwrap_or(existing.start_date);
-    let final_end_date = camp.end_date.unwrap_or(existing.end_date);
-    let final_children = camp.default_children.unwrap_or(existing.default_children);
-    let final_teens = camp.default_teens.unwrap_or(existing.default_teens);
-    let final_adults = camp.default_adults.unwrap_or(existing.default_adults);
-
-    // Validate date range
-    if final_start_date >= final_end_date {
-        return Err(sqlx::Error::Decode(
-            "End date must be after start date".into()
-        ));
-    }
+    let camp = CreateCamp {
+        name: camp.name.unwrap_or(existing.name),
+        start_date: camp.start_date.unwrap_or(existing.start_date),
+        end_date: camp.end_date.unwrap_or(existing.end_date),
+        default_children: camp.default_children.unwrap_or(existing.default_children),
+        default_teens: camp.default_teens.unwrap_or(existing.default_teens),
+        default_adults: camp.default_adults.unwrap_or(existing.default_adults),
+        notes: camp.notes.or(existing.notes),
+    };
+    camp.validate()?;
 
-    // Validate counts are non-negative
-    if final_children < 0 {
-        return Err(sqlx::Error::Decode(
-            "Number of children cannot be negative".into()
-        ));
-    }
-    if final_teens < 0 {
-        return Err(sqlx::Error::Decode(
-            "Number of teens cannot be negative".into()
-        ));
-    }
-    if final_adults < 0 {
-        return Err(sqlx::Error::Decode(
-            "Number of adults cannot be negative".into()
-        ));
+    let mut tx = pool.begin().await?;
+
+    // Meals must stay within the camp; day plans without meals just go
+    let stranded: Option<NaiveDate> = sqlx::query_scalar(
+        "SELECT mp.date FROM meal_plans mp
+         WHERE mp.camp_id = ? AND (mp.date < ? OR mp.date > ?)
+            AND EXISTS(SELECT 1 FROM planned_meals pm WHERE pm.meal_plan_id = mp.id)
+         ORDER BY mp.date
+         LIMIT 1"
+    )
+    .bind(id)
+    .bind(camp.start_date)
+    .bind(camp.end_date)
+    .fetch_optional(&mut *tx)
+    .await?;
+    if let Some(date) = stranded {
+        let field = if date < camp.start_date { "start_date" } else { "end_date" };
+        return Err(AppError::Validation(vec![
+            FieldError::new(field, "validation-meals-outside-camp").with_arg("date", date.format("%Y-%m-%d")),
+        ]));
     }
+    sqlx::query("DELETE FROM meal_plans WHERE camp_id = ? AND (date < ? OR date > ?)")
+        .bind(id)
+        .bind(camp.start_date)
+        .bind(camp.end_date)
+        .execute(&mut *tx)
+        .await?;
 
     sqlx::query(
         "UPDATE camps
@@ -111,21 +104,39 @@ pub async fn update_camp(
              default_teens = ?, default_adults = ?, notes = ?, updated_at = CURRENT_TIMESTAMP
          WHERE id = ?"
     )
-    .bind(camp.name.unwrap_or(existing.name))
-    .bind(final_start_date)
-    .bind(final_end_date)
-    .bind(final_children)
-    .bind(final_teens)
-    .bind(final_adults)
-    .bind(camp.notes.or(existing.notes))
+    .bind(&camp.name)
+    .bind(camp.start_date)
+    .bind(camp.end_date)
+    .bind(camp.default_children)
+    .bind(camp.default_teens)
+    .bind(camp.default_adults)
+    .bind(&camp.notes)
     .bind(id)
-    .execute(pool)
+    .execute(&mut *tx)
     .await?;
 
+    tx.commit().await?;
     get_camp(pool, id).await
 }
 
-pub async fn delete_camp(pool: &SqlitePool, id: i64) -> Result<(), sqlx::Error> {
+/// Token for the camp's calendar feed, created on first use
+pub async fn get_calendar_token(pool: &SqlitePool, id: i64) -> Result<String, AppError> {
+    sqlx::query(
+        "UPDATE camps SET calendar_token = lower(hex(randomblob(16)))
+         WHERE id = ? AND calendar_token IS NULL"
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    sqlx::query_scalar("SELECT calendar_token FROM camps WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+        .map_err(AppError::from)
+}
+
+pub async fn delete_camp(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
     sqlx::query("DELETE FROM camps WHERE id = ?")
         .bind(id)
         .execute(pool)
@@ -133,3 +144,97 @@ pub async fn delete_camp(pool: &SqlitePool, id: i64) -> Result<(), sqlx::Error>
 
     Ok(())
 }
+
+/// Hide a past camp from the lists, keeping its meal plan
+pub async fn archive_camp(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    super::trash::set_archived(pool, "camps", id, true).await
+}
+
+/// Bring an archived camp back into the lists
+pub async fn restore_camp(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    super::trash::set_archived(pool, "camps", id, false).await
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::meal_plans::{create_planned_meal, delete_planned_meal, update_planned_meal};
+    use crate::db::test_pool;
+    use crate::models::{CreatePlannedMeal, MealType, UpdatePlannedMeal};
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, day).unwrap()
+    }
+
+    fn dates(start_date: NaiveDate, end_date: NaiveDate) -> UpdateCamp {
+        UpdateCamp {
+            name: None,
+            start_date: Some(start_date),
+            end_date: Some(end_date),
+            default_children: None,
+            default_teens: None,
+            default_adults: None,
+            notes: None,
+        }
+    }
+
+    /// Camp from July 1 to 5 with a lunch on July 4; returns the camp and meal ids
+    async fn seed_camp(pool: &SqlitePool) -> (i64, i64) {
+        let recipe = sqlx::query("INSERT INTO recipes (name, base_servings) VALUES ('Soup', 4)")
+            .execute(pool)
+            .await
+            .unwrap()
+            .last_insert_rowid();
+        let camp = CreateCamp {
+            name: "Summer".to_string(),
+            start_date: day(1),
+            end_date: day(5),
+            default_children: 10,
+            default_teens: 0,
+            default_adults: 2,
+            notes: None,
+        };
+        let camp_id = create_camp(pool, camp).await.unwrap().id;
+        let meal = CreatePlannedMeal {
+            camp_id,
+            date: day(4),
+            meal_type: MealType::Lunch,
+            recipe_id: recipe,
+            attendance: None,
+        };
+        let meal_id = create_planned_meal(pool, meal).await.unwrap().planned_meal.id;
+        (camp_id, meal_id)
+    }
+
+    #[tokio::test]
+    async fn camps_cannot_shrink_past_their_meals() {
+        let pool = test_pool().await;
+        let (camp_id, meal_id) = seed_camp(&pool).await;
+
+        let error = update_camp(&pool, camp_id, dates(day(1), day(3))).await.unwrap_err();
+        assert_eq!(error.field_errors()[0].field, "end_date");
+        assert_eq!(error.field_errors()[0].message.key, "validation-meals-outside-camp");
+
+        // Without the meal, the empty day plan goes with the dates
+        delete_planned_meal(&pool, meal_id).await.unwrap();
+        let camp = update_camp(&pool, camp_id, dates(day(2), day(3))).await.unwrap();
+        assert_eq!((camp.start_date, camp.end_date), (day(2), day(3)));
+        let plans: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM meal_plans WHERE camp_id = ?")
+            .bind(camp_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(plans, 0);
+    }
+
+    #[tokio::test]
+    async fn unknown_planned_meals_are_not_found() {
+        let pool = test_pool().await;
+        let (_, meal_id) = seed_camp(&pool).await;
+        let update = UpdatePlannedMeal { recipe_id: None, attendance: None };
+
+        assert_eq!(update_planned_meal(&pool, meal_id + 1, update).await, Err(AppError::NotFound));
+        assert_eq!(delete_planned_meal(&pool, meal_id + 1).await, Err(AppError::NotFound));
+    }
+}
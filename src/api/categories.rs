@@ -1,31 +1,39 @@
-use crate::models::{Category, CreateCategory, UpdateCategory};
+use crate::error::{AppError, Message};
+use crate::models::{Category, CreateCategory, UpdateCategory, Validate};
 use sqlx::SqlitePool;
 
-pub async fn get_categories(pool: &SqlitePool) -> Result<Vec<Category>, sqlx::Error> {
+/// Archived categories only with `include_archived`
+pub async fn get_categories(pool: &SqlitePool, include_archived: bool) -> Result<Vec<Category>, AppError> {
     sqlx::query_as::<_, Category>(
-        "SELECT id, name, sort_order, created_at, updated_at 
+        "SELECT id, name, sort_order, archived_at, created_at, updated_at 
          FROM categories 
+         WHERE archived_at IS NULL OR ?
          ORDER BY sort_order, name"
     )
+    .bind(include_archived)
     .fetch_all(pool)
     .await
+    .map_err(AppError::from)
 }
 
-pub async fn get_category(pool: &SqlitePool, id: i64) -> Result<Category, sqlx::Error> {
+pub async fn get_category(pool: &SqlitePool, id: i64) -> Result<Category, AppError> {
     sqlx::query_as::<_, Category>(
-        "SELECT id, name, sort_order, created_at, updated_at 
+        "SELECT id, name, sort_order, archived_at, created_at, updated_at 
          FROM categories 
          WHERE id = ?"
     )
     .bind(id)
     .fetch_one(pool)
     .await
+    .map_err(AppError::from)
 }
 
 pub async fn create_category(
     pool: &SqlitePool,
     category: CreateCategory,
-) -> Result<Category, sqlx::Error> {
+) -> Result<Category, AppError> {
+    category.validate()?;
+
     let result = sqlx::query(
         "INSERT INTO categories (name, sort_order) 
          VALUES (?, ?)"
@@ -42,7 +50,8 @@ pub async fn update_category(
     pool: &SqlitePool,
     id: i64,
     category: UpdateCategory,
-) -> Result<Category, sqlx::Error> {
+) -> Result<Category, AppError> {
+    category.validate()?;
     let existing = get_category(pool, id).await?;
 
     sqlx::query(
@@ -59,7 +68,7 @@ pub async fn update_category(
     get_category(pool, id).await
 }
 
-pub async fn delete_category(pool: &SqlitePool, id: i64) -> Result<(), sqlx::Error> {
+pub async fn delete_category(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
     // Check if any ingredients are using this category
     let count: (i64,) = sqlx::query_as(
         "SELECT COUNT(*) FROM ingredients WHERE category_id = ?"
@@ -69,8 +78,8 @@ pub async fn delete_category(pool: &SqlitePool, id: i64) -> Result<(), sqlx::Err
     .await?;
 
     if count.0 > 0 {
-        return Err(sqlx::Error::Protocol(
-            format!("Cannot delete category: {} ingredient(s) are still using this category. Please reassign or delete those ingredients first.", count.0)
+        return Err(AppError::Conflict(
+            Message::new("error-category-in-use").with_arg("count", count.0)
         ));
     }
 
@@ -81,3 +90,14 @@ pub async fn delete_category(pool: &SqlitePool, id: i64) -> Result<(), sqlx::Err
 
     Ok(())
 }
+
+/// Hide a category from the lists; its ingredients keep it
+pub async fn archive_category(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    super::trash::set_archived(pool, "categories", id, true).await
+}
+
+/// Bring an archived category back into the lists
+pub async fn restore_category(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    super::trash::set_archived(pool, "categories", id, false).await
+}
+
@@ -0,0 +1,460 @@
+use crate::error::{AppError, FieldError};
+use crate::models::{
+    likely_same_name, normalize_name, unit_factor, DuplicateGroup, Ingredient, IngredientUsage,
+    MergeResult, RequiredConversion, UnitFactor,
+};
+use sqlx::{Sqlite, SqliteConnection, SqlitePool, Transaction};
+use std::collections::HashMap;
+
+/// Group ingredients whose names look like duplicates
+pub async fn find_duplicates(pool: &SqlitePool) -> Result<Vec<DuplicateGroup>, AppError> {
+    let ingredients = super::ingredients::get_ingredients(pool, false).await?;
+
+    let recipe_counts = usage_counts(pool, "recipe_ingredients").await?;
+    let stock_counts = usage_counts(pool, "inventory_items").await?;
+
+    // Union-find over all pairs; a few hundred ingredients keep this cheap
+    let mut parent: Vec<usize> = (0..ingredients.len()).collect();
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    for a in 0..ingredients.len() {
+        for b in a + 1..ingredients.len() {
+            if likely_same_name(&ingredients[a].name, &ingredients[b].name) {
+                let (ra, rb) = (root(&mut parent, a), root(&mut parent, b));
+                parent[rb] = ra;
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<IngredientUsage>> = HashMap::new();
+    for (index, ingredient) in ingredients.into_iter().enumerate() {
+        let group = root(&mut parent, index);
+        groups.entry(group).or_default().push(IngredientUsage {
+            recipe_count: recipe_counts.get(&ingredient.id).copied().unwrap_or(0),
+            stock_count: stock_counts.get(&ingredient.id).copied().unwrap_or(0),
+            ingredient,
+        });
+    }
+
+    let mut groups: Vec<DuplicateGroup> = groups
+        .into_values()
+        .filter(|members| members.len() > 1)
+        .map(|mut members| {
+            // Most used first; that is the usual merge target
+            members.sort_by(|a, b| b.recipe_count.cmp(&a.recipe_count).then(a.ingredient.name.cmp(&b.ingredient.name)));
+            DuplicateGroup { ingredients: members }
+        })
+        .collect();
+    groups.sort_by_key(|group| normalize_name(&group.ingredients[0].ingredient.name));
+
+    Ok(groups)
+}
+
+async fn usage_counts(pool: &SqlitePool, table: &str) -> Result<HashMap<i64, i64>, AppError> {
+    Ok(sqlx::query_as::<_, (i64, i64)>(&format!(
+        "SELECT ingredient_id, COUNT(*) FROM {} GROUP BY ingredient_id",
+        table
+    ))
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect())
+}
+
+/// Units of the source ingredients that cannot be converted to the target automatically
+pub async fn required_conversions(
+    pool: &SqlitePool,
+    target_id: i64,
+    source_ids: &[i64],
+) -> Result<Vec<RequiredConversion>, AppError> {
+    let target = super::ingredients::get_ingredient(pool, target_id).await?;
+    let mut conversions = Vec::new();
+
+    for &source_id in source_ids {
+        let source = super::ingredients::get_ingredient(pool, source_id).await?;
+        let units = sqlx::query_as::<_, (String, i64)>(
+            "SELECT unit, COUNT(*) FROM (
+                SELECT unit FROM recipe_ingredients WHERE ingredient_id = ?
+                UNION ALL
+                SELECT unit FROM inventory_items WHERE ingredient_id = ?
+             )
+             GROUP BY unit
+             ORDER BY unit"
+        )
+        .bind(source_id)
+        .bind(source_id)
+        .fetch_all(pool)
+        .await?;
+
+        for (unit, uses) in units {
+            if target_unit_for(&target, &unit).is_none() {
+                conversions.push(RequiredConversion {
+                    ingredient_id: source_id,
+                    ingredient_name: source.name.clone(),
+                    unit,
+                    target_unit: target.primary_unit.clone(),
+                    uses,
+                });
+            }
+        }
+    }
+
+    Ok(conversions)
+}
+
+/// Unit to store `unit` as on the target and the factor to multiply by
+pub(crate) fn target_unit_for(target: &Ingredient, unit: &str) -> Option<(String, f64)> {
+    if unit == target.primary_unit || target.secondary_unit.as_deref() == Some(unit) {
+        return Some((unit.to_string(), 1.0));
+    }
+    unit_factor(unit, &target.primary_unit).map(|factor| (target.primary_unit.clone(), factor))
+}
+
+/// Point a recipe line at `target_id` with `quantity` in `unit`. Where the recipe
+/// already has a line of the target in a convertible unit with the same
+/// multipliers and notes, the quantity is added to that line and this one is
+/// deleted; lines that scale differently are kept apart.
+pub(crate) async fn move_recipe_line(
+    conn: &mut SqliteConnection,
+    line_id: i64,
+    recipe_id: i64,
+    target_id: i64,
+    unit: &str,
+    quantity: f64,
+) -> Result<(), AppError> {
+    let target_lines = sqlx::query_as::<_, (i64, String)>(
+        "SELECT t.id, t.unit
+         FROM recipe_ingredients t
+         JOIN recipe_ingredients line ON line.id = ?
+         WHERE t.recipe_id = ? AND t.ingredient_id = ? AND t.id != line.id
+            AND COALESCE(t.child_multiplier, 1.0) = COALESCE(line.child_multiplier, 1.0)
+            AND COALESCE(t.teen_multiplier, 1.0) = COALESCE(line.teen_multiplier, 1.0)
+            AND COALESCE(t.adult_multiplier, 1.0) = COALESCE(line.adult_multiplier, 1.0)
+            AND COALESCE(t.notes, '') = COALESCE(line.notes, '')
+         ORDER BY t.id"
+    )
+    .bind(line_id)
+    .bind(recipe_id)
+    .bind(target_id)
+    .fetch_all(&mut *conn)
+    .await?;
+    let existing = target_lines.into_iter()
+        .find_map(|(id, line_unit)| unit_factor(unit, &line_unit).map(|to_line| (id, to_line)));
+
+    match existing {
+        Some((existing_id, to_line)) => {
+            sqlx::query("UPDATE recipe_ingredients SET base_quantity = base_quantity + ? WHERE id = ?")
+                .bind(quantity * to_line)
+                .bind(existing_id)
+                .execute(&mut *conn)
+                .await?;
+            sqlx::query("DELETE FROM recipe_ingredients WHERE id = ?")
+                .bind(line_id)
+                .execute(&mut *conn)
+                .await?;
+        }
+        None => {
+            sqlx::query(
+                "UPDATE recipe_ingredients SET ingredient_id = ?, unit = ?, base_quantity = ? WHERE id = ?"
+            )
+            .bind(target_id)
+            .bind(unit)
+            .bind(quantity)
+            .bind(line_id)
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Merge `source_ids` into `target_id`.
+///
+/// Recipe lines and stock are repointed, converting quantities into the target's
+/// primary unit where their unit differs (with `unit_factors` for units that have
+/// no known conversion). Where a recipe already has a line of the target in a
+/// convertible unit, or the same place already stocks the target in that unit,
+/// the quantity is added to it instead. Source names and aliases become aliases
+/// of the target, and the sources are deleted.
+pub async fn merge_ingredients(
+    pool: &SqlitePool,
+    target_id: i64,
+    source_ids: Vec<i64>,
+    unit_factors: Vec<UnitFactor>,
+) -> Result<MergeResult, AppError> {
+    if source_ids.is_empty() {
+        return Err(AppError::validation("source_ids", "validation-merge-select-source"));
+    }
+    if source_ids.contains(&target_id) {
+        return Err(AppError::validation("target_id", "validation-merge-into-itself"));
+    }
+    if unit_factors.iter().any(|f| f.factor <= 0.0) {
+        return Err(AppError::validation("unit_factors", "validation-factor-positive"));
+    }
+
+    let target = super::ingredients::get_ingredient(pool, target_id).await?;
+    let mut sources = Vec::new();
+    for &source_id in &source_ids {
+        sources.push(super::ingredients::get_ingredient(pool, source_id).await?);
+    }
+
+    let mut result = MergeResult::default();
+    let mut tx = pool.begin().await?;
+
+    for source in sources {
+        let source_id = source.id;
+
+        let convert = |unit: &str| {
+            target_unit_for(&target, unit)
+                .or_else(|| {
+                    unit_factors.iter()
+                        .find(|f| f.ingredient_id == source_id && f.unit == unit)
+                        .map(|f| (target.primary_unit.clone(), f.factor))
+                })
+                .ok_or_else(|| AppError::Validation(vec![
+                    FieldError::new("unit_factors", "validation-merge-no-conversion")
+                        .with_arg("unit", unit)
+                        .with_arg("source", &source.name)
+                        .with_arg("target_unit", &target.primary_unit)
+                        .with_arg("target", &target.name),
+                ]))
+        };
+
+        // A recipe that already uses the target gets one line with both quantities
+        let lines = sqlx::query_as::<_, (i64, i64, String, f64)>(
+            "SELECT id, recipe_id, unit, base_quantity FROM recipe_ingredients WHERE ingredient_id = ?"
+        )
+        .bind(source_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for (line_id, recipe_id, unit, quantity) in lines {
+            let (new_unit, factor) = convert(&unit)?;
+            move_recipe_line(&mut tx, line_id, recipe_id, target_id, &new_unit, quantity * factor).await?;
+            result.recipe_lines += 1;
+        }
+
+        // Likewise stock of the target in the same unit and place
+        let items = sqlx::query_as::<_, (i64, Option<i64>, String, String, f64)>(
+            "SELECT id, camp_id, storeroom, unit, quantity FROM inventory_items WHERE ingredient_id = ?"
+        )
+        .bind(source_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for (item_id, camp_id, storeroom, unit, quantity) in items {
+            let (new_unit, factor) = convert(&unit)?;
+            let existing: Option<i64> = sqlx::query_scalar(
+                "SELECT id FROM inventory_items
+                 WHERE ingredient_id = ? AND unit = ? AND camp_id IS ? AND storeroom = ?"
+            )
+            .bind(target_id)
+            .bind(&new_unit)
+            .bind(camp_id)
+            .bind(&storeroom)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+            match existing {
+                Some(existing_id) => {
+                    sqlx::query(
+                        "UPDATE inventory_items SET quantity = quantity + ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?"
+                    )
+                    .bind(quantity * factor)
+                    .bind(existing_id)
+                    .execute(&mut *tx)
+                    .await?;
+                    sqlx::query("DELETE FROM inventory_items WHERE id = ?")
+                        .bind(item_id)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+                None => {
+                    sqlx::query(
+                        "UPDATE inventory_items SET ingredient_id = ?, unit = ?, quantity = ?, updated_at = CURRENT_TIMESTAMP
+                         WHERE id = ?"
+                    )
+                    .bind(target_id)
+                    .bind(&new_unit)
+                    .bind(quantity * factor)
+                    .bind(item_id)
+                    .execute(&mut *tx)
+                    .await?;
+                }
+            }
+            result.stock_items += 1;
+        }
+
+        // Pack sizes only make sense in a unit the target is kept in
+        sqlx::query(
+            "UPDATE ingredient_packs SET ingredient_id = ?
+             WHERE ingredient_id = ? AND (unit = ? OR unit = ?)"
+        )
+        .bind(target_id)
+        .bind(source_id)
+        .bind(&target.primary_unit)
+        .bind(target.secondary_unit.as_deref().unwrap_or(&target.primary_unit))
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT OR IGNORE INTO supplier_ingredients (supplier_id, ingredient_id)
+             SELECT supplier_id, ? FROM supplier_ingredients WHERE ingredient_id = ?"
+        )
+        .bind(target_id)
+        .bind(source_id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("UPDATE ingredient_aliases SET ingredient_id = ? WHERE ingredient_id = ?")
+            .bind(target_id)
+            .bind(source_id)
+            .execute(&mut *tx)
+            .await?;
+
+        if add_alias(&mut tx, &target, &source.name).await? {
+            result.aliases.push(source.name.clone());
+        }
+
+        sqlx::query("DELETE FROM ingredients WHERE id = ?")
+            .bind(source_id)
+            .execute(&mut *tx)
+            .await?;
+
+        result.merged += 1;
+    }
+
+    tx.commit().await?;
+    Ok(result)
+}
+
+/// Record `alias` for `target` unless it is the target's own name or already known
+async fn add_alias(
+    tx: &mut Transaction<'_, Sqlite>,
+    target: &Ingredient,
+    alias: &str,
+) -> Result<bool, AppError> {
+    let normalized = normalize_name(alias);
+    if normalized == normalize_name(&target.name) {
+        return Ok(false);
+    }
+
+    let inserted = sqlx::query(
+        "INSERT OR IGNORE INTO ingredient_aliases (ingredient_id, alias, normalized) VALUES (?, ?, ?)"
+    )
+    .bind(target.id)
+    .bind(alias)
+    .bind(&normalized)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+
+    Ok(inserted > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    async fn insert(pool: &SqlitePool, sql: &str) -> i64 {
+        sqlx::query(sql).execute(pool).await.unwrap().last_insert_rowid()
+    }
+
+    #[tokio::test]
+    async fn merge_adds_to_existing_recipe_lines_and_stock() {
+        let pool = test_pool().await;
+        let flour = insert(&pool, "INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Flour', 1, 'g')").await;
+        let wheat = insert(&pool, "INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Wheat flour', 1, 'kg')").await;
+        let recipe = insert(&pool, "INSERT INTO recipes (name, base_servings) VALUES ('Pancakes', 4)").await;
+        for (ingredient, quantity, unit) in [(flour, 200.0, "g"), (wheat, 0.5, "kg")] {
+            sqlx::query("INSERT INTO recipe_ingredients (recipe_id, ingredient_id, base_quantity, unit) VALUES (?, ?, ?, ?)")
+                .bind(recipe).bind(ingredient).bind(quantity).bind(unit)
+                .execute(&pool).await.unwrap();
+            sqlx::query("INSERT INTO inventory_items (ingredient_id, quantity, unit) VALUES (?, ?, ?)")
+                .bind(ingredient).bind(quantity * 5.0).bind(unit)
+                .execute(&pool).await.unwrap();
+        }
+
+        let result = merge_ingredients(&pool, flour, vec![wheat], Vec::new()).await.unwrap();
+        assert_eq!((result.merged, result.recipe_lines, result.stock_items), (1, 1, 1));
+        assert_eq!(result.aliases, vec!["Wheat flour".to_string()]);
+
+        let lines: Vec<(i64, f64, String)> = sqlx::query_as(
+            "SELECT ingredient_id, base_quantity, unit FROM recipe_ingredients WHERE recipe_id = ?"
+        )
+        .bind(recipe)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(lines, vec![(flour, 700.0, "g".to_string())]);
+
+        let stock: Vec<(i64, f64, String)> = sqlx::query_as("SELECT ingredient_id, quantity, unit FROM inventory_items")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(stock, vec![(flour, 3500.0, "g".to_string())]);
+
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ingredients WHERE id = ?")
+            .bind(wheat)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+    }
+
+    #[tokio::test]
+    async fn merge_repoints_lines_without_a_target_line() {
+        let pool = test_pool().await;
+        let flour = insert(&pool, "INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Flour', 1, 'g')").await;
+        let wheat = insert(&pool, "INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Wheat flour', 1, 'kg')").await;
+        let recipe = insert(&pool, "INSERT INTO recipes (name, base_servings) VALUES ('Bread', 4)").await;
+        sqlx::query("INSERT INTO recipe_ingredients (recipe_id, ingredient_id, base_quantity, unit) VALUES (?, ?, 1.5, 'kg')")
+            .bind(recipe).bind(wheat)
+            .execute(&pool).await.unwrap();
+
+        merge_ingredients(&pool, flour, vec![wheat], Vec::new()).await.unwrap();
+
+        let lines: Vec<(i64, f64, String)> = sqlx::query_as(
+            "SELECT ingredient_id, base_quantity, unit FROM recipe_ingredients WHERE recipe_id = ?"
+        )
+        .bind(recipe)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(lines, vec![(flour, 1500.0, "g".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn merge_keeps_lines_that_scale_differently() {
+        let pool = test_pool().await;
+        let flour = insert(&pool, "INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Flour', 1, 'g')").await;
+        let wheat = insert(&pool, "INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Wheat flour', 1, 'g')").await;
+        let recipe = insert(&pool, "INSERT INTO recipes (name, base_servings) VALUES ('Pancakes', 4)").await;
+        for (ingredient, child_multiplier) in [(flour, 0.5), (wheat, 1.0)] {
+            sqlx::query(
+                "INSERT INTO recipe_ingredients (recipe_id, ingredient_id, base_quantity, unit, child_multiplier)
+                 VALUES (?, ?, 200, 'g', ?)"
+            )
+            .bind(recipe).bind(ingredient).bind(child_multiplier)
+            .execute(&pool).await.unwrap();
+        }
+
+        merge_ingredients(&pool, flour, vec![wheat], Vec::new()).await.unwrap();
+
+        let lines: Vec<(i64, f64, f64)> = sqlx::query_as(
+            "SELECT ingredient_id, base_quantity, child_multiplier FROM recipe_ingredients WHERE recipe_id = ? ORDER BY id"
+        )
+        .bind(recipe)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(lines, vec![(flour, 200.0, 0.5), (flour, 200.0, 1.0)]);
+    }
+}
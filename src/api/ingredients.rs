@@ -1,54 +1,105 @@
-use crate::models::{Ingredient, CreateIngredient, UpdateIngredient};
+use crate::error::{AppError, Message};
+use crate::models::{
+    normalize_name, split_list, Ingredient, CreateIngredient, UpdateIngredient, IngredientPack, CreateIngredientPack,
+    IngredientAlias, Validate,
+};
 use sqlx::SqlitePool;
+use std::collections::HashMap;
 
-pub async fn get_ingredients(pool: &SqlitePool) -> Result<Vec<Ingredient>, sqlx::Error> {
-    sqlx::query_as::<_, Ingredient>(
-        "SELECT id, name, category_id, primary_unit, secondary_unit, created_at, updated_at 
+/// Archived ingredients only with `include_archived`
+pub async fn get_ingredients(pool: &SqlitePool, include_archived: bool) -> Result<Vec<Ingredient>, AppError> {
+    let ingredients = sqlx::query_as::<_, Ingredient>(
+        "SELECT id, name, category_id, primary_unit, secondary_unit, safety_margin_percent,
+                shelf_life_days, storage_type, price_per_unit, allergens, archived_at, created_at, updated_at 
          FROM ingredients 
+         WHERE archived_at IS NULL OR ?
          ORDER BY name"
     )
+    .bind(include_archived)
     .fetch_all(pool)
-    .await
+    .await?;
+
+    with_aliases(pool, ingredients).await
 }
 
 pub async fn get_ingredients_by_category(
     pool: &SqlitePool,
     category_id: i64,
-) -> Result<Vec<Ingredient>, sqlx::Error> {
-    sqlx::query_as::<_, Ingredient>(
-        "SELECT id, name, category_id, primary_unit, secondary_unit, created_at, updated_at 
+    include_archived: bool,
+) -> Result<Vec<Ingredient>, AppError> {
+    let ingredients = sqlx::query_as::<_, Ingredient>(
+        "SELECT id, name, category_id, primary_unit, secondary_unit, safety_margin_percent,
+                shelf_life_days, storage_type, price_per_unit, allergens, archived_at, created_at, updated_at 
          FROM ingredients 
-         WHERE category_id = ?
+         WHERE category_id = ? AND (archived_at IS NULL OR ?)
          ORDER BY name"
     )
     .bind(category_id)
+    .bind(include_archived)
     .fetch_all(pool)
-    .await
+    .await?;
+
+    with_aliases(pool, ingredients).await
 }
 
-pub async fn get_ingredient(pool: &SqlitePool, id: i64) -> Result<Ingredient, sqlx::Error> {
-    sqlx::query_as::<_, Ingredient>(
-        "SELECT id, name, category_id, primary_unit, secondary_unit, created_at, updated_at 
+pub async fn get_ingredient(pool: &SqlitePool, id: i64) -> Result<Ingredient, AppError> {
+    let mut ingredient = sqlx::query_as::<_, Ingredient>(
+        "SELECT id, name, category_id, primary_unit, secondary_unit, safety_margin_percent,
+                shelf_life_days, storage_type, price_per_unit, allergens, archived_at, created_at, updated_at 
          FROM ingredients 
          WHERE id = ?"
     )
     .bind(id)
     .fetch_one(pool)
-    .await
+    .await?;
+
+    ingredient.aliases = sqlx::query_scalar("SELECT alias FROM ingredient_aliases WHERE ingredient_id = ? ORDER BY alias")
+        .bind(id)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(ingredient)
+}
+
+/// Fill in the aliases of each ingredient
+async fn with_aliases(pool: &SqlitePool, mut ingredients: Vec<Ingredient>) -> Result<Vec<Ingredient>, AppError> {
+    let mut aliases: HashMap<i64, Vec<String>> = HashMap::new();
+    for (ingredient_id, alias) in sqlx::query_as::<_, (i64, String)>(
+        "SELECT ingredient_id, alias FROM ingredient_aliases ORDER BY alias"
+    )
+    .fetch_all(pool)
+    .await?
+    {
+        aliases.entry(ingredient_id).or_default().push(alias);
+    }
+
+    for ingredient in &mut ingredients {
+        ingredient.aliases = aliases.remove(&ingredient.id).unwrap_or_default();
+    }
+
+    Ok(ingredients)
 }
 
 pub async fn create_ingredient(
     pool: &SqlitePool,
     ingredient: CreateIngredient,
-) -> Result<Ingredient, sqlx::Error> {
+) -> Result<Ingredient, AppError> {
+    ingredient.validate()?;
+
     let result = sqlx::query(
-        "INSERT INTO ingredients (name, category_id, primary_unit, secondary_unit) 
-         VALUES (?, ?, ?, ?)"
+        "INSERT INTO ingredients (name, category_id, primary_unit, secondary_unit, safety_margin_percent,
+                                  shelf_life_days, storage_type, price_per_unit, allergens) 
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
     )
     .bind(&ingredient.name)
     .bind(ingredient.category_id)
     .bind(&ingredient.primary_unit)
     .bind(&ingredient.secondary_unit)
+    .bind(ingredient.safety_margin_percent)
+    .bind(ingredient.shelf_life_days)
+    .bind(&ingredient.storage_type)
+    .bind(ingredient.price_per_unit)
+    .bind(split_list(&ingredient.allergens).join(", "))
     .execute(pool)
     .await?;
 
@@ -59,19 +110,36 @@ pub async fn update_ingredient(
     pool: &SqlitePool,
     id: i64,
     ingredient: UpdateIngredient,
-) -> Result<Ingredient, sqlx::Error> {
+) -> Result<Ingredient, AppError> {
+    ingredient.validate()?;
     let existing = get_ingredient(pool, id).await?;
 
+    let final_margin = ingredient.safety_margin_percent.unwrap_or(existing.safety_margin_percent);
+    let final_shelf_life = if ingredient.clear_shelf_life {
+        None
+    } else {
+        ingredient.shelf_life_days.or(existing.shelf_life_days)
+    };
+    let final_storage_type = ingredient.storage_type.unwrap_or(existing.storage_type);
+    let final_price = ingredient.price_per_unit.or(existing.price_per_unit);
+    let final_allergens = split_list(&ingredient.allergens.unwrap_or(existing.allergens)).join(", ");
+
     sqlx::query(
         "UPDATE ingredients 
          SET name = ?, category_id = ?, primary_unit = ?, 
-             secondary_unit = ?, updated_at = CURRENT_TIMESTAMP 
+             secondary_unit = ?, safety_margin_percent = ?, shelf_life_days = ?,
+             storage_type = ?, price_per_unit = ?, allergens = ?, updated_at = CURRENT_TIMESTAMP 
          WHERE id = ?"
     )
     .bind(ingredient.name.unwrap_or(existing.name))
     .bind(ingredient.category_id.unwrap_or(existing.category_id))
     .bind(ingredient.primary_unit.unwrap_or(existing.primary_unit))
     .bind(ingredient.secondary_unit.or(existing.secondary_unit))
+    .bind(final_margin)
+    .bind(final_shelf_life)
+    .bind(final_storage_type)
+    .bind(final_price)
+    .bind(final_allergens)
     .bind(id)
     .execute(pool)
     .await?;
@@ -79,7 +147,21 @@ pub async fn update_ingredient(
     get_ingredient(pool, id).await
 }
 
-pub async fn delete_ingredient(pool: &SqlitePool, id: i64) -> Result<(), sqlx::Error> {
+/// Delete an ingredient no recipe uses; its stock, packs and aliases go with it
+pub async fn delete_ingredient(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    let count: (i64,) = sqlx::query_as(
+        "SELECT COUNT(DISTINCT recipe_id) FROM recipe_ingredients WHERE ingredient_id = ?"
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await?;
+
+    if count.0 > 0 {
+        return Err(AppError::Conflict(
+            Message::new("error-ingredient-in-use").with_arg("count", count.0)
+        ));
+    }
+
     sqlx::query("DELETE FROM ingredients WHERE id = ?")
         .bind(id)
         .execute(pool)
@@ -87,3 +169,173 @@ pub async fn delete_ingredient(pool: &SqlitePool, id: i64) -> Result<(), sqlx::E
 
     Ok(())
 }
+
+/// Hide an ingredient from the lists while recipes keep using it
+pub async fn archive_ingredient(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    super::trash::set_archived(pool, "ingredients", id, true).await
+}
+
+/// Bring an archived ingredient back into the lists
+pub async fn restore_ingredient(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    super::trash::set_archived(pool, "ingredients", id, false).await
+}
+
+pub async fn get_ingredient_packs(
+    pool: &SqlitePool,
+    ingredient_id: i64,
+) -> Result<Vec<IngredientPack>, AppError> {
+    sqlx::query_as::<_, IngredientPack>(
+        "SELECT id, ingredient_id, unit, size, label, created_at
+         FROM ingredient_packs
+         WHERE ingredient_id = ?
+         ORDER BY unit, size"
+    )
+    .bind(ingredient_id)
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::from)
+}
+
+/// All pack sizes keyed by `(ingredient_id, unit)`
+pub async fn get_all_ingredient_packs(
+    pool: &SqlitePool,
+) -> Result<HashMap<(i64, String), Vec<IngredientPack>>, AppError> {
+    let packs = sqlx::query_as::<_, IngredientPack>(
+        "SELECT id, ingredient_id, unit, size, label, created_at
+         FROM ingredient_packs
+         ORDER BY ingredient_id, unit, size"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut by_ingredient: HashMap<(i64, String), Vec<IngredientPack>> = HashMap::new();
+    for pack in packs {
+        by_ingredient
+            .entry((pack.ingredient_id, pack.unit.clone()))
+            .or_default()
+            .push(pack);
+    }
+
+    Ok(by_ingredient)
+}
+
+pub async fn create_ingredient_pack(
+    pool: &SqlitePool,
+    pack: CreateIngredientPack,
+) -> Result<IngredientPack, AppError> {
+    if pack.size <= 0.0 {
+        return Err(AppError::validation("size", "validation-pack-size-positive"));
+    }
+
+    let result = sqlx::query(
+        "INSERT INTO ingredient_packs (ingredient_id, unit, size, label)
+         VALUES (?, ?, ?, ?)"
+    )
+    .bind(pack.ingredient_id)
+    .bind(&pack.unit)
+    .bind(pack.size)
+    .bind(&pack.label)
+    .execute(pool)
+    .await?;
+
+    sqlx::query_as::<_, IngredientPack>(
+        "SELECT id, ingredient_id, unit, size, label, created_at
+         FROM ingredient_packs
+         WHERE id = ?"
+    )
+    .bind(result.last_insert_rowid())
+    .fetch_one(pool)
+    .await
+    .map_err(AppError::from)
+}
+
+pub async fn delete_ingredient_pack(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM ingredient_packs WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_ingredient_aliases(
+    pool: &SqlitePool,
+    ingredient_id: i64,
+) -> Result<Vec<IngredientAlias>, AppError> {
+    sqlx::query_as::<_, IngredientAlias>(
+        "SELECT id, ingredient_id, alias, created_at
+         FROM ingredient_aliases
+         WHERE ingredient_id = ?
+         ORDER BY alias"
+    )
+    .bind(ingredient_id)
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::from)
+}
+
+/// Add another name for an ingredient. Names are compared without case and
+/// diacritics, and may not clash with another ingredient or alias.
+pub async fn create_ingredient_alias(
+    pool: &SqlitePool,
+    ingredient_id: i64,
+    alias: String,
+) -> Result<IngredientAlias, AppError> {
+    let alias = alias.trim().to_string();
+    let normalized = normalize_name(&alias);
+    if normalized.is_empty() {
+        return Err(AppError::validation("alias", "validation-alias-empty"));
+    }
+
+    let ingredients = sqlx::query_as::<_, (i64, String)>("SELECT id, name FROM ingredients")
+        .fetch_all(pool)
+        .await?;
+    if let Some((id, name)) = ingredients.iter().find(|(_, name)| normalize_name(name) == normalized) {
+        return Err(AppError::Conflict(if *id == ingredient_id {
+            Message::new("error-alias-is-own-name").with_arg("alias", &alias)
+        } else {
+            Message::new("error-alias-is-ingredient-name").with_arg("alias", &alias).with_arg("name", name)
+        }));
+    }
+
+    let taken = sqlx::query_scalar::<_, String>(
+        "SELECT i.name FROM ingredient_aliases a JOIN ingredients i ON a.ingredient_id = i.id
+         WHERE a.normalized = ?"
+    )
+    .bind(&normalized)
+    .fetch_optional(pool)
+    .await?;
+    if let Some(name) = taken {
+        return Err(AppError::Conflict(
+            Message::new("error-alias-taken").with_arg("alias", &alias).with_arg("name", name)
+        ));
+    }
+
+    let result = sqlx::query(
+        "INSERT INTO ingredient_aliases (ingredient_id, alias, normalized) VALUES (?, ?, ?)"
+    )
+    .bind(ingredient_id)
+    .bind(&alias)
+    .bind(&normalized)
+    .execute(pool)
+    .await?;
+
+    sqlx::query_as::<_, IngredientAlias>(
+        "SELECT id, ingredient_id, alias, created_at
+         FROM ingredient_aliases
+         WHERE id = ?"
+    )
+    .bind(result.last_insert_rowid())
+    .fetch_one(pool)
+    .await
+    .map_err(AppError::from)
+}
+
+pub async fn delete_ingredient_alias(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM ingredient_aliases WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,332 @@
+use crate::error::{AppError, Message};
+use crate::api::reports::calculate_requirements;
+use crate::models::{
+    unit_factor, ConsumedItem, ConsumptionResult, CreateInventoryItem, InventoryItem,
+    InventoryItemDetail, UpdateInventoryItem, Validate,
+};
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+const INVENTORY_DETAIL_SELECT: &str =
+    "SELECT
+        inv.id, inv.ingredient_id, inv.camp_id, inv.storeroom, inv.quantity, inv.unit,
+        inv.notes, inv.created_at, inv.updated_at,
+        i.name as ingredient_name,
+        c.name as category_name
+     FROM inventory_items inv
+     JOIN ingredients i ON inv.ingredient_id = i.id
+     JOIN categories c ON i.category_id = c.id";
+
+pub async fn get_inventory(pool: &SqlitePool) -> Result<Vec<InventoryItemDetail>, AppError> {
+    sqlx::query_as::<_, InventoryItemDetail>(&format!(
+        "{} ORDER BY inv.camp_id, inv.storeroom, c.sort_order, i.name",
+        INVENTORY_DETAIL_SELECT
+    ))
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::from)
+}
+
+/// Stock usable by a camp: its own items plus the shared storeroom (`camp_id IS NULL`)
+pub async fn get_inventory_for_camp(
+    pool: &SqlitePool,
+    camp_id: i64,
+) -> Result<Vec<InventoryItemDetail>, AppError> {
+    sqlx::query_as::<_, InventoryItemDetail>(&format!(
+        "{} WHERE inv.camp_id = ? OR inv.camp_id IS NULL
+         ORDER BY inv.camp_id IS NULL, inv.storeroom, c.sort_order, i.name",
+        INVENTORY_DETAIL_SELECT
+    ))
+    .bind(camp_id)
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::from)
+}
+
+pub async fn get_inventory_item(pool: &SqlitePool, id: i64) -> Result<InventoryItem, AppError> {
+    sqlx::query_as::<_, InventoryItem>(
+        "SELECT id, ingredient_id, camp_id, storeroom, quantity, unit, notes, created_at, updated_at
+         FROM inventory_items
+         WHERE id = ?"
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await
+    .map_err(AppError::from)
+}
+
+/// Quantity on hand per ingredient as `(unit, quantity)` pairs for a camp, including the
+/// shared storeroom
+pub async fn get_stock_totals(
+    pool: &SqlitePool,
+    camp_id: i64,
+) -> Result<HashMap<i64, Vec<(String, f64)>>, AppError> {
+    let rows: Vec<(i64, String, f64)> = sqlx::query_as(
+        "SELECT ingredient_id, unit, SUM(quantity)
+         FROM inventory_items
+         WHERE camp_id = ? OR camp_id IS NULL
+         GROUP BY ingredient_id, unit"
+    )
+    .bind(camp_id)
+    .fetch_all(pool)
+    .await?;
+
+    let mut stock: HashMap<i64, Vec<(String, f64)>> = HashMap::new();
+    for (ingredient_id, unit, quantity) in rows {
+        stock.entry(ingredient_id).or_default().push((unit, quantity));
+    }
+    Ok(stock)
+}
+
+/// Take up to `needed` (in `unit`) of an ingredient from `stock`, converting
+/// between units where possible. Returns the amount taken, in `unit`.
+pub(crate) fn take_from_stock(
+    stock: &mut HashMap<i64, Vec<(String, f64)>>,
+    ingredient_id: i64,
+    unit: &str,
+    needed: f64,
+) -> f64 {
+    let Some(entries) = stock.get_mut(&ingredient_id) else {
+        return 0.0;
+    };
+
+    let mut taken = 0.0;
+    for (stock_unit, quantity) in entries.iter_mut() {
+        let Some(factor) = unit_factor(stock_unit, unit) else {
+            continue;
+        };
+        let take = (*quantity * factor).min(needed - taken);
+        if take > 0.0 {
+            *quantity -= take / factor;
+            taken += take;
+        }
+        if needed - taken <= f64::EPSILON {
+            break;
+        }
+    }
+    taken
+}
+
+/// Add stock. Quantities for an existing ingredient/unit/location are added together.
+pub async fn create_inventory_item(
+    pool: &SqlitePool,
+    item: CreateInventoryItem,
+) -> Result<InventoryItem, AppError> {
+    item.validate()?;
+
+    let storeroom = item.storeroom.unwrap_or_default();
+
+    let existing: Option<(i64,)> = sqlx::query_as(
+        "SELECT id FROM inventory_items
+         WHERE ingredient_id = ? AND unit = ? AND camp_id IS ? AND storeroom = ?"
+    )
+    .bind(item.ingredient_id)
+    .bind(&item.unit)
+    .bind(item.camp_id)
+    .bind(&storeroom)
+    .fetch_optional(pool)
+    .await?;
+
+    if let Some((id,)) = existing {
+        sqlx::query(
+            "UPDATE inventory_items
+             SET quantity = quantity + ?, notes = COALESCE(?, notes), updated_at = CURRENT_TIMESTAMP
+             WHERE id = ?"
+        )
+        .bind(item.quantity)
+        .bind(&item.notes)
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        return get_inventory_item(pool, id).await;
+    }
+
+    let result = sqlx::query(
+        "INSERT INTO inventory_items (ingredient_id, camp_id, storeroom, quantity, unit, notes)
+         VALUES (?, ?, ?, ?, ?, ?)"
+    )
+    .bind(item.ingredient_id)
+    .bind(item.camp_id)
+    .bind(&storeroom)
+    .bind(item.quantity)
+    .bind(&item.unit)
+    .bind(&item.notes)
+    .execute(pool)
+    .await?;
+
+    get_inventory_item(pool, result.last_insert_rowid()).await
+}
+
+pub async fn update_inventory_item(
+    pool: &SqlitePool,
+    id: i64,
+    item: UpdateInventoryItem,
+) -> Result<InventoryItem, AppError> {
+    item.validate()?;
+    let existing = get_inventory_item(pool, id).await?;
+
+    let final_quantity = item.quantity.unwrap_or(existing.quantity);
+
+    sqlx::query(
+        "UPDATE inventory_items
+         SET quantity = ?, unit = ?, storeroom = ?, notes = ?, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ?"
+    )
+    .bind(final_quantity)
+    .bind(item.unit.unwrap_or(existing.unit))
+    .bind(item.storeroom.unwrap_or(existing.storeroom))
+    .bind(super::patch_text(item.notes, existing.notes))
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    get_inventory_item(pool, id).await
+}
+
+pub async fn delete_inventory_item(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM inventory_items WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Dates of a camp whose planned meals have already been deducted from stock
+pub async fn get_consumed_dates(
+    pool: &SqlitePool,
+    camp_id: i64,
+) -> Result<Vec<NaiveDate>, AppError> {
+    sqlx::query_scalar(
+        "SELECT date FROM inventory_consumptions WHERE camp_id = ? ORDER BY date"
+    )
+    .bind(camp_id)
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::from)
+}
+
+/// Deduct the quantities used by all planned meals of one camp day from stock.
+///
+/// Camp-specific stock is used before the shared storeroom, and stock in other units
+/// of the same kind (kg for g) is converted. Quantities never go below zero, and
+/// each day can only be consumed once.
+pub async fn consume_day(
+    pool: &SqlitePool,
+    camp_id: i64,
+    date: NaiveDate,
+) -> Result<ConsumptionResult, AppError> {
+    let required = calculate_requirements(pool, camp_id, date, date).await?;
+
+    let mut tx = pool.begin().await?;
+
+    let already_consumed: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM inventory_consumptions WHERE camp_id = ? AND date = ?)"
+    )
+    .bind(camp_id)
+    .bind(date)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if already_consumed {
+        return Err(AppError::Conflict(
+            Message::new("error-stock-already-consumed").with_arg("date", date.format("%Y-%m-%d"))
+        ));
+    }
+
+    let mut items = Vec::new();
+
+    for requirement in required {
+        let stock_rows: Vec<(i64, String, f64)> = sqlx::query_as(
+            "SELECT id, unit, quantity FROM inventory_items
+             WHERE ingredient_id = ? AND quantity > 0
+                AND (camp_id = ? OR camp_id IS NULL)
+             ORDER BY camp_id IS NULL, unit != ?, id"
+        )
+        .bind(requirement.ingredient_id)
+        .bind(camp_id)
+        .bind(&requirement.unit)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        // `remaining` is in the requirement's unit, stock is deducted in its own
+        let mut remaining = requirement.total_quantity;
+        for (stock_id, stock_unit, available) in stock_rows {
+            if remaining <= f64::EPSILON {
+                break;
+            }
+            let Some(factor) = unit_factor(&stock_unit, &requirement.unit) else {
+                continue;
+            };
+            let take = (available * factor).min(remaining);
+            sqlx::query(
+                "UPDATE inventory_items
+                 SET quantity = MAX(quantity - ?, 0), updated_at = CURRENT_TIMESTAMP
+                 WHERE id = ?"
+            )
+            .bind(take / factor)
+            .bind(stock_id)
+            .execute(&mut *tx)
+            .await?;
+            remaining -= take;
+        }
+
+        let deducted = requirement.total_quantity - remaining.max(0.0);
+        if deducted > 0.0 {
+            items.push(ConsumedItem {
+                ingredient_id: requirement.ingredient_id,
+                ingredient_name: requirement.ingredient_name,
+                unit: requirement.unit,
+                required: requirement.total_quantity,
+                deducted,
+            });
+        }
+    }
+
+    sqlx::query("INSERT INTO inventory_consumptions (camp_id, date) VALUES (?, ?)")
+        .bind(camp_id)
+        .bind(date)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(ConsumptionResult {
+        camp_id,
+        date,
+        items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    async fn empty_notes_clear_them() {
+        let pool = test_pool().await;
+        let flour = sqlx::query("INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Flour', 1, 'g')")
+            .execute(&pool)
+            .await
+            .unwrap()
+            .last_insert_rowid();
+        let item = CreateInventoryItem {
+            ingredient_id: flour,
+            camp_id: None,
+            storeroom: None,
+            quantity: 500.0,
+            unit: "g".to_string(),
+            notes: Some("Opened".to_string()),
+        };
+        let id = create_inventory_item(&pool, item).await.unwrap().id;
+
+        let keep = UpdateInventoryItem { quantity: Some(400.0), unit: None, storeroom: None, notes: None };
+        assert_eq!(update_inventory_item(&pool, id, keep).await.unwrap().notes.as_deref(), Some("Opened"));
+
+        let clear = UpdateInventoryItem { quantity: None, unit: None, storeroom: None, notes: Some(String::new()) };
+        assert_eq!(update_inventory_item(&pool, id, clear).await.unwrap().notes, None);
+    }
+}
@@ -1,15 +1,16 @@
+use crate::error::AppError;
 use crate::models::{
     MealPlan, PlannedMeal, MealAttendance, PlannedMealWithDetails,
-    CreatePlannedMeal, UpdatePlannedMeal, CreateAttendance,
+    CreatePlannedMeal, UpdatePlannedMeal, CreateAttendance, Validate, check, require_date_in_camp,
 };
 use chrono::NaiveDate;
-use sqlx::SqlitePool;
+use sqlx::{SqliteConnection, SqlitePool};
 
 pub async fn get_meal_plan(
     pool: &SqlitePool,
     camp_id: i64,
     date: NaiveDate,
-) -> Result<Option<MealPlan>, sqlx::Error> {
+) -> Result<Option<MealPlan>, AppError> {
     sqlx::query_as::<_, MealPlan>(
         "SELECT id, camp_id, date, created_at, updated_at 
          FROM meal_plans 
@@ -19,13 +20,14 @@ pub async fn get_meal_plan(
     .bind(date)
     .fetch_optional(pool)
     .await
+    .map_err(AppError::from)
 }
 
 async fn get_or_create_meal_plan(
     pool: &SqlitePool,
     camp_id: i64,
     date: NaiveDate,
-) -> Result<MealPlan, sqlx::Error> {
+) -> Result<MealPlan, AppError> {
     if let Some(plan) = get_meal_plan(pool, camp_id, date).await? {
         return Ok(plan);
     }
@@ -46,13 +48,14 @@ async fn get_or_create_meal_plan(
     .bind(result.last_insert_rowid())
     .fetch_one(pool)
     .await
+    .map_err(AppError::from)
 }
 
 pub async fn get_planned_meals_for_date(
     pool: &SqlitePool,
     camp_id: i64,
     date: NaiveDate,
-) -> Result<Vec<PlannedMealWithDetails>, sqlx::Error> {
+) -> Result<Vec<PlannedMealWithDetails>, AppError> {
     use sqlx::Row;
     
     let rows = sqlx::query(
@@ -118,7 +121,7 @@ pub async fn get_planned_meals_for_date(
 pub async fn get_planned_meals_for_camp(
     pool: &SqlitePool,
     camp_id: i64,
-) -> Result<Vec<(NaiveDate, Vec<PlannedMealWithDetails>)>, sqlx::Error> {
+) -> Result<Vec<(NaiveDate, Vec<PlannedMealWithDetails>)>, AppError> {
     let meal_plans = sqlx::query_as::<_, MealPlan>(
         "SELECT id, camp_id, date, created_at, updated_at 
          FROM meal_plans 
@@ -138,10 +141,34 @@ pub async fn get_planned_meals_for_camp(
     Ok(result)
 }
 
+/// A single planned meal with its recipe name and attendance
+pub async fn get_planned_meal(pool: &SqlitePool, id: i64) -> Result<PlannedMealWithDetails, AppError> {
+    let (camp_id, date): (i64, NaiveDate) = sqlx::query_as(
+        "SELECT mp.camp_id, mp.date
+         FROM planned_meals pm
+         JOIN meal_plans mp ON pm.meal_plan_id = mp.id
+         WHERE pm.id = ?"
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await?;
+
+    get_planned_meals_for_date(pool, camp_id, date)
+        .await?
+        .into_iter()
+        .find(|m| m.planned_meal.id == id)
+        .ok_or(AppError::NotFound)
+}
+
 pub async fn create_planned_meal(
     pool: &SqlitePool,
     meal: CreatePlannedMeal,
-) -> Result<PlannedMealWithDetails, sqlx::Error> {
+) -> Result<PlannedMealWithDetails, AppError> {
+    meal.validate()?;
+    let camp = super::camps::get_camp(pool, meal.camp_id).await?;
+    check(require_date_in_camp("date", meal.date, &camp).into_iter().collect())?;
+    require_plannable_recipe(&mut *pool.acquire().await?, meal.recipe_id).await?;
+
     let meal_plan = get_or_create_meal_plan(pool, meal.camp_id, meal.date).await?;
 
     let result = sqlx::query(
@@ -164,15 +191,28 @@ pub async fn create_planned_meal(
     let meals = get_planned_meals_for_date(pool, meal.camp_id, meal.date).await?;
     meals.into_iter()
         .find(|m| m.planned_meal.id == planned_meal_id)
-        .ok_or_else(|| sqlx::Error::RowNotFound)
+        .ok_or(AppError::NotFound)
 }
 
 pub async fn update_planned_meal(
     pool: &SqlitePool,
     id: i64,
     update: UpdatePlannedMeal,
-) -> Result<(), sqlx::Error> {
+) -> Result<(), AppError> {
+    if let Some(attendance) = &update.attendance {
+        attendance.validate()?;
+    }
+
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM planned_meals WHERE id = ?)")
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+    if !exists {
+        return Err(AppError::NotFound);
+    }
+
     if let Some(recipe_id) = update.recipe_id {
+        require_plannable_recipe(&mut *pool.acquire().await?, recipe_id).await?;
         sqlx::query("UPDATE planned_meals SET recipe_id = ? WHERE id = ?")
             .bind(recipe_id)
             .bind(id)
@@ -187,28 +227,28 @@ pub async fn update_planned_meal(
     Ok(())
 }
 
+/// Meals can only be planned with recipes that exist and are not archived
+pub(crate) async fn require_plannable_recipe(conn: &mut SqliteConnection, recipe_id: i64) -> Result<(), AppError> {
+    let plannable: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM recipes WHERE id = ? AND archived_at IS NULL)"
+    )
+    .bind(recipe_id)
+    .fetch_one(conn)
+    .await?;
+
+    if plannable {
+        Ok(())
+    } else {
+        Err(AppError::validation("recipe_id", "validation-recipe-not-plannable"))
+    }
+}
+
+/// Callers validate the attendance first
 async fn create_or_update_attendance(
     pool: &SqlitePool,
     planned_meal_id: i64,
     attendance: CreateAttendance,
-) -> Result<(), sqlx::Error> {
-    // Validate attendance counts are non-negative
-    if attendance.children < 0 {
-        return Err(sqlx::Error::Decode(
-            "Number of children cannot be negative".into()
-        ));
-    }
-    if attendance.teens < 0 {
-        return Err(sqlx::Error::Decode(
-            "Number of teens cannot be negative".into()
-        ));
-    }
-    if attendance.adults < 0 {
-        return Err(sqlx::Error::Decode(
-            "Number of adults cannot be negative".into()
-        ));
-    }
-
+) -> Result<(), AppError> {
     sqlx::query(
         "INSERT INTO meal_attendance (planned_meal_id, children, teens, adults)
          VALUES (?, ?, ?, ?)
@@ -228,11 +268,14 @@ async fn create_or_update_attendance(
     Ok(())
 }
 
-pub async fn delete_planned_meal(pool: &SqlitePool, id: i64) -> Result<(), sqlx::Error> {
-    sqlx::query("DELETE FROM planned_meals WHERE id = ?")
+pub async fn delete_planned_meal(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    let result = sqlx::query("DELETE FROM planned_meals WHERE id = ?")
         .bind(id)
         .execute(pool)
         .await?;
 
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound);
+    }
     Ok(())
 }
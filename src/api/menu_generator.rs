@@ -0,0 +1,338 @@
+use crate::error::{AppError, Message};
+use crate::models::{
+    check, require_date_in_camp, GeneratedDay, GeneratedMeal, GeneratedMenu, MealType,
+    MenuGeneratorSettings, MenuSlotRule, UnfilledSlot, COOK_INTENSIVE_TAG, HOT_TAG,
+};
+use super::meal_plans::require_plannable_recipe;
+use super::recipe_search::load_recipe_facts;
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+/// SplitMix64, so that a seed gives the same menu on every platform and version
+struct SeededRng(u64);
+
+impl SeededRng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// What the generator needs to know about one recipe
+struct Candidate {
+    id: i64,
+    name: String,
+    tags: Vec<String>,
+    allergens: Vec<String>,
+    cost_per_serving: Option<f64>,
+}
+
+impl Candidate {
+    fn fits_slot(&self, slot: &MenuSlotRule) -> bool {
+        (slot.tags.is_empty() || slot.tags.iter().any(|tag| self.tags.contains(tag)))
+            && (!slot.hot || self.is_tagged(HOT_TAG))
+    }
+
+    fn is_tagged(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+/// Propose recipes for the empty meal slots of a camp.
+///
+/// Days are filled in order and each slot picks a random recipe among those
+/// that satisfy all constraints, so a seed always gives the same menu for the
+/// same recipes and plan. Meals already planned stay and count towards the
+/// repeat, budget and cook-intensive limits. Nothing is saved; see
+/// `accept_generated_day`.
+pub async fn generate_menu(
+    pool: &SqlitePool,
+    camp_id: i64,
+    settings: &MenuGeneratorSettings,
+) -> Result<GeneratedMenu, AppError> {
+    if settings.slots.is_empty() {
+        return Err(AppError::validation("slots", "validation-select-meal-slot"));
+    }
+    if settings.max_cost_per_day.is_some_and(|cost| cost < 0.0) {
+        return Err(AppError::validation("max_cost_per_day", "validation-budget-negative"));
+    }
+
+    let camp = super::camps::get_camp(pool, camp_id).await?;
+
+    let excluded_allergens: Vec<String> = settings.excluded_allergens.iter()
+        .map(|allergen| allergen.trim().to_lowercase())
+        .collect();
+    let mut slots: Vec<MenuSlotRule> = Vec::new();
+    for slot in &settings.slots {
+        if !slots.iter().any(|s| s.meal_type == slot.meal_type) {
+            slots.push(MenuSlotRule {
+                meal_type: slot.meal_type,
+                tags: slot.tags.iter().map(|tag| tag.trim().to_lowercase()).filter(|tag| !tag.is_empty()).collect(),
+                hot: slot.hot,
+            });
+        }
+    }
+    slots.sort_by_key(|slot| slot.meal_type.sort_order());
+
+    let mut facts = load_recipe_facts(pool, None).await?;
+    let mut candidates: Vec<Candidate> = super::recipes::get_recipes(pool, false)
+        .await?
+        .into_iter()
+        .map(|recipe| {
+            let recipe_facts = facts.remove(&recipe.id).unwrap_or_default();
+            Candidate {
+                id: recipe.id,
+                tags: recipe.tag_list(),
+                name: recipe.name,
+                allergens: recipe_facts.allergens,
+                cost_per_serving: recipe_facts.cost_per_serving,
+            }
+        })
+        .collect();
+    candidates.sort_by_key(|candidate| candidate.id);
+    let by_id: HashMap<i64, usize> = candidates.iter().enumerate().map(|(index, c)| (c.id, index)).collect();
+
+    // Dates each recipe is served on, planned and generated
+    let mut served: HashMap<i64, Vec<NaiveDate>> = HashMap::new();
+    let mut planned: HashMap<NaiveDate, Vec<(String, i64)>> = HashMap::new();
+    for (date, meals) in super::meal_plans::get_planned_meals_for_camp(pool, camp_id).await? {
+        for meal in meals {
+            served.entry(meal.planned_meal.recipe_id).or_default().push(date);
+            planned.entry(date).or_default().push((meal.planned_meal.meal_type, meal.planned_meal.recipe_id));
+        }
+    }
+
+    let recently_served = |served: &HashMap<i64, Vec<NaiveDate>>, recipe_id: i64, date: NaiveDate| {
+        served.get(&recipe_id).is_some_and(|dates| {
+            dates.iter().any(|other| {
+                let days = (date - *other).num_days().abs();
+                days == 0 || days <= i64::from(settings.no_repeat_days)
+            })
+        })
+    };
+
+    let mut rng = SeededRng(settings.seed);
+    let mut days = Vec::new();
+
+    for date in camp.start_date.iter_days().take_while(|date| *date <= camp.end_date) {
+        let existing = planned.remove(&date).unwrap_or_default();
+        let empty_slots: Vec<&MenuSlotRule> = slots.iter()
+            .filter(|slot| !existing.iter().any(|(meal_type, _)| meal_type == slot.meal_type.as_str()))
+            .collect();
+        if empty_slots.is_empty() {
+            continue;
+        }
+
+        let existing_recipes: Vec<&Candidate> = existing.iter()
+            .filter_map(|(_, recipe_id)| by_id.get(recipe_id).map(|index| &candidates[*index]))
+            .collect();
+        // `day_cost` is shown and unknown once a recipe has no price; the budget
+        // counts what is known
+        let mut day_cost: Option<f64> = existing_recipes.iter().map(|c| c.cost_per_serving).sum();
+        let mut spent: f64 = existing_recipes.iter().filter_map(|c| c.cost_per_serving).sum();
+        let mut cook_intensive = existing_recipes.iter().filter(|c| c.is_tagged(COOK_INTENSIVE_TAG)).count() as u32;
+        let mut meals = Vec::new();
+        let mut unfilled = Vec::new();
+
+        for (index, slot) in empty_slots.iter().enumerate() {
+            let mut options: Vec<&Candidate> = candidates.iter().filter(|c| c.fits_slot(slot)).collect();
+            let mut reason = None;
+
+            if options.is_empty() {
+                reason = Some(match (slot.tags.is_empty(), slot.hot) {
+                    (true, false) => Message::new("menu-unfilled-no-recipes"),
+                    (true, true) => Message::new("menu-unfilled-no-tag").with_arg("tags", HOT_TAG),
+                    (false, false) => Message::new("menu-unfilled-no-tag").with_arg("tags", slot.tags.join(", ")),
+                    (false, true) => Message::new("menu-unfilled-no-hot-tag").with_arg("tags", slot.tags.join(", ")),
+                });
+            }
+            if reason.is_none() {
+                options.retain(|c| !c.allergens.iter().any(|allergen| excluded_allergens.contains(allergen)));
+                if options.is_empty() {
+                    reason = Some(Message::new("menu-unfilled-allergens"));
+                }
+            }
+            if reason.is_none() {
+                options.retain(|c| !recently_served(&served, c.id, date));
+                if options.is_empty() {
+                    reason = Some(Message::new("menu-unfilled-repeated").with_arg("days", settings.no_repeat_days));
+                }
+            }
+            if reason.is_none() && settings.max_cook_intensive_per_day.is_some_and(|max| cook_intensive >= max) {
+                options.retain(|c| !c.is_tagged(COOK_INTENSIVE_TAG));
+                if options.is_empty() {
+                    reason = Some(Message::new("menu-unfilled-cook-intensive"));
+                }
+            }
+            if let (None, Some(max_cost)) = (&reason, settings.max_cost_per_day) {
+                // Keep enough of the budget for the cheapest recipe of each later slot
+                let reserve: f64 = empty_slots[index + 1..].iter()
+                    .filter_map(|later| {
+                        candidates.iter()
+                            .filter(|c| c.fits_slot(later))
+                            .filter_map(|c| c.cost_per_serving)
+                            .min_by(f64::total_cmp)
+                    })
+                    .sum();
+                options.retain(|c| c.cost_per_serving.is_some_and(|cost| spent + cost + reserve <= max_cost + 1e-9));
+                if options.is_empty() {
+                    reason = Some(Message::new("menu-unfilled-budget"));
+                }
+            }
+
+            if let Some(reason) = reason {
+                unfilled.push(UnfilledSlot { meal_type: slot.meal_type, reason });
+                continue;
+            }
+
+            let pick = options[rng.below(options.len())];
+            served.entry(pick.id).or_default().push(date);
+            day_cost = day_cost.zip(pick.cost_per_serving).map(|(total, cost)| total + cost);
+            spent += pick.cost_per_serving.unwrap_or(0.0);
+            if pick.is_tagged(COOK_INTENSIVE_TAG) {
+                cook_intensive += 1;
+            }
+            meals.push(GeneratedMeal {
+                meal_type: slot.meal_type,
+                recipe_id: pick.id,
+                recipe_name: pick.name.clone(),
+                cost_per_serving: pick.cost_per_serving,
+            });
+        }
+
+        days.push(GeneratedDay { date, meals, unfilled, cost_per_serving: day_cost });
+    }
+
+    Ok(GeneratedMenu { camp_id, seed: settings.seed, days })
+}
+
+/// Save the proposed meals of one day. Slots that were planned in the meantime
+/// are skipped. Returns the number of meals added.
+///
+/// The day must be within the camp and the recipes plannable, as for
+/// `create_planned_meal`.
+pub async fn accept_generated_day(
+    pool: &SqlitePool,
+    camp_id: i64,
+    day: &GeneratedDay,
+) -> Result<usize, AppError> {
+    let camp = super::camps::get_camp(pool, camp_id).await?;
+    check(require_date_in_camp("date", day.date, &camp).into_iter().collect())?;
+
+    let mut tx = pool.begin().await?;
+    for meal in &day.meals {
+        require_plannable_recipe(&mut tx, meal.recipe_id).await?;
+    }
+
+    sqlx::query("INSERT INTO meal_plans (camp_id, date) VALUES (?, ?) ON CONFLICT(camp_id, date) DO NOTHING")
+        .bind(camp_id)
+        .bind(day.date)
+        .execute(&mut *tx)
+        .await?;
+    let meal_plan_id: i64 = sqlx::query_scalar("SELECT id FROM meal_plans WHERE camp_id = ? AND date = ?")
+        .bind(camp_id)
+        .bind(day.date)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    let planned: Vec<String> = sqlx::query_scalar("SELECT meal_type FROM planned_meals WHERE meal_plan_id = ?")
+        .bind(meal_plan_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+    let mut added = 0;
+    let mut added_types: Vec<MealType> = Vec::new();
+    for meal in &day.meals {
+        if planned.iter().any(|meal_type| meal_type == meal.meal_type.as_str()) || added_types.contains(&meal.meal_type) {
+            continue;
+        }
+        sqlx::query("INSERT INTO planned_meals (meal_plan_id, recipe_id, meal_type) VALUES (?, ?, ?)")
+            .bind(meal_plan_id)
+            .bind(meal.recipe_id)
+            .bind(meal.meal_type.as_str())
+            .execute(&mut *tx)
+            .await?;
+        added_types.push(meal.meal_type);
+        added += 1;
+    }
+
+    tx.commit().await?;
+    Ok(added)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{camps::create_camp, recipes::create_recipe};
+    use crate::db::test_pool;
+    use crate::models::{CreateCamp, CreateRecipe};
+
+    async fn seed_camp(pool: &SqlitePool) -> i64 {
+        for (name, tags) in [
+            ("Porridge", "breakfast"),
+            ("Pancakes", "breakfast"),
+            ("Bread rolls", "breakfast"),
+            ("Apple", "snack"),
+            ("Yoghurt", "snack"),
+            ("Goulash", "lunch, hot"),
+            ("Risotto", "lunch, hot"),
+            ("Soup", "lunch, dinner, hot"),
+            ("Pasta", "dinner, hot"),
+            ("Sandwiches", "dinner"),
+        ] {
+            let recipe = CreateRecipe {
+                name: name.to_string(),
+                instructions: None,
+                base_servings: 4,
+                tags: tags.to_string(),
+                ingredients: Vec::new(),
+            };
+            create_recipe(pool, recipe).await.unwrap();
+        }
+
+        let camp = CreateCamp {
+            name: "Summer".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 7, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 7, 7).unwrap(),
+            default_children: 10,
+            default_teens: 5,
+            default_adults: 2,
+            notes: None,
+        };
+        create_camp(pool, camp).await.unwrap().id
+    }
+
+    #[tokio::test]
+    async fn same_seed_gives_same_menu() {
+        let pool = test_pool().await;
+        let camp_id = seed_camp(&pool).await;
+        let settings = MenuGeneratorSettings { seed: 42, ..Default::default() };
+
+        let first = generate_menu(&pool, camp_id, &settings).await.unwrap();
+        let second = generate_menu(&pool, camp_id, &settings).await.unwrap();
+
+        assert_eq!(first.days.len(), 7);
+        assert_eq!(first, second);
+    }
+
+    #[tokio::test]
+    async fn accepting_a_day_outside_the_camp_fails() {
+        let pool = test_pool().await;
+        let camp_id = seed_camp(&pool).await;
+        let menu = generate_menu(&pool, camp_id, &MenuGeneratorSettings::default()).await.unwrap();
+
+        let mut day = menu.days[0].clone();
+        day.date = NaiveDate::from_ymd_opt(2026, 8, 1).unwrap();
+
+        assert!(matches!(accept_generated_day(&pool, camp_id, &day).await, Err(AppError::Validation(_))));
+        assert_eq!(accept_generated_day(&pool, camp_id, &menu.days[0]).await.unwrap(), menu.days[0].meals.len());
+    }
+}
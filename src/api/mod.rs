@@ -4,6 +4,19 @@ pub mod recipes;
 pub mod camps;
 pub mod meal_plans;
 pub mod reports;
+pub mod inventory;
+pub mod suppliers;
+pub mod transfer;
+pub mod yaml_import;
+pub mod recipe_formats;
+pub mod ingredient_merge;
+pub mod recipe_search;
+pub mod stock_finder;
+pub mod menu_generator;
+pub mod plan_check;
+pub mod api_tokens;
+pub mod usage;
+pub mod trash;
 
 pub use categories::*;
 pub use ingredients::*;
@@ -11,3 +24,26 @@ pub use recipes::*;
 pub use camps::*;
 pub use meal_plans::*;
 pub use reports::*;
+pub use inventory::*;
+pub use suppliers::*;
+pub use transfer::*;
+pub use yaml_import::*;
+pub use recipe_formats::*;
+pub use ingredient_merge::*;
+pub use recipe_search::*;
+pub use stock_finder::*;
+pub use menu_generator::*;
+pub use plan_check::*;
+pub use api_tokens::*;
+pub use usage::*;
+pub use trash::*;
+
+/// New value of an optional text column in an update: `None` keeps `current`,
+/// an empty string clears it
+pub(crate) fn patch_text(update: Option<String>, current: Option<String>) -> Option<String> {
+    match update {
+        Some(text) if text.trim().is_empty() => None,
+        Some(text) => Some(text),
+        None => current,
+    }
+}
@@ -0,0 +1,144 @@
+use crate::error::{AppError, Message};
+use crate::models::{normalize_name, MealType, PlanCheck, PlanWarning, PlanWarningKind, PRODUCE_CATEGORY_WORDS};
+use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
+
+/// Per recipe: whether it has ingredients, and whether one of them is vegetables or fruit
+async fn recipe_contents(pool: &SqlitePool) -> Result<HashMap<i64, bool>, AppError> {
+    let rows: Vec<(i64, String)> = sqlx::query_as(
+        "SELECT ri.recipe_id, c.name
+         FROM recipe_ingredients ri
+         JOIN ingredients i ON ri.ingredient_id = i.id
+         JOIN categories c ON i.category_id = c.id"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut contents: HashMap<i64, bool> = HashMap::new();
+    for (recipe_id, category) in rows {
+        let category = normalize_name(&category);
+        let produce = PRODUCE_CATEGORY_WORDS.iter().any(|word| category.contains(word));
+        *contents.entry(recipe_id).or_default() |= produce;
+    }
+    Ok(contents)
+}
+
+/// Check the plan of a camp for meals served twice in a row, days without
+/// vegetables or fruit, meals without attendance, empty meal slots and
+/// recipes without ingredients.
+pub async fn check_camp_plan(pool: &SqlitePool, camp_id: i64) -> Result<PlanCheck, AppError> {
+    let contents = recipe_contents(pool).await?;
+    check_with_contents(pool, camp_id, &contents).await
+}
+
+/// `check_camp_plan` for every camp, in the order of the camp list
+pub async fn check_all_camp_plans(pool: &SqlitePool) -> Result<Vec<PlanCheck>, AppError> {
+    let contents = recipe_contents(pool).await?;
+    let mut checks = Vec::new();
+    for camp in super::camps::get_camps(pool, false).await? {
+        checks.push(check_with_contents(pool, camp.id, &contents).await?);
+    }
+    Ok(checks)
+}
+
+async fn check_with_contents(
+    pool: &SqlitePool,
+    camp_id: i64,
+    contents: &HashMap<i64, bool>,
+) -> Result<PlanCheck, AppError> {
+    let camp = super::camps::get_camp(pool, camp_id).await?;
+    let planned: HashMap<_, _> = super::meal_plans::get_planned_meals_for_camp(pool, camp_id)
+        .await?
+        .into_iter()
+        .collect();
+
+    let mut warnings = Vec::new();
+    let mut previous_recipe: Option<i64> = None;
+    let mut dates: Vec<_> = camp.start_date.iter_days().take_while(|date| *date <= camp.end_date).collect();
+    // Meals planned outside the camp dates are checked as well
+    for date in planned.keys() {
+        if !dates.contains(date) {
+            dates.push(*date);
+        }
+    }
+    dates.sort();
+
+    for date in dates {
+        let mut meals = planned.get(&date).cloned().unwrap_or_default();
+        meals.sort_by_key(|meal| {
+            MealType::from_str(&meal.planned_meal.meal_type)
+                .map(|meal_type| meal_type.sort_order())
+                .unwrap_or(99)
+        });
+
+        let planned_types: HashSet<&str> = meals.iter().map(|meal| meal.planned_meal.meal_type.as_str()).collect();
+        let empty: Vec<MealType> = MealType::all()
+            .into_iter()
+            .filter(|meal_type| !planned_types.contains(meal_type.as_str()))
+            .collect();
+        if meals.is_empty() {
+            warnings.push(PlanWarning {
+                kind: PlanWarningKind::EmptySlots,
+                date,
+                meal_type: None,
+                message: Message::new("plan-no-meals"),
+            });
+            previous_recipe = None;
+            continue;
+        }
+        for meal_type in empty {
+            warnings.push(PlanWarning {
+                kind: PlanWarningKind::EmptySlots,
+                date,
+                meal_type: Some(meal_type),
+                message: Message::new("plan-nothing-planned"),
+            });
+        }
+
+        let mut has_produce = false;
+        for meal in &meals {
+            let meal_type = MealType::from_str(&meal.planned_meal.meal_type);
+            let recipe_id = meal.planned_meal.recipe_id;
+
+            if previous_recipe == Some(recipe_id) {
+                warnings.push(PlanWarning {
+                    kind: PlanWarningKind::RepeatedRecipe,
+                    date,
+                    meal_type,
+                    message: Message::new("plan-repeated-recipe").with_arg("recipe", &meal.recipe_name),
+                });
+            }
+            previous_recipe = Some(recipe_id);
+
+            match contents.get(&recipe_id) {
+                Some(produce) => has_produce |= produce,
+                None => warnings.push(PlanWarning {
+                    kind: PlanWarningKind::EmptyRecipe,
+                    date,
+                    meal_type,
+                    message: Message::new("plan-empty-recipe").with_arg("recipe", &meal.recipe_name),
+                }),
+            }
+
+            if meal.attendance.is_none() {
+                warnings.push(PlanWarning {
+                    kind: PlanWarningKind::MissingAttendance,
+                    date,
+                    meal_type,
+                    message: Message::new("plan-missing-attendance").with_arg("recipe", &meal.recipe_name),
+                });
+            }
+        }
+
+        if !has_produce {
+            warnings.push(PlanWarning {
+                kind: PlanWarningKind::NoProduce,
+                date,
+                meal_type: None,
+                message: Message::new("plan-no-produce"),
+            });
+        }
+    }
+
+    Ok(PlanCheck { camp_id, warnings })
+}
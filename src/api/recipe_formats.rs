@@ -0,0 +1,527 @@
+use crate::error::{AppError, FieldError};
+use crate::models::{
+    normalize_name, CreateRecipe, CreateRecipeIngredient, IngredientResolution, ParsedIngredient, ParsedRecipe,
+    RecipeFormat, RecipeImportPreview, RecipeWithIngredients, Validate,
+};
+use serde_json::{json, Value};
+use sqlx::{SqliteConnection, SqlitePool};
+use std::collections::HashMap;
+
+/// Units recognised after the quantity in free-text ingredient lines
+const KNOWN_UNITS: &[&str] = &[
+    "g", "kg", "mg", "ml", "dl", "l", "ks", "pcs", "pc", "pieces", "piece", "cup", "cups",
+    "tbsp", "tsp", "lb", "lbs", "oz", "pinch", "clove", "cloves", "can", "cans", "bal", "balení",
+    "lžíce", "lžička", "hrnek", "stroužek", "špetka",
+];
+
+/// schema.org `Recipe` as JSON-LD. Quantities are for `base_servings` adults.
+pub fn to_json_ld(recipe: &RecipeWithIngredients) -> Value {
+    let ingredients: Vec<String> = recipe.ingredients.iter()
+        .map(|item| {
+            let ri = &item.recipe_ingredient;
+            format!("{} {} {}", format_quantity(ri.base_quantity), ri.unit, item.ingredient_name)
+        })
+        .collect();
+
+    let steps: Vec<Value> = instruction_steps(recipe.recipe.instructions.as_deref())
+        .into_iter()
+        .map(|text| json!({ "@type": "HowToStep", "text": text }))
+        .collect();
+
+    json!({
+        "@context": "https://schema.org",
+        "@type": "Recipe",
+        "name": recipe.recipe.name,
+        "recipeYield": format!("{} servings", recipe.recipe.base_servings),
+        "keywords": recipe.recipe.tags,
+        "recipeIngredient": ingredients,
+        "recipeInstructions": steps,
+    })
+}
+
+/// Cooklang text. Ingredients are marked where the instructions mention them;
+/// the others are listed in a first step.
+pub fn to_cooklang(recipe: &RecipeWithIngredients) -> String {
+    let mut out = format!(
+        ">> title: {}\n>> servings: {}\n",
+        recipe.recipe.name, recipe.recipe.base_servings
+    );
+    if !recipe.recipe.tags.is_empty() {
+        out.push_str(&format!(">> tags: {}\n", recipe.recipe.tags));
+    }
+    out.push('\n');
+
+    let mut steps = instruction_steps(recipe.recipe.instructions.as_deref());
+    let mut unmentioned = Vec::new();
+
+    for item in &recipe.ingredients {
+        let ri = &item.recipe_ingredient;
+        let tag = format!("@{}{{{}%{}}}", item.ingredient_name, format_quantity(ri.base_quantity), ri.unit);
+        let name = item.ingredient_name.to_lowercase();
+
+        let position = steps.iter().enumerate().find_map(|(index, step)| {
+            find_case_insensitive(step, &name).map(|start| (index, start))
+        });
+        match position {
+            Some((index, start)) => {
+                let step = &mut steps[index];
+                step.replace_range(start..start + name.len(), &tag);
+            }
+            None => unmentioned.push(tag),
+        }
+    }
+
+    if !unmentioned.is_empty() {
+        steps.insert(0, format!("Prepare {}.", unmentioned.join(", ")));
+    }
+
+    out.push_str(&steps.join("\n\n"));
+    out.push('\n');
+    out
+}
+
+/// Byte offset of `needle` (already lowercase) in `haystack`, ignoring case,
+/// as long as lowercasing keeps byte lengths
+fn find_case_insensitive(haystack: &str, needle: &str) -> Option<usize> {
+    let lower = haystack.to_lowercase();
+    if lower.len() != haystack.len() {
+        return haystack.find(needle);
+    }
+    lower.find(needle)
+}
+
+fn instruction_steps(instructions: Option<&str>) -> Vec<String> {
+    instructions
+        .unwrap_or("")
+        .split("\n\n")
+        .flat_map(|paragraph| paragraph.lines())
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+fn format_quantity(quantity: f64) -> String {
+    let rounded = (quantity * 1000.0).round() / 1000.0;
+    if rounded.fract() == 0.0 {
+        format!("{}", rounded as i64)
+    } else {
+        format!("{}", rounded)
+    }
+}
+
+pub fn parse_recipe(content: &str, format: RecipeFormat) -> Result<ParsedRecipe, String> {
+    match format {
+        RecipeFormat::JsonLd => parse_json_ld(content),
+        RecipeFormat::Cooklang => Ok(parse_cooklang(content)),
+    }
+}
+
+/// Read the first schema.org `Recipe` in a JSON-LD document; plain objects,
+/// arrays and `@graph` wrappers are all accepted
+pub fn parse_json_ld(content: &str) -> Result<ParsedRecipe, String> {
+    let document: Value = serde_json::from_str(content).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let recipe = find_recipe(&document).ok_or("No schema.org Recipe found in the document")?;
+
+    let name = recipe.get("name")
+        .and_then(Value::as_str)
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .ok_or("The recipe has no name")?;
+
+    let servings = match recipe.get("recipeYield") {
+        Some(Value::Array(values)) => values.iter().find_map(yield_servings),
+        Some(value) => yield_servings(value),
+        None => None,
+    }
+    .unwrap_or(1);
+
+    let ingredients = recipe.get("recipeIngredient")
+        .or_else(|| recipe.get("ingredients"))
+        .and_then(Value::as_array)
+        .map(|lines| {
+            lines.iter()
+                .filter_map(Value::as_str)
+                .map(parse_ingredient_text)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Keywords are either one comma-separated string or a list
+    let tags = match recipe.get("keywords") {
+        Some(Value::String(keywords)) => keywords.clone(),
+        Some(Value::Array(keywords)) => keywords.iter().filter_map(Value::as_str).collect::<Vec<_>>().join(", "),
+        _ => String::new(),
+    };
+
+    let mut steps = Vec::new();
+    if let Some(instructions) = recipe.get("recipeInstructions") {
+        collect_steps(instructions, &mut steps);
+    }
+
+    Ok(ParsedRecipe {
+        name,
+        servings,
+        tags,
+        instructions: (!steps.is_empty()).then(|| steps.join("\n\n")),
+        ingredients,
+    })
+}
+
+fn find_recipe(value: &Value) -> Option<&Value> {
+    match value {
+        Value::Array(items) => items.iter().find_map(find_recipe),
+        Value::Object(map) => {
+            let is_recipe = match map.get("@type") {
+                Some(Value::String(kind)) => kind == "Recipe",
+                Some(Value::Array(kinds)) => kinds.iter().any(|kind| kind == "Recipe"),
+                _ => false,
+            };
+            if is_recipe {
+                Some(value)
+            } else {
+                map.get("@graph").and_then(find_recipe)
+            }
+        }
+        _ => None,
+    }
+}
+
+fn yield_servings(value: &Value) -> Option<i32> {
+    match value {
+        Value::Number(n) => n.as_f64().map(|n| n.round() as i32),
+        Value::String(s) => s.split_whitespace().find_map(|word| word.parse::<i32>().ok()),
+        _ => None,
+    }
+    .filter(|n| *n > 0)
+}
+
+fn collect_steps(value: &Value, steps: &mut Vec<String>) {
+    match value {
+        Value::String(text) => steps.extend(
+            text.lines().map(str::trim).filter(|line| !line.is_empty()).map(String::from)
+        ),
+        Value::Array(items) => items.iter().for_each(|item| collect_steps(item, steps)),
+        Value::Object(map) => {
+            if let Some(items) = map.get("itemListElement") {
+                collect_steps(items, steps);
+            } else if let Some(text) = map.get("text").or_else(|| map.get("name")) {
+                collect_steps(text, steps);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Split a free-text line like `1 1/2 kg potatoes` into quantity, unit and name
+pub fn parse_ingredient_text(line: &str) -> ParsedIngredient {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let mut index = 0;
+    let mut quantity = None;
+
+    while let Some(value) = words.get(index).and_then(|word| parse_number(word)) {
+        quantity = Some(quantity.unwrap_or(0.0) + value);
+        index += 1;
+    }
+
+    let mut unit = None;
+    if let Some(word) = words.get(index).filter(|_| quantity.is_some()) {
+        let candidate = word.trim_end_matches('.').to_lowercase();
+        if KNOWN_UNITS.contains(&candidate.as_str()) {
+            unit = Some(candidate);
+            index += 1;
+        }
+    }
+
+    let name = words[index.min(words.len())..].join(" ");
+    let name = name.trim_start_matches("of ").trim().to_string();
+
+    ParsedIngredient {
+        name: if name.is_empty() { line.trim().to_string() } else { name },
+        quantity,
+        unit,
+        raw: line.trim().to_string(),
+    }
+}
+
+fn parse_number(word: &str) -> Option<f64> {
+    let word = word.replace(',', ".");
+    if let Some((numerator, denominator)) = word.split_once('/') {
+        let numerator: f64 = numerator.parse().ok()?;
+        let denominator: f64 = denominator.parse().ok()?;
+        return (denominator != 0.0).then(|| numerator / denominator);
+    }
+    match word.as_str() {
+        "½" => Some(0.5),
+        "¼" => Some(0.25),
+        "¾" => Some(0.75),
+        _ => word.parse().ok().filter(|n: &f64| n.is_finite()),
+    }
+}
+
+/// Parse Cooklang: `>> key: value` metadata, `@name{qty%unit}` ingredients,
+/// `#cookware{}` and `~{time}` markup, `--` and `[- -]` comments
+pub fn parse_cooklang(content: &str) -> ParsedRecipe {
+    let mut name = None;
+    let mut servings = None;
+    let mut tags = String::new();
+    let mut ingredients: Vec<ParsedIngredient> = Vec::new();
+    let mut steps = Vec::new();
+
+    let content = strip_block_comments(content);
+
+    for paragraph in content.split("\n\n") {
+        let mut step_lines = Vec::new();
+
+        for line in paragraph.lines() {
+            let line = match line.find("--") {
+                Some(start) => &line[..start],
+                None => line,
+            }
+            .trim();
+
+            if let Some(meta) = line.strip_prefix(">>") {
+                if let Some((key, value)) = meta.split_once(':') {
+                    match key.trim().to_lowercase().as_str() {
+                        "title" => name = Some(value.trim().to_string()),
+                        "tags" => tags = value.trim().to_string(),
+                        "servings" | "serves" | "yield" => {
+                            servings = value.split_whitespace().find_map(|w| w.parse::<i32>().ok());
+                        }
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+            if line.is_empty() {
+                continue;
+            }
+
+            step_lines.push(parse_cooklang_line(line, &mut ingredients));
+        }
+
+        if !step_lines.is_empty() {
+            steps.push(step_lines.join(" "));
+        }
+    }
+
+    ParsedRecipe {
+        name: name.unwrap_or_default(),
+        servings: servings.filter(|n| *n > 0).unwrap_or(1),
+        tags,
+        instructions: (!steps.is_empty()).then(|| steps.join("\n\n")),
+        ingredients,
+    }
+}
+
+fn strip_block_comments(content: &str) -> String {
+    let mut out = String::new();
+    let mut rest = content;
+    while let Some(start) = rest.find("[-") {
+        out.push_str(&rest[..start]);
+        match rest[start..].find("-]") {
+            Some(end) => rest = &rest[start + end + 2..],
+            None => {
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Turn one step line into plain text, collecting its ingredients
+fn parse_cooklang_line(line: &str, ingredients: &mut Vec<ParsedIngredient>) -> String {
+    let mut text = String::new();
+    let mut rest = line;
+
+    while let Some(start) = rest.find(['@', '#', '~']) {
+        text.push_str(&rest[..start]);
+        let marker = rest[start..].chars().next().unwrap_or('@');
+        let body = &rest[start + 1..];
+
+        // Multi-word names end with `{...}`; single words end at the first non-word character
+        let brace = body.find('{');
+        let word_end = body.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-')).unwrap_or(body.len());
+        let (item_name, amount, consumed) = match brace {
+            Some(open) if !body[..open].contains(['@', '#', '~']) => match body[open..].find('}') {
+                Some(close) => (
+                    body[..open].trim(),
+                    Some(&body[open + 1..open + close]),
+                    open + close + 1,
+                ),
+                None => (&body[..word_end], None, word_end),
+            },
+            _ => (&body[..word_end], None, word_end),
+        };
+
+        if marker == '@' && !item_name.is_empty() {
+            let (quantity, unit) = match amount.map(str::trim).filter(|a| !a.is_empty()) {
+                Some(amount) => {
+                    let (quantity, unit) = amount.split_once('%').unwrap_or((amount, ""));
+                    let unit = unit.trim();
+                    (parse_number(quantity.trim()), (!unit.is_empty()).then(|| unit.to_string()))
+                }
+                None => (None, None),
+            };
+            ingredients.push(ParsedIngredient {
+                name: item_name.to_string(),
+                quantity,
+                unit,
+                raw: rest[start..start + 1 + consumed].to_string(),
+            });
+        }
+
+        match marker {
+            '~' => text.push_str(&amount.unwrap_or("").replace('%', " ")),
+            _ => text.push_str(item_name),
+        }
+        rest = &body[consumed..];
+    }
+
+    text.push_str(rest);
+    text
+}
+
+/// Parse an uploaded recipe and match its ingredients to existing ones by name
+pub async fn preview_recipe_import(
+    pool: &SqlitePool,
+    content: &str,
+    format: RecipeFormat,
+) -> Result<RecipeImportPreview, AppError> {
+    let recipe = parse_recipe(content, format).map_err(|e| AppError::invalid_file("content", e))?;
+    let names = ingredient_names(&mut *pool.acquire().await?).await?;
+
+    let matches = recipe.ingredients.iter()
+        .map(|ingredient| lookup_ingredient(&names, &ingredient.name))
+        .collect();
+
+    Ok(RecipeImportPreview { recipe, matches })
+}
+
+/// Ingredient IDs by lowercase name, normalized name and normalized alias
+async fn ingredient_names(conn: &mut SqliteConnection) -> Result<HashMap<String, i64>, AppError> {
+    let rows = sqlx::query_as::<_, (i64, String)>("SELECT id, name FROM ingredients")
+        .fetch_all(&mut *conn)
+        .await?;
+
+    let mut names: HashMap<String, i64> = rows.iter()
+        .map(|(id, name)| (name.to_lowercase(), *id))
+        .collect();
+    for (id, name) in rows {
+        names.entry(normalize_name(&name)).or_insert(id);
+    }
+
+    for (id, alias) in sqlx::query_as::<_, (i64, String)>("SELECT ingredient_id, normalized FROM ingredient_aliases")
+        .fetch_all(&mut *conn)
+        .await?
+    {
+        names.entry(alias).or_insert(id);
+    }
+
+    Ok(names)
+}
+
+fn lookup_ingredient(names: &HashMap<String, i64>, name: &str) -> Option<i64> {
+    names.get(&name.to_lowercase())
+        .or_else(|| names.get(&normalize_name(name)))
+        .copied()
+}
+
+/// Create the recipe with one resolution per parsed ingredient line.
+///
+/// New ingredients go to the `Other` category with the line's unit. They are
+/// created in the same transaction as the recipe, so a failed import leaves none
+/// behind.
+pub async fn import_recipe(
+    pool: &SqlitePool,
+    content: &str,
+    format: RecipeFormat,
+    name: String,
+    resolutions: Vec<IngredientResolution>,
+) -> Result<RecipeWithIngredients, AppError> {
+    let parsed = parse_recipe(content, format).map_err(|e| AppError::invalid_file("content", e))?;
+
+    if name.trim().is_empty() {
+        return Err(AppError::validation("name", "validation-name-required"));
+    }
+    if resolutions.len() != parsed.ingredients.len() {
+        return Err(AppError::Validation(vec![
+            FieldError::new("resolutions", "validation-resolutions-count")
+                .with_arg("expected", parsed.ingredients.len())
+                .with_arg("got", resolutions.len())
+        ]));
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let other_category: i64 = sqlx::query_scalar(
+        "SELECT id FROM categories ORDER BY CASE WHEN name = 'Other' THEN 0 ELSE 1 END, id LIMIT 1"
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let mut names = ingredient_names(&mut tx).await?;
+    let mut ingredients = Vec::new();
+    for (line, resolution) in parsed.ingredients.iter().zip(resolutions) {
+        let unit = line.unit.clone().unwrap_or_else(|| "pcs".to_string());
+
+        let ingredient_id = match resolution {
+            IngredientResolution::Skip => continue,
+            IngredientResolution::Existing(id) => {
+                let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM ingredients WHERE id = ?)")
+                    .bind(id)
+                    .fetch_one(&mut *tx)
+                    .await?;
+                if !exists {
+                    return Err(AppError::Validation(vec![
+                        FieldError::new("resolutions", "validation-resolution-unknown-ingredient")
+                            .with_arg("line", line.raw.clone())
+                    ]));
+                }
+                id
+            }
+            IngredientResolution::Create => {
+                // Several lines may name the same new ingredient
+                match lookup_ingredient(&names, &line.name) {
+                    Some(id) => id,
+                    None => {
+                        let id = sqlx::query("INSERT INTO ingredients (name, category_id, primary_unit) VALUES (?, ?, ?)")
+                            .bind(&line.name)
+                            .bind(other_category)
+                            .bind(&unit)
+                            .execute(&mut *tx)
+                            .await?
+                            .last_insert_rowid();
+                        names.insert(line.name.to_lowercase(), id);
+                        names.entry(normalize_name(&line.name)).or_insert(id);
+                        id
+                    }
+                }
+            }
+        };
+
+        ingredients.push(CreateRecipeIngredient {
+            ingredient_id,
+            base_quantity: line.quantity.filter(|q| *q > 0.0).unwrap_or(1.0),
+            unit,
+            child_multiplier: None,
+            teen_multiplier: None,
+            adult_multiplier: None,
+            notes: line.quantity.is_none().then(|| line.raw.clone()),
+        });
+    }
+
+    let recipe = CreateRecipe {
+        name: name.trim().to_string(),
+        instructions: parsed.instructions,
+        base_servings: parsed.servings.max(1),
+        tags: parsed.tags,
+        ingredients,
+    };
+    recipe.validate()?;
+
+    let recipe_id = super::recipes::insert_recipe(&mut tx, recipe).await?;
+    tx.commit().await?;
+
+    super::recipes::get_recipe_with_ingredients(pool, recipe_id).await
+}
@@ -0,0 +1,264 @@
+use crate::error::AppError;
+use crate::models::{
+    split_list, unit_factor, RecipeSearchHit, RecipeSearchPage, RecipeSearchQuery, RECIPE_SEARCH_MAX_PAGE_SIZE,
+    RECIPE_SEARCH_PAGE_SIZE,
+};
+use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
+
+/// What the filters need to know about one recipe line
+#[derive(sqlx::FromRow)]
+struct LineInfo {
+    recipe_id: i64,
+    base_servings: i32,
+    ingredient_id: i64,
+    base_quantity: f64,
+    unit: String,
+    primary_unit: String,
+    price_per_unit: Option<f64>,
+    allergens: String,
+}
+
+/// Full-text search over the `recipe_search` index with ingredient, cost and
+/// allergen filters. Text matches are ranked name first, then tags, ingredients
+/// and instructions; without text the recipes are listed by name.
+///
+/// Without filters the match and the page are cut in SQL. The filters need all
+/// lines of every matching recipe, so with filters the matches are filtered and
+/// paged in memory, which is fine for the few thousand recipes a kitchen keeps.
+pub async fn search_recipes(
+    pool: &SqlitePool,
+    query: &RecipeSearchQuery,
+) -> Result<RecipeSearchPage, AppError> {
+    let per_page = match query.per_page {
+        0 => RECIPE_SEARCH_PAGE_SIZE,
+        per_page => per_page.min(RECIPE_SEARCH_MAX_PAGE_SIZE),
+    };
+    let page = query.page.max(1);
+    let offset = (page - 1).saturating_mul(per_page);
+
+    let excluded_allergens: Vec<String> = query.free_of_allergens.iter().map(|a| a.trim().to_lowercase()).collect();
+    let filtered = !query.with_ingredients.is_empty()
+        || !query.without_ingredients.is_empty()
+        || !excluded_allergens.is_empty()
+        || query.max_cost_per_serving.is_some();
+
+    let fts = fts_query(&query.text);
+    let (from, order) = match fts {
+        Some(_) => (
+            "FROM recipe_search JOIN recipes r ON r.id = recipe_search.rowid
+             WHERE recipe_search MATCH ? AND r.archived_at IS NULL",
+            "bm25(recipe_search, 10.0, 1.0, 5.0, 3.0)",
+        ),
+        None => ("FROM recipes r WHERE r.archived_at IS NULL", "r.name"),
+    };
+
+    let (page_ids, total, mut facts) = if filtered {
+        let sql = format!("SELECT r.id {} ORDER BY {}", from, order);
+        let mut ids = sqlx::query_scalar::<_, i64>(&sql);
+        if let Some(fts) = &fts {
+            ids = ids.bind(fts);
+        }
+        let facts = load_recipe_facts(pool, None).await?;
+        let no_ingredients = RecipeFacts::default();
+        let matching: Vec<i64> = ids
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .filter(|id| {
+                let facts = facts.get(id).unwrap_or(&no_ingredients);
+                matches_filters(query, &excluded_allergens, facts)
+            })
+            .collect();
+
+        let total = matching.len() as u32;
+        let page_ids = matching.into_iter().skip(offset as usize).take(per_page as usize).collect();
+        (page_ids, total, facts)
+    } else {
+        let sql = format!("SELECT r.id {} ORDER BY {} LIMIT ? OFFSET ?", from, order);
+        let mut ids = sqlx::query_scalar::<_, i64>(&sql);
+        let count_sql = format!("SELECT COUNT(*) {}", from);
+        let mut count = sqlx::query_scalar::<_, i64>(&count_sql);
+        if let Some(fts) = &fts {
+            ids = ids.bind(fts);
+            count = count.bind(fts);
+        }
+        let page_ids: Vec<i64> = ids.bind(per_page).bind(offset).fetch_all(pool).await?;
+        let total = count.fetch_one(pool).await? as u32;
+        let facts = load_recipe_facts(pool, Some(&page_ids)).await?;
+        (page_ids, total, facts)
+    };
+
+    let mut hits = Vec::new();
+    for id in page_ids {
+        let recipe = super::recipes::get_recipe(pool, id).await?;
+        let RecipeFacts { cost_per_serving, allergens, .. } = facts.remove(&id).unwrap_or_default();
+        hits.push(RecipeSearchHit { recipe, cost_per_serving, allergens });
+    }
+
+    Ok(RecipeSearchPage { hits, total, page, per_page })
+}
+
+/// Whether a recipe passes the ingredient, allergen and cost filters of `query`
+fn matches_filters(query: &RecipeSearchQuery, excluded_allergens: &[String], facts: &RecipeFacts) -> bool {
+    query.with_ingredients.iter().all(|ingredient_id| facts.ingredient_ids.contains(ingredient_id))
+        && !query.without_ingredients.iter().any(|ingredient_id| facts.ingredient_ids.contains(ingredient_id))
+        && !facts.allergens.iter().any(|allergen| excluded_allergens.contains(allergen))
+        && query.max_cost_per_serving
+            .is_none_or(|max_cost| facts.cost_per_serving.is_some_and(|cost| cost <= max_cost))
+}
+
+/// Ingredients, cost and allergens of a recipe, as used by the search filters
+#[derive(Debug, Default)]
+pub(crate) struct RecipeFacts {
+    pub ingredient_ids: HashSet<i64>,
+    /// Cost of one adult serving; `None` when an ingredient has no price
+    pub cost_per_serving: Option<f64>,
+    /// Allergens of all ingredients, lowercase and sorted
+    pub allergens: Vec<String>,
+}
+
+/// `RecipeFacts` by recipe id of the given recipes, or of all of them, for
+/// those that have ingredients
+pub(crate) async fn load_recipe_facts(
+    pool: &SqlitePool,
+    recipe_ids: Option<&[i64]>,
+) -> Result<HashMap<i64, RecipeFacts>, AppError> {
+    let mut sql = String::from(
+        "SELECT ri.recipe_id, r.base_servings, ri.ingredient_id, ri.base_quantity, ri.unit,
+                i.primary_unit, i.price_per_unit, i.allergens
+         FROM recipe_ingredients ri
+         JOIN recipes r ON ri.recipe_id = r.id
+         JOIN ingredients i ON ri.ingredient_id = i.id"
+    );
+    if let Some(ids) = recipe_ids {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        sql.push_str(&format!(" WHERE ri.recipe_id IN ({})", vec!["?"; ids.len()].join(", ")));
+    }
+
+    let mut query = sqlx::query_as::<_, LineInfo>(&sql);
+    for id in recipe_ids.unwrap_or_default() {
+        query = query.bind(id);
+    }
+
+    let mut lines: HashMap<i64, (i32, Vec<LineInfo>)> = HashMap::new();
+    for line in query.fetch_all(pool).await? {
+        lines.entry(line.recipe_id).or_insert_with(|| (line.base_servings, Vec::new())).1.push(line);
+    }
+
+    Ok(lines
+        .into_iter()
+        .map(|(recipe_id, (base_servings, recipe_lines))| {
+            let mut allergens: Vec<String> = Vec::new();
+            for line in &recipe_lines {
+                for allergen in split_list(&line.allergens) {
+                    if !allergens.contains(&allergen) {
+                        allergens.push(allergen);
+                    }
+                }
+            }
+            allergens.sort();
+
+            let facts = RecipeFacts {
+                ingredient_ids: recipe_lines.iter().map(|line| line.ingredient_id).collect(),
+                cost_per_serving: recipe_cost(&recipe_lines).map(|cost| cost / base_servings.max(1) as f64),
+                allergens,
+            };
+            (recipe_id, facts)
+        })
+        .collect())
+}
+
+/// Price of all lines at their base quantity; `None` if any line cannot be priced
+fn recipe_cost(lines: &[LineInfo]) -> Option<f64> {
+    if lines.is_empty() {
+        return None;
+    }
+    lines.iter()
+        .map(|line| {
+            let price = line.price_per_unit?;
+            let factor = unit_factor(&line.unit, &line.primary_unit)?;
+            Some(line.base_quantity * factor * price)
+        })
+        .sum()
+}
+
+/// FTS5 query matching every word as a prefix. Words are quoted so that
+/// characters like `-` or `"` in user input are not read as query syntax.
+fn fts_query(text: &str) -> Option<String> {
+    let terms: Vec<String> = text
+        .split_whitespace()
+        .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    async fn add_recipe(pool: &SqlitePool, name: &str, ingredient_id: Option<i64>) -> i64 {
+        let id = sqlx::query("INSERT INTO recipes (name, base_servings) VALUES (?, 4)")
+            .bind(name)
+            .execute(pool)
+            .await
+            .unwrap()
+            .last_insert_rowid();
+        if let Some(ingredient_id) = ingredient_id {
+            sqlx::query("INSERT INTO recipe_ingredients (recipe_id, ingredient_id, base_quantity, unit) VALUES (?, ?, 1, 'kg')")
+                .bind(id)
+                .bind(ingredient_id)
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+        id
+    }
+
+    fn names(page: &RecipeSearchPage) -> Vec<&str> {
+        page.hits.iter().map(|hit| hit.recipe.name.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn pages_text_matches_and_skips_archived_recipes() {
+        let pool = test_pool().await;
+        let apples = sqlx::query("INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Apples', 1, 'kg')")
+            .execute(&pool)
+            .await
+            .unwrap()
+            .last_insert_rowid();
+        add_recipe(&pool, "Apple pie", Some(apples)).await;
+        add_recipe(&pool, "Bread", None).await;
+        add_recipe(&pool, "Baked apple", Some(apples)).await;
+        let archived = add_recipe(&pool, "Apple juice", Some(apples)).await;
+        sqlx::query("UPDATE recipes SET archived_at = CURRENT_TIMESTAMP WHERE id = ?")
+            .bind(archived)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let query = RecipeSearchQuery { text: "apple".into(), per_page: 1, page: 2, ..Default::default() };
+        let page = search_recipes(&pool, &query).await.unwrap();
+        assert_eq!((page.total, page.page, page.per_page, page.hits.len()), (2, 2, 1, 1));
+
+        let query = RecipeSearchQuery { without_ingredients: vec![apples], ..Default::default() };
+        let page = search_recipes(&pool, &query).await.unwrap();
+        assert_eq!(names(&page), vec!["Bread"]);
+        assert_eq!(page.total, 1);
+    }
+
+    #[tokio::test]
+    async fn limits_page_size_and_far_pages() {
+        let pool = test_pool().await;
+        add_recipe(&pool, "Bread", None).await;
+
+        let query = RecipeSearchQuery { per_page: u32::MAX, page: u32::MAX, ..Default::default() };
+        let page = search_recipes(&pool, &query).await.unwrap();
+        assert_eq!(page.per_page, RECIPE_SEARCH_MAX_PAGE_SIZE);
+        assert!(page.hits.is_empty());
+        assert_eq!(page.total, 1);
+    }
+}
@@ -1,31 +1,37 @@
-use crate::models::{Recipe, RecipeIngredientDetail, RecipeWithIngredients, CreateRecipe, UpdateRecipe};
-use sqlx::SqlitePool;
+use crate::error::{AppError, Message};
+use crate::models::{split_list, Recipe, RecipeIngredientDetail, RecipeWithIngredients, CreateRecipe, UpdateRecipe, Validate};
+use sqlx::{Sqlite, SqlitePool, Transaction};
 
-pub async fn get_recipes(pool: &SqlitePool) -> Result<Vec<Recipe>, sqlx::Error> {
+/// Archived recipes only with `include_archived`
+pub async fn get_recipes(pool: &SqlitePool, include_archived: bool) -> Result<Vec<Recipe>, AppError> {
     sqlx::query_as::<_, Recipe>(
-        "SELECT id, name, instructions, base_servings, created_at, updated_at 
+        "SELECT id, name, instructions, base_servings, tags, archived_at, created_at, updated_at 
          FROM recipes 
+         WHERE archived_at IS NULL OR ?
          ORDER BY name"
     )
+    .bind(include_archived)
     .fetch_all(pool)
     .await
+    .map_err(AppError::from)
 }
 
-pub async fn get_recipe(pool: &SqlitePool, id: i64) -> Result<Recipe, sqlx::Error> {
+pub async fn get_recipe(pool: &SqlitePool, id: i64) -> Result<Recipe, AppError> {
     sqlx::query_as::<_, Recipe>(
-        "SELECT id, name, instructions, base_servings, created_at, updated_at 
+        "SELECT id, name, instructions, base_servings, tags, archived_at, created_at, updated_at 
          FROM recipes 
          WHERE id = ?"
     )
     .bind(id)
     .fetch_one(pool)
     .await
+    .map_err(AppError::from)
 }
 
 pub async fn get_recipe_with_ingredients(
     pool: &SqlitePool,
     id: i64,
-) -> Result<RecipeWithIngredients, sqlx::Error> {
+) -> Result<RecipeWithIngredients, AppError> {
     let recipe = get_recipe(pool, id).await?;
     let ingredients = get_recipe_ingredients_with_details(pool, id).await?;
 
@@ -38,7 +44,7 @@ pub async fn get_recipe_with_ingredients(
 async fn get_recipe_ingredients_with_details(
     pool: &SqlitePool,
     recipe_id: i64,
-) -> Result<Vec<RecipeIngredientDetail>, sqlx::Error> {
+) -> Result<Vec<RecipeIngredientDetail>, AppError> {
     sqlx::query_as::<_, RecipeIngredientDetail>(
         "SELECT 
             ri.id, ri.recipe_id, ri.ingredient_id, ri.base_quantity, ri.unit,
@@ -52,58 +58,36 @@ async fn get_recipe_ingredients_with_details(
     .bind(recipe_id)
     .fetch_all(pool)
     .await
+    .map_err(AppError::from)
 }
 
 pub async fn create_recipe(
     pool: &SqlitePool,
     recipe: CreateRecipe,
-) -> Result<RecipeWithIngredients, sqlx::Error> {
-    // Validate base servings is positive
-    if recipe.base_servings <= 0 {
-        return Err(sqlx::Error::Decode(
-            "Base servings must be greater than 0".into()
-        ));
-    }
+) -> Result<RecipeWithIngredients, AppError> {
+    recipe.validate()?;
 
-    // Validate ingredient quantities and multipliers
-    for ingredient in &recipe.ingredients {
-        if ingredient.base_quantity <= 0.0 {
-            return Err(sqlx::Error::Decode(
-                "All ingredient quantities must be greater than 0".into()
-            ));
-        }
+    let mut tx = pool.begin().await?;
+    let recipe_id = insert_recipe(&mut tx, recipe).await?;
+    tx.commit().await?;
 
-        if let Some(mult) = ingredient.child_multiplier {
-            if mult < 0.0 {
-                return Err(sqlx::Error::Decode(
-                    "Multipliers cannot be negative".into()
-                ));
-            }
-        }
-        if let Some(mult) = ingredient.teen_multiplier {
-            if mult < 0.0 {
-                return Err(sqlx::Error::Decode(
-                    "Multipliers cannot be negative".into()
-                ));
-            }
-        }
-        if let Some(mult) = ingredient.adult_multiplier {
-            if mult < 0.0 {
-                return Err(sqlx::Error::Decode(
-                    "Multipliers cannot be negative".into()
-                ));
-            }
-        }
-    }
+    get_recipe_with_ingredients(pool, recipe_id).await
+}
 
+/// Insert an already validated recipe with its ingredients and return its ID
+pub(crate) async fn insert_recipe(
+    tx: &mut Transaction<'_, Sqlite>,
+    recipe: CreateRecipe,
+) -> Result<i64, AppError> {
     let result = sqlx::query(
-        "INSERT INTO recipes (name, instructions, base_servings) 
-         VALUES (?, ?, ?)"
+        "INSERT INTO recipes (name, instructions, base_servings, tags) 
+         VALUES (?, ?, ?, ?)"
     )
     .bind(&recipe.name)
     .bind(&recipe.instructions)
     .bind(recipe.base_servings)
-    .execute(pool)
+    .bind(split_list(&recipe.tags).join(", "))
+    .execute(&mut **tx)
     .await?;
 
     let recipe_id = result.last_insert_rowid();
@@ -123,70 +107,33 @@ pub async fn create_recipe(
         .bind(ingredient.teen_multiplier.unwrap_or(0.75))
         .bind(ingredient.adult_multiplier.unwrap_or(1.0))
         .bind(&ingredient.notes)
-        .execute(pool)
+        .execute(&mut **tx)
         .await?;
     }
 
-    get_recipe_with_ingredients(pool, recipe_id).await
+    Ok(recipe_id)
 }
 
 pub async fn update_recipe(
     pool: &SqlitePool,
     id: i64,
     recipe: UpdateRecipe,
-) -> Result<RecipeWithIngredients, sqlx::Error> {
+) -> Result<RecipeWithIngredients, AppError> {
     let existing = get_recipe(pool, id).await?;
 
-    // Validate base servings if provided
+    recipe.validate()?;
     let final_base_servings = recipe.base_servings.unwrap_or(existing.base_servings);
-    if final_base_servings <= 0 {
-        return Err(sqlx::Error::Decode(
-            "Base servings must be greater than 0".into()
-        ));
-    }
-
-    // Validate ingredients if provided
-    if let Some(ref ingredients) = recipe.ingredients {
-        for ingredient in ingredients {
-            if ingredient.base_quantity <= 0.0 {
-                return Err(sqlx::Error::Decode(
-                    "All ingredient quantities must be greater than 0".into()
-                ));
-            }
-
-            if let Some(mult) = ingredient.child_multiplier {
-                if mult < 0.0 {
-                    return Err(sqlx::Error::Decode(
-                        "Multipliers cannot be negative".into()
-                    ));
-                }
-            }
-            if let Some(mult) = ingredient.teen_multiplier {
-                if mult < 0.0 {
-                    return Err(sqlx::Error::Decode(
-                        "Multipliers cannot be negative".into()
-                    ));
-                }
-            }
-            if let Some(mult) = ingredient.adult_multiplier {
-                if mult < 0.0 {
-                    return Err(sqlx::Error::Decode(
-                        "Multipliers cannot be negative".into()
-                    ));
-                }
-            }
-        }
-    }
 
     sqlx::query(
         "UPDATE recipes
          SET name = ?, instructions = ?,
-             base_servings = ?, updated_at = CURRENT_TIMESTAMP
+             base_servings = ?, tags = ?, updated_at = CURRENT_TIMESTAMP
          WHERE id = ?"
     )
     .bind(recipe.name.unwrap_or(existing.name))
     .bind(recipe.instructions.or(existing.instructions))
     .bind(final_base_servings)
+    .bind(split_list(&recipe.tags.unwrap_or(existing.tags)).join(", "))
     .bind(id)
     .execute(pool)
     .await?;
@@ -222,7 +169,21 @@ pub async fn update_recipe(
     get_recipe_with_ingredients(pool, id).await
 }
 
-pub async fn delete_recipe(pool: &SqlitePool, id: i64) -> Result<(), sqlx::Error> {
+/// Delete a recipe no meal plan uses
+pub async fn delete_recipe(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    let count: (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM planned_meals WHERE recipe_id = ?"
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await?;
+
+    if count.0 > 0 {
+        return Err(AppError::Conflict(
+            Message::new("error-recipe-in-use").with_arg("count", count.0)
+        ));
+    }
+
     sqlx::query("DELETE FROM recipes WHERE id = ?")
         .bind(id)
         .execute(pool)
@@ -230,3 +191,14 @@ pub async fn delete_recipe(pool: &SqlitePool, id: i64) -> Result<(), sqlx::Error
 
     Ok(())
 }
+
+/// Hide a recipe from the lists while planned meals keep using it
+pub async fn archive_recipe(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    super::trash::set_archived(pool, "recipes", id, true).await
+}
+
+/// Bring an archived recipe back into the lists
+pub async fn restore_recipe(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    super::trash::set_archived(pool, "recipes", id, false).await
+}
+
@@ -1,14 +1,59 @@
-use crate::models::{ShoppingListItem, MealScheduleItem, AttendanceSummary};
+use crate::error::AppError;
+use crate::api::camps::get_camp;
+use crate::api::{ingredients, inventory};
+use crate::models::{
+    ShoppingListItem, MealScheduleItem, AttendanceSummary, ShoppingTrip, ShoppingTripItem,
+    ShoppingTripPlan, StorageType,
+};
 use chrono::NaiveDate;
 use sqlx::{SqlitePool, Row};
+use std::collections::{BTreeMap, HashMap};
 
-/// Generate shopping list for a camp within a date range
+/// Generate shopping list for a camp within a date range.
+///
+/// Each ingredient's safety margin is added and stock on hand (camp-specific and
+/// shared storeroom) is subtracted, so only ingredients that still have to be
+/// bought are returned. Quantities are rounded up to the ingredient's pack sizes.
 pub async fn generate_shopping_list(
     pool: &SqlitePool,
     camp_id: i64,
     start_date: NaiveDate,
     end_date: NaiveDate,
-) -> Result<Vec<ShoppingListItem>, sqlx::Error> {
+) -> Result<Vec<ShoppingListItem>, AppError> {
+    let required = calculate_requirements(pool, camp_id, start_date, end_date).await?;
+    let mut stock = inventory::get_stock_totals(pool, camp_id).await?;
+    let packs = ingredients::get_all_ingredient_packs(pool).await?;
+
+    let items = required
+        .into_iter()
+        .filter_map(|mut item| {
+            let key = (item.ingredient_id, item.unit.clone());
+            let needed = item.total_quantity + item.safety_margin;
+            item.in_stock = inventory::take_from_stock(&mut stock, item.ingredient_id, &item.unit, needed);
+            item.to_buy = needed - item.in_stock;
+            if item.to_buy <= f64::EPSILON {
+                return None;
+            }
+            item.apply_packs(packs.get(&key).map(Vec::as_slice).unwrap_or(&[]));
+            Some(item)
+        })
+        .collect();
+
+    Ok(items)
+}
+
+/// Aggregate the ingredient quantities needed by all planned meals in the date range,
+/// without looking at stock. The safety margin is reported separately and not
+/// included in `total_quantity`.
+///
+/// Days already deducted from stock with [`inventory::consume_day`] are left out,
+/// their needs are no longer in the stock it is compared with.
+pub(crate) async fn calculate_requirements(
+    pool: &SqlitePool,
+    camp_id: i64,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<ShoppingListItem>, AppError> {
     // Query to aggregate ingredients across all meals in the date range
     let rows = sqlx::query(
         r#"
@@ -17,6 +62,7 @@ pub async fn generate_shopping_list(
             i.name as ingredient_name,
             c.name as category_name,
             ri.unit,
+            i.safety_margin_percent,
             SUM(
                 ri.base_quantity * (
                     COALESCE(ri.child_multiplier, 1.0) * COALESCE(ma.children, camp.default_children) +
@@ -35,6 +81,10 @@ pub async fn generate_shopping_list(
         WHERE mp.camp_id = ?
             AND mp.date >= ?
             AND mp.date <= ?
+            AND NOT EXISTS (
+                SELECT 1 FROM inventory_consumptions ic
+                WHERE ic.camp_id = mp.camp_id AND ic.date = mp.date
+            )
         GROUP BY i.id, ri.unit, c.name
         ORDER BY c.name, i.name
         "#
@@ -46,23 +96,138 @@ pub async fn generate_shopping_list(
     .await?;
 
     let items = rows.into_iter().map(|row| {
+        let total_quantity: f64 = row.get("total_quantity");
+        let safety_margin_percent: f64 = row.get("safety_margin_percent");
+        let safety_margin = total_quantity * safety_margin_percent / 100.0;
         ShoppingListItem {
             ingredient_id: row.get("ingredient_id"),
             ingredient_name: row.get("ingredient_name"),
             category_name: row.get("category_name"),
-            total_quantity: row.get("total_quantity"),
+            total_quantity,
             unit: row.get("unit"),
+            safety_margin,
+            in_stock: 0.0,
+            to_buy: total_quantity + safety_margin,
+            packs: Vec::new(),
+            purchase_quantity: total_quantity + safety_margin,
+            leftover: 0.0,
         }
     }).collect();
 
     Ok(items)
 }
 
+/// Quantities to buy for each camp day, earliest day first.
+///
+/// Stock on hand is used up first, covering the earliest days; `to_buy` holds the
+/// part stock does not cover and days fully covered by stock are left out.
+pub(crate) async fn calculate_daily_shortfall(
+    pool: &SqlitePool,
+    camp_id: i64,
+) -> Result<Vec<(NaiveDate, ShoppingListItem)>, AppError> {
+    let camp = get_camp(pool, camp_id).await?;
+    let mut stock = inventory::get_stock_totals(pool, camp_id).await?;
+    let mut shortfall = Vec::new();
+
+    for date in camp.start_date.iter_days().take_while(|d| *d <= camp.end_date) {
+        for mut item in calculate_requirements(pool, camp_id, date, date).await? {
+            let needed = item.total_quantity + item.safety_margin;
+            let from_stock = inventory::take_from_stock(&mut stock, item.ingredient_id, &item.unit, needed);
+
+            item.in_stock = from_stock;
+            item.to_buy = needed - from_stock;
+            item.purchase_quantity = item.to_buy;
+            if item.to_buy > f64::EPSILON {
+                shortfall.push((date, item));
+            }
+        }
+    }
+
+    Ok(shortfall)
+}
+
+/// Split a camp's shopping over the given trip dates.
+///
+/// Each day's needs are bought on the latest trip on or before that day that is
+/// within the ingredient's shelf life, so fresh goods are bought as late as
+/// possible. When no trip is close enough the latest earlier trip buys them and
+/// the item is flagged; needs before the first trip are returned as unassigned.
+pub async fn generate_shopping_trips(
+    pool: &SqlitePool,
+    camp_id: i64,
+    mut trip_dates: Vec<NaiveDate>,
+) -> Result<ShoppingTripPlan, AppError> {
+    trip_dates.sort();
+    trip_dates.dedup();
+
+    let storage: HashMap<i64, (Option<i32>, String)> = sqlx::query_as::<_, (i64, Option<i32>, String)>(
+        "SELECT id, shelf_life_days, storage_type FROM ingredients"
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|(id, shelf_life_days, storage_type)| (id, (shelf_life_days, storage_type)))
+    .collect();
+
+    // trip date (None = before the first trip) -> (ingredient_id, unit) -> item
+    let mut by_trip: BTreeMap<Option<NaiveDate>, BTreeMap<(i64, String), ShoppingTripItem>> =
+        trip_dates.iter().map(|date| (Some(*date), BTreeMap::new())).collect();
+
+    for (needed_on, item) in calculate_daily_shortfall(pool, camp_id).await? {
+        let (shelf_life_days, storage_type) = storage
+            .get(&item.ingredient_id)
+            .cloned()
+            .unwrap_or((None, StorageType::Dry.as_str().to_string()));
+        let mut earlier_trips = trip_dates.iter().rev().filter(|date| **date <= needed_on).copied();
+        let fresh_trip = earlier_trips.clone().find(|date| {
+            shelf_life_days.is_none_or(|days| (needed_on - *date).num_days() <= days as i64)
+        });
+        let exceeds_shelf_life = fresh_trip.is_none() && earlier_trips.clone().next().is_some();
+        let trip = fresh_trip.or_else(|| earlier_trips.next());
+
+        let line = by_trip
+            .entry(trip)
+            .or_default()
+            .entry((item.ingredient_id, item.unit.clone()))
+            .or_insert_with(|| ShoppingTripItem {
+                ingredient_id: item.ingredient_id,
+                ingredient_name: item.ingredient_name.clone(),
+                category_name: item.category_name.clone(),
+                storage_type,
+                quantity: 0.0,
+                unit: item.unit.clone(),
+                first_needed: needed_on,
+                last_needed: needed_on,
+                shelf_life_days,
+                exceeds_shelf_life: false,
+            });
+        line.quantity += item.to_buy;
+        line.last_needed = needed_on;
+        line.exceeds_shelf_life |= exceeds_shelf_life;
+    }
+
+    let mut plan = ShoppingTripPlan::default();
+    for (trip, items) in by_trip {
+        let mut items: Vec<ShoppingTripItem> = items.into_values().collect();
+        items.sort_by(|a, b| {
+            a.storage_type.cmp(&b.storage_type)
+                .then_with(|| a.category_name.cmp(&b.category_name))
+                .then_with(|| a.ingredient_name.cmp(&b.ingredient_name))
+        });
+        match trip {
+            Some(date) => plan.trips.push(ShoppingTrip { date, items }),
+            None => plan.unassigned = items,
+        }
+    }
+
+    Ok(plan)
+}
+
 /// Generate meal schedule for a camp
 pub async fn generate_meal_schedule(
     pool: &SqlitePool,
     camp_id: i64,
-) -> Result<Vec<MealScheduleItem>, sqlx::Error> {
+) -> Result<Vec<MealScheduleItem>, AppError> {
     let rows = sqlx::query(
         r#"
         SELECT
@@ -103,7 +268,7 @@ pub async fn generate_meal_schedule(
 pub async fn generate_attendance_summary(
     pool: &SqlitePool,
     camp_id: i64,
-) -> Result<Vec<AttendanceSummary>, sqlx::Error> {
+) -> Result<Vec<AttendanceSummary>, AppError> {
     let rows = sqlx::query(
         r#"
         SELECT
@@ -141,3 +306,90 @@ pub async fn generate_attendance_summary(
 
     Ok(items)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{camps::create_camp, inventory::consume_day, meal_plans::create_planned_meal};
+    use crate::db::test_pool;
+    use crate::models::{CreateCamp, CreatePlannedMeal, MealType};
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, day).unwrap()
+    }
+
+    async fn insert(pool: &SqlitePool, sql: &str) -> i64 {
+        sqlx::query(sql).execute(pool).await.unwrap().last_insert_rowid()
+    }
+
+    /// A camp of one adult from July 1 to 3 eating 100 g of flour every lunch,
+    /// with 150 g of flour in stock; returns the camp id
+    async fn seed_camp(pool: &SqlitePool) -> i64 {
+        let flour = insert(pool, "INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Flour', 1, 'g')").await;
+        let recipe = insert(pool, "INSERT INTO recipes (name, base_servings) VALUES ('Pancakes', 1)").await;
+        sqlx::query("INSERT INTO recipe_ingredients (recipe_id, ingredient_id, base_quantity, unit) VALUES (?, ?, 100, 'g')")
+            .bind(recipe).bind(flour)
+            .execute(pool).await.unwrap();
+        sqlx::query("INSERT INTO inventory_items (ingredient_id, quantity, unit) VALUES (?, 150, 'g')")
+            .bind(flour)
+            .execute(pool).await.unwrap();
+
+        let camp = CreateCamp {
+            name: "Summer".to_string(),
+            start_date: day(1),
+            end_date: day(3),
+            default_children: 0,
+            default_teens: 0,
+            default_adults: 1,
+            notes: None,
+        };
+        let camp_id = create_camp(pool, camp).await.unwrap().id;
+        for date in [day(1), day(2), day(3)] {
+            let meal = CreatePlannedMeal {
+                camp_id,
+                date,
+                meal_type: MealType::Lunch,
+                recipe_id: recipe,
+                attendance: None,
+            };
+            create_planned_meal(pool, meal).await.unwrap();
+        }
+        camp_id
+    }
+
+    #[tokio::test]
+    async fn consumed_days_are_not_bought_again() {
+        let pool = test_pool().await;
+        let camp_id = seed_camp(&pool).await;
+
+        let list = generate_shopping_list(&pool, camp_id, day(1), day(3)).await.unwrap();
+        assert_eq!((list[0].in_stock, list[0].to_buy), (150.0, 150.0));
+
+        // Day 1 uses 100 g of the stock; days 2 and 3 need 200 g with 50 g left
+        consume_day(&pool, camp_id, day(1)).await.unwrap();
+        let list = generate_shopping_list(&pool, camp_id, day(1), day(3)).await.unwrap();
+        assert_eq!((list[0].total_quantity, list[0].in_stock, list[0].to_buy), (200.0, 50.0, 150.0));
+
+        let shortfall = calculate_daily_shortfall(&pool, camp_id).await.unwrap();
+        let days: Vec<(NaiveDate, f64)> = shortfall.iter().map(|(date, item)| (*date, item.to_buy)).collect();
+        assert_eq!(days, vec![(day(2), 50.0), (day(3), 100.0)]);
+    }
+
+    #[tokio::test]
+    async fn trips_buy_within_shelf_life() {
+        let pool = test_pool().await;
+        let camp_id = seed_camp(&pool).await;
+        sqlx::query("UPDATE ingredients SET shelf_life_days = 1").execute(&pool).await.unwrap();
+
+        // Stock covers day 1 and half of day 2; the rest keeps one day
+        let plan = generate_shopping_trips(&pool, camp_id, vec![day(1), day(3)]).await.unwrap();
+        let bought: Vec<(NaiveDate, f64, bool)> = plan.trips.iter()
+            .flat_map(|trip| trip.items.iter().map(|item| (trip.date, item.quantity, item.exceeds_shelf_life)))
+            .collect();
+        assert_eq!(bought, vec![(day(1), 50.0, false), (day(3), 100.0, false)]);
+
+        let plan = generate_shopping_trips(&pool, camp_id, vec![day(1)]).await.unwrap();
+        let item = &plan.trips[0].items[0];
+        assert_eq!((item.quantity, item.last_needed, item.exceeds_shelf_life), (150.0, day(3), true));
+    }
+}
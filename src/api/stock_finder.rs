@@ -0,0 +1,146 @@
+use crate::api::inventory::take_from_stock;
+use crate::error::AppError;
+use crate::models::{
+    MissingIngredient, RecipeIngredientDetail, StockFinderRequest, StockMatch, StockSource,
+};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+/// Rank recipes by how much of them the available stock covers.
+///
+/// Quantities are scaled to the requested headcount the same way as the shopping
+/// reports. Stock in another unit of the same kind (g/kg, ml/l) is converted; an
+/// ingredient used on several lines of a recipe is only counted once.
+pub async fn find_recipes_from_stock(
+    pool: &SqlitePool,
+    request: &StockFinderRequest,
+) -> Result<Vec<StockMatch>, AppError> {
+    if request.headcount.children < 0 || request.headcount.teens < 0 || request.headcount.adults < 0 {
+        return Err(AppError::validation("headcount", "validation-headcount-negative"));
+    }
+    if request.headcount.children + request.headcount.teens + request.headcount.adults == 0 {
+        return Err(AppError::validation("headcount", "validation-headcount-empty"));
+    }
+
+    let stock = load_stock(pool, &request.source).await?;
+
+    let mut lines: HashMap<i64, Vec<RecipeIngredientDetail>> = HashMap::new();
+    for line in sqlx::query_as::<_, RecipeIngredientDetail>(
+        "SELECT
+            ri.id, ri.recipe_id, ri.ingredient_id, ri.base_quantity, ri.unit,
+            ri.child_multiplier, ri.teen_multiplier, ri.adult_multiplier, ri.notes, ri.created_at,
+            i.name as ingredient_name
+         FROM recipe_ingredients ri
+         JOIN ingredients i ON ri.ingredient_id = i.id
+         ORDER BY ri.id"
+    )
+    .fetch_all(pool)
+    .await?
+    {
+        lines.entry(line.recipe_ingredient.recipe_id).or_default().push(line);
+    }
+
+    let mut matches = Vec::new();
+    for recipe in super::recipes::get_recipes(pool, false).await? {
+        let Some(recipe_lines) = lines.remove(&recipe.id) else {
+            continue;
+        };
+
+        // Each recipe draws from its own copy, so lines of the same ingredient share the stock
+        let mut remaining = stock.clone();
+        let mut coverage_sum = 0.0;
+        let mut covered_lines = 0;
+        let mut missing = Vec::new();
+
+        for detail in &recipe_lines {
+            let line = &detail.recipe_ingredient;
+            let needed = line.quantity_for(&request.headcount, recipe.base_servings);
+            if needed <= f64::EPSILON {
+                coverage_sum += 1.0;
+                covered_lines += 1;
+                continue;
+            }
+
+            let available = take_from_stock(&mut remaining, line.ingredient_id, &line.unit, needed);
+            coverage_sum += available / needed;
+            if needed - available <= needed * 1e-6 {
+                covered_lines += 1;
+            } else {
+                missing.push(MissingIngredient {
+                    ingredient_id: line.ingredient_id,
+                    ingredient_name: detail.ingredient_name.clone(),
+                    unit: line.unit.clone(),
+                    needed,
+                    available,
+                    missing: needed - available,
+                });
+            }
+        }
+
+        let coverage = coverage_sum / recipe_lines.len() as f64;
+        if coverage <= 0.0 || coverage + 1e-9 < request.min_coverage {
+            continue;
+        }
+
+        matches.push(StockMatch {
+            recipe,
+            coverage,
+            covered_lines,
+            total_lines: recipe_lines.len(),
+            missing,
+        });
+    }
+
+    matches.sort_by(|a, b| {
+        b.coverage.total_cmp(&a.coverage)
+            .then(a.missing.len().cmp(&b.missing.len()))
+            .then(a.recipe.name.cmp(&b.recipe.name))
+    });
+    if request.limit > 0 {
+        matches.truncate(request.limit);
+    }
+
+    Ok(matches)
+}
+
+/// Available quantities per ingredient, as `(unit, quantity)` pairs
+async fn load_stock(
+    pool: &SqlitePool,
+    source: &StockSource,
+) -> Result<HashMap<i64, Vec<(String, f64)>>, AppError> {
+    let entries: Vec<(i64, String, f64)> = match source {
+        StockSource::Inventory { camp_id: Some(camp_id) } => {
+            super::inventory::get_stock_totals(pool, *camp_id)
+                .await?
+                .into_iter()
+                .flat_map(|(ingredient_id, units)| {
+                    units.into_iter().map(move |(unit, quantity)| (ingredient_id, unit, quantity))
+                })
+                .collect()
+        }
+        StockSource::Inventory { camp_id: None } => sqlx::query_as(
+            "SELECT ingredient_id, unit, SUM(quantity)
+             FROM inventory_items
+             WHERE camp_id IS NULL
+             GROUP BY ingredient_id, unit"
+        )
+        .fetch_all(pool)
+        .await?,
+        StockSource::Items(items) => {
+            if items.iter().any(|item| item.quantity < 0.0) {
+                return Err(AppError::validation("items", "validation-quantities-negative"));
+            }
+            items.iter()
+                .map(|item| (item.ingredient_id, item.unit.clone(), item.quantity))
+                .collect()
+        }
+    };
+
+    let mut stock: HashMap<i64, Vec<(String, f64)>> = HashMap::new();
+    for (ingredient_id, unit, quantity) in entries {
+        if quantity > 0.0 {
+            stock.entry(ingredient_id).or_default().push((unit, quantity));
+        }
+    }
+    Ok(stock)
+}
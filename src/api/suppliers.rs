@@ -0,0 +1,363 @@
+use crate::error::{AppError, Message};
+use crate::api::reports::calculate_daily_shortfall;
+use crate::models::{
+    CreateSupplier, PurchaseOrder, PurchaseOrderLine, Supplier, SupplierWithAssignments,
+    UpdateSupplier, Validate,
+};
+use chrono::NaiveDate;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::collections::{BTreeMap, HashMap};
+
+pub async fn get_suppliers(pool: &SqlitePool) -> Result<Vec<SupplierWithAssignments>, AppError> {
+    let suppliers = sqlx::query_as::<_, Supplier>(
+        "SELECT id, name, contact, lead_time_days, delivery_days, deliver_upfront, notes, created_at, updated_at
+         FROM suppliers
+         ORDER BY name"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut result = Vec::new();
+    for supplier in suppliers {
+        result.push(with_assignments(pool, supplier).await?);
+    }
+
+    Ok(result)
+}
+
+pub async fn get_supplier(pool: &SqlitePool, id: i64) -> Result<SupplierWithAssignments, AppError> {
+    let supplier = sqlx::query_as::<_, Supplier>(
+        "SELECT id, name, contact, lead_time_days, delivery_days, deliver_upfront, notes, created_at, updated_at
+         FROM suppliers
+         WHERE id = ?"
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await?;
+
+    with_assignments(pool, supplier).await
+}
+
+async fn with_assignments(
+    pool: &SqlitePool,
+    supplier: Supplier,
+) -> Result<SupplierWithAssignments, AppError> {
+    let category_ids = sqlx::query_scalar(
+        "SELECT category_id FROM supplier_categories WHERE supplier_id = ? ORDER BY category_id"
+    )
+    .bind(supplier.id)
+    .fetch_all(pool)
+    .await?;
+
+    let ingredient_ids = sqlx::query_scalar(
+        "SELECT ingredient_id FROM supplier_ingredients WHERE supplier_id = ? ORDER BY ingredient_id"
+    )
+    .bind(supplier.id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(SupplierWithAssignments {
+        supplier,
+        category_ids,
+        ingredient_ids,
+    })
+}
+
+/// Replace the categories and ingredients a supplier delivers. Each category and
+/// ingredient can be assigned to one supplier only.
+async fn replace_assignments(
+    tx: &mut Transaction<'_, Sqlite>,
+    supplier_id: i64,
+    category_ids: Option<Vec<i64>>,
+    ingredient_ids: Option<Vec<i64>>,
+) -> Result<(), AppError> {
+    if let Some(category_ids) = category_ids {
+        sqlx::query("DELETE FROM supplier_categories WHERE supplier_id = ?")
+            .bind(supplier_id)
+            .execute(&mut **tx)
+            .await?;
+
+        for category_id in category_ids {
+            let taken = sqlx::query_as::<_, (String, String)>(
+                "SELECT c.name, s.name
+                 FROM supplier_categories sc
+                 JOIN categories c ON c.id = sc.category_id
+                 JOIN suppliers s ON s.id = sc.supplier_id
+                 WHERE sc.category_id = ? AND sc.supplier_id != ?"
+            )
+            .bind(category_id)
+            .bind(supplier_id)
+            .fetch_optional(&mut **tx)
+            .await?;
+            if let Some((category, supplier)) = taken {
+                return Err(AppError::Conflict(
+                    Message::new("error-supplier-category-taken")
+                        .with_arg("category", category)
+                        .with_arg("supplier", supplier)
+                ));
+            }
+
+            sqlx::query("INSERT OR IGNORE INTO supplier_categories (supplier_id, category_id) VALUES (?, ?)")
+                .bind(supplier_id)
+                .bind(category_id)
+                .execute(&mut **tx)
+                .await?;
+        }
+    }
+
+    if let Some(ingredient_ids) = ingredient_ids {
+        sqlx::query("DELETE FROM supplier_ingredients WHERE supplier_id = ?")
+            .bind(supplier_id)
+            .execute(&mut **tx)
+            .await?;
+
+        for ingredient_id in ingredient_ids {
+            let taken = sqlx::query_as::<_, (String, String)>(
+                "SELECT i.name, s.name
+                 FROM supplier_ingredients si
+                 JOIN ingredients i ON i.id = si.ingredient_id
+                 JOIN suppliers s ON s.id = si.supplier_id
+                 WHERE si.ingredient_id = ? AND si.supplier_id != ?"
+            )
+            .bind(ingredient_id)
+            .bind(supplier_id)
+            .fetch_optional(&mut **tx)
+            .await?;
+            if let Some((ingredient, supplier)) = taken {
+                return Err(AppError::Conflict(
+                    Message::new("error-supplier-ingredient-taken")
+                        .with_arg("ingredient", ingredient)
+                        .with_arg("supplier", supplier)
+                ));
+            }
+
+            sqlx::query("INSERT OR IGNORE INTO supplier_ingredients (supplier_id, ingredient_id) VALUES (?, ?)")
+                .bind(supplier_id)
+                .bind(ingredient_id)
+                .execute(&mut **tx)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn create_supplier(
+    pool: &SqlitePool,
+    supplier: CreateSupplier,
+) -> Result<SupplierWithAssignments, AppError> {
+    supplier.validate()?;
+
+    let mut tx = pool.begin().await?;
+    let result = sqlx::query(
+        "INSERT INTO suppliers (name, contact, lead_time_days, delivery_days, deliver_upfront, notes)
+         VALUES (?, ?, ?, ?, ?, ?)"
+    )
+    .bind(&supplier.name)
+    .bind(&supplier.contact)
+    .bind(supplier.lead_time_days)
+    .bind(&supplier.delivery_days)
+    .bind(supplier.deliver_upfront)
+    .bind(&supplier.notes)
+    .execute(&mut *tx)
+    .await?;
+
+    let id = result.last_insert_rowid();
+    replace_assignments(&mut tx, id, Some(supplier.category_ids), Some(supplier.ingredient_ids)).await?;
+    tx.commit().await?;
+
+    get_supplier(pool, id).await
+}
+
+pub async fn update_supplier(
+    pool: &SqlitePool,
+    id: i64,
+    supplier: UpdateSupplier,
+) -> Result<SupplierWithAssignments, AppError> {
+    supplier.validate()?;
+    let existing = get_supplier(pool, id).await?.supplier;
+
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "UPDATE suppliers
+         SET name = ?, contact = ?, lead_time_days = ?, delivery_days = ?,
+             deliver_upfront = ?, notes = ?, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ?"
+    )
+    .bind(supplier.name.unwrap_or(existing.name))
+    .bind(super::patch_text(supplier.contact, existing.contact))
+    .bind(supplier.lead_time_days.unwrap_or(existing.lead_time_days))
+    .bind(supplier.delivery_days.unwrap_or(existing.delivery_days))
+    .bind(supplier.deliver_upfront.unwrap_or(existing.deliver_upfront))
+    .bind(super::patch_text(supplier.notes, existing.notes))
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+
+    replace_assignments(&mut tx, id, supplier.category_ids, supplier.ingredient_ids).await?;
+    tx.commit().await?;
+
+    get_supplier(pool, id).await
+}
+
+pub async fn delete_supplier(pool: &SqlitePool, id: i64) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM suppliers WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// (supplier index, delivery date) -> (ingredient_id, unit) -> line
+type OrderMap = BTreeMap<(Option<usize>, NaiveDate), BTreeMap<(i64, String), PurchaseOrderLine>>;
+
+/// Split a camp's needs into purchase orders per supplier and delivery date.
+///
+/// Each ingredient goes to the supplier it is assigned to directly, otherwise to
+/// the supplier of its category. Fresh goods are delivered on the supplier's last
+/// delivery day before the day they are used; suppliers marked `deliver_upfront`
+/// deliver everything before the first day any of their goods are needed. Stock
+/// on hand is used up first, covering the earliest days.
+pub async fn generate_purchase_orders(
+    pool: &SqlitePool,
+    camp_id: i64,
+) -> Result<Vec<PurchaseOrder>, AppError> {
+    let suppliers = get_suppliers(pool).await?;
+
+    let mut by_ingredient: HashMap<i64, usize> = HashMap::new();
+    let mut by_category: HashMap<i64, usize> = HashMap::new();
+    for (index, supplier) in suppliers.iter().enumerate() {
+        for ingredient_id in &supplier.ingredient_ids {
+            by_ingredient.entry(*ingredient_id).or_insert(index);
+        }
+        for category_id in &supplier.category_ids {
+            by_category.entry(*category_id).or_insert(index);
+        }
+    }
+
+    let ingredient_categories: HashMap<i64, i64> = sqlx::query_as::<_, (i64, i64)>(
+        "SELECT id, category_id FROM ingredients"
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+
+    let mut orders: OrderMap = BTreeMap::new();
+    let mut first_needed: HashMap<Option<usize>, NaiveDate> = HashMap::new();
+    let mut daily_needs = Vec::new();
+
+    for (date, item) in calculate_daily_shortfall(pool, camp_id).await? {
+        let supplier_index = by_ingredient
+            .get(&item.ingredient_id)
+            .or_else(|| {
+                ingredient_categories
+                    .get(&item.ingredient_id)
+                    .and_then(|category_id| by_category.get(category_id))
+            })
+            .copied();
+
+        first_needed.entry(supplier_index).or_insert(date);
+        daily_needs.push((supplier_index, date, item));
+    }
+
+    for (supplier_index, needed_on, item) in daily_needs {
+        let supplier = supplier_index.map(|index| &suppliers[index].supplier);
+        let delivery_date = match supplier {
+            Some(s) if s.deliver_upfront => s.delivery_date_for(first_needed[&supplier_index]),
+            Some(s) => s.delivery_date_for(needed_on),
+            None => first_needed[&supplier_index],
+        };
+
+        let line = orders
+            .entry((supplier_index, delivery_date))
+            .or_default()
+            .entry((item.ingredient_id, item.unit.clone()))
+            .or_insert_with(|| PurchaseOrderLine {
+                ingredient_id: item.ingredient_id,
+                ingredient_name: item.ingredient_name.clone(),
+                category_name: item.category_name.clone(),
+                quantity: 0.0,
+                unit: item.unit.clone(),
+                needed_on,
+            });
+        line.quantity += item.to_buy;
+    }
+
+    let mut result: Vec<PurchaseOrder> = orders
+        .into_iter()
+        .map(|((supplier_index, delivery_date), lines)| {
+            let supplier = supplier_index.map(|index| &suppliers[index].supplier);
+            let mut lines: Vec<PurchaseOrderLine> = lines.into_values().collect();
+            lines.sort_by(|a, b| {
+                a.category_name.cmp(&b.category_name)
+                    .then_with(|| a.ingredient_name.cmp(&b.ingredient_name))
+            });
+            PurchaseOrder {
+                supplier_id: supplier.map(|s| s.id),
+                supplier_name: supplier.map(|s| s.name.clone()).unwrap_or_default(),
+                delivery_date,
+                order_by: supplier.map(|s| s.order_by(delivery_date)).unwrap_or(delivery_date),
+                lines,
+            }
+        })
+        .collect();
+
+    result.sort_by(|a, b| {
+        a.delivery_date.cmp(&b.delivery_date)
+            .then_with(|| a.supplier_name.cmp(&b.supplier_name))
+    });
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    fn supplier(name: &str, category_ids: Vec<i64>) -> CreateSupplier {
+        CreateSupplier {
+            name: name.to_string(),
+            contact: Some("555 123".to_string()),
+            lead_time_days: 1,
+            delivery_days: String::new(),
+            deliver_upfront: false,
+            notes: Some("Call before noon".to_string()),
+            category_ids,
+            ingredient_ids: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_category_has_one_supplier() {
+        let pool = test_pool().await;
+        create_supplier(&pool, supplier("Bakery", vec![1])).await.unwrap();
+
+        let error = create_supplier(&pool, supplier("Wholesaler", vec![1])).await.unwrap_err();
+        assert!(matches!(error, AppError::Conflict(message) if message.key == "error-supplier-category-taken"));
+        assert_eq!(get_suppliers(&pool).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn empty_contact_and_notes_clear_them() {
+        let pool = test_pool().await;
+        let created = create_supplier(&pool, supplier("Bakery", vec![1])).await.unwrap();
+
+        let update = UpdateSupplier {
+            name: None,
+            contact: Some(String::new()),
+            lead_time_days: None,
+            delivery_days: None,
+            deliver_upfront: None,
+            notes: Some(String::new()),
+            category_ids: None,
+            ingredient_ids: None,
+        };
+        let updated = update_supplier(&pool, created.supplier.id, update).await.unwrap();
+        assert_eq!(updated.supplier.contact, None);
+        assert_eq!(updated.supplier.notes, None);
+        assert_eq!(updated.category_ids, vec![1]);
+    }
+}
@@ -0,0 +1,719 @@
+use crate::error::AppError;
+use crate::api::{camps, categories, ingredients, inventory, recipes, suppliers};
+use crate::models::{
+    CreateAttendance, DataExport, ExportCamp, ExportInventoryItem, ExportPlannedMeal, ExportRecipe,
+    ImportMode, ImportReport, IngredientPack, MealType, RecipeIngredient, StorageType,
+    DATA_EXPORT_VERSION, normalize_name,
+};
+use chrono::Utc;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::collections::{HashMap, HashSet};
+
+/// Dump categories, ingredients with their pack sizes, recipes, suppliers, stock,
+/// and camps with their meal plans
+pub async fn export_data(pool: &SqlitePool) -> Result<DataExport, AppError> {
+    let categories = categories::get_categories(pool, true).await?;
+    let ingredients = ingredients::get_ingredients(pool, true).await?;
+
+    let mut export_recipes = Vec::new();
+    for recipe in recipes::get_recipes(pool, true).await? {
+        let ingredients = sqlx::query_as::<_, RecipeIngredient>(
+            "SELECT id, recipe_id, ingredient_id, base_quantity, unit,
+                    child_multiplier, teen_multiplier, adult_multiplier, notes, created_at
+             FROM recipe_ingredients
+             WHERE recipe_id = ?
+             ORDER BY id"
+        )
+        .bind(recipe.id)
+        .fetch_all(pool)
+        .await?;
+
+        export_recipes.push(ExportRecipe { recipe, ingredients });
+    }
+
+    let mut export_camps = Vec::new();
+    for camp in camps::get_camps(pool, true).await? {
+        let meals = sqlx::query_as::<_, (chrono::NaiveDate, String, i64, Option<i32>, Option<i32>, Option<i32>)>(
+            "SELECT mp.date, pm.meal_type, pm.recipe_id, ma.children, ma.teens, ma.adults
+             FROM planned_meals pm
+             JOIN meal_plans mp ON pm.meal_plan_id = mp.id
+             LEFT JOIN meal_attendance ma ON pm.id = ma.planned_meal_id
+             WHERE mp.camp_id = ?
+             ORDER BY mp.date, pm.id"
+        )
+        .bind(camp.id)
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|(date, meal_type, recipe_id, children, teens, adults)| ExportPlannedMeal {
+            date,
+            meal_type,
+            recipe_id,
+            attendance: match (children, teens, adults) {
+                (Some(children), Some(teens), Some(adults)) => Some(CreateAttendance { children, teens, adults }),
+                _ => None,
+            },
+        })
+        .collect();
+
+        export_camps.push(ExportCamp {
+            name: camp.name,
+            start_date: camp.start_date,
+            end_date: camp.end_date,
+            default_children: camp.default_children,
+            default_teens: camp.default_teens,
+            default_adults: camp.default_adults,
+            notes: camp.notes,
+            archived_at: camp.archived_at,
+            meals,
+            inventory: stock_items(pool, Some(camp.id)).await?,
+            consumed_days: inventory::get_consumed_dates(pool, camp.id).await?,
+        });
+    }
+
+    let ingredient_packs = sqlx::query_as::<_, IngredientPack>(
+        "SELECT id, ingredient_id, unit, size, label, created_at
+         FROM ingredient_packs
+         ORDER BY ingredient_id, unit, size"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(DataExport {
+        version: DATA_EXPORT_VERSION,
+        exported_at: Utc::now(),
+        categories,
+        ingredients,
+        recipes: export_recipes,
+        camps: export_camps,
+        ingredient_packs,
+        suppliers: suppliers::get_suppliers(pool).await?,
+        inventory: stock_items(pool, None).await?,
+    })
+}
+
+/// Stock of a camp, or of the shared storeroom for `None`
+async fn stock_items(pool: &SqlitePool, camp_id: Option<i64>) -> Result<Vec<ExportInventoryItem>, AppError> {
+    sqlx::query_as::<_, ExportInventoryItem>(
+        "SELECT ingredient_id, storeroom, quantity, unit, notes
+         FROM inventory_items
+         WHERE camp_id IS ?
+         ORDER BY id"
+    )
+    .bind(camp_id)
+    .fetch_all(pool)
+    .await
+    .map_err(AppError::from)
+}
+
+/// Check that every reference in the dump points at something inside the dump
+pub fn validate_data(data: &DataExport) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if data.version > DATA_EXPORT_VERSION {
+        errors.push(format!(
+            "Export version {} is newer than supported version {}",
+            data.version, DATA_EXPORT_VERSION
+        ));
+    }
+
+    let category_ids: HashSet<i64> = data.categories.iter().map(|c| c.id).collect();
+    let ingredient_ids: HashSet<i64> = data.ingredients.iter().map(|i| i.id).collect();
+    let recipe_ids: HashSet<i64> = data.recipes.iter().map(|r| r.recipe.id).collect();
+
+    for ingredient in &data.ingredients {
+        if !category_ids.contains(&ingredient.category_id) {
+            errors.push(format!(
+                "Ingredient '{}' references unknown category {}",
+                ingredient.name, ingredient.category_id
+            ));
+        }
+        if ingredient.storage_type.parse::<StorageType>().is_err() {
+            errors.push(format!(
+                "Ingredient '{}' has unknown storage type '{}'",
+                ingredient.name, ingredient.storage_type
+            ));
+        }
+    }
+
+    for pack in &data.ingredient_packs {
+        if !ingredient_ids.contains(&pack.ingredient_id) {
+            errors.push(format!("Pack size {} references unknown ingredient {}", pack.id, pack.ingredient_id));
+        }
+        if pack.size <= 0.0 {
+            errors.push(format!("Pack size {} must be greater than zero", pack.id));
+        }
+    }
+
+    for supplier in &data.suppliers {
+        let name = &supplier.supplier.name;
+        if let Some(id) = supplier.category_ids.iter().find(|id| !category_ids.contains(id)) {
+            errors.push(format!("Supplier '{}' references unknown category {}", name, id));
+        }
+        if let Some(id) = supplier.ingredient_ids.iter().find(|id| !ingredient_ids.contains(id)) {
+            errors.push(format!("Supplier '{}' references unknown ingredient {}", name, id));
+        }
+    }
+
+    let stock = data.inventory.iter().map(|item| ("shared storeroom", item))
+        .chain(data.camps.iter().flat_map(|camp| camp.inventory.iter().map(move |item| (camp.name.as_str(), item))));
+    for (owner, item) in stock {
+        if !ingredient_ids.contains(&item.ingredient_id) {
+            errors.push(format!("Stock of '{}' references unknown ingredient {}", owner, item.ingredient_id));
+        }
+    }
+
+    for recipe in &data.recipes {
+        if recipe.recipe.base_servings < 1 {
+            errors.push(format!("Recipe '{}' must serve at least one person", recipe.recipe.name));
+        }
+        for item in &recipe.ingredients {
+            if !ingredient_ids.contains(&item.ingredient_id) {
+                errors.push(format!(
+                    "Recipe '{}' references unknown ingredient {}",
+                    recipe.recipe.name, item.ingredient_id
+                ));
+            }
+        }
+    }
+
+    for camp in &data.camps {
+        if camp.start_date >= camp.end_date {
+            errors.push(format!("Camp '{}' must end after it starts", camp.name));
+        }
+        for meal in &camp.meals {
+            if !recipe_ids.contains(&meal.recipe_id) {
+                errors.push(format!(
+                    "Camp '{}' plans unknown recipe {} on {}",
+                    camp.name, meal.recipe_id, meal.date
+                ));
+            }
+            if MealType::from_str(&meal.meal_type).is_none() {
+                errors.push(format!(
+                    "Camp '{}' has unknown meal type '{}' on {}",
+                    camp.name, meal.meal_type, meal.date
+                ));
+            }
+        }
+    }
+
+    errors
+}
+
+/// Import a dump, remapping all IDs.
+///
+/// In merge mode categories, ingredients, recipes and suppliers are matched by
+/// name (case-insensitive, for ingredients also ignoring diacritics and following
+/// aliases) and camps by name and start date; matched records are
+/// kept as they are, so stock and pack sizes are only imported for new ones.
+/// Everything runs in one transaction, which a dry run rolls back.
+///
+/// Replace mode deletes stock, pack sizes and consumed days together with the
+/// ingredients and camps. Dumps from before they were exported can't restore
+/// them, so the report warns about what is lost, and suppliers are kept.
+pub async fn import_data(
+    pool: &SqlitePool,
+    data: DataExport,
+    mode: ImportMode,
+    dry_run: bool,
+) -> Result<ImportReport, AppError> {
+    let mut report = ImportReport {
+        dry_run,
+        errors: validate_data(&data),
+        ..Default::default()
+    };
+    if !report.errors.is_empty() {
+        return Ok(report);
+    }
+
+    let mut tx = pool.begin().await?;
+
+    if mode == ImportMode::Replace {
+        if data.version < 2 {
+            warn_unrestorable(&mut tx, &data, &mut report).await?;
+        }
+        let tables: &[&str] = if data.version < 2 {
+            &["camps", "recipes", "ingredients", "categories"]
+        } else {
+            &["camps", "recipes", "ingredients", "categories", "suppliers"]
+        };
+        for table in tables {
+            sqlx::query(&format!("DELETE FROM {}", table))
+                .execute(&mut *tx)
+                .await?;
+        }
+    }
+
+    let category_map = import_categories(&mut tx, &data, &mut report).await?;
+    let (ingredient_map, new_ingredients) =
+        import_ingredients(&mut tx, &data, &category_map, &mut report).await?;
+    import_packs(&mut tx, &data, &ingredient_map, &new_ingredients, &mut report).await?;
+    let shared_stock: Vec<ExportInventoryItem> = data.inventory.iter()
+        .filter(|item| new_ingredients.contains(&item.ingredient_id))
+        .cloned()
+        .collect();
+    import_stock(&mut tx, None, &shared_stock, &ingredient_map, &mut report).await?;
+    let recipe_map = import_recipes(&mut tx, &data, &ingredient_map, &mut report).await?;
+    import_suppliers(&mut tx, &data, &category_map, &ingredient_map, &mut report).await?;
+    import_camps(&mut tx, &data, &recipe_map, &ingredient_map, &mut report).await?;
+
+    if dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+    }
+
+    Ok(report)
+}
+
+/// Warn about data a replace deletes that a version 1 dump can't bring back
+async fn warn_unrestorable(
+    tx: &mut Transaction<'_, Sqlite>,
+    data: &DataExport,
+    report: &mut ImportReport,
+) -> Result<(), AppError> {
+    let (stock, packs, consumed, assignments): (i64, i64, i64, i64) = sqlx::query_as(
+        "SELECT (SELECT COUNT(*) FROM inventory_items),
+                (SELECT COUNT(*) FROM ingredient_packs),
+                (SELECT COUNT(*) FROM inventory_consumptions),
+                (SELECT COUNT(*) FROM supplier_categories) + (SELECT COUNT(*) FROM supplier_ingredients)"
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+
+    let lost: Vec<String> = [
+        (stock, "stock items"),
+        (packs, "pack sizes"),
+        (consumed, "consumed days"),
+        (assignments, "supplier assignments"),
+    ]
+    .into_iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, what)| format!("{} {}", count, what))
+    .collect();
+
+    if !lost.is_empty() {
+        report.warnings.push(format!(
+            "Export version {} doesn't contain stock, pack sizes, consumed days or supplier assignments; replacing deletes {}",
+            data.version,
+            lost.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+async fn existing_names(
+    tx: &mut Transaction<'_, Sqlite>,
+    table: &str,
+) -> Result<HashMap<String, i64>, AppError> {
+    Ok(sqlx::query_as::<_, (i64, String)>(&format!("SELECT id, name FROM {}", table))
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|(id, name)| (name.to_lowercase(), id))
+        .collect())
+}
+
+/// Existing ingredients by normalized name, then by alias
+async fn existing_ingredients(tx: &mut Transaction<'_, Sqlite>) -> Result<HashMap<String, i64>, AppError> {
+    let mut existing: HashMap<String, i64> = sqlx::query_as::<_, (i64, String)>("SELECT id, name FROM ingredients")
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|(id, name)| (normalize_name(&name), id))
+        .collect();
+    let aliases = sqlx::query_as::<_, (String, i64)>("SELECT normalized, ingredient_id FROM ingredient_aliases")
+        .fetch_all(&mut **tx)
+        .await?;
+    for (alias, id) in aliases {
+        existing.entry(alias).or_insert(id);
+    }
+    Ok(existing)
+}
+
+async fn import_categories(
+    tx: &mut Transaction<'_, Sqlite>,
+    data: &DataExport,
+    report: &mut ImportReport,
+) -> Result<HashMap<i64, i64>, AppError> {
+    let existing = existing_names(tx, "categories").await?;
+    let mut id_map = HashMap::new();
+
+    for category in &data.categories {
+        let id = match existing.get(&category.name.to_lowercase()) {
+            Some(id) => {
+                report.categories_matched += 1;
+                *id
+            }
+            None => {
+                report.categories_created += 1;
+                sqlx::query("INSERT INTO categories (name, sort_order, archived_at) VALUES (?, ?, ?)")
+                    .bind(&category.name)
+                    .bind(category.sort_order)
+                    .bind(category.archived_at)
+                    .execute(&mut **tx)
+                    .await?
+                    .last_insert_rowid()
+            }
+        };
+        id_map.insert(category.id, id);
+    }
+
+    Ok(id_map)
+}
+
+async fn import_ingredients(
+    tx: &mut Transaction<'_, Sqlite>,
+    data: &DataExport,
+    category_map: &HashMap<i64, i64>,
+    report: &mut ImportReport,
+) -> Result<(HashMap<i64, i64>, HashSet<i64>), AppError> {
+    let existing = existing_ingredients(tx).await?;
+    let mut id_map = HashMap::new();
+    // Export IDs of the ingredients created here
+    let mut created = HashSet::new();
+
+    for ingredient in &data.ingredients {
+        let matched = std::iter::once(&ingredient.name)
+            .chain(&ingredient.aliases)
+            .find_map(|name| existing.get(&normalize_name(name)));
+        let id = match matched {
+            Some(id) => {
+                report.ingredients_matched += 1;
+                let unit: String = sqlx::query_scalar("SELECT primary_unit FROM ingredients WHERE id = ?")
+                    .bind(id)
+                    .fetch_one(&mut **tx)
+                    .await?;
+                if unit != ingredient.primary_unit {
+                    report.warnings.push(format!(
+                        "Ingredient '{}' exists with unit '{}', import uses '{}'",
+                        ingredient.name, unit, ingredient.primary_unit
+                    ));
+                }
+                *id
+            }
+            None => {
+                report.ingredients_created += 1;
+                created.insert(ingredient.id);
+                sqlx::query(
+                    "INSERT INTO ingredients (name, category_id, primary_unit, secondary_unit,
+                                              safety_margin_percent, shelf_life_days, storage_type,
+                                              price_per_unit, allergens, archived_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+                )
+                .bind(&ingredient.name)
+                .bind(category_map[&ingredient.category_id])
+                .bind(&ingredient.primary_unit)
+                .bind(&ingredient.secondary_unit)
+                .bind(ingredient.safety_margin_percent)
+                .bind(ingredient.shelf_life_days)
+                .bind(&ingredient.storage_type)
+                .bind(ingredient.price_per_unit)
+                .bind(&ingredient.allergens)
+                .bind(ingredient.archived_at)
+                .execute(&mut **tx)
+                .await?
+                .last_insert_rowid()
+            }
+        };
+        id_map.insert(ingredient.id, id);
+
+        for alias in &ingredient.aliases {
+            sqlx::query(
+                "INSERT OR IGNORE INTO ingredient_aliases (ingredient_id, alias, normalized) VALUES (?, ?, ?)"
+            )
+            .bind(id)
+            .bind(alias)
+            .bind(normalize_name(alias))
+            .execute(&mut **tx)
+            .await?;
+        }
+    }
+
+    Ok((id_map, created))
+}
+
+async fn import_packs(
+    tx: &mut Transaction<'_, Sqlite>,
+    data: &DataExport,
+    ingredient_map: &HashMap<i64, i64>,
+    new_ingredients: &HashSet<i64>,
+    report: &mut ImportReport,
+) -> Result<(), AppError> {
+    for pack in data.ingredient_packs.iter().filter(|p| new_ingredients.contains(&p.ingredient_id)) {
+        sqlx::query("INSERT INTO ingredient_packs (ingredient_id, unit, size, label) VALUES (?, ?, ?, ?)")
+            .bind(ingredient_map[&pack.ingredient_id])
+            .bind(&pack.unit)
+            .bind(pack.size)
+            .bind(&pack.label)
+            .execute(&mut **tx)
+            .await?;
+        report.packs_created += 1;
+    }
+    Ok(())
+}
+
+/// Add stock for a camp, or for the shared storeroom with `None`
+async fn import_stock(
+    tx: &mut Transaction<'_, Sqlite>,
+    camp_id: Option<i64>,
+    items: &[ExportInventoryItem],
+    ingredient_map: &HashMap<i64, i64>,
+    report: &mut ImportReport,
+) -> Result<(), AppError> {
+    for item in items {
+        sqlx::query(
+            "INSERT INTO inventory_items (ingredient_id, camp_id, storeroom, quantity, unit, notes)
+             VALUES (?, ?, ?, ?, ?, ?)"
+        )
+        .bind(ingredient_map[&item.ingredient_id])
+        .bind(camp_id)
+        .bind(&item.storeroom)
+        .bind(item.quantity)
+        .bind(&item.unit)
+        .bind(&item.notes)
+        .execute(&mut **tx)
+        .await?;
+        report.stock_items_created += 1;
+    }
+    Ok(())
+}
+
+async fn import_suppliers(
+    tx: &mut Transaction<'_, Sqlite>,
+    data: &DataExport,
+    category_map: &HashMap<i64, i64>,
+    ingredient_map: &HashMap<i64, i64>,
+    report: &mut ImportReport,
+) -> Result<(), AppError> {
+    let existing = existing_names(tx, "suppliers").await?;
+
+    for export in &data.suppliers {
+        let supplier = &export.supplier;
+        if existing.contains_key(&supplier.name.to_lowercase()) {
+            report.suppliers_matched += 1;
+            continue;
+        }
+
+        report.suppliers_created += 1;
+        let id = sqlx::query(
+            "INSERT INTO suppliers (name, contact, lead_time_days, delivery_days, deliver_upfront, notes)
+             VALUES (?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&supplier.name)
+        .bind(&supplier.contact)
+        .bind(supplier.lead_time_days)
+        .bind(&supplier.delivery_days)
+        .bind(supplier.deliver_upfront)
+        .bind(&supplier.notes)
+        .execute(&mut **tx)
+        .await?
+        .last_insert_rowid();
+
+        for category_id in &export.category_ids {
+            sqlx::query("INSERT OR IGNORE INTO supplier_categories (supplier_id, category_id) VALUES (?, ?)")
+                .bind(id)
+                .bind(category_map[category_id])
+                .execute(&mut **tx)
+                .await?;
+        }
+        for ingredient_id in &export.ingredient_ids {
+            sqlx::query("INSERT OR IGNORE INTO supplier_ingredients (supplier_id, ingredient_id) VALUES (?, ?)")
+                .bind(id)
+                .bind(ingredient_map[ingredient_id])
+                .execute(&mut **tx)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn import_recipes(
+    tx: &mut Transaction<'_, Sqlite>,
+    data: &DataExport,
+    ingredient_map: &HashMap<i64, i64>,
+    report: &mut ImportReport,
+) -> Result<HashMap<i64, i64>, AppError> {
+    let existing = existing_names(tx, "recipes").await?;
+    let mut id_map = HashMap::new();
+
+    for export in &data.recipes {
+        let recipe = &export.recipe;
+        if let Some(id) = existing.get(&recipe.name.to_lowercase()) {
+            report.recipes_matched += 1;
+            id_map.insert(recipe.id, *id);
+            continue;
+        }
+
+        report.recipes_created += 1;
+        let id = sqlx::query(
+            "INSERT INTO recipes (name, instructions, base_servings, tags, archived_at) VALUES (?, ?, ?, ?, ?)"
+        )
+        .bind(&recipe.name)
+        .bind(&recipe.instructions)
+        .bind(recipe.base_servings)
+        .bind(&recipe.tags)
+        .bind(recipe.archived_at)
+        .execute(&mut **tx)
+        .await?
+        .last_insert_rowid();
+
+        for item in &export.ingredients {
+            sqlx::query(
+                "INSERT INTO recipe_ingredients
+                 (recipe_id, ingredient_id, base_quantity, unit, child_multiplier, teen_multiplier, adult_multiplier, notes)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+            )
+            .bind(id)
+            .bind(ingredient_map[&item.ingredient_id])
+            .bind(item.base_quantity)
+            .bind(&item.unit)
+            .bind(item.child_multiplier)
+            .bind(item.teen_multiplier)
+            .bind(item.adult_multiplier)
+            .bind(&item.notes)
+            .execute(&mut **tx)
+            .await?;
+        }
+
+        id_map.insert(recipe.id, id);
+    }
+
+    Ok(id_map)
+}
+
+async fn import_camps(
+    tx: &mut Transaction<'_, Sqlite>,
+    data: &DataExport,
+    recipe_map: &HashMap<i64, i64>,
+    ingredient_map: &HashMap<i64, i64>,
+    report: &mut ImportReport,
+) -> Result<(), AppError> {
+    for camp in &data.camps {
+        let existing: Option<i64> = sqlx::query_scalar(
+            "SELECT id FROM camps WHERE lower(name) = lower(?) AND start_date = ?"
+        )
+        .bind(&camp.name)
+        .bind(camp.start_date)
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        if existing.is_some() {
+            report.camps_matched += 1;
+            report.warnings.push(format!(
+                "Camp '{}' starting {} already exists, its meal plan was not imported",
+                camp.name, camp.start_date
+            ));
+            continue;
+        }
+
+        report.camps_created += 1;
+        let camp_id = sqlx::query(
+            "INSERT INTO camps (name, start_date, end_date, default_children, default_teens, default_adults, notes, archived_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&camp.name)
+        .bind(camp.start_date)
+        .bind(camp.end_date)
+        .bind(camp.default_children)
+        .bind(camp.default_teens)
+        .bind(camp.default_adults)
+        .bind(&camp.notes)
+        .bind(camp.archived_at)
+        .execute(&mut **tx)
+        .await?
+        .last_insert_rowid();
+
+        let mut meal_plans: HashMap<chrono::NaiveDate, i64> = HashMap::new();
+        for meal in &camp.meals {
+            let meal_plan_id = match meal_plans.get(&meal.date) {
+                Some(id) => *id,
+                None => {
+                    let id = sqlx::query("INSERT INTO meal_plans (camp_id, date) VALUES (?, ?)")
+                        .bind(camp_id)
+                        .bind(meal.date)
+                        .execute(&mut **tx)
+                        .await?
+                        .last_insert_rowid();
+                    meal_plans.insert(meal.date, id);
+                    id
+                }
+            };
+
+            let planned_meal_id = sqlx::query(
+                "INSERT INTO planned_meals (meal_plan_id, recipe_id, meal_type) VALUES (?, ?, ?)"
+            )
+            .bind(meal_plan_id)
+            .bind(recipe_map[&meal.recipe_id])
+            .bind(&meal.meal_type)
+            .execute(&mut **tx)
+            .await?
+            .last_insert_rowid();
+
+            if let Some(attendance) = &meal.attendance {
+                sqlx::query(
+                    "INSERT INTO meal_attendance (planned_meal_id, children, teens, adults) VALUES (?, ?, ?, ?)"
+                )
+                .bind(planned_meal_id)
+                .bind(attendance.children)
+                .bind(attendance.teens)
+                .bind(attendance.adults)
+                .execute(&mut **tx)
+                .await?;
+            }
+
+            report.meals_created += 1;
+        }
+
+        import_stock(tx, Some(camp_id), &camp.inventory, ingredient_map, report).await?;
+        for date in &camp.consumed_days {
+            sqlx::query("INSERT OR IGNORE INTO inventory_consumptions (camp_id, date) VALUES (?, ?)")
+                .bind(camp_id)
+                .bind(date)
+                .execute(&mut **tx)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a dump written as JSON or YAML
+pub fn parse_data(content: &str) -> Result<DataExport, String> {
+    serde_json::from_str(content)
+        .or_else(|json_err| {
+            serde_yaml::from_str(content)
+                .map_err(|yaml_err| format!("Not a valid JSON ({}) or YAML ({}) export", json_err, yaml_err))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    async fn merge_matches_ingredients_without_diacritics_and_by_alias() {
+        let pool = test_pool().await;
+        let id = sqlx::query("INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Rajčata', 1, 'g')")
+            .execute(&pool)
+            .await
+            .unwrap()
+            .last_insert_rowid();
+        sqlx::query("INSERT INTO ingredient_aliases (ingredient_id, alias, normalized) VALUES (?, 'Tomatoes', 'tomatoes')")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let export = export_data(&pool).await.unwrap();
+
+        for name in ["RAJCATA", "Tomatoes"] {
+            let mut data = export.clone();
+            data.ingredients[0].name = name.to_string();
+            data.ingredients[0].aliases.clear();
+            let report = import_data(&pool, data, ImportMode::Merge, true).await.unwrap();
+            assert_eq!((report.ingredients_matched, report.ingredients_created), (1, 0), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::error::AppError;
+use crate::models::Trash;
+use sqlx::SqlitePool;
+
+/// Archived camps, categories, ingredients and recipes
+pub async fn get_trash(pool: &SqlitePool) -> Result<Trash, AppError> {
+    let camps = super::camps::get_camps(pool, true).await?;
+    let categories = super::categories::get_categories(pool, true).await?;
+    let ingredients = super::ingredients::get_ingredients(pool, true).await?;
+    let recipes = super::recipes::get_recipes(pool, true).await?;
+
+    Ok(Trash {
+        camps: camps.into_iter().filter(|camp| camp.archived_at.is_some()).collect(),
+        categories: categories.into_iter().filter(|category| category.archived_at.is_some()).collect(),
+        ingredients: ingredients.into_iter().filter(|ingredient| ingredient.archived_at.is_some()).collect(),
+        recipes: recipes.into_iter().filter(|recipe| recipe.archived_at.is_some()).collect(),
+    })
+}
+
+/// Archive or restore the row `id` of `table`, one of the archivable tables.
+///
+/// Archiving keeps the time an already archived row was first archived.
+pub(crate) async fn set_archived(pool: &SqlitePool, table: &str, id: i64, archived: bool) -> Result<(), AppError> {
+    let sql = if archived {
+        format!("UPDATE {} SET archived_at = CURRENT_TIMESTAMP WHERE id = ? AND archived_at IS NULL", table)
+    } else {
+        format!("UPDATE {} SET archived_at = NULL WHERE id = ?", table)
+    };
+    let result = sqlx::query(&sql).bind(id).execute(pool).await?;
+
+    if result.rows_affected() == 0 {
+        let exists: bool = sqlx::query_scalar(&format!("SELECT EXISTS (SELECT 1 FROM {} WHERE id = ?)", table))
+            .bind(id)
+            .fetch_one(pool)
+            .await?;
+        if !exists {
+            return Err(AppError::NotFound);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    async fn archiving_again_keeps_the_first_time() {
+        let pool = test_pool().await;
+        let id = sqlx::query("INSERT INTO recipes (name, base_servings, archived_at) VALUES ('Soup', 4, '2026-01-01 10:00:00')")
+            .execute(&pool)
+            .await
+            .unwrap()
+            .last_insert_rowid();
+
+        set_archived(&pool, "recipes", id, true).await.unwrap();
+        let archived_at: String = sqlx::query_scalar("SELECT archived_at FROM recipes WHERE id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(archived_at, "2026-01-01 10:00:00");
+
+        set_archived(&pool, "recipes", id, false).await.unwrap();
+        assert_eq!(get_trash(&pool).await.unwrap().recipes.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn archiving_an_unknown_row_fails() {
+        let pool = test_pool().await;
+        assert_eq!(set_archived(&pool, "camps", 42, true).await, Err(AppError::NotFound));
+    }
+}
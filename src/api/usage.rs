@@ -0,0 +1,156 @@
+use crate::error::AppError;
+use crate::models::{IngredientUsageReport, PlannedMealUsage, RecipeLineUsage, RecipeUsageReport};
+use sqlx::SqlitePool;
+
+/// Recipes using an ingredient, and how much stock of it is kept
+pub async fn get_ingredient_usage(pool: &SqlitePool, id: i64) -> Result<IngredientUsageReport, AppError> {
+    let ingredient = super::ingredients::get_ingredient(pool, id).await?;
+
+    let recipes = sqlx::query_as::<_, RecipeLineUsage>(
+        "SELECT r.id AS recipe_id, r.name AS recipe_name, ri.base_quantity, ri.unit
+         FROM recipe_ingredients ri
+         JOIN recipes r ON ri.recipe_id = r.id
+         WHERE ri.ingredient_id = ?
+         ORDER BY r.name, ri.id"
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await?;
+
+    let stock_items: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM inventory_items WHERE ingredient_id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(IngredientUsageReport { ingredient, recipes, stock_items })
+}
+
+/// Camps and days a recipe is planned for
+pub async fn get_recipe_usage(pool: &SqlitePool, id: i64) -> Result<RecipeUsageReport, AppError> {
+    let recipe = super::recipes::get_recipe(pool, id).await?;
+
+    let meals = sqlx::query_as::<_, PlannedMealUsage>(
+        "SELECT pm.id AS planned_meal_id, c.id AS camp_id, c.name AS camp_name, mp.date, pm.meal_type
+         FROM planned_meals pm
+         JOIN meal_plans mp ON pm.meal_plan_id = mp.id
+         JOIN camps c ON mp.camp_id = c.id
+         WHERE pm.recipe_id = ?
+         ORDER BY c.start_date DESC, mp.date, pm.id"
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(RecipeUsageReport { recipe, meals })
+}
+
+/// Point every recipe line of `id` at `replacement_id`.
+///
+/// Quantities are converted where the replacement is kept in another unit with
+/// a known conversion; other lines keep their quantity and unit. A recipe that
+/// already uses the replacement gets one line with both quantities. Returns the
+/// number of recipe lines changed.
+pub async fn replace_ingredient(pool: &SqlitePool, id: i64, replacement_id: i64) -> Result<u64, AppError> {
+    if id == replacement_id {
+        return Err(AppError::validation("replacement_id", "validation-replace-with-itself"));
+    }
+    super::ingredients::get_ingredient(pool, id).await?;
+    let replacement = super::ingredients::get_ingredient(pool, replacement_id).await?;
+    if replacement.archived_at.is_some() {
+        return Err(AppError::validation("replacement_id", "validation-replacement-archived"));
+    }
+
+    let mut tx = pool.begin().await?;
+    let lines = sqlx::query_as::<_, (i64, i64, String, f64)>(
+        "SELECT id, recipe_id, unit, base_quantity FROM recipe_ingredients WHERE ingredient_id = ?"
+    )
+    .bind(id)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    for (line_id, recipe_id, unit, quantity) in &lines {
+        let (new_unit, factor) = super::ingredient_merge::target_unit_for(&replacement, unit)
+            .unwrap_or_else(|| (unit.clone(), 1.0));
+        super::ingredient_merge::move_recipe_line(&mut tx, *line_id, *recipe_id, replacement_id, &new_unit, quantity * factor)
+            .await?;
+    }
+
+    tx.commit().await?;
+    Ok(lines.len() as u64)
+}
+
+/// Serve `replacement_id` at every planned meal of `id`; returns the number of meals changed
+pub async fn replace_recipe(pool: &SqlitePool, id: i64, replacement_id: i64) -> Result<u64, AppError> {
+    if id == replacement_id {
+        return Err(AppError::validation("replacement_id", "validation-replace-with-itself"));
+    }
+    super::recipes::get_recipe(pool, id).await?;
+
+    let mut tx = pool.begin().await?;
+    let archived: bool = sqlx::query_scalar("SELECT archived_at IS NOT NULL FROM recipes WHERE id = ?")
+        .bind(replacement_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(AppError::NotFound)?;
+    if archived {
+        return Err(AppError::validation("replacement_id", "validation-replacement-archived"));
+    }
+
+    let result = sqlx::query("UPDATE planned_meals SET recipe_id = ? WHERE recipe_id = ?")
+        .bind(replacement_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    async fn insert(pool: &SqlitePool, sql: &str) -> i64 {
+        sqlx::query(sql).execute(pool).await.unwrap().last_insert_rowid()
+    }
+
+    #[tokio::test]
+    async fn replacing_an_ingredient_adds_to_the_existing_line() {
+        let pool = test_pool().await;
+        let butter = insert(&pool, "INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Butter', 1, 'g')").await;
+        let margarine = insert(&pool, "INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Margarine', 1, 'kg')").await;
+        let recipe = insert(&pool, "INSERT INTO recipes (name, base_servings) VALUES ('Cake', 4)").await;
+        for (ingredient, quantity, unit) in [(butter, 100.0, "g"), (margarine, 0.2, "kg")] {
+            sqlx::query("INSERT INTO recipe_ingredients (recipe_id, ingredient_id, base_quantity, unit) VALUES (?, ?, ?, ?)")
+                .bind(recipe).bind(ingredient).bind(quantity).bind(unit)
+                .execute(&pool).await.unwrap();
+        }
+
+        assert_eq!(replace_ingredient(&pool, margarine, butter).await.unwrap(), 1);
+
+        let lines: Vec<(i64, f64, String)> = sqlx::query_as(
+            "SELECT ingredient_id, base_quantity, unit FROM recipe_ingredients WHERE recipe_id = ?"
+        )
+        .bind(recipe)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(lines, vec![(butter, 300.0, "g".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn archived_replacements_are_rejected() {
+        let pool = test_pool().await;
+        let butter = insert(&pool, "INSERT INTO ingredients (name, category_id, primary_unit, archived_at) VALUES ('Butter', 1, 'g', CURRENT_TIMESTAMP)").await;
+        let margarine = insert(&pool, "INSERT INTO ingredients (name, category_id, primary_unit) VALUES ('Margarine', 1, 'g')").await;
+        let cake = insert(&pool, "INSERT INTO recipes (name, base_servings, archived_at) VALUES ('Cake', 4, CURRENT_TIMESTAMP)").await;
+        let pie = insert(&pool, "INSERT INTO recipes (name, base_servings) VALUES ('Pie', 4)").await;
+
+        let error = replace_ingredient(&pool, margarine, butter).await.unwrap_err();
+        assert_eq!(error.field_errors()[0].message.key, "validation-replacement-archived");
+
+        let error = replace_recipe(&pool, pie, cake).await.unwrap_err();
+        assert_eq!(error.field_errors()[0].message.key, "validation-replacement-archived");
+    }
+}
@@ -0,0 +1,515 @@
+use crate::error::{AppError, FieldError};
+use crate::models::{
+    normalize_name, ImportItem, ImportMapping, ImportStatus, SkippedLine, UnitMismatch,
+    YamlImportOptions, YamlImportReport,
+};
+use serde::Deserialize;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::collections::{BTreeSet, HashMap};
+
+/// Entry of `ingredients.yaml`, keyed by the name recipes refer to it by
+#[derive(Debug, Clone, Deserialize)]
+pub struct YamlIngredient {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub unit: String,
+}
+
+/// Entry of `recipes.yaml`, keyed by recipe name
+#[derive(Debug, Clone, Deserialize)]
+pub struct YamlRecipe {
+    /// Portion group → lines like `0.5 ks  pomeranč`
+    #[serde(default)]
+    pub ingredients: HashMap<String, Vec<String>>,
+    /// Number of portions the quantities are for
+    pub porci: String,
+}
+
+pub fn parse_ingredients_yaml(content: &str) -> Result<HashMap<String, YamlIngredient>, serde_yaml::Error> {
+    serde_yaml::from_str(content)
+}
+
+pub fn parse_recipes_yaml(content: &str) -> Result<HashMap<String, YamlRecipe>, serde_yaml::Error> {
+    serde_yaml::from_str(content)
+}
+
+/// Split `<quantity> <unit>  <ingredient>` (two spaces before the ingredient)
+pub fn parse_ingredient_line(line: &str) -> Option<(f64, String, String)> {
+    let (qty_unit, key) = line.split_once("  ")?;
+    let (qty_str, unit) = qty_unit.split_once(' ')?;
+    let qty = qty_str.trim().parse::<f64>().ok()?;
+    Some((qty, unit.trim().to_string(), key.trim().to_string()))
+}
+
+struct ExistingIngredient {
+    id: i64,
+    category_id: i64,
+    primary_unit: String,
+    secondary_unit: Option<String>,
+}
+
+#[derive(Default)]
+struct GroupQuantities {
+    general: f64,
+    child: f64,
+    teen: f64,
+    adult: f64,
+}
+
+/// One line of the recipe as it will be stored
+struct ImportLine {
+    ingredient_key: String,
+    /// `None` when the ingredient still has to be created
+    ingredient_id: Option<i64>,
+    base_quantity: f64,
+    unit: String,
+    child_multiplier: f64,
+    teen_multiplier: f64,
+    adult_multiplier: f64,
+}
+
+/// Import ingredients and recipes in the `source_data` YAML format.
+///
+/// Ingredients are matched by name and recipes by name (both case-insensitive).
+/// Everything runs in one transaction, which a dry run rolls back, so the report
+/// is the same whether or not anything was written.
+pub async fn import_yaml(
+    pool: &SqlitePool,
+    ingredients: &HashMap<String, YamlIngredient>,
+    recipes: &HashMap<String, YamlRecipe>,
+    mapping: &ImportMapping,
+    options: YamlImportOptions,
+) -> Result<YamlImportReport, AppError> {
+    let mut report = YamlImportReport {
+        dry_run: options.dry_run,
+        ..Default::default()
+    };
+
+    let mut tx = pool.begin().await?;
+
+    let categories: HashMap<String, i64> = sqlx::query_as::<_, (i64, String)>("SELECT id, name FROM categories")
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|(id, name)| (name.to_lowercase(), id))
+        .collect();
+
+    let default_category_id = *categories
+        .get(&mapping.default_category.to_lowercase())
+        .ok_or_else(|| AppError::Validation(vec![
+            FieldError::new("default_category", "validation-default-category-missing")
+                .with_arg("name", &mapping.default_category)
+        ]))?;
+
+    let mut existing: HashMap<String, ExistingIngredient> = sqlx::query_as::<_, (i64, String, i64, String, Option<String>)>(
+        "SELECT id, name, category_id, primary_unit, secondary_unit FROM ingredients"
+    )
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .map(|(id, name, category_id, primary_unit, secondary_unit)| {
+        (name.to_lowercase(), ExistingIngredient { id, category_id, primary_unit, secondary_unit })
+    })
+    .collect();
+
+    // Aliases (e.g. names of merged duplicates) and names without diacritics
+    // point at the ingredient's lowercase name
+    let mut aliases: HashMap<String, String> = sqlx::query_as::<_, (String, String)>(
+        "SELECT a.normalized, i.name FROM ingredient_aliases a JOIN ingredients i ON a.ingredient_id = i.id"
+    )
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .map(|(alias, name)| (alias, name.to_lowercase()))
+    .collect();
+    for name in existing.keys() {
+        aliases.entry(normalize_name(name)).or_insert_with(|| name.clone());
+    }
+
+    // Recipe lines refer to ingredients by their key in ingredients.yaml
+    let mut key_to_name: HashMap<String, String> = HashMap::new();
+    let mut unmapped_types = BTreeSet::new();
+
+    let mut ingredient_keys: Vec<&String> = ingredients.keys().collect();
+    ingredient_keys.sort();
+
+    for key in ingredient_keys {
+        let ing = &ingredients[key];
+        let category_id = match mapping.categories.get(&ing.kind)
+            .and_then(|category| categories.get(&category.to_lowercase()))
+        {
+            Some(id) => *id,
+            None => {
+                unmapped_types.insert(ing.kind.clone());
+                default_category_id
+            }
+        };
+
+        let lower_name = resolve_name(&ing.name, &existing, &aliases);
+        key_to_name.insert(key.to_lowercase(), lower_name.clone());
+
+        let item = match existing.get_mut(&lower_name) {
+            None => {
+                let id = sqlx::query("INSERT INTO ingredients (name, category_id, primary_unit) VALUES (?, ?, ?)")
+                    .bind(&ing.name)
+                    .bind(category_id)
+                    .bind(&ing.unit)
+                    .execute(&mut *tx)
+                    .await?
+                    .last_insert_rowid();
+
+                existing.insert(lower_name, ExistingIngredient {
+                    id,
+                    category_id,
+                    primary_unit: ing.unit.clone(),
+                    secondary_unit: None,
+                });
+
+                ImportItem { name: ing.name.clone(), status: ImportStatus::New, applied: true, details: Vec::new() }
+            }
+            Some(current) => {
+                let mut details = Vec::new();
+                if current.category_id != category_id {
+                    details.push(format!("category → {}", mapping.categories.get(&ing.kind).unwrap_or(&mapping.default_category)));
+                }
+                if current.primary_unit != ing.unit {
+                    details.push(format!("unit {} → {}", current.primary_unit, ing.unit));
+                }
+
+                if details.is_empty() {
+                    ImportItem { name: ing.name.clone(), status: ImportStatus::Unchanged, applied: false, details }
+                } else {
+                    // Recipes and stock already use the old unit
+                    let status = if current.primary_unit != ing.unit {
+                        ImportStatus::Conflict
+                    } else {
+                        ImportStatus::Changed
+                    };
+
+                    if options.update_existing {
+                        sqlx::query(
+                            "UPDATE ingredients SET category_id = ?, primary_unit = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?"
+                        )
+                        .bind(category_id)
+                        .bind(&ing.unit)
+                        .bind(current.id)
+                        .execute(&mut *tx)
+                        .await?;
+
+                        current.category_id = category_id;
+                        current.primary_unit = ing.unit.clone();
+                    }
+                    ImportItem { name: ing.name.clone(), status, applied: options.update_existing, details }
+                }
+            }
+        };
+        report.ingredients.push(item);
+    }
+    report.unmapped_types = unmapped_types.into_iter().collect();
+
+    let existing_recipes: HashMap<String, i64> = sqlx::query_as::<_, (i64, String)>("SELECT id, name FROM recipes")
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|(id, name)| (name.to_lowercase(), id))
+        .collect();
+
+    let mut unknown_groups = BTreeSet::new();
+
+    let mut recipe_names: Vec<&String> = recipes.keys().collect();
+    recipe_names.sort();
+
+    for recipe_name in recipe_names {
+        let recipe = &recipes[recipe_name];
+        let porci = recipe.porci.trim().parse::<f64>().unwrap_or(1.0).max(1.0);
+
+        // Sum each ingredient's quantities per group, keeping the first unit seen
+        let mut entries: HashMap<String, (String, GroupQuantities)> = HashMap::new();
+
+        let mut groups: Vec<&String> = recipe.ingredients.keys().collect();
+        groups.sort();
+
+        for group in groups {
+            let g = &mapping.groups;
+            if ![&g.general, &g.child, &g.teen, &g.adult].contains(&group) {
+                unknown_groups.insert(group.clone());
+                continue;
+            }
+
+            for line in &recipe.ingredients[group] {
+                let Some((qty, unit, key)) = parse_ingredient_line(line) else {
+                    report.skipped_lines.push(SkippedLine {
+                        recipe: recipe_name.clone(),
+                        group: group.clone(),
+                        line: line.clone(),
+                        reason: "expected '<quantity> <unit>  <ingredient>'".to_string(),
+                    });
+                    continue;
+                };
+
+                let (entry_unit, quantities) = entries.entry(key.clone())
+                    .or_insert_with(|| (unit.clone(), GroupQuantities::default()));
+                if *entry_unit != unit {
+                    report.skipped_lines.push(SkippedLine {
+                        recipe: recipe_name.clone(),
+                        group: group.clone(),
+                        line: line.clone(),
+                        reason: format!("'{}' is already listed in {}", key, entry_unit),
+                    });
+                    continue;
+                }
+
+                if group == &g.general {
+                    quantities.general += qty;
+                } else if group == &g.child {
+                    quantities.child += qty;
+                } else if group == &g.teen {
+                    quantities.teen += qty;
+                } else {
+                    quantities.adult += qty;
+                }
+            }
+        }
+
+        let mut entry_keys: Vec<String> = entries.keys().cloned().collect();
+        entry_keys.sort();
+
+        let mut lines = Vec::new();
+        for key in entry_keys {
+            let (unit, q) = &entries[&key];
+
+            let lower_key = key.to_lowercase();
+            let lookup_name = key_to_name.get(&lower_key).cloned().unwrap_or_else(|| resolve_name(&key, &existing, &aliases));
+            let ingredient_id = match existing.get(&lookup_name) {
+                Some(ingredient) => {
+                    if &ingredient.primary_unit != unit && ingredient.secondary_unit.as_ref() != Some(unit) {
+                        report.unit_mismatches.push(UnitMismatch {
+                            recipe: recipe_name.clone(),
+                            ingredient: key.clone(),
+                            recipe_unit: unit.clone(),
+                            ingredient_unit: ingredient.primary_unit.clone(),
+                        });
+                    }
+                    Some(ingredient.id)
+                }
+                None if options.auto_create => None,
+                None => {
+                    report.skipped_lines.push(SkippedLine {
+                        recipe: recipe_name.clone(),
+                        group: String::new(),
+                        line: key.clone(),
+                        reason: "unknown ingredient".to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            // Per-person quantities, relative to the adult portion where there is one
+            let adult_per = (q.adult + q.general) / porci;
+            let child_per = (q.child + q.general) / porci;
+            let teen_per = (q.teen + q.general) / porci;
+
+            let (base_quantity, child_multiplier, teen_multiplier, adult_multiplier) = if adult_per > 0.0 {
+                (adult_per, child_per / adult_per, teen_per / adult_per, 1.0)
+            } else if child_per > 0.0 {
+                (child_per, 1.0, teen_per / child_per, 0.0)
+            } else if teen_per > 0.0 {
+                (teen_per, 0.0, 1.0, 0.0)
+            } else {
+                continue; // zero quantity everywhere
+            };
+
+            lines.push(ImportLine {
+                ingredient_key: key,
+                ingredient_id,
+                base_quantity,
+                unit: unit.clone(),
+                child_multiplier,
+                teen_multiplier,
+                adult_multiplier,
+            });
+        }
+
+        let item = match existing_recipes.get(&recipe_name.to_lowercase()) {
+            None => {
+                let recipe_id = sqlx::query("INSERT INTO recipes (name, base_servings) VALUES (?, 1)")
+                    .bind(recipe_name)
+                    .execute(&mut *tx)
+                    .await?
+                    .last_insert_rowid();
+
+                insert_lines(&mut tx, recipe_id, &mut lines, &mut existing, default_category_id, &mut report).await?;
+
+                ImportItem { name: recipe_name.clone(), status: ImportStatus::New, applied: true, details: Vec::new() }
+            }
+            Some(&recipe_id) => {
+                let mut details = diff_lines(&mut tx, recipe_id, &lines).await?;
+
+                if details.is_empty() {
+                    ImportItem { name: recipe_name.clone(), status: ImportStatus::Unchanged, applied: false, details }
+                } else {
+                    let planned: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM planned_meals WHERE recipe_id = ?")
+                        .bind(recipe_id)
+                        .fetch_one(&mut *tx)
+                        .await?;
+                    if planned > 0 {
+                        details.push(format!("planned {} times", planned));
+                    }
+
+                    if options.update_existing {
+                        sqlx::query("DELETE FROM recipe_ingredients WHERE recipe_id = ?")
+                            .bind(recipe_id)
+                            .execute(&mut *tx)
+                            .await?;
+                        sqlx::query("UPDATE recipes SET base_servings = 1, updated_at = CURRENT_TIMESTAMP WHERE id = ?")
+                            .bind(recipe_id)
+                            .execute(&mut *tx)
+                            .await?;
+
+                        insert_lines(&mut tx, recipe_id, &mut lines, &mut existing, default_category_id, &mut report).await?;
+                    }
+                    ImportItem {
+                        name: recipe_name.clone(),
+                        status: if planned > 0 { ImportStatus::Conflict } else { ImportStatus::Changed },
+                        applied: options.update_existing,
+                        details,
+                    }
+                }
+            }
+        };
+        report.recipes.push(item);
+    }
+    report.unknown_groups = unknown_groups.into_iter().collect();
+
+    if options.dry_run {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+    }
+
+    Ok(report)
+}
+
+/// Key of `name` in the existing ingredients, ignoring diacritics and following aliases
+fn resolve_name(
+    name: &str,
+    existing: &HashMap<String, ExistingIngredient>,
+    aliases: &HashMap<String, String>,
+) -> String {
+    let lower = name.to_lowercase();
+    if existing.contains_key(&lower) {
+        return lower;
+    }
+    aliases.get(&normalize_name(name)).cloned().unwrap_or(lower)
+}
+
+/// Write recipe lines, creating missing ingredients in the default category
+async fn insert_lines(
+    tx: &mut Transaction<'_, Sqlite>,
+    recipe_id: i64,
+    lines: &mut [ImportLine],
+    existing: &mut HashMap<String, ExistingIngredient>,
+    default_category_id: i64,
+    report: &mut YamlImportReport,
+) -> Result<(), AppError> {
+    for line in lines.iter_mut() {
+        let ingredient_id = match line.ingredient_id {
+            Some(id) => id,
+            None => {
+                // An earlier recipe may have created it already
+                let id = match existing.get(&line.ingredient_key.to_lowercase()) {
+                    Some(ingredient) => ingredient.id,
+                    None => {
+                        let id = sqlx::query("INSERT INTO ingredients (name, category_id, primary_unit) VALUES (?, ?, ?)")
+                            .bind(&line.ingredient_key)
+                            .bind(default_category_id)
+                            .bind(&line.unit)
+                            .execute(&mut **tx)
+                            .await?
+                            .last_insert_rowid();
+
+                        existing.insert(line.ingredient_key.to_lowercase(), ExistingIngredient {
+                            id,
+                            category_id: default_category_id,
+                            primary_unit: line.unit.clone(),
+                            secondary_unit: None,
+                        });
+                        report.auto_created.push(line.ingredient_key.clone());
+                        id
+                    }
+                };
+                line.ingredient_id = Some(id);
+                id
+            }
+        };
+
+        sqlx::query(
+            "INSERT INTO recipe_ingredients
+             (recipe_id, ingredient_id, base_quantity, unit, child_multiplier, teen_multiplier, adult_multiplier)
+             VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(recipe_id)
+        .bind(ingredient_id)
+        .bind(line.base_quantity)
+        .bind(&line.unit)
+        .bind(line.child_multiplier)
+        .bind(line.teen_multiplier)
+        .bind(line.adult_multiplier)
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Describe how the stored recipe differs from the imported lines
+async fn diff_lines(
+    tx: &mut Transaction<'_, Sqlite>,
+    recipe_id: i64,
+    lines: &[ImportLine],
+) -> Result<Vec<String>, AppError> {
+    let current = sqlx::query_as::<_, (i64, String, f64, String, f64, f64, f64)>(
+        "SELECT ri.ingredient_id, i.name, ri.base_quantity, ri.unit,
+                ri.child_multiplier, ri.teen_multiplier, ri.adult_multiplier
+         FROM recipe_ingredients ri
+         JOIN ingredients i ON ri.ingredient_id = i.id
+         WHERE ri.recipe_id = ?"
+    )
+    .bind(recipe_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let same = |a: f64, b: f64| (a - b).abs() < 1e-6;
+    let mut details = Vec::new();
+
+    for line in lines {
+        let stored = line.ingredient_id
+            .and_then(|id| current.iter().find(|(ingredient_id, ..)| *ingredient_id == id));
+
+        match stored {
+            None => details.push(format!("adds {}", line.ingredient_key)),
+            Some((_, _, base, unit, child, teen, adult)) => {
+                if unit != &line.unit
+                    || !same(*base, line.base_quantity)
+                    || !same(*child, line.child_multiplier)
+                    || !same(*teen, line.teen_multiplier)
+                    || !same(*adult, line.adult_multiplier)
+                {
+                    details.push(format!(
+                        "{}: {:.2} {} → {:.2} {}",
+                        line.ingredient_key, base, unit, line.base_quantity, line.unit
+                    ));
+                }
+            }
+        }
+    }
+
+    for (ingredient_id, name, ..) in &current {
+        if !lines.iter().any(|line| line.ingredient_id == Some(*ingredient_id)) {
+            details.push(format!("removes {}", name));
+        }
+    }
+
+    Ok(details)
+}
@@ -7,11 +7,14 @@ use leptos_router::{
 
 use crate::components::nav::NavBar;
 use crate::components::ToastProvider;
+use crate::i18n::{provide_language, use_language};
 use crate::pages::*;
+use crate::t;
 
 #[component]
 pub fn App() -> impl IntoView {
     provide_meta_context();
+    provide_language();
 
     view! {
         <ToastProvider>
@@ -21,9 +24,9 @@ pub fn App() -> impl IntoView {
                 <Routes fallback=|| view! {
                     <div class="card text-center py-16">
                         <div class="text-8xl mb-6">"🔍"</div>
-                        <p class="text-3xl font-bold text-slate-800 mb-4">"Page not found"</p>
-                        <p class="text-xl text-slate-600 mb-8">"The page you're looking for doesn't exist."</p>
-                        <a href="/" class="btn btn-primary">"Go Home"</a>
+                        <p class="text-3xl font-bold text-slate-800 mb-4">{t!("page-not-found")}</p>
+                        <p class="text-xl text-slate-600 mb-8">{t!("page-not-found-text")}</p>
+                        <a href="/" class="btn btn-primary">{t!("go-home")}</a>
                     </div>
                 }>
                     <Route path=path!("login") view=LoginPage/>
@@ -31,9 +34,11 @@ pub fn App() -> impl IntoView {
                     <Route path=path!("camps") view=CampsPage/>
                     <Route path=path!("recipes") view=RecipesPage/>
                     <Route path=path!("ingredients") view=IngredientsPage/>
+                    <Route path=path!("inventory") view=InventoryPage/>
                     <Route path=path!("planner") view=MealPlannerPage/>
                     <Route path=path!("planner/:camp_id") view=MealPlannerPage/>
                     <Route path=path!("reports") view=ReportsPage/>
+                    <Route path=path!("admin") view=AdminPage/>
                 </Routes>
             </main>
             </Router>
@@ -45,7 +50,7 @@ pub fn App() -> impl IntoView {
 pub fn Shell(options: LeptosOptions) -> impl IntoView {
     view! {
         <!DOCTYPE html>
-        <html lang="en">
+        <html lang=use_language().html_lang()>
             <head>
                 <meta charset="utf-8"/>
                 <meta name="viewport" content="width=device-width, initial-scale=1"/>
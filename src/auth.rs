@@ -1,23 +1,48 @@
 use axum::{
-    extract::Request,
+    extract::{Request, State},
+    http::header,
     middleware::Next,
     response::{IntoResponse, Redirect, Response},
 };
+use sqlx::SqlitePool;
 use tower_sessions::Session;
 
-pub async fn require_auth(session: Session, request: Request, next: Next) -> Response {
+use crate::api::api_tokens;
+use crate::rest::{ApiError, API_PREFIX};
+
+pub async fn require_auth(
+    State(pool): State<SqlitePool>,
+    session: Session,
+    request: Request,
+    next: Next,
+) -> Response {
     let path = request.uri().path().to_string();
 
     let is_public = path == "/login"
         || path.starts_with("/api/login")
         || path.starts_with("/api/logout")
         || path.starts_with("/pkg")
-        || path.starts_with("/style");
+        || path.starts_with("/style")
+        || path == "/api/v1/openapi.json"
+        // Calendar feeds check their own token, phones subscribe without a session
+        || (path.starts_with("/camps/") && path.ends_with("/menu.ics"));
 
     if is_public {
         return next.run(request).await;
     }
 
+    // Scripts authenticate to the REST API with a personal token instead of a session
+    if is_api_path(&path)
+        && let Some(secret) = bearer_token(&request)
+    {
+        return match api_tokens::authenticate_api_token(&pool, &secret).await {
+            Ok(Some(scope)) if scope.allows(request.method().as_str()) => next.run(request).await,
+            Ok(Some(_)) => ApiError::forbidden("This token is read-only").into_response(),
+            Ok(None) => ApiError::unauthorized("Invalid or revoked API token").into_response(),
+            Err(e) => ApiError::from(e).into_response(),
+        };
+    }
+
     let authenticated = session
         .get::<bool>("authenticated")
         .await
@@ -25,7 +50,23 @@ pub async fn require_auth(session: Session, request: Request, next: Next) -> Res
 
     if authenticated == Some(true) {
         next.run(request).await
+    } else if is_api_path(&path) {
+        // API clients get a JSON error rather than the login page
+        ApiError::unauthorized("Log in first or send an API token as `Authorization: Bearer <token>`").into_response()
     } else {
         Redirect::to("/login").into_response()
     }
 }
+
+/// Whether `path` is the REST API itself or below it, not just a path sharing its prefix
+fn is_api_path(path: &str) -> bool {
+    path.strip_prefix(API_PREFIX)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Secret of an `Authorization: Bearer <token>` header
+fn bearer_token(request: &Request) -> Option<String> {
+    let value = request.headers().get(header::AUTHORIZATION)?.to_str().ok()?;
+    let token = value.strip_prefix("Bearer ")?.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
@@ -0,0 +1,64 @@
+use ai_meal_planning::db::connect;
+use ai_meal_planning::migrate::{migrate_down, migrate_up, status, MigrationState};
+
+const USAGE: &str = "Usage:
+  migrate list
+  migrate up [<name>]       apply pending migrations, up to and including <name>
+  migrate down [<steps>]    roll back the last <steps> migrations (default 1)";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let Some(command) = args.first() else {
+        eprintln!("{}", USAGE);
+        std::process::exit(2);
+    };
+    let argument = args.get(1).map(String::as_str);
+
+    let pool = connect().await?;
+
+    match command.as_str() {
+        "list" => {
+            for migration in status(&pool).await? {
+                let state = match migration.state {
+                    MigrationState::Pending => "pending",
+                    MigrationState::Applied => "applied",
+                    MigrationState::Modified => "MODIFIED",
+                    MigrationState::Unknown => "UNKNOWN",
+                };
+                println!(
+                    "{:<8} {:<36} {:<19} {}",
+                    state,
+                    migration.name,
+                    migration.applied_at.map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap_or_default(),
+                    if migration.reversible { "reversible" } else { "" }
+                );
+            }
+        }
+        "up" => {
+            let applied = migrate_up(&pool, argument).await?;
+            if applied.is_empty() {
+                println!("Nothing to apply");
+            }
+            for name in applied {
+                println!("Applied {}", name);
+            }
+        }
+        "down" => {
+            let steps = match argument {
+                Some(steps) => steps.parse()?,
+                None => 1,
+            };
+            for name in migrate_down(&pool, steps).await? {
+                println!("Rolled back {}", name);
+            }
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    }
+
+    Ok(())
+}
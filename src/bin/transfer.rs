@@ -0,0 +1,75 @@
+use std::fs;
+
+use ai_meal_planning::api::transfer::{export_data, import_data, parse_data};
+use ai_meal_planning::db::init_db;
+use ai_meal_planning::models::ImportMode;
+
+const USAGE: &str = "Usage:
+  data_transfer export <file.json|file.yaml>
+  data_transfer import <file> [--replace] [--dry-run]";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let (Some(command), Some(path)) = (args.first(), args.get(1)) else {
+        eprintln!("{}", USAGE);
+        std::process::exit(2);
+    };
+    let flags = &args[2..];
+
+    let pool = init_db().await?;
+
+    match command.as_str() {
+        "export" => {
+            let data = export_data(&pool).await?;
+            let body = if path.ends_with(".yaml") || path.ends_with(".yml") {
+                serde_yaml::to_string(&data)?
+            } else {
+                serde_json::to_string_pretty(&data)?
+            };
+            fs::write(path, body)?;
+            println!(
+                "Exported {} categories, {} ingredients, {} recipes and {} camps to {}",
+                data.categories.len(),
+                data.ingredients.len(),
+                data.recipes.len(),
+                data.camps.len(),
+                path
+            );
+        }
+        "import" => {
+            let mode = if flags.iter().any(|f| f == "--replace") { ImportMode::Replace } else { ImportMode::Merge };
+            let dry_run = flags.iter().any(|f| f == "--dry-run");
+
+            let data = parse_data(&fs::read_to_string(path)?)?;
+            let report = import_data(&pool, data, mode, dry_run).await?;
+
+            for error in &report.errors {
+                eprintln!("error: {}", error);
+            }
+            for warning in &report.warnings {
+                eprintln!("warning: {}", warning);
+            }
+            if !report.errors.is_empty() {
+                std::process::exit(1);
+            }
+
+            println!("{}", if dry_run { "Dry run, nothing was written:" } else { "Import finished:" });
+            println!("  categories:  {} created, {} matched", report.categories_created, report.categories_matched);
+            println!("  ingredients: {} created, {} matched", report.ingredients_created, report.ingredients_matched);
+            println!("  recipes:     {} created, {} matched", report.recipes_created, report.recipes_matched);
+            println!("  camps:       {} created, {} matched", report.camps_created, report.camps_matched);
+            println!("  meals:       {} created", report.meals_created);
+            println!("  suppliers:   {} created, {} matched", report.suppliers_created, report.suppliers_matched);
+            println!("  stock items: {} created", report.stock_items_created);
+            println!("  pack sizes:  {} created", report.packs_created);
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    }
+
+    Ok(())
+}
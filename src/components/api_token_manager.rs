@@ -0,0 +1,205 @@
+use crate::models::{ApiToken, ApiTokenScope};
+use crate::server_functions::api_tokens::{create_api_token, get_api_tokens, revoke_api_token};
+use crate::components::{ConfirmModal, toast_success, toast_error};
+use crate::format::format_date;
+use crate::i18n::use_language;
+use crate::t;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+/// Personal tokens for scripts using the `/api/v1` REST API
+#[component]
+pub fn ApiTokenManager() -> impl IntoView {
+    let language = use_language();
+    let (tokens, set_tokens) = signal(Vec::<ApiToken>::new());
+    let (name, set_name) = signal(String::new());
+    let (scope, set_scope) = signal(ApiTokenScope::Read);
+    // Secret of the token just created, shown until the next one is created
+    let (new_secret, set_new_secret) = signal(None::<String>);
+    let (loading, set_loading) = signal(false);
+    let (show_revoke_modal, set_show_revoke_modal) = signal(false);
+    let (revoke_id, set_revoke_id) = signal(0i64);
+
+    let load_tokens = move || {
+        spawn_local(async move {
+            match get_api_tokens().await {
+                Ok(data) => set_tokens.set(data),
+                Err(e) => toast_error(t!("error-load-api-tokens", error = e.to_string())),
+            }
+        });
+    };
+
+    Effect::new(move |_| {
+        load_tokens();
+    });
+
+    let handle_create = move |ev: SubmitEvent| {
+        ev.prevent_default();
+
+        let name_val = name.get_untracked();
+        if name_val.trim().is_empty() {
+            toast_error(t!("validation-required-fields"));
+            return;
+        }
+        let scope_val = scope.get_untracked();
+
+        spawn_local(async move {
+            set_loading.set(true);
+            match create_api_token(name_val, scope_val).await {
+                Ok(created) => {
+                    toast_success(t!("api-token-created"));
+                    set_new_secret.set(Some(created.secret));
+                    set_name.set(String::new());
+                    load_tokens();
+                }
+                Err(e) => toast_error(t!("error-create-api-token", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let confirm_revoke = move || {
+        let id = revoke_id.get_untracked();
+        set_show_revoke_modal.set(false);
+
+        spawn_local(async move {
+            match revoke_api_token(id).await {
+                Ok(_) => {
+                    toast_success(t!("api-token-revoked"));
+                    load_tokens();
+                }
+                Err(e) => toast_error(t!("error-revoke-api-token", error = e.to_string())),
+            }
+        });
+    };
+
+    let cancel_revoke = move || {
+        set_show_revoke_modal.set(false);
+    };
+
+    view! {
+        <div class="space-y-6">
+            <h2 class="text-3xl font-bold text-gradient flex items-center gap-3">
+                <span class="text-4xl">"🔑"</span>
+                {t!("api-tokens")}
+            </h2>
+
+            <div class="card space-y-4">
+                <p class="text-slate-600">{t!("api-tokens-help")}</p>
+                <form on:submit=handle_create class="flex flex-wrap items-end gap-3">
+                    <div class="flex-1 min-w-48">
+                        <label for="api-token-name" class="form-label">{t!("api-token-name")}</label>
+                        <input
+                            id="api-token-name"
+                            type="text"
+                            class="form-input"
+                            placeholder=t!("api-token-name-placeholder")
+                            prop:value=move || name.get()
+                            on:input=move |ev| set_name.set(event_target_value(&ev))
+                        />
+                    </div>
+                    <div>
+                        <label for="api-token-scope" class="form-label">{t!("api-token-scope")}</label>
+                        <select
+                            id="api-token-scope"
+                            class="form-input"
+                            on:change=move |ev| {
+                                if let Ok(value) = event_target_value(&ev).parse::<ApiTokenScope>() {
+                                    set_scope.set(value);
+                                }
+                            }
+                        >
+                            {ApiTokenScope::ALL.into_iter().map(|option| view! {
+                                <option value=option.as_str() selected=move || scope.get() == option>
+                                    {t!(option.message_key())}
+                                </option>
+                            }).collect_view()}
+                        </select>
+                    </div>
+                    <button type="submit" class="btn btn-primary" disabled=move || loading.get()>
+                        {t!("api-token-create")}
+                    </button>
+                </form>
+
+                {move || new_secret.get().map(|secret| view! {
+                    <div class="p-4 rounded-lg bg-emerald-50 border border-emerald-200 space-y-2">
+                        <p class="font-medium text-emerald-800">{t!("api-token-copy-now")}</p>
+                        <code class="block p-2 bg-white rounded border break-all select-all">{secret}</code>
+                    </div>
+                })}
+            </div>
+
+            {move || if tokens.get().is_empty() {
+                view! {
+                    <div class="card text-center py-8 text-slate-500">{t!("no-api-tokens")}</div>
+                }.into_any()
+            } else {
+                view! {
+                    <div class="card overflow-x-auto">
+                        <table class="w-full text-left">
+                            <thead>
+                                <tr class="border-b">
+                                    <th class="py-2">{t!("api-token-name")}</th>
+                                    <th class="py-2">{t!("api-token-scope")}</th>
+                                    <th class="py-2">{t!("api-token-prefix")}</th>
+                                    <th class="py-2">{t!("api-token-last-used")}</th>
+                                    <th class="py-2"></th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                <For
+                                    each=move || tokens.get()
+                                    key=|token| token.id
+                                    let:token
+                                >
+                                    <tr class="border-b last:border-0">
+                                        <td class="py-2 font-medium">{token.name.clone()}</td>
+                                        <td class="py-2">
+                                            <span class="badge badge-secondary">
+                                                {token.scope.parse::<ApiTokenScope>()
+                                                    .map(|scope| t!(scope.message_key()))
+                                                    .unwrap_or_else(|_| token.scope.clone())}
+                                            </span>
+                                        </td>
+                                        <td class="py-2"><code>{format!("{}…", token.prefix)}</code></td>
+                                        <td class="py-2 text-slate-600">
+                                            {token.last_used_at
+                                                .map(|used| format_date(used.date_naive(), language))
+                                                .unwrap_or_else(|| t!("api-token-never-used"))}
+                                        </td>
+                                        <td class="py-2 text-right">
+                                            <button
+                                                class="btn btn-danger text-sm"
+                                                on:click={
+                                                    let id = token.id;
+                                                    move |_| {
+                                                        set_revoke_id.set(id);
+                                                        set_show_revoke_modal.set(true);
+                                                    }
+                                                }
+                                            >
+                                                {t!("api-token-revoke")}
+                                            </button>
+                                        </td>
+                                    </tr>
+                                </For>
+                            </tbody>
+                        </table>
+                    </div>
+                }.into_any()
+            }}
+
+            <ConfirmModal
+                show=show_revoke_modal.into()
+                on_confirm=confirm_revoke
+                on_cancel=cancel_revoke
+                title=t!("api-token-revoke")
+                message=t!("api-token-revoke-confirm")
+                confirm_text=t!("api-token-revoke")
+                cancel_text=t!("cancel")
+                variant="danger".to_string()
+            />
+        </div>
+    }
+}
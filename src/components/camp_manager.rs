@@ -1,6 +1,12 @@
-use crate::models::Camp;
-use crate::server_functions::camps::{get_camps, create_camp, delete_camp};
-use crate::components::{ConfirmModal, toast_success, toast_error};
+use crate::models::{Camp, CreateCamp, PlanCheck};
+use crate::server_functions::camps::{get_camps, create_camp, delete_camp, archive_camp, get_calendar_url};
+use crate::server_functions::meal_plans::check_all_camp_plans;
+use crate::components::{ConfirmModal, FieldErrors, toast_success, toast_error, validate_form};
+use crate::error::FieldError;
+use crate::t;
+use crate::format::format_date;
+use crate::i18n::use_language;
+use chrono::NaiveDate;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use leptos::ev::SubmitEvent;
@@ -8,12 +14,16 @@ use leptos_router::hooks::use_navigate;
 
 #[component]
 pub fn CampManager() -> impl IntoView {
+    let language = use_language();
     let navigate = use_navigate();
     let nav_stored = StoredValue::new(navigate);
     
     let (camps, set_camps) = signal(Vec::<Camp>::new());
+    let (plan_checks, set_plan_checks) = signal(Vec::<PlanCheck>::new());
     let (show_form, set_show_form) = signal(false);
     let (error, set_error) = signal(None::<String>);
+    // Field errors the server returned for the last save
+    let (field_errors, set_field_errors) = signal(Vec::<FieldError>::new());
     let (loading, set_loading) = signal(false);
 
     // Modal state
@@ -35,9 +45,14 @@ pub fn CampManager() -> impl IntoView {
             set_loading.set(true);
             set_error.set(None);
             
-            match get_camps().await {
+            match get_camps(false).await {
                 Ok(data) => set_camps.set(data),
-                Err(e) => set_error.set(Some(format!("Failed to load camps: {}", e))),
+                Err(e) => set_error.set(Some(t!("error-load-camps", error = e.to_string()))),
+            }
+
+            match check_all_camp_plans().await {
+                Ok(data) => set_plan_checks.set(data),
+                Err(e) => set_error.set(Some(t!("error-check-plans", error = e.to_string()))),
             }
             
             set_loading.set(false);
@@ -51,6 +66,7 @@ pub fn CampManager() -> impl IntoView {
     let cancel_form = move |_| {
         set_show_form.set(false);
         set_error.set(None);
+        set_field_errors.set(Vec::new());
     };
 
     // Reset form fields
@@ -63,6 +79,7 @@ pub fn CampManager() -> impl IntoView {
         set_default_adults.set(String::from("0"));
         set_notes.set(String::new());
         set_error.set(None);
+        set_field_errors.set(Vec::new());
     };
 
     let handle_submit = move |ev: SubmitEvent| {
@@ -77,14 +94,14 @@ pub fn CampManager() -> impl IntoView {
         let notes_val = notes.get();
         
         if name_val.is_empty() || start_date_val.is_empty() || end_date_val.is_empty() {
-            toast_error("Please fill in all required fields");
+            toast_error(t!("validation-required-fields"));
             return;
         }
 
         let children_count = match default_children_val.parse::<i32>() {
             Ok(val) => val,
             Err(_) => {
-                toast_error("Invalid number for children");
+                toast_error(t!("validation-children-number"));
                 return;
             }
         };
@@ -92,7 +109,7 @@ pub fn CampManager() -> impl IntoView {
         let teens_count = match default_teens_val.parse::<i32>() {
             Ok(val) => val,
             Err(_) => {
-                toast_error("Invalid number for teens");
+                toast_error(t!("validation-teens-number"));
                 return;
             }
         };
@@ -100,28 +117,30 @@ pub fn CampManager() -> impl IntoView {
         let adults_count = match default_adults_val.parse::<i32>() {
             Ok(val) => val,
             Err(_) => {
-                toast_error("Invalid number for adults");
+                toast_error(t!("validation-adults-number"));
                 return;
             }
         };
 
-        // Validate date range
-        if start_date_val >= end_date_val {
-            toast_error("End date must be after start date");
+        let (Ok(start), Ok(end)) = (
+            NaiveDate::parse_from_str(&start_date_val, "%Y-%m-%d"),
+            NaiveDate::parse_from_str(&end_date_val, "%Y-%m-%d"),
+        ) else {
+            toast_error(t!("validation-invalid-date"));
             return;
-        }
+        };
 
-        // Validate counts are non-negative
-        if children_count < 0 {
-            toast_error("Number of children cannot be negative");
-            return;
-        }
-        if teens_count < 0 {
-            toast_error("Number of teens cannot be negative");
-            return;
-        }
-        if adults_count < 0 {
-            toast_error("Number of adults cannot be negative");
+        // Same rules as the server
+        let camp = CreateCamp {
+            name: name_val.clone(),
+            start_date: start,
+            end_date: end,
+            default_children: children_count,
+            default_teens: teens_count,
+            default_adults: adults_count,
+            notes: None,
+        };
+        if !validate_form(&camp, set_field_errors) {
             return;
         }
 
@@ -141,12 +160,15 @@ pub fn CampManager() -> impl IntoView {
                 notes_opt,
             ).await {
                 Ok(_) => {
-                    toast_success("Camp created successfully!");
+                    toast_success(t!("camp-created"));
                     reset_form();
                     set_show_form.set(false);
                     load_data();
                 },
-                Err(e) => toast_error(&format!("Failed to create camp: {}", e)),
+                Err(e) => {
+                    set_field_errors.set(e.field_errors().to_vec());
+                    toast_error(t!("error-create-camp", error = e.to_string()));
+                }
             }
             
             set_loading.set(false);
@@ -154,6 +176,21 @@ pub fn CampManager() -> impl IntoView {
     };
 
     // Trigger delete modal
+    let handle_calendar_click = move |id: i64| {
+        spawn_local(async move {
+            match get_calendar_url(id).await {
+                Ok(path) => {
+                    let origin = window().location().origin().unwrap_or_default();
+                    let _ = window().prompt_with_message_and_default(
+                        &t!("calendar-subscribe-prompt"),
+                        &format!("{}{}", origin, path),
+                    );
+                }
+                Err(e) => toast_error(t!("error-calendar-link", error = e.to_string())),
+            }
+        });
+    };
+
     let handle_delete_click = move |id: i64| {
         set_delete_id.set(id);
         set_show_delete_modal.set(true);
@@ -170,11 +207,11 @@ pub fn CampManager() -> impl IntoView {
 
             match delete_camp(id).await {
                 Ok(_) => {
-                    toast_success("Camp deleted successfully!");
+                    toast_success(t!("camp-deleted"));
                     load_data();
                 },
                 Err(e) => {
-                    toast_error(&format!("Failed to delete camp: {}", e));
+                    toast_error(t!("error-delete-camp", error = e.to_string()));
                 },
             }
 
@@ -186,12 +223,26 @@ pub fn CampManager() -> impl IntoView {
         set_show_delete_modal.set(false);
     };
 
+    let handle_archive = move |id: i64| {
+        spawn_local(async move {
+            set_loading.set(true);
+            match archive_camp(id).await {
+                Ok(_) => {
+                    toast_success(t!("camp-archived"));
+                    load_data();
+                },
+                Err(e) => toast_error(t!("error-archive-camp", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
     view! {
         <div class="space-y-6">
             <div class="flex justify-between items-center">
                 <h2 class="text-3xl font-bold text-gradient flex items-center gap-3">
                     <span class="text-4xl">"🏕️"</span>
-                    "Camps"
+                    {t!("camps")}
                 </h2>
                 <button
                     type="button"
@@ -210,13 +261,13 @@ pub fn CampManager() -> impl IntoView {
                     disabled=move || loading.get()
                 >
                     <span class="mr-1">"+"</span>
-                    " Add Camp"
+                    {t!("add-camp")}
                 </button>
             </div>
 
             {move || error.get().map(|err| view! {
                 <div class="alert-error">
-                    <span class="font-semibold mr-2">"⚠️ Error:"</span>
+                    <span class="font-semibold mr-2">"⚠️ " {t!("error")} ":"</span>
                     {err}
                 </div>
             })}
@@ -225,11 +276,11 @@ pub fn CampManager() -> impl IntoView {
                 <div class="card border-2 border-blue-200">
                     <h3 class="text-2xl font-bold mb-6 text-gradient flex items-center gap-2">
                         <span>"✨"</span>
-                        "New Camp"
+                        {t!("add-camp")}
                     </h3>
                     <form on:submit=handle_submit class="space-y-4">
                         <div>
-                            <label for="camp-name" class="form-label">"Camp Name" <span class="text-red-500">"*"</span></label>
+                            <label for="camp-name" class="form-label">{t!("camp-name")} <span class="text-red-500">"*"</span></label>
                             <input
                                 id="camp-name"
                                 type="text"
@@ -240,10 +291,11 @@ pub fn CampManager() -> impl IntoView {
                                 required
                                 aria-required="true"
                             />
+                            <FieldErrors errors=field_errors field="name" />
                         </div>
                         <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
                             <div>
-                                <label for="camp-start-date" class="form-label">"Start Date" <span class="text-red-500">"*"</span></label>
+                                <label for="camp-start-date" class="form-label">{t!("start-date")} <span class="text-red-500">"*"</span></label>
                                 <input
                                     id="camp-start-date"
                                     type="date"
@@ -255,7 +307,7 @@ pub fn CampManager() -> impl IntoView {
                                 />
                             </div>
                             <div>
-                                <label for="camp-end-date" class="form-label">"End Date" <span class="text-red-500">"*"</span></label>
+                                <label for="camp-end-date" class="form-label">{t!("end-date")} <span class="text-red-500">"*"</span></label>
                                 <input
                                     id="camp-end-date"
                                     type="date"
@@ -265,11 +317,12 @@ pub fn CampManager() -> impl IntoView {
                                     required
                                     aria-required="true"
                                 />
+                                <FieldErrors errors=field_errors field="end_date" />
                             </div>
                         </div>
                         <div class="grid grid-cols-1 md:grid-cols-3 gap-4">
                             <div>
-                                <label for="camp-children" class="form-label">"Default Children"</label>
+                                <label for="camp-children" class="form-label">{t!("default-children")}</label>
                                 <input
                                     id="camp-children"
                                     type="number"
@@ -277,11 +330,12 @@ pub fn CampManager() -> impl IntoView {
                                     prop:value=move || default_children.get()
                                     on:input=move |ev| set_default_children.set(event_target_value(&ev))
                                     min="0"
-                                    aria-label="Default number of children"
+                                    aria-label=t!("default-children")
                                 />
+                                <FieldErrors errors=field_errors field="default_children" />
                             </div>
                             <div>
-                                <label for="camp-teens" class="form-label">"Default Teens"</label>
+                                <label for="camp-teens" class="form-label">{t!("default-teens")}</label>
                                 <input
                                     id="camp-teens"
                                     type="number"
@@ -289,11 +343,12 @@ pub fn CampManager() -> impl IntoView {
                                     prop:value=move || default_teens.get()
                                     on:input=move |ev| set_default_teens.set(event_target_value(&ev))
                                     min="0"
-                                    aria-label="Default number of teens"
+                                    aria-label=t!("default-teens")
                                 />
+                                <FieldErrors errors=field_errors field="default_teens" />
                             </div>
                             <div>
-                                <label for="camp-adults" class="form-label">"Default Adults"</label>
+                                <label for="camp-adults" class="form-label">{t!("default-adults")}</label>
                                 <input
                                     id="camp-adults"
                                     type="number"
@@ -301,28 +356,29 @@ pub fn CampManager() -> impl IntoView {
                                     prop:value=move || default_adults.get()
                                     on:input=move |ev| set_default_adults.set(event_target_value(&ev))
                                     min="0"
-                                    aria-label="Default number of adults"
+                                    aria-label=t!("default-adults")
                                 />
+                                <FieldErrors errors=field_errors field="default_adults" />
                             </div>
                         </div>
                         <div>
-                            <label for="camp-notes" class="form-label">"Notes (optional)"</label>
+                            <label for="camp-notes" class="form-label">{t!("notes-optional")}</label>
                             <textarea
                                 id="camp-notes"
                                 class="form-input"
                                 prop:value=move || notes.get()
                                 on:input=move |ev| set_notes.set(event_target_value(&ev))
-                                placeholder="Any additional notes about this camp"
+                                placeholder=t!("camp-notes-placeholder")
                                 rows="3"
-                                aria-label="Camp notes"
+                                aria-label=t!("notes")
                             />
                         </div>
                         <div class="flex gap-2">
                             <button type="submit" class="btn btn-primary" disabled=move || loading.get()>
-                                {move || if loading.get() { "Saving..." } else { "Save" }}
+                                {move || if loading.get() { t!("saving") } else { t!("save") }}
                             </button>
                             <button type="button" class="btn btn-secondary" on:click=cancel_form disabled=move || loading.get()>
-                                "Cancel"
+                                {t!("cancel")}
                             </button>
                         </div>
                     </form>
@@ -332,16 +388,16 @@ pub fn CampManager() -> impl IntoView {
             {move || if loading.get() && !show_form.get() {
                 view! {
                     <div class="card text-center py-12">
-                        <div class="spinner mx-auto mb-4" role="status" aria-label="Loading camps"></div>
-                        <p class="text-slate-600" aria-live="polite">"Loading camps..."</p>
+                        <div class="spinner mx-auto mb-4" role="status" aria-label=t!("loading")></div>
+                        <p class="text-slate-600" aria-live="polite">{t!("loading")}</p>
                     </div>
                 }.into_any()
             } else if camps.get().is_empty() {
                 view! { 
                     <div class="card text-center py-16 bg-gradient-to-br from-slate-50 to-blue-50 border-2 border-dashed border-slate-300">
                         <div class="text-7xl mb-6">"🏕️"</div>
-                        <h3 class="text-2xl font-bold text-slate-800 mb-3">"No camps yet"</h3>
-                        <p class="text-lg text-slate-600 mb-8">"Get started by creating your first camp"</p>
+                        <h3 class="text-2xl font-bold text-slate-800 mb-3">{t!("no-camps")}</h3>
+                        <p class="text-lg text-slate-600 mb-8">{t!("no-camps-text")}</p>
                     </div> 
                 }.into_any()
             } else {
@@ -359,19 +415,46 @@ pub fn CampManager() -> impl IntoView {
                                 <h3 class="text-xl font-bold text-slate-800 mb-2">{camp.name.clone()}</h3>
                                 <div class="flex items-center gap-2 text-sm text-slate-600 mb-3">
                                     <span>"📅"</span>
-                                    <span>{format!("{} to {}", camp.start_date, camp.end_date)}</span>
+                                    <span>{format!("{} – {}", format_date(camp.start_date, language), format_date(camp.end_date, language))}</span>
                                 </div>
                                 <div class="flex gap-2 flex-wrap mb-3">
                                     <span class="badge badge-primary">
-                                        "👶 " {camp.default_children} " children"
+                                        "👶 " {t!("children-count", count = camp.default_children)}
                                     </span>
                                     <span class="badge badge-primary">
-                                        "🧒 " {camp.default_teens} " teens"
+                                        "🧒 " {t!("teens-count", count = camp.default_teens)}
                                     </span>
                                     <span class="badge badge-primary">
-                                        "👨 " {camp.default_adults} " adults"
+                                        "👨 " {t!("adults-count", count = camp.default_adults)}
                                     </span>
                                 </div>
+                                {
+                                    let id = camp.id;
+                                    move || plan_checks.get().into_iter().find(|check| check.camp_id == id).map(|check| {
+                                        let summary = check.summary();
+                                        view! {
+                                            <a
+                                                href=format!("/planner/{}?check=1", id)
+                                                class="block text-sm mb-3 hover:underline"
+                                                title=t!("open-plan-check")
+                                            >
+                                                {if summary.is_empty() {
+                                                    view! { <span class="text-emerald-700">"✅ " {t!("plan-looks-fine")}</span> }.into_any()
+                                                } else {
+                                                    view! {
+                                                        <span class="text-amber-700">
+                                                            {format!("⚠️ {}: ", t!("plan-warnings", count = check.warnings.len()))}
+                                                            {summary.iter()
+                                                                .map(|(kind, count)| format!("{} {}", count, t!(kind.message_key()).to_lowercase()))
+                                                                .collect::<Vec<_>>()
+                                                                .join(", ")}
+                                                        </span>
+                                                    }.into_any()
+                                                }}
+                                            </a>
+                                        }
+                                    })
+                                }
                                 {camp.notes.clone().map(|n| view! {
                                     <p class="text-sm text-slate-600 mb-4 italic bg-slate-50 p-2 rounded">{n}</p>
                                 })}
@@ -388,7 +471,30 @@ pub fn CampManager() -> impl IntoView {
                                         }
                                         disabled=move || loading.get()
                                     >
-                                        "📅 Plan Meals"
+                                        "📅 " {t!("plan-meals")}
+                                    </button>
+                                    <button
+                                        class="btn btn-secondary text-sm"
+                                        title=t!("calendar-feed-title")
+                                        on:click={
+                                            let id = camp.id;
+                                            move |_| handle_calendar_click(id)
+                                        }
+                                        aria-label=t!("calendar-feed")
+                                    >
+                                        "🔗"
+                                    </button>
+                                    <button
+                                        class="btn btn-secondary text-sm"
+                                        title=t!("archive-camp")
+                                        on:click={
+                                            let id = camp.id;
+                                            move |_| handle_archive(id)
+                                        }
+                                        disabled=move || loading.get()
+                                        aria-label=t!("archive-camp")
+                                    >
+                                        "📦"
                                     </button>
                                     <button
                                         class="btn btn-danger text-sm"
@@ -397,7 +503,7 @@ pub fn CampManager() -> impl IntoView {
                                             move |_| handle_delete_click(id)
                                         }
                                         disabled=move || loading.get()
-                                        aria-label="Delete camp"
+                                        aria-label=t!("delete-camp")
                                     >
                                         "🗑️"
                                     </button>
@@ -413,10 +519,10 @@ pub fn CampManager() -> impl IntoView {
                 show=show_delete_modal.into()
                 on_confirm=confirm_delete
                 on_cancel=cancel_delete
-                title="Delete Camp".to_string()
-                message="Are you sure you want to delete this camp? This action cannot be undone.".to_string()
-                confirm_text="Delete".to_string()
-                cancel_text="Cancel".to_string()
+                title=t!("delete-camp")
+                message=t!("delete-camp-confirm")
+                confirm_text=t!("delete")
+                cancel_text=t!("cancel")
                 variant="danger".to_string()
             />
         </div>
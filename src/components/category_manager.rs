@@ -1,8 +1,10 @@
-use crate::models::Category;
+use crate::models::{Category, CreateCategory};
 use crate::server_functions::categories::{
-    create_category, delete_category, get_categories, update_category,
+    archive_category, create_category, delete_category, get_categories, update_category,
 };
-use crate::components::{ConfirmModal, toast_success, toast_error};
+use crate::components::{ConfirmModal, FieldErrors, toast_success, toast_error, validate_form};
+use crate::error::FieldError;
+use crate::t;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
@@ -14,6 +16,7 @@ pub fn CategoryManager() -> impl IntoView {
     let (editing_id, set_editing_id) = signal(None::<i64>);
     let (error, set_error) = signal(None::<String>);
     let (loading, set_loading) = signal(false);
+    let (field_errors, set_field_errors) = signal(Vec::<FieldError>::new());
 
     // Modal state
     let (show_delete_modal, set_show_delete_modal) = signal(false);
@@ -29,9 +32,9 @@ pub fn CategoryManager() -> impl IntoView {
             set_loading.set(true);
             set_error.set(None);
 
-            match get_categories().await {
+            match get_categories(false).await {
                 Ok(data) => set_categories.set(data),
-                Err(e) => set_error.set(Some(format!("Failed to load categories: {}", e))),
+                Err(e) => set_error.set(Some(t!("error-load-categories", error = e.to_string()))),
             }
 
             set_loading.set(false);
@@ -47,6 +50,7 @@ pub fn CategoryManager() -> impl IntoView {
         set_sort_order.set(0);
         set_editing_id.set(None);
         set_error.set(None);
+        set_field_errors.set(Vec::new());
     };
 
     let start_add = move |_| {
@@ -64,17 +68,21 @@ pub fn CategoryManager() -> impl IntoView {
     let cancel_form = move |_| {
         set_show_form.set(false);
         set_error.set(None);
+        set_field_errors.set(Vec::new());
     };
 
     let handle_submit = move |ev: SubmitEvent| {
         ev.prevent_default();
 
-        let name_val = name.get();
-        let sort_order_val = sort_order.get();
         let editing_id_val = editing_id.get();
 
-        if name_val.is_empty() {
-            toast_error("Please fill in all required fields");
+        let category = CreateCategory {
+            name: name.get(),
+            sort_order: sort_order.get(),
+        };
+
+        // Same rules as the server
+        if !validate_form(&category, set_field_errors) {
             return;
         }
 
@@ -83,23 +91,26 @@ pub fn CategoryManager() -> impl IntoView {
             set_error.set(None);
 
             let result = if let Some(id) = editing_id_val {
-                update_category(id, Some(name_val), Some(sort_order_val)).await
+                update_category(id, Some(category.name), Some(category.sort_order)).await
             } else {
-                create_category(name_val, sort_order_val).await
+                create_category(category.name, category.sort_order).await
             };
 
             match result {
                 Ok(_) => {
                     if editing_id_val.is_some() {
-                        toast_success("Category updated successfully!");
+                        toast_success(t!("category-updated"));
                     } else {
-                        toast_success("Category created successfully!");
+                        toast_success(t!("category-created"));
                     }
                     reset_form();
                     set_show_form.set(false);
                     load_data();
                 }
-                Err(e) => toast_error(&format!("Failed to save category: {}", e)),
+                Err(e) => {
+                    set_field_errors.set(e.field_errors().to_vec());
+                    toast_error(t!("error-save-category", error = e.to_string()));
+                }
             }
 
             set_loading.set(false);
@@ -119,10 +130,10 @@ pub fn CategoryManager() -> impl IntoView {
             set_loading.set(true);
             match delete_category(id).await {
                 Ok(_) => {
-                    toast_success("Category deleted successfully!");
+                    toast_success(t!("category-deleted"));
                     load_data();
                 },
-                Err(e) => toast_error(&format!("Failed to delete: {}", e)),
+                Err(e) => toast_error(t!("error-delete", error = e.to_string())),
             }
             set_loading.set(false);
         });
@@ -132,12 +143,26 @@ pub fn CategoryManager() -> impl IntoView {
         set_show_delete_modal.set(false);
     };
 
+    let handle_archive = move |id: i64| {
+        spawn_local(async move {
+            set_loading.set(true);
+            match archive_category(id).await {
+                Ok(_) => {
+                    toast_success(t!("category-archived"));
+                    load_data();
+                },
+                Err(e) => toast_error(t!("error-archive", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
     view! {
         <div class="space-y-6">
             <div class="flex justify-between items-center">
                 <h3 class="text-2xl font-bold text-gradient flex items-center gap-2">
                     <span class="text-3xl">"📁"</span>
-                    "Categories"
+                    {t!("categories")}
                 </h3>
                 <button
                     type="button"
@@ -146,13 +171,13 @@ pub fn CategoryManager() -> impl IntoView {
                     disabled=move || loading.get()
                 >
                     <span class="mr-1">"+"</span>
-                    " Add Category"
+                    " " {t!("add-category")}
                 </button>
             </div>
 
             {move || error.get().map(|err| view! {
                 <div class="alert-error">
-                    <span class="font-semibold mr-2">"⚠️ Error:"</span>
+                    <span class="font-semibold mr-2">"⚠️ " {t!("error")} ":"</span>
                     {err}
                 </div>
             })}
@@ -161,11 +186,11 @@ pub fn CategoryManager() -> impl IntoView {
                 <div class="card border-2 border-blue-200">
                     <h3 class="text-2xl font-bold mb-6 text-gradient flex items-center gap-2">
                         <span>{move || if editing_id.get().is_some() { "✏️" } else { "✨" }}</span>
-                        {move || if editing_id.get().is_some() { "Edit Category" } else { "New Category" }}
+                        {move || if editing_id.get().is_some() { t!("edit-category") } else { t!("new-category") }}
                     </h3>
                     <form on:submit=handle_submit class="space-y-4">
                         <div>
-                            <label class="form-label">"Name" <span class="text-red-500">"*"</span></label>
+                            <label class="form-label">{t!("category-name")} <span class="text-red-500">"*"</span></label>
                             <input
                                 type="text"
                                 class="form-input"
@@ -173,9 +198,10 @@ pub fn CategoryManager() -> impl IntoView {
                                 on:input=move |ev| set_name.set(event_target_value(&ev))
                                 required
                             />
+                            <FieldErrors errors=field_errors field="name" />
                         </div>
                         <div>
-                            <label class="form-label">"Sort Order"</label>
+                            <label class="form-label">{t!("sort-order")}</label>
                             <input
                                 type="number"
                                 class="form-input"
@@ -189,10 +215,10 @@ pub fn CategoryManager() -> impl IntoView {
                         </div>
                         <div class="flex gap-2">
                             <button type="submit" class="btn btn-primary" disabled=move || loading.get()>
-                                {move || if loading.get() { "Saving..." } else { "Save" }}
+                                {move || if loading.get() { t!("saving") } else { t!("save") }}
                             </button>
                             <button type="button" class="btn btn-secondary" on:click=cancel_form disabled=move || loading.get()>
-                                "Cancel"
+                                {t!("cancel")}
                             </button>
                         </div>
                     </form>
@@ -203,15 +229,15 @@ pub fn CategoryManager() -> impl IntoView {
                 view! {
                     <div class="card text-center py-12">
                         <div class="spinner mx-auto mb-4"></div>
-                        <p class="text-slate-600">"Loading categories..."</p>
+                        <p class="text-slate-600">{t!("loading")}</p>
                     </div>
                 }.into_any()
             } else if categories.get().is_empty() {
                 view! {
                     <div class="card text-center py-16 bg-gradient-to-br from-slate-50 to-blue-50 border-2 border-dashed border-slate-300">
                         <div class="text-7xl mb-6">"📁"</div>
-                        <h3 class="text-2xl font-bold text-slate-800 mb-3">"No categories yet"</h3>
-                        <p class="text-lg text-slate-600 mb-8">"Get started by creating your first category"</p>
+                        <h3 class="text-2xl font-bold text-slate-800 mb-3">{t!("no-categories")}</h3>
+                        <p class="text-lg text-slate-600 mb-8">{t!("no-categories-text")}</p>
                     </div>
                 }.into_any()
             } else {
@@ -225,7 +251,7 @@ pub fn CategoryManager() -> impl IntoView {
                             <div class="card group">
                                 <div class="flex items-start justify-between mb-3">
                                     <div class="text-3xl group-hover:scale-110 transition-transform duration-200">"📁"</div>
-                                    <span class="badge badge-secondary text-xs">"Sort: " {category.sort_order}</span>
+                                    <span class="badge badge-secondary text-xs">{t!("sort-order-value", order = category.sort_order)}</span>
                                 </div>
                                 <h3 class="text-xl font-bold text-slate-800 mb-4">{category.name.clone()}</h3>
                                 <div class="mt-auto flex gap-2">
@@ -237,7 +263,18 @@ pub fn CategoryManager() -> impl IntoView {
                                         }
                                         disabled=move || loading.get()
                                     >
-                                        "✏️ Edit"
+                                        "✏️ " {t!("edit")}
+                                    </button>
+                                    <button
+                                        class="btn btn-secondary text-sm"
+                                        title=t!("usage-archive")
+                                        on:click={
+                                            let id = category.id;
+                                            move |_| handle_archive(id)
+                                        }
+                                        disabled=move || loading.get()
+                                    >
+                                        "📦"
                                     </button>
                                     <button
                                         class="btn btn-danger text-sm"
@@ -260,10 +297,10 @@ pub fn CategoryManager() -> impl IntoView {
                 show=show_delete_modal.into()
                 on_confirm=confirm_delete
                 on_cancel=cancel_delete
-                title="Delete Category".to_string()
-                message="Are you sure? This action cannot be undone.".to_string()
-                confirm_text="Delete".to_string()
-                cancel_text="Cancel".to_string()
+                title=t!("delete-category")
+                message=t!("delete-category-confirm")
+                confirm_text=t!("delete")
+                cancel_text=t!("cancel")
                 variant="danger".to_string()
             />
         </div>
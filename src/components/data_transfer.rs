@@ -0,0 +1,238 @@
+use crate::models::{ImportMode, ImportReport};
+use crate::server_functions::transfer::import_data;
+use crate::components::{ConfirmModal, toast_success, toast_error};
+use crate::t;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+/// First file chosen in an `<input type="file">`
+pub(crate) fn selected_file(ev: &leptos::ev::Event) -> Option<web_sys::File> {
+    event_target::<web_sys::HtmlInputElement>(ev)
+        .files()
+        .and_then(|files| files.get(0))
+}
+
+/// Read a chosen file as text, showing a toast when that fails
+pub(crate) async fn read_file_text(file: web_sys::File) -> Option<String> {
+    match wasm_bindgen_futures::JsFuture::from(file.text()).await {
+        Ok(text) => text.as_string(),
+        Err(_) => {
+            toast_error(t!("error-read-file", name = file.name()));
+            None
+        }
+    }
+}
+
+#[component]
+pub fn DataTransfer() -> impl IntoView {
+    let (file_name, set_file_name) = signal(None::<String>);
+    let (content, set_content) = signal(None::<String>);
+    let (mode, set_mode) = signal(ImportMode::Merge);
+    let (report, set_report) = signal(None::<ImportReport>);
+    let (loading, set_loading) = signal(false);
+    let (show_replace_modal, set_show_replace_modal) = signal(false);
+
+    let handle_file = move |ev: leptos::ev::Event| {
+        let Some(file) = selected_file(&ev) else {
+            return;
+        };
+
+        set_file_name.set(Some(file.name()));
+        set_report.set(None);
+        spawn_local(async move {
+            set_content.set(read_file_text(file).await);
+        });
+    };
+
+    let run_import = move |dry_run: bool| {
+        let Some(data) = content.get_untracked() else {
+            toast_error(t!("transfer-choose-file"));
+            return;
+        };
+        let import_mode = mode.get_untracked();
+
+        spawn_local(async move {
+            set_loading.set(true);
+            match import_data(data, import_mode, dry_run).await {
+                Ok(result) => {
+                    if !result.errors.is_empty() {
+                        toast_error(t!("transfer-file-invalid"));
+                    } else if dry_run {
+                        toast_success(t!("transfer-file-valid"));
+                    } else {
+                        toast_success(t!("transfer-imported"));
+                    }
+                    set_report.set(Some(result));
+                }
+                Err(e) => toast_error(t!("error-import", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let handle_import = move |_| {
+        if mode.get_untracked() == ImportMode::Replace {
+            set_show_replace_modal.set(true);
+        } else {
+            run_import(false);
+        }
+    };
+
+    let confirm_replace = move || {
+        set_show_replace_modal.set(false);
+        run_import(false);
+    };
+
+    let cancel_replace = move || {
+        set_show_replace_modal.set(false);
+    };
+
+    view! {
+        <div class="space-y-6">
+            <h2 class="text-3xl font-bold text-gradient flex items-center gap-3">
+                <span class="text-4xl">"⚙️"</span>
+                {t!("data-transfer")}
+            </h2>
+
+            <div class="card">
+                <h3 class="text-xl font-bold mb-2">{t!("export")}</h3>
+                <p class="text-slate-600 mb-4">
+                    {t!("transfer-export-help")}
+                </p>
+                <div class="flex gap-2">
+                    <a href="/admin/export?format=json" class="btn btn-primary" rel="external">"⬇️ JSON"</a>
+                    <a href="/admin/export?format=yaml" class="btn btn-secondary" rel="external">"⬇️ YAML"</a>
+                </div>
+            </div>
+
+            <div class="card space-y-4">
+                <h3 class="text-xl font-bold">{t!("import")}</h3>
+                <div>
+                    <label for="import-file" class="form-label">{t!("transfer-file")}</label>
+                    <input
+                        id="import-file"
+                        type="file"
+                        accept=".json,.yaml,.yml"
+                        class="form-input"
+                        on:change=handle_file
+                    />
+                    {move || file_name.get().map(|name| view! {
+                        <p class="text-sm text-slate-500 mt-1">{name}</p>
+                    })}
+                </div>
+                <div>
+                    <label for="import-mode" class="form-label">{t!("transfer-mode")}</label>
+                    <select
+                        id="import-mode"
+                        class="form-input"
+                        on:change=move |ev| set_mode.set(
+                            if event_target_value(&ev) == "replace" { ImportMode::Replace } else { ImportMode::Merge }
+                        )
+                    >
+                        <option value="merge" selected>{t!("transfer-mode-merge")}</option>
+                        <option value="replace">{t!("transfer-mode-replace")}</option>
+                    </select>
+                </div>
+                <div class="flex gap-2">
+                    <button
+                        type="button"
+                        class="btn btn-secondary"
+                        on:click=move |_| run_import(true)
+                        disabled=move || loading.get() || content.get().is_none()
+                    >
+                        {t!("transfer-dry-run")}
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        on:click=handle_import
+                        disabled=move || loading.get() || content.get().is_none()
+                    >
+                        {t!("import")}
+                    </button>
+                </div>
+            </div>
+
+            {move || report.get().map(|report| view! { <ImportReportView report=report/> })}
+
+            <ConfirmModal
+                show=show_replace_modal.into()
+                on_confirm=confirm_replace
+                on_cancel=cancel_replace
+                title=t!("transfer-replace-title")
+                message=t!("transfer-replace-confirm")
+                confirm_text=t!("transfer-replace")
+                cancel_text=t!("cancel")
+                variant="danger".to_string()
+            />
+        </div>
+    }
+}
+
+#[component]
+fn ImportReportView(report: ImportReport) -> impl IntoView {
+    let title = if report.dry_run { t!("transfer-dry-run-result") } else { t!("transfer-import-result") };
+    let rows = vec![
+        (t!("categories"), report.categories_created, report.categories_matched),
+        (t!("ingredients"), report.ingredients_created, report.ingredients_matched),
+        (t!("recipes"), report.recipes_created, report.recipes_matched),
+        (t!("camps"), report.camps_created, report.camps_matched),
+        (t!("suppliers"), report.suppliers_created, report.suppliers_matched),
+    ];
+
+    view! {
+        <div class="card space-y-4">
+            <h3 class="text-xl font-bold">{title}</h3>
+
+            {(!report.errors.is_empty()).then(|| view! {
+                <div class="alert-error">
+                    <span class="font-semibold mr-2">"⚠️ " {t!("transfer-errors")} ":"</span>
+                    <ul class="list-disc ml-6">
+                        {report.errors.iter().map(|e| view! { <li>{e.clone()}</li> }).collect_view()}
+                    </ul>
+                </div>
+            })}
+
+            {report.errors.is_empty().then(|| view! {
+                <table class="w-full">
+                    <thead>
+                        <tr>
+                            <th class="text-left">{t!("transfer-type")}</th>
+                            <th class="text-right">{t!("transfer-created")}</th>
+                            <th class="text-right">{t!("transfer-matched")}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {rows.into_iter().map(|(label, created, matched)| view! {
+                            <tr>
+                                <td>{label}</td>
+                                <td class="text-right">{created}</td>
+                                <td class="text-right">{matched}</td>
+                            </tr>
+                        }).collect_view()}
+                        {[
+                            (t!("transfer-planned-meals"), report.meals_created),
+                            (t!("transfer-stock-items"), report.stock_items_created),
+                            (t!("transfer-pack-sizes"), report.packs_created),
+                        ].into_iter().map(|(label, created)| view! {
+                            <tr>
+                                <td>{label}</td>
+                                <td class="text-right">{created}</td>
+                                <td class="text-right">"–"</td>
+                            </tr>
+                        }).collect_view()}
+                    </tbody>
+                </table>
+            })}
+
+            {(!report.warnings.is_empty()).then(|| view! {
+                <div>
+                    <p class="font-semibold text-amber-700">{t!("transfer-warnings")}</p>
+                    <ul class="list-disc ml-6 text-amber-700">
+                        {report.warnings.iter().map(|w| view! { <li>{w.clone()}</li> }).collect_view()}
+                    </ul>
+                </div>
+            })}
+        </div>
+    }
+}
@@ -1,28 +1,47 @@
-use crate::models::{Ingredient, Category};
-use crate::server_functions::ingredients::{get_ingredients, create_ingredient, update_ingredient, delete_ingredient};
+use crate::models::{matches_search, CreateIngredient, Ingredient, UpdateIngredient, ALLERGENS, IngredientAlias, IngredientPack, Category, StorageType};
+use crate::server_functions::ingredients::{
+    get_ingredients, create_ingredient, update_ingredient, delete_ingredient,
+    get_ingredient_usage, replace_ingredient, archive_ingredient,
+    get_ingredient_packs, create_ingredient_pack, delete_ingredient_pack,
+    get_ingredient_aliases, create_ingredient_alias, delete_ingredient_alias,
+};
 use crate::server_functions::categories::get_categories;
-use crate::components::{SearchableSelect, ConfirmModal, toast_success, toast_error};
+use crate::components::{SearchableSelect, ConfirmModal, FieldErrors, UsageModal, toast_success, toast_error, validate_form};
+use crate::error::FieldError;
+use crate::format::{format_number, format_quantity};
+use crate::i18n::use_language;
+use crate::t;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use leptos::ev::SubmitEvent;
 
 #[component]
 pub fn IngredientManager() -> impl IntoView {
+    let language = use_language();
     let (ingredients, set_ingredients) = signal(Vec::<Ingredient>::new());
     let (categories, set_categories) = signal(Vec::<Category>::new());
     let (show_form, set_show_form) = signal(false);
     let (error, set_error) = signal(None::<String>);
     let (loading, set_loading) = signal(false);
+    let (field_errors, set_field_errors) = signal(Vec::<FieldError>::new());
 
     // Modal state
     let (show_delete_modal, set_show_delete_modal) = signal(false);
     let (delete_id, set_delete_id) = signal(0i64);
+    // Recipes still using the ingredient picked for deleting
+    let (show_usage_modal, set_show_usage_modal) = signal(false);
+    let (usages, set_usages) = signal(Vec::<String>::new());
 
     // New ingredient form fields
     let (name, set_name) = signal(String::new());
     let (category_id, set_category_id) = signal(0i64);
     let (primary_unit, set_primary_unit) = signal(String::new());
     let (secondary_unit, set_secondary_unit) = signal(String::new());
+    let (safety_margin, set_safety_margin) = signal(String::from("0"));
+    let (storage_type, set_storage_type) = signal(StorageType::Dry);
+    let (shelf_life, set_shelf_life) = signal(String::new());
+    let (price, set_price) = signal(String::new());
+    let (allergens, set_allergens) = signal(String::new());
 
     // Inline edit state
     let (editing_id, set_editing_id) = signal(None::<i64>);
@@ -30,6 +49,15 @@ pub fn IngredientManager() -> impl IntoView {
     let (edit_category_id, set_edit_category_id) = signal(0i64);
     let (edit_primary_unit, set_edit_primary_unit) = signal(String::new());
     let (edit_secondary_unit, set_edit_secondary_unit) = signal(String::new());
+    let (edit_safety_margin, set_edit_safety_margin) = signal(String::new());
+    let (edit_storage_type, set_edit_storage_type) = signal(StorageType::Dry);
+    let (edit_shelf_life, set_edit_shelf_life) = signal(String::new());
+    let (edit_price, set_edit_price) = signal(String::new());
+    let (edit_allergens, set_edit_allergens) = signal(String::new());
+
+    // Pack sizes and aliases panels
+    let (packs_open_id, set_packs_open_id) = signal(None::<i64>);
+    let (aliases_open_id, set_aliases_open_id) = signal(None::<i64>);
 
     // Search
     let (search_query, set_search_query) = signal(String::new());
@@ -39,12 +67,12 @@ pub fn IngredientManager() -> impl IntoView {
             set_loading.set(true);
             set_error.set(None);
 
-            match get_ingredients().await {
+            match get_ingredients(false).await {
                 Ok(data) => set_ingredients.set(data),
-                Err(e) => set_error.set(Some(format!("Failed to load ingredients: {}", e))),
+                Err(e) => set_error.set(Some(t!("error-load-ingredients", error = e.to_string()))),
             }
 
-            match get_categories().await {
+            match get_categories(false).await {
                 Ok(data) => {
                     if category_id.get() == 0 {
                         if let Some(first) = data.first() {
@@ -53,7 +81,7 @@ pub fn IngredientManager() -> impl IntoView {
                     }
                     set_categories.set(data);
                 },
-                Err(e) => set_error.set(Some(format!("Failed to load categories: {}", e))),
+                Err(e) => set_error.set(Some(t!("error-load-categories", error = e.to_string()))),
             }
 
             set_loading.set(false);
@@ -68,15 +96,22 @@ pub fn IngredientManager() -> impl IntoView {
         set_name.set(String::new());
         set_primary_unit.set(String::new());
         set_secondary_unit.set(String::new());
+        set_safety_margin.set(String::from("0"));
+        set_storage_type.set(StorageType::Dry);
+        set_shelf_life.set(String::new());
+        set_price.set(String::new());
+        set_allergens.set(String::new());
         if let Some(first) = categories.get().first() {
             set_category_id.set(first.id);
         }
         set_error.set(None);
+        set_field_errors.set(Vec::new());
     };
 
     let cancel_form = move |_| {
         set_show_form.set(false);
         set_error.set(None);
+        set_field_errors.set(Vec::new());
     };
 
     let handle_submit = move |ev: SubmitEvent| {
@@ -87,8 +122,20 @@ pub fn IngredientManager() -> impl IntoView {
         let primary_unit_val = primary_unit.get();
         let secondary_unit_val = secondary_unit.get();
 
-        if name_val.is_empty() || primary_unit_val.is_empty() {
-            toast_error("Please fill in all required fields");
+        let ingredient = CreateIngredient {
+            name: name_val,
+            category_id: category_id_val,
+            primary_unit: primary_unit_val,
+            secondary_unit: (!secondary_unit_val.is_empty()).then_some(secondary_unit_val),
+            safety_margin_percent: optional_number(&safety_margin.get()).unwrap_or_default(),
+            shelf_life_days: optional_number(&shelf_life.get()),
+            storage_type: storage_type.get().as_str().to_string(),
+            price_per_unit: optional_number(&price.get()),
+            allergens: allergens.get(),
+        };
+
+        // Same rules as the server
+        if !validate_form(&ingredient, set_field_errors) {
             return;
         }
 
@@ -96,16 +143,17 @@ pub fn IngredientManager() -> impl IntoView {
             set_loading.set(true);
             set_error.set(None);
 
-            let secondary = if secondary_unit_val.is_empty() { None } else { Some(secondary_unit_val) };
-
-            match create_ingredient(name_val, category_id_val, primary_unit_val, secondary).await {
+            match create_ingredient(ingredient).await {
                 Ok(_) => {
-                    toast_success("Ingredient created successfully!");
+                    toast_success(t!("ingredient-created"));
                     reset_form();
                     set_show_form.set(false);
                     load_data();
                 },
-                Err(e) => toast_error(&format!("Failed to create ingredient: {}", e)),
+                Err(e) => {
+                    set_field_errors.set(e.field_errors().to_vec());
+                    toast_error(t!("error-create-ingredient", error = e.to_string()));
+                }
             }
 
             set_loading.set(false);
@@ -118,21 +166,38 @@ pub fn IngredientManager() -> impl IntoView {
         let primary_val = edit_primary_unit.get();
         let secondary_val = edit_secondary_unit.get();
 
-        if name_val.is_empty() || primary_val.is_empty() {
-            toast_error("Name and primary unit are required");
+        let shelf_life_val = optional_number(&edit_shelf_life.get());
+        let update = UpdateIngredient {
+            name: Some(name_val),
+            category_id: Some(cat_val),
+            primary_unit: Some(primary_val),
+            secondary_unit: (!secondary_val.is_empty()).then_some(secondary_val),
+            safety_margin_percent: Some(optional_number(&edit_safety_margin.get()).unwrap_or_default()),
+            shelf_life_days: shelf_life_val,
+            clear_shelf_life: shelf_life_val.is_none(),
+            storage_type: Some(edit_storage_type.get().as_str().to_string()),
+            price_per_unit: optional_number(&edit_price.get()),
+            allergens: Some(edit_allergens.get()),
+        };
+
+        // Same rules as the server
+        if !validate_form(&update, set_field_errors) {
             return;
         }
 
         spawn_local(async move {
             set_loading.set(true);
-            let secondary = if secondary_val.is_empty() { None } else { Some(secondary_val) };
-            match update_ingredient(id, name_val, cat_val, primary_val, secondary).await {
+
+            match update_ingredient(id, update).await {
                 Ok(_) => {
-                    toast_success("Ingredient updated!");
+                    toast_success(t!("ingredient-updated"));
                     set_editing_id.set(None);
                     load_data();
                 },
-                Err(e) => toast_error(&format!("Failed to update: {}", e)),
+                Err(e) => {
+                    set_field_errors.set(e.field_errors().to_vec());
+                    toast_error(t!("error-update-ingredient", error = e.to_string()));
+                }
             }
             set_loading.set(false);
         });
@@ -140,7 +205,19 @@ pub fn IngredientManager() -> impl IntoView {
 
     let handle_delete_click = move |id: i64| {
         set_delete_id.set(id);
-        set_show_delete_modal.set(true);
+
+        spawn_local(async move {
+            match get_ingredient_usage(id).await {
+                Ok(report) if report.in_use() => {
+                    set_usages.set(report.recipes.iter().map(|line| {
+                        format!("{} ({})", line.recipe_name, format_quantity(line.base_quantity, &line.unit, language))
+                    }).collect());
+                    set_show_usage_modal.set(true);
+                }
+                Ok(_) => set_show_delete_modal.set(true),
+                Err(e) => toast_error(t!("error-load-usage", error = e.to_string())),
+            }
+        });
     };
 
     let confirm_delete = move || {
@@ -151,10 +228,10 @@ pub fn IngredientManager() -> impl IntoView {
             set_loading.set(true);
             match delete_ingredient(id).await {
                 Ok(_) => {
-                    toast_success("Ingredient deleted successfully!");
+                    toast_success(t!("ingredient-deleted"));
                     load_data();
                 },
-                Err(e) => toast_error(&format!("Failed to delete: {}", e)),
+                Err(e) => toast_error(t!("error-delete", error = e.to_string())),
             }
             set_loading.set(false);
         });
@@ -164,12 +241,52 @@ pub fn IngredientManager() -> impl IntoView {
         set_show_delete_modal.set(false);
     };
 
+    let replace_and_delete = move |replacement_id: i64| {
+        let id = delete_id.get_untracked();
+        set_show_usage_modal.set(false);
+
+        spawn_local(async move {
+            set_loading.set(true);
+            let result = match replace_ingredient(id, replacement_id).await {
+                Ok(_) => delete_ingredient(id).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(_) => {
+                    toast_success(t!("ingredient-replaced"));
+                    load_data();
+                }
+                Err(e) => toast_error(t!("error-replace", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let confirm_archive = move || {
+        let id = delete_id.get_untracked();
+        set_show_usage_modal.set(false);
+
+        spawn_local(async move {
+            match archive_ingredient(id).await {
+                Ok(_) => {
+                    toast_success(t!("ingredient-archived"));
+                    load_data();
+                }
+                Err(e) => toast_error(t!("error-archive", error = e.to_string())),
+            }
+        });
+    };
+
+    let cancel_usage = move || {
+        set_show_usage_modal.set(false);
+    };
+
     view! {
         <div class="space-y-4">
             <div class="flex justify-between items-center">
                 <h3 class="text-2xl font-bold text-gradient flex items-center gap-2">
                     <span class="text-3xl">"🥕"</span>
-                    "Ingredients"
+                    {t!("ingredients")}
                 </h3>
                 <button
                     type="button"
@@ -182,13 +299,13 @@ pub fn IngredientManager() -> impl IntoView {
                     disabled=move || loading.get()
                 >
                     <span class="mr-1">"+"</span>
-                    " Add Ingredient"
+                    " " {t!("add-ingredient")}
                 </button>
             </div>
 
             {move || error.get().map(|err| view! {
                 <div class="alert-error">
-                    <span class="font-semibold mr-2">"⚠️ Error:"</span>
+                    <span class="font-semibold mr-2">"⚠️ " {t!("error")} ":"</span>
                     {err}
                 </div>
             })}
@@ -197,13 +314,13 @@ pub fn IngredientManager() -> impl IntoView {
                 <div class="card border-2 border-blue-200">
                     <h4 class="text-lg font-bold mb-3 text-gradient flex items-center gap-2">
                         <span>"✨"</span>
-                        "New Ingredient"
+                        {t!("new-ingredient")}
                     </h4>
                     <form on:submit=handle_submit>
                         <div class="grid gap-3 items-end"
-                             style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) 7rem 7rem auto">
+                             style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) 7rem 7rem 6rem 7rem 6rem 6rem minmax(0,2fr) auto">
                             <div>
-                                <label class="form-label text-xs">"Name *"</label>
+                                <label class="form-label text-xs">{t!("ingredient-name")} <span class="text-red-500">"*"</span></label>
                                 <input
                                     type="text"
                                     class="form-input text-sm"
@@ -211,6 +328,7 @@ pub fn IngredientManager() -> impl IntoView {
                                     on:input=move |ev| set_name.set(event_target_value(&ev))
                                     required
                                 />
+                                <FieldErrors errors=field_errors field="name" />
                             </div>
                             <div>
                                 <SearchableSelect
@@ -219,13 +337,13 @@ pub fn IngredientManager() -> impl IntoView {
                                     on_change=move |id| set_category_id.set(id)
                                     get_id=|c: &Category| c.id.to_string()
                                     get_display=|c: &Category| c.name.clone()
-                                    placeholder="Category..."
-                                    label="Category *"
+                                    placeholder=t!("select-category")
+                                    label=format!("{} *", t!("category"))
                                     required=true
                                 />
                             </div>
                             <div>
-                                <label class="form-label text-xs">"Primary Unit *"</label>
+                                <label class="form-label text-xs">{t!("primary-unit")} <span class="text-red-500">"*"</span></label>
                                 <input
                                     type="text"
                                     class="form-input text-sm"
@@ -234,9 +352,10 @@ pub fn IngredientManager() -> impl IntoView {
                                     placeholder="kg, l, ks..."
                                     required
                                 />
+                                <FieldErrors errors=field_errors field="primary_unit" />
                             </div>
                             <div>
-                                <label class="form-label text-xs">"Secondary Unit"</label>
+                                <label class="form-label text-xs">{t!("secondary-unit")}</label>
                                 <input
                                     type="text"
                                     class="form-input text-sm"
@@ -245,12 +364,75 @@ pub fn IngredientManager() -> impl IntoView {
                                     placeholder="pcs, cans..."
                                 />
                             </div>
+                            <div>
+                                <label class="form-label text-xs">{t!("safety-margin-percent")}</label>
+                                <input
+                                    type="number"
+                                    min="0"
+                                    step="any"
+                                    class="form-input text-sm"
+                                    prop:value=move || safety_margin.get()
+                                    on:input=move |ev| set_safety_margin.set(event_target_value(&ev))
+                                />
+                                <FieldErrors errors=field_errors field="safety_margin_percent" />
+                            </div>
+                            <div>
+                                <label class="form-label text-xs">{t!("storage")}</label>
+                                <select
+                                    class="form-input text-sm"
+                                    prop:value=move || storage_type.get().as_str()
+                                    on:change=move |ev| {
+                                        if let Ok(st) = event_target_value(&ev).parse::<StorageType>() {
+                                            set_storage_type.set(st);
+                                        }
+                                    }
+                                >
+                                    {StorageType::ALL.iter().map(|st| view! {
+                                        <option value=st.as_str()>{t!(st.message_key())}</option>
+                                    }).collect_view()}
+                                </select>
+                            </div>
+                            <div>
+                                <label class="form-label text-xs">{t!("shelf-life-days")}</label>
+                                <input
+                                    type="number"
+                                    min="0"
+                                    class="form-input text-sm"
+                                    prop:value=move || shelf_life.get()
+                                    on:input=move |ev| set_shelf_life.set(event_target_value(&ev))
+                                    placeholder="∞"
+                                />
+                                <FieldErrors errors=field_errors field="shelf_life_days" />
+                            </div>
+                            <div>
+                                <label class="form-label text-xs">{t!("price-per-unit")}</label>
+                                <input
+                                    type="number"
+                                    min="0"
+                                    step="any"
+                                    class="form-input text-sm"
+                                    prop:value=move || price.get()
+                                    on:input=move |ev| set_price.set(event_target_value(&ev))
+                                />
+                                <FieldErrors errors=field_errors field="price_per_unit" />
+                            </div>
+                            <div>
+                                <label class="form-label text-xs">{t!("allergens")}</label>
+                                <input
+                                    type="text"
+                                    class="form-input text-sm"
+                                    list="allergen-names"
+                                    prop:value=move || allergens.get()
+                                    on:input=move |ev| set_allergens.set(event_target_value(&ev))
+                                    placeholder="gluten, milk..."
+                                />
+                            </div>
                             <div class="flex gap-2">
                                 <button type="submit" class="btn btn-primary text-sm" disabled=move || loading.get()>
-                                    {move || if loading.get() { "Saving..." } else { "Save" }}
+                                    {move || if loading.get() { t!("saving") } else { t!("save") }}
                                 </button>
                                 <button type="button" class="btn btn-secondary text-sm" on:click=cancel_form disabled=move || loading.get()>
-                                    "Cancel"
+                                    {t!("cancel")}
                                 </button>
                             </div>
                         </div>
@@ -262,15 +444,15 @@ pub fn IngredientManager() -> impl IntoView {
                 view! {
                     <div class="card text-center py-12">
                         <div class="spinner mx-auto mb-4"></div>
-                        <p class="text-slate-600">"Loading ingredients..."</p>
+                        <p class="text-slate-600">{t!("loading")}</p>
                     </div>
                 }.into_any()
             } else if ingredients.get().is_empty() {
                 view! {
                     <div class="card text-center py-16 bg-gradient-to-br from-slate-50 to-blue-50 border-2 border-dashed border-slate-300">
                         <div class="text-7xl mb-6">"🥕"</div>
-                        <h3 class="text-2xl font-bold text-slate-800 mb-3">"No ingredients yet"</h3>
-                        <p class="text-lg text-slate-600 mb-8">"Get started by creating your first ingredient"</p>
+                        <h3 class="text-2xl font-bold text-slate-800 mb-3">{t!("no-ingredients")}</h3>
+                        <p class="text-lg text-slate-600 mb-8">{t!("no-ingredients-text")}</p>
                     </div>
                 }.into_any()
             } else {
@@ -282,40 +464,51 @@ pub fn IngredientManager() -> impl IntoView {
                             <input
                                 type="text"
                                 class="form-input flex-1 text-sm py-1.5"
-                                placeholder="Search ingredients..."
+                                placeholder=t!("search-ingredients")
                                 prop:value=move || search_query.get()
                                 on:input=move |ev| set_search_query.set(event_target_value(&ev))
                             />
                         </div>
                         // Header row
                         <div class="grid gap-3 px-4 py-2 text-xs font-semibold text-slate-500 uppercase tracking-wide border-b border-slate-200"
-                             style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) 6rem 6rem 5rem">
-                            <span>"Name"</span>
-                            <span>"Category"</span>
-                            <span>"Primary Unit"</span>
-                            <span>"Secondary Unit"</span>
+                             style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) 6rem 6rem 5rem 6rem 5rem 5rem minmax(0,2fr) 9rem">
+                            <span>{t!("trash-name")}</span>
+                            <span>{t!("category")}</span>
+                            <span>{t!("primary-unit")}</span>
+                            <span>{t!("secondary-unit")}</span>
+                            <span>{t!("report-margin")}</span>
+                            <span>{t!("storage")}</span>
+                            <span>{t!("report-keeps")}</span>
+                            <span>{t!("price")}</span>
+                            <span>{t!("allergens")}</span>
                             <span></span>
                         </div>
                         // Rows
                         <div>
                             {move || {
-                                let query = search_query.get().to_lowercase();
+                                let query = search_query.get();
                                 ingredients.get()
                                     .into_iter()
-                                    .filter(|i| query.is_empty() || i.name.to_lowercase().contains(&query))
+                                    .filter(|i| matches_search(&query, std::iter::once(i.name.as_str()).chain(i.aliases.iter().map(String::as_str))))
                                     .map(|ing| {
                                         let id = ing.id;
                                         let cat_id = ing.category_id;
                                         let name_s = ing.name.clone();
+                                        let aliases_s = ing.aliases.join(", ");
                                         let primary_s = ing.primary_unit.clone();
                                         let secondary_s = ing.secondary_unit.clone();
                                         let secondary_disp = ing.secondary_unit.clone().unwrap_or_default();
+                                        let margin = ing.safety_margin_percent;
+                                        let storage = ing.storage_type.parse().unwrap_or(StorageType::Dry);
+                                        let keeps = ing.shelf_life_days;
+                                        let price_per_unit = ing.price_per_unit;
+                                        let allergens_s = ing.allergens.clone();
 
                                         view! {
                                             {move || if editing_id.get() == Some(id) {
                                                 view! {
                                                     <div class="grid gap-3 px-4 py-2 items-center border-b border-slate-100 bg-blue-50/60"
-                                                         style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) 6rem 6rem 5rem">
+                                                         style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) 6rem 6rem 5rem 6rem 5rem 5rem minmax(0,2fr) 9rem">
                                                         <input type="text" class="form-input text-sm"
                                                             prop:value=move || edit_name.get()
                                                             on:input=move |ev| set_edit_name.set(event_target_value(&ev))
@@ -326,7 +519,7 @@ pub fn IngredientManager() -> impl IntoView {
                                                             on_change=move |cid| set_edit_category_id.set(cid)
                                                             get_id=|c: &Category| c.id.to_string()
                                                             get_display=|c: &Category| c.name.clone()
-                                                            placeholder="Category..."
+                                                            placeholder=t!("select-category")
                                                         />
                                                         <input type="text" class="form-input text-sm"
                                                             prop:value=move || edit_primary_unit.get()
@@ -336,6 +529,34 @@ pub fn IngredientManager() -> impl IntoView {
                                                             prop:value=move || edit_secondary_unit.get()
                                                             on:input=move |ev| set_edit_secondary_unit.set(event_target_value(&ev))
                                                         />
+                                                        <input type="number" min="0" step="any" class="form-input text-sm"
+                                                            prop:value=move || edit_safety_margin.get()
+                                                            on:input=move |ev| set_edit_safety_margin.set(event_target_value(&ev))
+                                                        />
+                                                        <select class="form-input text-sm"
+                                                            prop:value=move || edit_storage_type.get().as_str()
+                                                            on:change=move |ev| {
+                                                                if let Ok(st) = event_target_value(&ev).parse::<StorageType>() {
+                                                                    set_edit_storage_type.set(st);
+                                                                }
+                                                            }
+                                                        >
+                                                            {StorageType::ALL.iter().map(|st| view! {
+                                                                <option value=st.as_str()>{t!(st.message_key())}</option>
+                                                            }).collect_view()}
+                                                        </select>
+                                                        <input type="number" min="0" class="form-input text-sm"
+                                                            prop:value=move || edit_shelf_life.get()
+                                                            on:input=move |ev| set_edit_shelf_life.set(event_target_value(&ev))
+                                                        />
+                                                        <input type="number" min="0" step="any" class="form-input text-sm"
+                                                            prop:value=move || edit_price.get()
+                                                            on:input=move |ev| set_edit_price.set(event_target_value(&ev))
+                                                        />
+                                                        <input type="text" class="form-input text-sm" list="allergen-names"
+                                                            prop:value=move || edit_allergens.get()
+                                                            on:input=move |ev| set_edit_allergens.set(event_target_value(&ev))
+                                                        />
                                                         <div class="flex gap-1">
                                                             <button type="button"
                                                                 class="text-emerald-600 hover:text-emerald-800 hover:bg-emerald-50 rounded-lg p-1.5 transition-colors font-bold text-base leading-none"
@@ -358,35 +579,78 @@ pub fn IngredientManager() -> impl IntoView {
                                                 let name_c = name_s.clone();
                                                 let primary_c = primary_s.clone();
                                                 let secondary_c = secondary_s.clone();
+                                                let pack_unit = primary_s.clone();
+                                                let allergens_c = allergens_s.clone();
                                                 view! {
                                                     <div class="grid gap-3 px-4 py-2.5 items-center border-b border-slate-100 hover:bg-slate-50 transition-colors"
-                                                         style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) 6rem 6rem 5rem">
-                                                        <span class="font-medium text-slate-800 text-sm truncate">{name_s.clone()}</span>
+                                                         style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) 6rem 6rem 5rem 6rem 5rem 5rem minmax(0,2fr) 9rem">
+                                                        <span class="font-medium text-slate-800 text-sm truncate" title=aliases_s.clone()>
+                                                            {name_s.clone()}
+                                                            {(!aliases_s.is_empty()).then(|| view! {
+                                                                <span class="ml-1 text-xs font-normal text-slate-400">{format!("({})", aliases_s)}</span>
+                                                            })}
+                                                        </span>
                                                         <span class="text-sm text-slate-600 truncate">{cat_label}</span>
                                                         <span class="text-xs font-medium text-blue-700 bg-blue-100 px-2 py-0.5 rounded-full w-fit">{primary_s.clone()}</span>
                                                         <span class="text-sm text-slate-500">{secondary_disp.clone()}</span>
+                                                        <span class="text-sm text-slate-500">
+                                                            {if margin > 0.0 { format!("+{}%", margin) } else { String::new() }}
+                                                        </span>
+                                                        <span class="text-sm text-slate-500">{t!(storage.message_key())}</span>
+                                                        <span class="text-sm text-slate-500">
+                                                            {keeps.map(|days| t!("days-short", count = days)).unwrap_or_default()}
+                                                        </span>
+                                                        <span class="text-sm text-slate-500">
+                                                            {price_per_unit.map(|p| format!("{}/{}", format_number(p, 2, language), primary_s)).unwrap_or_default()}
+                                                        </span>
+                                                        <span class="text-sm text-slate-500 truncate">{allergens_s.clone()}</span>
                                                         <div class="flex gap-1">
                                                             <button type="button"
                                                                 class="text-slate-400 hover:text-blue-600 hover:bg-blue-50 rounded-lg p-1.5 transition-colors"
-                                                                title="Edit"
+                                                                title=t!("edit")
                                                                 on:click=move |_| {
                                                                     set_edit_name.set(name_c.clone());
                                                                     set_edit_category_id.set(cat_id);
                                                                     set_edit_primary_unit.set(primary_c.clone());
                                                                     set_edit_secondary_unit.set(secondary_c.clone().unwrap_or_default());
+                                                                    set_edit_safety_margin.set(margin.to_string());
+                                                                    set_edit_storage_type.set(storage);
+                                                                    set_edit_shelf_life.set(keeps.map(|days| days.to_string()).unwrap_or_default());
+                                                                    set_edit_price.set(price_per_unit.map(|p| p.to_string()).unwrap_or_default());
+                                                                    set_edit_allergens.set(allergens_c.clone());
                                                                     set_editing_id.set(Some(id));
                                                                     set_show_form.set(false);
                                                                 }
                                                                 disabled=move || loading.get()
                                                             >"✏️"</button>
+                                                            <button type="button"
+                                                                class="text-slate-400 hover:text-amber-600 hover:bg-amber-50 rounded-lg p-1.5 transition-colors"
+                                                                title=t!("pack-sizes")
+                                                                on:click=move |_| set_packs_open_id.update(|open| {
+                                                                    *open = if *open == Some(id) { None } else { Some(id) };
+                                                                })
+                                                            >"📦"</button>
+                                                            <button type="button"
+                                                                class="text-slate-400 hover:text-violet-600 hover:bg-violet-50 rounded-lg p-1.5 transition-colors"
+                                                                title=t!("aliases")
+                                                                on:click=move |_| set_aliases_open_id.update(|open| {
+                                                                    *open = if *open == Some(id) { None } else { Some(id) };
+                                                                })
+                                                            >"🏷️"</button>
                                                             <button type="button"
                                                                 class="text-slate-400 hover:text-red-600 hover:bg-red-50 rounded-lg p-1.5 transition-colors"
-                                                                title="Delete"
+                                                                title=t!("delete")
                                                                 on:click=move |_| handle_delete_click(id)
                                                                 disabled=move || loading.get()
                                                             >"🗑️"</button>
                                                         </div>
                                                     </div>
+                                                    {move || (packs_open_id.get() == Some(id)).then(|| view! {
+                                                        <PackSizeEditor ingredient_id=id default_unit=pack_unit.clone()/>
+                                                    })}
+                                                    {move || (aliases_open_id.get() == Some(id)).then(|| view! {
+                                                        <AliasEditor ingredient_id=id on_change=load_data/>
+                                                    })}
                                                 }.into_any()
                                             }}
                                         }
@@ -398,16 +662,238 @@ pub fn IngredientManager() -> impl IntoView {
                 }.into_any()
             }}
 
+            <datalist id="allergen-names">
+                {ALLERGENS.iter().map(|allergen| view! { <option value=*allergen></option> }).collect_view()}
+            </datalist>
+
             <ConfirmModal
                 show=show_delete_modal.into()
                 on_confirm=confirm_delete
                 on_cancel=cancel_delete
-                title="Delete Ingredient".to_string()
-                message="Are you sure? This action cannot be undone.".to_string()
-                confirm_text="Delete".to_string()
-                cancel_text="Cancel".to_string()
+                title=t!("delete-ingredient")
+                message=t!("delete-ingredient-confirm")
+                confirm_text=t!("delete")
+                cancel_text=t!("cancel")
                 variant="danger".to_string()
             />
+
+            <UsageModal
+                show=show_usage_modal.into()
+                title=t!("ingredient-in-use")
+                usages=usages.into()
+                replacements=Signal::derive(move || {
+                    let id = delete_id.get();
+                    ingredients.get()
+                        .into_iter()
+                        .filter(|ingredient| ingredient.id != id)
+                        .map(|ingredient| (ingredient.id, ingredient.name))
+                        .collect()
+                })
+                on_replace=replace_and_delete
+                on_archive=confirm_archive
+                on_cancel=cancel_usage
+            />
+        </div>
+    }
+}
+
+/// Value of an optional number input; the browser leaves it empty when the
+/// text is not a number, which means no value
+fn optional_number<T: std::str::FromStr>(value: &str) -> Option<T> {
+    value.trim().replace(',', ".").parse().ok()
+}
+
+/// Purchasable pack sizes of one ingredient, shown below its row
+#[component]
+fn PackSizeEditor(ingredient_id: i64, default_unit: String) -> impl IntoView {
+    let (packs, set_packs) = signal(Vec::<IngredientPack>::new());
+    let (size, set_size) = signal(String::new());
+    let (unit, set_unit) = signal(default_unit);
+    let (label, set_label) = signal(String::new());
+
+    let load_packs = move || {
+        spawn_local(async move {
+            match get_ingredient_packs(ingredient_id).await {
+                Ok(data) => set_packs.set(data),
+                Err(e) => toast_error(t!("error-load-packs", error = e.to_string())),
+            }
+        });
+    };
+
+    Effect::new(move |_| {
+        load_packs();
+    });
+
+    let add_pack = move |_| {
+        let size_val = match size.get().parse::<f64>() {
+            Ok(val) if val > 0.0 => val,
+            _ => {
+                toast_error(t!("validation-pack-size-positive"));
+                return;
+            }
+        };
+        let unit_val = unit.get();
+        if unit_val.is_empty() {
+            toast_error(t!("validation-unit-required"));
+            return;
+        }
+        let label_val = label.get();
+        let label_opt = if label_val.is_empty() { None } else { Some(label_val) };
+
+        spawn_local(async move {
+            match create_ingredient_pack(ingredient_id, unit_val, size_val, label_opt).await {
+                Ok(_) => {
+                    set_size.set(String::new());
+                    set_label.set(String::new());
+                    load_packs();
+                },
+                Err(e) => toast_error(t!("error-add-pack", error = e.to_string())),
+            }
+        });
+    };
+
+    let remove_pack = move |id: i64| {
+        spawn_local(async move {
+            match delete_ingredient_pack(id).await {
+                Ok(_) => load_packs(),
+                Err(e) => toast_error(t!("error-delete-pack", error = e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <div class="px-4 py-3 border-b border-slate-100 bg-amber-50/50 space-y-2">
+            <div class="flex gap-2 flex-wrap items-center">
+                <span class="text-xs font-semibold text-slate-500 uppercase tracking-wide">{t!("pack-sizes")}</span>
+                {move || if packs.get().is_empty() {
+                    view! { <span class="text-sm text-slate-500">{t!("pack-sizes-none")}</span> }.into_any()
+                } else {
+                    packs.get().into_iter().map(|pack| {
+                        let pack_id = pack.id;
+                        let text = match pack.label {
+                            Some(label) => format!("{} ({} {})", label, pack.size, pack.unit),
+                            None => format!("{} {}", pack.size, pack.unit),
+                        };
+                        view! {
+                            <span class="badge badge-primary">
+                                {text}
+                                <button type="button" class="ml-1" on:click=move |_| remove_pack(pack_id)>"×"</button>
+                            </span>
+                        }
+                    }).collect_view().into_any()
+                }}
+            </div>
+            <div class="flex gap-2 items-center">
+                <input type="number" min="0" step="any" class="form-input text-sm w-24"
+                    placeholder=t!("pack-size")
+                    prop:value=move || size.get()
+                    on:input=move |ev| set_size.set(event_target_value(&ev))
+                />
+                <input type="text" class="form-input text-sm w-20"
+                    placeholder=t!("unit")
+                    prop:value=move || unit.get()
+                    on:input=move |ev| set_unit.set(event_target_value(&ev))
+                />
+                <input type="text" class="form-input text-sm flex-1"
+                    placeholder=t!("pack-label-placeholder")
+                    prop:value=move || label.get()
+                    on:input=move |ev| set_label.set(event_target_value(&ev))
+                />
+                <button type="button" class="btn btn-secondary text-sm" on:click=add_pack>{t!("add")}</button>
+            </div>
+        </div>
+    }
+}
+
+/// Other names of one ingredient, shown below its row
+#[component]
+fn AliasEditor<F>(
+    ingredient_id: i64,
+    /// Called after an alias was added or removed
+    on_change: F,
+) -> impl IntoView
+where
+    F: Fn() + 'static + Copy + Send + Sync,
+{
+    let (aliases, set_aliases) = signal(Vec::<IngredientAlias>::new());
+    let (alias, set_alias) = signal(String::new());
+
+    let load_aliases = move || {
+        spawn_local(async move {
+            match get_ingredient_aliases(ingredient_id).await {
+                Ok(data) => set_aliases.set(data),
+                Err(e) => toast_error(t!("error-load-aliases", error = e.to_string())),
+            }
+        });
+    };
+
+    Effect::new(move |_| {
+        load_aliases();
+    });
+
+    let add_alias = move || {
+        let alias_val = alias.get();
+        if alias_val.trim().is_empty() {
+            toast_error(t!("validation-alias-empty"));
+            return;
+        }
+
+        spawn_local(async move {
+            match create_ingredient_alias(ingredient_id, alias_val).await {
+                Ok(_) => {
+                    set_alias.set(String::new());
+                    load_aliases();
+                    on_change();
+                },
+                Err(e) => toast_error(t!("error-add-alias", error = e.to_string())),
+            }
+        });
+    };
+
+    let remove_alias = move |id: i64| {
+        spawn_local(async move {
+            match delete_ingredient_alias(id).await {
+                Ok(_) => {
+                    load_aliases();
+                    on_change();
+                },
+                Err(e) => toast_error(t!("error-delete-alias", error = e.to_string())),
+            }
+        });
+    };
+
+    view! {
+        <div class="px-4 py-3 border-b border-slate-100 bg-violet-50/50 space-y-2">
+            <div class="flex gap-2 flex-wrap items-center">
+                <span class="text-xs font-semibold text-slate-500 uppercase tracking-wide">{t!("aliases")}</span>
+                {move || if aliases.get().is_empty() {
+                    view! { <span class="text-sm text-slate-500">{t!("aliases-none")}</span> }.into_any()
+                } else {
+                    aliases.get().into_iter().map(|entry| {
+                        let alias_id = entry.id;
+                        view! {
+                            <span class="badge badge-primary">
+                                {entry.alias}
+                                <button type="button" class="ml-1" on:click=move |_| remove_alias(alias_id)>"×"</button>
+                            </span>
+                        }
+                    }).collect_view().into_any()
+                }}
+            </div>
+            <div class="flex gap-2 items-center">
+                <input type="text" class="form-input text-sm flex-1"
+                    placeholder=t!("alias-placeholder")
+                    prop:value=move || alias.get()
+                    on:input=move |ev| set_alias.set(event_target_value(&ev))
+                    on:keydown=move |ev| {
+                        if ev.key() == "Enter" {
+                            ev.prevent_default();
+                            add_alias();
+                        }
+                    }
+                />
+                <button type="button" class="btn btn-secondary text-sm" on:click=move |_| add_alias()>{t!("add")}</button>
+            </div>
         </div>
     }
 }
@@ -0,0 +1,249 @@
+use crate::models::{DuplicateGroup, RequiredConversion, UnitFactor};
+use crate::server_functions::ingredients::{find_duplicate_ingredients, get_merge_conversions, merge_ingredients};
+use crate::components::{toast_success, toast_error, toast_info};
+use crate::t;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+/// Group being merged, waiting for confirmation
+#[derive(Clone, Debug)]
+struct PendingMerge {
+    target_id: i64,
+    target_name: String,
+    source_ids: Vec<i64>,
+    source_names: Vec<String>,
+    conversions: Vec<RequiredConversion>,
+}
+
+#[component]
+pub fn IngredientMergeTool() -> impl IntoView {
+    let (groups, set_groups) = signal(Vec::<(DuplicateGroup, RwSignal<i64>, RwSignal<Vec<i64>>)>::new());
+    let (loading, set_loading) = signal(false);
+    let (error, set_error) = signal(None::<String>);
+    let (pending, set_pending) = signal(None::<PendingMerge>);
+    let factors = RwSignal::new(Vec::<String>::new());
+
+    let load_data = move || {
+        spawn_local(async move {
+            set_loading.set(true);
+            set_error.set(None);
+            match find_duplicate_ingredients().await {
+                Ok(data) => set_groups.set(
+                    data.into_iter()
+                        .map(|group| {
+                            // Suggest the most used ingredient as target and all others as sources
+                            let target = group.ingredients[0].ingredient.id;
+                            let sources = group.ingredients[1..].iter().map(|u| u.ingredient.id).collect();
+                            (group, RwSignal::new(target), RwSignal::new(sources))
+                        })
+                        .collect()
+                ),
+                Err(e) => set_error.set(Some(t!("error-find-duplicates", error = e.to_string()))),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    Effect::new(move |_| {
+        load_data();
+    });
+
+    let start_merge = move |group: DuplicateGroup, target_id: i64, source_ids: Vec<i64>| {
+        let source_ids: Vec<i64> = source_ids.into_iter().filter(|id| *id != target_id).collect();
+        if source_ids.is_empty() {
+            toast_info(t!("validation-merge-select-source"));
+            return;
+        }
+        let name_of = |id: i64| group.ingredients.iter()
+            .find(|u| u.ingredient.id == id)
+            .map(|u| u.ingredient.name.clone())
+            .unwrap_or_default();
+        let target_name = name_of(target_id);
+        let source_names: Vec<String> = source_ids.iter().map(|id| name_of(*id)).collect();
+
+        spawn_local(async move {
+            match get_merge_conversions(target_id, source_ids.clone()).await {
+                Ok(conversions) => {
+                    factors.set(vec![String::new(); conversions.len()]);
+                    set_pending.set(Some(PendingMerge { target_id, target_name, source_ids, source_names, conversions }));
+                }
+                Err(e) => toast_error(t!("error-check-units", error = e.to_string())),
+            }
+        });
+    };
+
+    let confirm_merge = move |_| {
+        let Some(merge) = pending.get_untracked() else {
+            return;
+        };
+
+        let mut unit_factors = Vec::new();
+        for (conversion, value) in merge.conversions.iter().zip(factors.get_untracked()) {
+            match value.trim().replace(',', ".").parse::<f64>() {
+                Ok(factor) if factor > 0.0 => unit_factors.push(UnitFactor {
+                    ingredient_id: conversion.ingredient_id,
+                    unit: conversion.unit.clone(),
+                    factor,
+                }),
+                _ => {
+                    toast_error(t!(
+                        "merge-enter-factor",
+                        target_unit = conversion.target_unit.clone(),
+                        unit = conversion.unit.clone(),
+                        ingredient = conversion.ingredient_name.clone(),
+                    ));
+                    return;
+                }
+            }
+        }
+
+        spawn_local(async move {
+            set_loading.set(true);
+            match merge_ingredients(merge.target_id, merge.source_ids, unit_factors).await {
+                Ok(result) => {
+                    toast_success(t!(
+                        "merge-done",
+                        count = result.merged,
+                        target = merge.target_name.clone(),
+                        recipe_lines = result.recipe_lines,
+                        stock_items = result.stock_items,
+                    ));
+                    set_pending.set(None);
+                    load_data();
+                }
+                Err(e) => toast_error(t!("error-merge", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="space-y-6">
+            <div>
+                <h2 class="text-3xl font-bold text-gradient flex items-center gap-3">
+                    <span class="text-4xl">"🔀"</span>
+                    {t!("merge-title")}
+                </h2>
+                <p class="text-slate-600 mt-2">
+                    {t!("merge-help")}
+                </p>
+            </div>
+
+            {move || error.get().map(|err| view! {
+                <div class="alert-error">
+                    <span class="font-semibold mr-2">"⚠️ " {t!("error")} ":"</span>
+                    {err}
+                </div>
+            })}
+
+            {move || pending.get().map(|merge| view! {
+                <div class="card border-2 border-blue-200 space-y-4">
+                    <h3 class="text-xl font-bold">
+                        {t!("merge-heading", sources = merge.source_names.join(", "), target = merge.target_name.clone())}
+                    </h3>
+                    {if merge.conversions.is_empty() {
+                        view! { <p class="text-slate-600">{t!("merge-units-convert")}</p> }.into_any()
+                    } else {
+                        view! {
+                            <div class="space-y-2">
+                                <p class="text-slate-600">{t!("merge-units-missing")}</p>
+                                {merge.conversions.iter().enumerate().map(|(index, conversion)| view! {
+                                    <div class="flex items-center gap-2">
+                                        <span>{t!("merge-one-unit-of", unit = conversion.unit.clone(), ingredient = conversion.ingredient_name.clone())}</span>
+                                        <input
+                                            type="number"
+                                            step="any"
+                                            min="0"
+                                            class="form-input w-32"
+                                            on:input=move |ev| factors.update(|values| values[index] = event_target_value(&ev))
+                                        />
+                                        <span>{conversion.target_unit.clone()}</span>
+                                        <span class="text-sm text-slate-500">{t!("merge-uses", count = conversion.uses)}</span>
+                                    </div>
+                                }).collect_view()}
+                            </div>
+                        }.into_any()
+                    }}
+                    <div class="flex gap-2">
+                        <button type="button" class="btn btn-primary" on:click=confirm_merge disabled=move || loading.get()>
+                            {t!("merge")}
+                        </button>
+                        <button type="button" class="btn btn-secondary" on:click=move |_| set_pending.set(None)>
+                            {t!("cancel")}
+                        </button>
+                    </div>
+                </div>
+            })}
+
+            {move || if loading.get() && groups.get().is_empty() {
+                view! {
+                    <div class="card text-center py-12">
+                        <div class="spinner mx-auto mb-4"></div>
+                        <p class="text-slate-600">{t!("merge-searching")}</p>
+                    </div>
+                }.into_any()
+            } else if groups.get().is_empty() {
+                view! {
+                    <div class="card text-center py-12">
+                        <div class="text-5xl mb-4">"✅"</div>
+                        <p class="text-slate-600">{t!("merge-none-found")}</p>
+                    </div>
+                }.into_any()
+            } else {
+                groups.get().into_iter().map(|(group, target, sources)| {
+                    let group_for_merge = group.clone();
+                    view! {
+                        <div class="card space-y-2">
+                            {group.ingredients.iter().map(|usage| {
+                                let id = usage.ingredient.id;
+                                view! {
+                                    <div class="flex items-center gap-4">
+                                        <label class="flex items-center gap-2">
+                                            <input
+                                                type="radio"
+                                                name=format!("merge-target-{}", group.ingredients[0].ingredient.id)
+                                                prop:checked=move || target.get() == id
+                                                on:change=move |_| target.set(id)
+                                            />
+                                            <span class="text-sm text-slate-500">{t!("merge-target")}</span>
+                                        </label>
+                                        <label class="flex items-center gap-2">
+                                            <input
+                                                type="checkbox"
+                                                class="form-checkbox"
+                                                prop:checked=move || sources.get().contains(&id)
+                                                prop:disabled=move || target.get() == id
+                                                on:change=move |ev| {
+                                                    let checked = event_target_checked(&ev);
+                                                    sources.update(|ids| {
+                                                        ids.retain(|other| *other != id);
+                                                        if checked {
+                                                            ids.push(id);
+                                                        }
+                                                    });
+                                                }
+                                            />
+                                            <span class="font-semibold">{usage.ingredient.name.clone()}</span>
+                                        </label>
+                                        <span class="badge badge-primary">{usage.ingredient.primary_unit.clone()}</span>
+                                        <span class="text-sm text-slate-500">
+                                            {t!("merge-usage", recipes = usage.recipe_count, stock_items = usage.stock_count)}
+                                        </span>
+                                    </div>
+                                }
+                            }).collect_view()}
+                            <button
+                                type="button"
+                                class="btn btn-secondary text-sm"
+                                on:click=move |_| start_merge(group_for_merge.clone(), target.get_untracked(), sources.get_untracked())
+                                disabled=move || loading.get()
+                            >
+                                "🔀 " {t!("merge-selected")}
+                            </button>
+                        </div>
+                    }
+                }).collect_view().into_any()
+            }}
+        </div>
+    }
+}
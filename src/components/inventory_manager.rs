@@ -0,0 +1,487 @@
+use crate::models::{Camp, CreateInventoryItem, Ingredient, InventoryItemDetail, UpdateInventoryItem};
+use crate::server_functions::inventory::{
+    get_inventory, create_inventory_item, update_inventory_item, delete_inventory_item,
+    get_consumed_dates, consume_day,
+};
+use crate::server_functions::ingredients::get_ingredients;
+use crate::server_functions::camps::get_camps;
+use crate::components::{SearchableSelect, ConfirmModal, FieldErrors, toast_success, toast_error, toast_info, validate_form};
+use crate::error::FieldError;
+use crate::format::{format_amount, format_number};
+use crate::i18n::use_language;
+use crate::t;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos::ev::SubmitEvent;
+
+#[component]
+pub fn InventoryManager() -> impl IntoView {
+    let language = use_language();
+    let (items, set_items) = signal(Vec::<InventoryItemDetail>::new());
+    let (ingredients, set_ingredients) = signal(Vec::<Ingredient>::new());
+    let (camps, set_camps) = signal(Vec::<Camp>::new());
+    let (show_form, set_show_form) = signal(false);
+    let (error, set_error) = signal(None::<String>);
+    let (loading, set_loading) = signal(false);
+    let (field_errors, set_field_errors) = signal(Vec::<FieldError>::new());
+
+    // Camp filter (0 = all stock)
+    let (camp_filter, set_camp_filter) = signal(0i64);
+    let (consumed_dates, set_consumed_dates) = signal(Vec::<String>::new());
+    let (consume_date, set_consume_date) = signal(String::new());
+
+    // Modal state
+    let (show_delete_modal, set_show_delete_modal) = signal(false);
+    let (delete_id, set_delete_id) = signal(0i64);
+
+    // New stock form fields
+    let (ingredient_id, set_ingredient_id) = signal(0i64);
+    let (form_camp_id, set_form_camp_id) = signal(0i64);
+    let (storeroom, set_storeroom) = signal(String::new());
+    let (quantity, set_quantity) = signal(String::new());
+    let (unit, set_unit) = signal(String::new());
+
+    // Inline edit state
+    let (editing_id, set_editing_id) = signal(None::<i64>);
+    let (edit_quantity, set_edit_quantity) = signal(String::new());
+    let (edit_unit, set_edit_unit) = signal(String::new());
+
+    let load_data = move || {
+        let camp = camp_filter.get_untracked();
+        spawn_local(async move {
+            set_loading.set(true);
+            set_error.set(None);
+
+            let camp_opt = if camp > 0 { Some(camp) } else { None };
+            match get_inventory(camp_opt).await {
+                Ok(data) => set_items.set(data),
+                Err(e) => set_error.set(Some(t!("error-load-stock", error = e.to_string()))),
+            }
+
+            if camp > 0 {
+                match get_consumed_dates(camp).await {
+                    Ok(dates) => set_consumed_dates.set(dates),
+                    Err(e) => set_error.set(Some(t!("error-load-consumed-days", error = e.to_string()))),
+                }
+            } else {
+                set_consumed_dates.set(Vec::new());
+            }
+
+            set_loading.set(false);
+        });
+    };
+
+    Effect::new(move |_| {
+        spawn_local(async move {
+            match get_ingredients(false).await {
+                Ok(data) => set_ingredients.set(data),
+                Err(e) => set_error.set(Some(t!("error-load-ingredients", error = e.to_string()))),
+            }
+            match get_camps(false).await {
+                Ok(data) => set_camps.set(data),
+                Err(e) => set_error.set(Some(t!("error-load-camps", error = e.to_string()))),
+            }
+        });
+    });
+
+    Effect::new(move |_| {
+        let _ = camp_filter.get();
+        load_data();
+    });
+
+    // Default the unit to the ingredient's primary unit
+    Effect::new(move |_| {
+        let id = ingredient_id.get();
+        if let Some(ing) = ingredients.get_untracked().iter().find(|i| i.id == id) {
+            set_unit.set(ing.primary_unit.clone());
+        }
+    });
+
+    let reset_form = move || {
+        set_ingredient_id.set(0);
+        set_form_camp_id.set(camp_filter.get_untracked());
+        set_storeroom.set(String::new());
+        set_quantity.set(String::new());
+        set_unit.set(String::new());
+        set_error.set(None);
+        set_field_errors.set(Vec::new());
+    };
+
+    let handle_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+
+        let camp_val = form_camp_id.get();
+        let storeroom_val = storeroom.get();
+
+        let Ok(quantity_val) = quantity.get().trim().replace(',', ".").parse::<f64>() else {
+            toast_error(t!("validation-invalid-quantity"));
+            return;
+        };
+
+        let item = CreateInventoryItem {
+            ingredient_id: ingredient_id.get(),
+            camp_id: (camp_val > 0).then_some(camp_val),
+            storeroom: (!storeroom_val.is_empty()).then_some(storeroom_val),
+            quantity: quantity_val,
+            unit: unit.get(),
+            notes: None,
+        };
+
+        // Same rules as the server
+        if !validate_form(&item, set_field_errors) {
+            return;
+        }
+
+        spawn_local(async move {
+            set_loading.set(true);
+
+            match create_inventory_item(item.ingredient_id, item.camp_id, item.storeroom, item.quantity, item.unit, item.notes).await {
+                Ok(_) => {
+                    toast_success(t!("stock-added"));
+                    reset_form();
+                    set_show_form.set(false);
+                    load_data();
+                },
+                Err(e) => {
+                    set_field_errors.set(e.field_errors().to_vec());
+                    toast_error(t!("error-add-stock", error = e.to_string()));
+                }
+            }
+
+            set_loading.set(false);
+        });
+    };
+
+    let save_edit = move |id: i64| {
+        let Ok(quantity_val) = edit_quantity.get().trim().replace(',', ".").parse::<f64>() else {
+            toast_error(t!("validation-invalid-quantity"));
+            return;
+        };
+
+        let update = UpdateInventoryItem {
+            quantity: Some(quantity_val),
+            unit: Some(edit_unit.get()),
+            storeroom: None,
+            notes: None,
+        };
+
+        // Same rules as the server
+        if !validate_form(&update, set_field_errors) {
+            return;
+        }
+
+        spawn_local(async move {
+            set_loading.set(true);
+            match update_inventory_item(id, quantity_val, update.unit.unwrap_or_default(), None, None).await {
+                Ok(_) => {
+                    toast_success(t!("stock-updated"));
+                    set_editing_id.set(None);
+                    load_data();
+                },
+                Err(e) => toast_error(t!("error-update-stock", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let handle_consume = move |_| {
+        let camp = camp_filter.get();
+        let date = consume_date.get();
+        if camp == 0 || date.is_empty() {
+            toast_error(t!("validation-select-camp-date"));
+            return;
+        }
+
+        spawn_local(async move {
+            set_loading.set(true);
+            match consume_day(camp, date).await {
+                Ok(result) if result.items.is_empty() => {
+                    toast_info(t!("stock-nothing-consumed"));
+                    load_data();
+                },
+                Ok(result) => {
+                    toast_success(t!("stock-consumed", count = result.items.len()));
+                    load_data();
+                },
+                Err(e) => toast_error(t!("error-consume-stock", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let confirm_delete = move || {
+        let id = delete_id.get();
+        set_show_delete_modal.set(false);
+
+        spawn_local(async move {
+            set_loading.set(true);
+            match delete_inventory_item(id).await {
+                Ok(_) => {
+                    toast_success(t!("stock-removed"));
+                    load_data();
+                },
+                Err(e) => toast_error(t!("error-delete", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let camp_name = move |id: Option<i64>| match id {
+        Some(id) => camps.get()
+            .iter()
+            .find(|c| c.id == id)
+            .map(|c| c.name.clone())
+            .unwrap_or_default(),
+        None => t!("stock-shared-storeroom"),
+    };
+
+    view! {
+        <div class="space-y-4">
+            <div class="flex justify-between items-center">
+                <h2 class="text-3xl font-bold text-gradient flex items-center gap-3">
+                    <span class="text-4xl">"📦"</span>
+                    {t!("nav-pantry")}
+                </h2>
+                <button
+                    type="button"
+                    class="btn btn-primary"
+                    on:click=move |_| {
+                        reset_form();
+                        set_editing_id.set(None);
+                        set_show_form.set(true);
+                    }
+                    disabled=move || loading.get()
+                >
+                    <span class="mr-1">"+"</span>
+                    " " {t!("add-stock")}
+                </button>
+            </div>
+
+            {move || error.get().map(|err| view! {
+                <div class="alert-error">
+                    <span class="font-semibold mr-2">"⚠️ " {t!("error")} ":"</span>
+                    {err}
+                </div>
+            })}
+
+            <div class="card">
+                <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-end">
+                    <SearchableSelect
+                        options=camps.into()
+                        selected_value=camp_filter.into()
+                        on_change=move |id| set_camp_filter.set(id)
+                        get_id=|c: &Camp| c.id.to_string()
+                        get_display=|c: &Camp| c.name.clone()
+                        placeholder=t!("stock-all")
+                        label=t!("camp")
+                    />
+                    <div>
+                        <label class="form-label">{t!("stock-consume-day")}</label>
+                        <input
+                            type="date"
+                            class="form-input"
+                            prop:value=move || consume_date.get()
+                            on:input=move |ev| set_consume_date.set(event_target_value(&ev))
+                            disabled=move || camp_filter.get() == 0
+                        />
+                    </div>
+                    <button
+                        type="button"
+                        class="btn btn-secondary"
+                        on:click=handle_consume
+                        disabled=move || loading.get() || camp_filter.get() == 0 || consume_date.get().is_empty()
+                    >
+                        "➖ " {t!("stock-deduct")}
+                    </button>
+                </div>
+                {move || (!consumed_dates.get().is_empty()).then(|| view! {
+                    <p class="text-sm text-slate-600 mt-3">
+                        {t!("stock-already-deducted", dates = consumed_dates.get().join(", "))}
+                    </p>
+                })}
+            </div>
+
+            {move || show_form.get().then(|| view! {
+                <div class="card border-2 border-blue-200">
+                    <h4 class="text-lg font-bold mb-3 text-gradient flex items-center gap-2">
+                        <span>"✨"</span>
+                        {t!("new-stock")}
+                    </h4>
+                    <form on:submit=handle_submit>
+                        <div class="grid gap-3 items-end"
+                             style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) minmax(0,2fr) 6rem 6rem auto">
+                            <SearchableSelect
+                                options=ingredients.into()
+                                selected_value=ingredient_id.into()
+                                on_change=move |id| set_ingredient_id.set(id)
+                                get_id=|i: &Ingredient| i.id.to_string()
+                                get_display=|i: &Ingredient| i.name.clone()
+                                search_terms=|i: Ingredient| i.aliases
+                                placeholder=t!("search-ingredients")
+                                label=format!("{} *", t!("ingredient"))
+                                required=true
+                            />
+                            <SearchableSelect
+                                options=camps.into()
+                                selected_value=form_camp_id.into()
+                                on_change=move |id| set_form_camp_id.set(id)
+                                get_id=|c: &Camp| c.id.to_string()
+                                get_display=|c: &Camp| c.name.clone()
+                                placeholder=t!("stock-shared-storeroom")
+                                label=t!("camp")
+                            />
+                            <div>
+                                <label class="form-label text-xs">{t!("storeroom")}</label>
+                                <input
+                                    type="text"
+                                    class="form-input text-sm"
+                                    prop:value=move || storeroom.get()
+                                    on:input=move |ev| set_storeroom.set(event_target_value(&ev))
+                                    placeholder=t!("storeroom-placeholder")
+                                />
+                            </div>
+                            <div>
+                                <label class="form-label text-xs">{t!("quantity")} <span class="text-red-500">"*"</span></label>
+                                <input
+                                    type="text"
+                                    inputmode="decimal"
+                                    class="form-input text-sm"
+                                    prop:value=move || quantity.get()
+                                    on:input=move |ev| set_quantity.set(event_target_value(&ev))
+                                    required
+                                />
+                                <FieldErrors errors=field_errors field="quantity" />
+                            </div>
+                            <div>
+                                <label class="form-label text-xs">{t!("unit")} <span class="text-red-500">"*"</span></label>
+                                <input
+                                    type="text"
+                                    class="form-input text-sm"
+                                    prop:value=move || unit.get()
+                                    on:input=move |ev| set_unit.set(event_target_value(&ev))
+                                    required
+                                />
+                                <FieldErrors errors=field_errors field="unit" />
+                            </div>
+                            <div class="flex gap-2">
+                                <button type="submit" class="btn btn-primary text-sm" disabled=move || loading.get()>
+                                    {t!("save")}
+                                </button>
+                                <button type="button" class="btn btn-secondary text-sm" on:click=move |_| set_show_form.set(false)>
+                                    {t!("cancel")}
+                                </button>
+                            </div>
+                        </div>
+                    </form>
+                </div>
+            })}
+
+            {move || if items.get().is_empty() {
+                view! {
+                    <div class="card text-center py-16 bg-gradient-to-br from-slate-50 to-blue-50 border-2 border-dashed border-slate-300">
+                        <div class="text-7xl mb-6">"📦"</div>
+                        <h3 class="text-2xl font-bold text-slate-800 mb-3">{t!("no-stock")}</h3>
+                        <p class="text-lg text-slate-600">{t!("no-stock-text")}</p>
+                    </div>
+                }.into_any()
+            } else {
+                view! {
+                    <div class="card p-0 overflow-hidden">
+                        <div class="grid gap-3 px-4 py-2 text-xs font-semibold text-slate-500 uppercase tracking-wide border-b border-slate-200"
+                             style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) minmax(0,2fr) 6rem 5rem 5rem">
+                            <span>{t!("ingredient")}</span>
+                            <span>{t!("camp")}</span>
+                            <span>{t!("storeroom")}</span>
+                            <span class="text-right">{t!("quantity")}</span>
+                            <span>{t!("unit")}</span>
+                            <span></span>
+                        </div>
+                        {move || items.get().into_iter().map(|detail| {
+                            let id = detail.item.id;
+                            let quantity_s = format_amount(detail.item.quantity, &detail.item.unit, language);
+                            let edit_quantity_s = format_number(detail.item.quantity, 2, language);
+                            let unit_s = detail.item.unit.clone();
+                            let location = camp_name(detail.item.camp_id);
+                            view! {
+                                {move || if editing_id.get() == Some(id) {
+                                    view! {
+                                        <div class="grid gap-3 px-4 py-2 items-center border-b border-slate-100 bg-blue-50/60"
+                                             style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) minmax(0,2fr) 6rem 5rem 5rem">
+                                            <span class="font-medium text-slate-800 text-sm truncate">{detail.ingredient_name.clone()}</span>
+                                            <span class="text-sm text-slate-600 truncate">{location.clone()}</span>
+                                            <span class="text-sm text-slate-600 truncate">{detail.item.storeroom.clone()}</span>
+                                            <input type="text" inputmode="decimal" class="form-input text-sm text-right"
+                                                prop:value=move || edit_quantity.get()
+                                                on:input=move |ev| set_edit_quantity.set(event_target_value(&ev))
+                                            />
+                                            <input type="text" class="form-input text-sm"
+                                                prop:value=move || edit_unit.get()
+                                                on:input=move |ev| set_edit_unit.set(event_target_value(&ev))
+                                            />
+                                            <div class="flex gap-1">
+                                                <button type="button"
+                                                    class="text-emerald-600 hover:text-emerald-800 hover:bg-emerald-50 rounded-lg p-1.5 transition-colors font-bold text-base leading-none"
+                                                    on:click=move |_| save_edit(id)
+                                                    disabled=move || loading.get()
+                                                >"✓"</button>
+                                                <button type="button"
+                                                    class="text-slate-400 hover:text-slate-600 hover:bg-slate-100 rounded-lg p-1.5 transition-colors text-base leading-none"
+                                                    on:click=move |_| set_editing_id.set(None)
+                                                >"✕"</button>
+                                            </div>
+                                        </div>
+                                    }.into_any()
+                                } else {
+                                    let quantity_c = edit_quantity_s.clone();
+                                    let unit_c = unit_s.clone();
+                                    view! {
+                                        <div class="grid gap-3 px-4 py-2.5 items-center border-b border-slate-100 hover:bg-slate-50 transition-colors"
+                                             style="grid-template-columns: minmax(0,3fr) minmax(0,2fr) minmax(0,2fr) 6rem 5rem 5rem">
+                                            <span class="font-medium text-slate-800 text-sm truncate">{detail.ingredient_name.clone()}</span>
+                                            <span class="text-sm text-slate-600 truncate">{location.clone()}</span>
+                                            <span class="text-sm text-slate-600 truncate">{detail.item.storeroom.clone()}</span>
+                                            <span class="text-sm text-right">{quantity_s.clone()}</span>
+                                            <span class="text-xs font-medium text-blue-700 bg-blue-100 px-2 py-0.5 rounded-full w-fit">{unit_s.clone()}</span>
+                                            <div class="flex gap-1">
+                                                <button type="button"
+                                                    class="text-slate-400 hover:text-blue-600 hover:bg-blue-50 rounded-lg p-1.5 transition-colors"
+                                                    title=t!("edit")
+                                                    on:click=move |_| {
+                                                        set_edit_quantity.set(quantity_c.clone());
+                                                        set_edit_unit.set(unit_c.clone());
+                                                        set_editing_id.set(Some(id));
+                                                    }
+                                                    disabled=move || loading.get()
+                                                >"✏️"</button>
+                                                <button type="button"
+                                                    class="text-slate-400 hover:text-red-600 hover:bg-red-50 rounded-lg p-1.5 transition-colors"
+                                                    title=t!("delete")
+                                                    on:click=move |_| {
+                                                        set_delete_id.set(id);
+                                                        set_show_delete_modal.set(true);
+                                                    }
+                                                    disabled=move || loading.get()
+                                                >"🗑️"</button>
+                                            </div>
+                                        </div>
+                                    }.into_any()
+                                }}
+                            }
+                        }).collect_view()}
+                    </div>
+                }.into_any()
+            }}
+
+            <ConfirmModal
+                show=show_delete_modal.into()
+                on_confirm=confirm_delete
+                on_cancel=move || set_show_delete_modal.set(false)
+                title=t!("remove-stock")
+                message=t!("remove-stock-confirm")
+                confirm_text=t!("delete")
+                cancel_text=t!("cancel")
+                variant="danger".to_string()
+            />
+        </div>
+    }
+}
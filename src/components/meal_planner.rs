@@ -1,11 +1,15 @@
-use crate::models::{Recipe, PlannedMealWithDetails, MealType, Camp};
+use crate::models::{Recipe, PlannedMealWithDetails, MealType, Camp, CreateAttendance, CreatePlannedMeal};
 use crate::server_functions::meal_plans::{get_planned_meals_for_date, get_planned_meals_for_camp, create_planned_meal, update_planned_meal, delete_planned_meal};
 use crate::server_functions::recipes::get_recipes;
 use crate::server_functions::camps::{get_camp, get_camps};
-use crate::components::{SearchableSelect, ConfirmModal, toast_success, toast_error};
+use crate::components::{SearchableSelect, ConfirmModal, FieldErrors, MenuGenerator, PlanCheckPanel, toast_success, toast_error, validate_form};
+use crate::error::FieldError;
+use crate::t;
+use crate::format::{format_date, format_day, meal_type_str_label};
+use crate::i18n::use_language;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use leptos_router::hooks::{use_params_map, use_navigate};
+use leptos_router::hooks::{use_params_map, use_query_map, use_navigate};
 use leptos::ev::SubmitEvent;
 use chrono::{NaiveDate, Duration};
 use std::collections::HashMap;
@@ -27,6 +31,9 @@ pub fn MealPlanner() -> impl IntoView {
         })
     });
 
+    let query = use_query_map();
+    let language = use_language();
+
     let navigate = use_navigate();
 
     let (planned_meals, set_planned_meals) = signal(Vec::<PlannedMealWithDetails>::new());
@@ -37,8 +44,11 @@ pub fn MealPlanner() -> impl IntoView {
     let (show_form, set_show_form) = signal(false);
     let (error, set_error) = signal(None::<String>);
     let (loading, set_loading) = signal(false);
+    let (field_errors, set_field_errors) = signal(Vec::<FieldError>::new());
     let (selected_date, set_selected_date) = signal(String::new());
     let (view_mode, set_view_mode) = signal(ViewMode::SingleDay);
+    let (show_generator, set_show_generator) = signal(false);
+    let (show_check, set_show_check) = signal(false);
 
     // Form fields
     let (editing_meal_id, set_editing_meal_id) = signal(None::<i64>);
@@ -56,20 +66,33 @@ pub fn MealPlanner() -> impl IntoView {
     Effect::new(move |_| {
         spawn_local(async move {
             // Load all camps
-            match get_camps().await {
+            match get_camps(false).await {
                 Ok(data) => set_camps.set(data),
-                Err(e) => set_error.set(Some(format!("Failed to load camps: {}", e))),
+                Err(e) => set_error.set(Some(t!("error-load-camps", error = e.to_string()))),
             }
             
             // Load recipes
-            match get_recipes().await {
+            match get_recipes(false).await {
                 Ok(data) => {
                     set_recipes.set(data.clone());
                     if let Some(first) = data.first() {
                         set_recipe_id.set(first.id);
                     }
                 },
-                Err(e) => set_error.set(Some(format!("Failed to load recipes: {}", e))),
+                Err(e) => set_error.set(Some(t!("error-load-recipes", error = e.to_string()))),
+            }
+        });
+    });
+
+    // Links from the plan check open a day (`?date=`) or the check itself (`?check=1`)
+    Effect::new(move |_| {
+        query.with(|q| {
+            if let Some(date) = q.get("date") {
+                set_selected_date.set(date);
+                set_view_mode.set(ViewMode::SingleDay);
+            }
+            if q.get("check").is_some() {
+                set_show_check.set(true);
             }
         });
     });
@@ -90,7 +113,7 @@ pub fn MealPlanner() -> impl IntoView {
                         }
                         set_camp.set(Some(camp_data));
                     },
-                    Err(e) => set_error.set(Some(format!("Failed to load camp: {}", e))),
+                    Err(e) => set_error.set(Some(t!("error-load-camp", error = e.to_string()))),
                 }
             } else {
                 set_camp.set(None);
@@ -105,7 +128,7 @@ pub fn MealPlanner() -> impl IntoView {
         let current_view_mode = view_mode.get_untracked();
 
         if current_camp_id == 0 {
-            set_error.set(Some("No camp selected".to_string()));
+            set_error.set(Some(t!("no-camp-selected")));
             return;
         }
 
@@ -128,7 +151,7 @@ pub fn MealPlanner() -> impl IntoView {
                             });
                             set_planned_meals.set(data);
                         },
-                        Err(e) => set_error.set(Some(format!("Failed to load meals: {}", e))),
+                        Err(e) => set_error.set(Some(t!("error-load-meals", error = e.to_string()))),
                     }
                 },
                 ViewMode::AllDays => {
@@ -145,7 +168,7 @@ pub fn MealPlanner() -> impl IntoView {
                             }
                             set_multi_day_meals.set(map);
                         },
-                        Err(e) => set_error.set(Some(format!("Failed to load meals: {}", e))),
+                        Err(e) => set_error.set(Some(t!("error-load-meals", error = e.to_string()))),
                     }
                 },
             }
@@ -176,11 +199,13 @@ pub fn MealPlanner() -> impl IntoView {
             set_adults.set(0);
         }
         set_error.set(None);
+        set_field_errors.set(Vec::new());
     };
 
     let cancel_form = move |_| {
         set_show_form.set(false);
         set_error.set(None);
+        set_field_errors.set(Vec::new());
     };
 
     let handle_submit = move |ev: SubmitEvent| {
@@ -196,21 +221,28 @@ pub fn MealPlanner() -> impl IntoView {
         let editing_id = editing_meal_id.get();
 
         if current_camp_id == 0 {
-            toast_error("No camp selected");
+            toast_error(t!("no-camp-selected"));
             return;
         }
 
-        // Validate attendance counts are non-negative
-        if children_val < 0 {
-            toast_error("Number of children cannot be negative");
+        let Ok(date) = NaiveDate::parse_from_str(&date_val, "%Y-%m-%d") else {
+            toast_error(t!("validation-invalid-date"));
             return;
-        }
-        if teens_val < 0 {
-            toast_error("Number of teens cannot be negative");
-            return;
-        }
-        if adults_val < 0 {
-            toast_error("Number of adults cannot be negative");
+        };
+
+        // Same rules as the server
+        let meal = CreatePlannedMeal {
+            camp_id: current_camp_id,
+            date,
+            meal_type: meal_type_val,
+            recipe_id: recipe_id_val,
+            attendance: Some(CreateAttendance {
+                children: children_val,
+                teens: teens_val,
+                adults: adults_val,
+            }),
+        };
+        if !validate_form(&meal, set_field_errors) {
             return;
         }
 
@@ -232,7 +264,7 @@ pub fn MealPlanner() -> impl IntoView {
                     attendance.0,
                     attendance.1,
                     attendance.2,
-                ).await.map_err(|e| e.to_string())
+                ).await
             } else {
                 // Create new meal
                 let meal_type_str = meal_type_val.as_str().to_string();
@@ -244,22 +276,23 @@ pub fn MealPlanner() -> impl IntoView {
                     attendance.0,
                     attendance.1,
                     attendance.2,
-                ).await.map(|_| ()).map_err(|e| e.to_string())
+                ).await.map(|_| ())
             };
 
             match result {
                 Ok(_) => {
                     toast_success(if editing_id.is_some() {
-                        "Meal updated successfully!"
+                        t!("meal-updated")
                     } else {
-                        "Meal added successfully!"
+                        t!("meal-added")
                     });
                     reset_form();
                     set_show_form.set(false);
                     load_meals();
                 },
-                Err(error_msg) => {
-                    toast_error(&format!("Failed to save meal: {}", error_msg));
+                Err(e) => {
+                    set_field_errors.set(e.field_errors().to_vec());
+                    toast_error(t!("error-save-meal", error = e.to_string()));
                 },
             }
 
@@ -306,10 +339,10 @@ pub fn MealPlanner() -> impl IntoView {
 
             match delete_planned_meal(id).await {
                 Ok(_) => {
-                    toast_success("Meal deleted successfully!");
+                    toast_success(t!("meal-deleted"));
                     load_meals();
                 },
-                Err(e) => toast_error(&format!("Failed to delete meal: {}", e)),
+                Err(e) => toast_error(&t!("error-delete-meal", error = e.to_string())),
             }
 
             set_loading.set(false);
@@ -320,16 +353,7 @@ pub fn MealPlanner() -> impl IntoView {
         set_show_delete_modal.set(false);
     };
 
-    let format_meal_type = |meal_type_str: &str| -> &'static str {
-        match meal_type_str {
-            "breakfast" => "Breakfast",
-            "morning_snack" => "Morning Snack",
-            "lunch" => "Lunch",
-            "afternoon_snack" => "Afternoon Snack",
-            "dinner" => "Dinner",
-            _ => "Unknown",
-        }
-    };
+    let format_meal_type = move |meal_type_str: &str| meal_type_str_label(meal_type_str, language);
 
     let go_to_previous_day = move |_| {
         let current_date = selected_date.get();
@@ -367,7 +391,7 @@ pub fn MealPlanner() -> impl IntoView {
             if let Ok(date) = NaiveDate::parse_from_str(&current_date, "%Y-%m-%d") {
                 if date >= c.start_date && date <= c.end_date {
                     let day_num = (date - c.start_date).num_days() + 1;
-                    return Some(format!("Day {} of {}", day_num, (c.end_date - c.start_date).num_days() + 1));
+                    return Some(t!("camp-day-of", day = day_num, days = (c.end_date - c.start_date).num_days() + 1));
                 }
             }
         }
@@ -410,46 +434,72 @@ pub fn MealPlanner() -> impl IntoView {
                         }
                     }
                     get_id=|camp: &Camp| camp.id.to_string()
-                    get_display=|camp: &Camp| format!("{} ({} to {})",
+                    get_display=move |camp: &Camp| format!("{} ({} – {})",
                         camp.name,
-                        camp.start_date.format("%Y-%m-%d"),
-                        camp.end_date.format("%Y-%m-%d")
+                        format_date(camp.start_date, language),
+                        format_date(camp.end_date, language)
                     )
-                    placeholder="Search camps..."
-                    label="Select Camp"
+                    placeholder=t!("search-camps")
+                    label=t!("select-camp")
                 />
             </div>
 
             <div class="flex justify-between items-center flex-wrap gap-4">
                 <div>
-                    <h2 class="text-2xl font-bold">"Meal Planner"</h2>
+                    <h2 class="text-2xl font-bold">{t!("meal-planner")}</h2>
                     {move || camp.get().map(|c| view! {
                         <p class="text-slate-600 mt-1">
                             {c.name.clone()} " ("
-                            {c.start_date.format("%Y-%m-%d").to_string()}
-                            " to "
-                            {c.end_date.format("%Y-%m-%d").to_string()}
+                            {format_date(c.start_date, language)}
+                            " – "
+                            {format_date(c.end_date, language)}
                             ")"
                         </p>
                     })}
                 </div>
-                <button
-                    type="button"
-                    class="btn btn-primary"
-                    on:click=move |_| {
-                        reset_form();
-                        set_show_form.set(true);
-                    }
-                    disabled=move || loading.get() || camp_id.get() == 0
-                >
-                    "+ Add Meal"
-                </button>
+                <div class="flex gap-2">
+                    <button
+                        type="button"
+                        class="btn btn-secondary"
+                        on:click=move |_| set_show_check.update(|show| *show = !*show)
+                        disabled=move || camp_id.get() == 0
+                    >
+                        "🩺 " {t!("plan-check-button")}
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-secondary"
+                        on:click=move |_| set_show_generator.update(|show| *show = !*show)
+                        disabled=move || camp_id.get() == 0
+                    >
+                        "🪄 " {t!("generate-button")}
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        on:click=move |_| {
+                            reset_form();
+                            set_show_form.set(true);
+                        }
+                        disabled=move || loading.get() || camp_id.get() == 0
+                    >
+                        "+ " {t!("add-meal")}
+                    </button>
+                </div>
             </div>
 
+            {move || (show_check.get() && camp_id.get() > 0).then(|| view! {
+                <PlanCheckPanel camp_id=camp_id.get()/>
+            })}
+
+            {move || (show_generator.get() && camp_id.get() > 0).then(|| view! {
+                <MenuGenerator camp_id=camp_id.get() on_accept=load_meals/>
+            })}
+
             // View Mode Selector
             <div class="card">
                 <div class="flex items-center gap-2 flex-wrap">
-                    <span class="text-sm font-medium text-slate-700">"View:"</span>
+                    <span class="text-sm font-medium text-slate-700">{t!("view-label")}</span>
                     <div class="flex gap-2">
                         <button
                             type="button"
@@ -461,7 +511,7 @@ pub fn MealPlanner() -> impl IntoView {
                             on:click=move |_| set_view_mode.set(ViewMode::SingleDay)
                             disabled=move || loading.get() || camp_id.get() == 0
                         >
-                            "Single Day"
+                            {t!("view-single-day")}
                         </button>
                         <button
                             type="button"
@@ -473,7 +523,7 @@ pub fn MealPlanner() -> impl IntoView {
                             on:click=move |_| set_view_mode.set(ViewMode::AllDays)
                             disabled=move || loading.get() || camp_id.get() == 0
                         >
-                            "All Days"
+                            {t!("view-all-days")}
                         </button>
                     </div>
                 </div>
@@ -490,7 +540,7 @@ pub fn MealPlanner() -> impl IntoView {
                                 on:click=go_to_previous_day
                                 disabled=move || !can_go_previous() || loading.get()
                             >
-                                "← Previous Day"
+                                "← " {t!("previous-day")}
                             </button>
 
                             <div class="flex flex-col items-center gap-2">
@@ -513,7 +563,7 @@ pub fn MealPlanner() -> impl IntoView {
                                 on:click=go_to_next_day
                                 disabled=move || !can_go_next() || loading.get()
                             >
-                                "Next Day →"
+                                {t!("next-day")} " →"
                             </button>
                         </div>
                     </div>
@@ -528,15 +578,15 @@ pub fn MealPlanner() -> impl IntoView {
                 Some(view! {
                     <div class="card text-center py-16 bg-gradient-to-br from-slate-50 to-blue-50 border-2 border-dashed border-slate-300">
                         <div class="text-7xl mb-6">"📅"</div>
-                        <h3 class="text-2xl font-bold text-slate-800 mb-3">"No Camp Selected"</h3>
+                        <h3 class="text-2xl font-bold text-slate-800 mb-3">{t!("no-camp-selected")}</h3>
                         <p class="text-lg text-slate-600 mb-8">
-                            "Please select a camp to start planning meals"
+                            {t!("select-camp-to-plan")}
                         </p>
                         <button
                             class="btn btn-primary"
                             on:click=move |_| nav("/camps", Default::default())
                         >
-                            "Go to Camps"
+                            {t!("go-to-camps")}
                         </button>
                     </div>
                 })
@@ -555,16 +605,16 @@ pub fn MealPlanner() -> impl IntoView {
                 <div class="card">
                     <h3 class="text-xl font-semibold mb-4">
                         {move || if editing_meal_id.get().is_some() {
-                            "Edit Meal"
+                            t!("edit-meal")
                         } else {
-                            "Plan a Meal"
+                            t!("plan-meal")
                         }}
                     </h3>
 
                     <form on:submit=handle_submit class="space-y-4">
                         <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
                             <div>
-                                <label class="form-label">"Meal Type" <span class="text-red-500">"*"</span></label>
+                                <label class="form-label">{t!("meal-type")} <span class="text-red-500">"*"</span></label>
                                 <select
                                     class="form-input"
                                     prop:value=move || meal_type.get().as_str()
@@ -575,11 +625,9 @@ pub fn MealPlanner() -> impl IntoView {
                                     }
                                     disabled=move || editing_meal_id.get().is_some()
                                 >
-                                    <option value="breakfast">"Breakfast"</option>
-                                    <option value="morning_snack">"Morning Snack"</option>
-                                    <option value="lunch">"Lunch"</option>
-                                    <option value="afternoon_snack">"Afternoon Snack"</option>
-                                    <option value="dinner">"Dinner"</option>
+                                    {MealType::all().into_iter().map(|meal_type| view! {
+                                        <option value=meal_type.as_str()>{t!(meal_type.message_key())}</option>
+                                    }).collect_view()}
                                 </select>
                             </div>
                             <div>
@@ -589,18 +637,19 @@ pub fn MealPlanner() -> impl IntoView {
                                     on_change=move |id| set_recipe_id.set(id)
                                     get_id=|recipe: &Recipe| recipe.id.to_string()
                                     get_display=|recipe: &Recipe| recipe.name.clone()
-                                    placeholder="Search recipes..."
-                                    label="Recipe"
+                                    search_terms=|recipe: Recipe| recipe.tag_list()
+                                    placeholder=t!("search-recipes")
+                                    label=t!("recipe")
                                     required=true
                                 />
                             </div>
                         </div>
                         
                         <div class="border-t pt-4">
-                            <h4 class="font-semibold mb-3">"Attendance (optional)"</h4>
+                            <h4 class="font-semibold mb-3">{t!("attendance-optional")}</h4>
                             <div class="grid grid-cols-3 gap-4">
                                 <div>
-                                    <label class="form-label">"Children"</label>
+                                    <label class="form-label">{t!("children")}</label>
                                     <input
                                         type="number"
                                         class="form-input"
@@ -612,9 +661,10 @@ pub fn MealPlanner() -> impl IntoView {
                                         }
                                         min="0"
                                     />
+                                    <FieldErrors errors=field_errors field="children" />
                                 </div>
                                 <div>
-                                    <label class="form-label">"Teens"</label>
+                                    <label class="form-label">{t!("teens")}</label>
                                     <input
                                         type="number"
                                         class="form-input"
@@ -626,9 +676,10 @@ pub fn MealPlanner() -> impl IntoView {
                                         }
                                         min="0"
                                     />
+                                    <FieldErrors errors=field_errors field="teens" />
                                 </div>
                                 <div>
-                                    <label class="form-label">"Adults"</label>
+                                    <label class="form-label">{t!("adults")}</label>
                                     <input
                                         type="number"
                                         class="form-input"
@@ -640,6 +691,7 @@ pub fn MealPlanner() -> impl IntoView {
                                         }
                                         min="0"
                                     />
+                                    <FieldErrors errors=field_errors field="adults" />
                                 </div>
                             </div>
                         </div>
@@ -651,15 +703,15 @@ pub fn MealPlanner() -> impl IntoView {
                                 disabled=move || loading.get()
                             >
                                 {move || if loading.get() {
-                                    "Saving..."
+                                    t!("saving")
                                 } else if editing_meal_id.get().is_some() {
-                                    "Update Meal"
+                                    t!("update-meal")
                                 } else {
-                                    "Add Meal"
+                                    t!("add-meal")
                                 }}
                             </button>
                             <button type="button" class="btn btn-secondary" on:click=cancel_form disabled=move || loading.get()>
-                                "Cancel"
+                                {t!("cancel")}
                             </button>
                         </div>
                     </form>
@@ -668,13 +720,13 @@ pub fn MealPlanner() -> impl IntoView {
             })}
 
             {move || if loading.get() && !show_form.get() {
-                view! { <div class="text-center py-8">"Loading..."</div> }.into_any()
+                view! { <div class="text-center py-8">{t!("loading")}</div> }.into_any()
             } else if view_mode.get() == ViewMode::SingleDay {
                 // Single day view
                 if planned_meals.get().is_empty() {
                     view! {
                         <div class="card text-center text-slate-600">
-                            "No meals planned for this date. Click 'Add Meal' to plan one."
+                            {t!("no-meals-for-date")}
                         </div>
                     }.into_any()
                 } else {
@@ -696,10 +748,7 @@ pub fn MealPlanner() -> impl IntoView {
                                             <h3 class="text-lg font-semibold mt-1">{meal.recipe_name.clone()}</h3>
                                             {meal.attendance.clone().map(|att| view! {
                                                 <p class="text-sm text-slate-500 mt-2">
-                                                    "Attendance: "
-                                                    {att.children} " children, "
-                                                    {att.teens} " teens, "
-                                                    {att.adults} " adults"
+                                                    {t!("attendance-summary", children = att.children, teens = att.teens, adults = att.adults)}
                                                 </p>
                                             })}
                                         </div>
@@ -712,7 +761,7 @@ pub fn MealPlanner() -> impl IntoView {
                                                 }
                                                 disabled=move || loading.get()
                                             >
-                                                "✏️ Edit"
+                                                "✏️ " {t!("edit")}
                                             </button>
                                             <button
                                                 class="btn btn-danger text-sm whitespace-nowrap"
@@ -722,7 +771,7 @@ pub fn MealPlanner() -> impl IntoView {
                                                 }
                                                 disabled=move || loading.get()
                                             >
-                                                "🗑️ Delete"
+                                                "🗑️ " {t!("delete")}
                                             </button>
                                         </div>
                                     </div>
@@ -737,7 +786,7 @@ pub fn MealPlanner() -> impl IntoView {
                 if meals_map.is_empty() {
                     view! {
                         <div class="card text-center text-slate-600">
-                            "No meals planned yet. Select 'Single Day' view to add meals."
+                            {t!("no-meals-planned-yet")}
                         </div>
                     }.into_any()
                 } else {
@@ -755,7 +804,9 @@ pub fn MealPlanner() -> impl IntoView {
                             >
                                 {
                                     let meals_for_date = multi_day_meals.get().get(&date).cloned().unwrap_or_default();
-                                    let date_str = date.clone();
+                                    let date_str = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                                        .map(|parsed_date| format_day(parsed_date, language))
+                                        .unwrap_or_else(|_| date.clone());
                                     let camp_data = camp.get();
 
                                     // Calculate day number
@@ -763,7 +814,7 @@ pub fn MealPlanner() -> impl IntoView {
                                         if let Ok(parsed_date) = NaiveDate::parse_from_str(&date, "%Y-%m-%d") {
                                             if parsed_date >= c.start_date && parsed_date <= c.end_date {
                                                 let day_num = (parsed_date - c.start_date).num_days() + 1;
-                                                Some(format!("Day {}", day_num))
+                                                Some(t!("camp-day", day = day_num))
                                             } else {
                                                 None
                                             }
@@ -786,7 +837,7 @@ pub fn MealPlanner() -> impl IntoView {
                                             </div>
                                             {if meals_for_date.is_empty() {
                                                 view! {
-                                                    <p class="text-slate-500 italic">"No meals planned for this day"</p>
+                                                    <p class="text-slate-500 italic">{t!("no-meals-for-day")}</p>
                                                 }.into_any()
                                             } else {
                                                 view! {
@@ -807,10 +858,7 @@ pub fn MealPlanner() -> impl IntoView {
                                                                         <h4 class="font-semibold mt-1">{meal.recipe_name.clone()}</h4>
                                                                         {meal.attendance.clone().map(|att| view! {
                                                                             <p class="text-xs text-slate-500 mt-1">
-                                                                                "Attendance: "
-                                                                                {att.children} " children, "
-                                                                                {att.teens} " teens, "
-                                                                                {att.adults} " adults"
+                                                                                {t!("attendance-summary", children = att.children, teens = att.teens, adults = att.adults)}
                                                                             </p>
                                                                         })}
                                                                     </div>
@@ -827,7 +875,7 @@ pub fn MealPlanner() -> impl IntoView {
                                                                             }
                                                                             disabled=move || loading.get()
                                                                         >
-                                                                            "✏️ Edit"
+                                                                            "✏️ " {t!("edit")}
                                                                         </button>
                                                                         <button
                                                                             class="btn btn-danger text-xs px-3 py-1 whitespace-nowrap"
@@ -837,7 +885,7 @@ pub fn MealPlanner() -> impl IntoView {
                                                                             }
                                                                             disabled=move || loading.get()
                                                                         >
-                                                                            "🗑️ Delete"
+                                                                            "🗑️ " {t!("delete")}
                                                                         </button>
                                                                     </div>
                                                                 </div>
@@ -860,10 +908,10 @@ pub fn MealPlanner() -> impl IntoView {
                 show=show_delete_modal.into()
                 on_confirm=confirm_delete
                 on_cancel=cancel_delete
-                title="Delete Meal".to_string()
-                message="Are you sure you want to delete this meal? This action cannot be undone.".to_string()
-                confirm_text="Delete".to_string()
-                cancel_text="Cancel".to_string()
+                title=t!("delete-meal")
+                message=t!("delete-meal-confirm")
+                confirm_text=t!("delete")
+                cancel_text=t!("cancel")
                 variant="danger".to_string()
             />
         </div>
@@ -0,0 +1,348 @@
+use crate::models::{split_list, GeneratedMenu, MealType, MenuGeneratorSettings, MenuSlotRule, ALLERGENS};
+use crate::server_functions::meal_plans::{generate_menu, accept_generated_day};
+use crate::components::{toast_success, toast_error, toast_info};
+use crate::t;
+use crate::format::{format_date, format_day, format_number};
+use crate::i18n::use_language;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+/// Fills the empty meal slots of a camp with a preview that is accepted or
+/// rejected day by day
+#[component]
+pub fn MenuGenerator<F>(
+    camp_id: i64,
+    /// Called after meals were added to the plan
+    on_accept: F,
+) -> impl IntoView
+where
+    F: Fn() + 'static + Copy + Send + Sync,
+{
+    let language = use_language();
+    let settings = RwSignal::new(MenuGeneratorSettings::default());
+    let preview = RwSignal::new(None::<GeneratedMenu>);
+    let (loading, set_loading) = signal(false);
+
+    let slot_of = move |meal_type: MealType| {
+        settings.get().slots.into_iter().find(|slot| slot.meal_type == meal_type)
+    };
+
+    let update_slot = move |meal_type: MealType, change: &dyn Fn(&mut MenuSlotRule)| {
+        settings.update(|s| {
+            if let Some(slot) = s.slots.iter_mut().find(|slot| slot.meal_type == meal_type) {
+                change(slot);
+            }
+        });
+    };
+
+    let toggle_slot = move |meal_type: MealType| {
+        settings.update(|s| {
+            if s.slots.iter().any(|slot| slot.meal_type == meal_type) {
+                s.slots.retain(|slot| slot.meal_type != meal_type);
+            } else if let Some(slot) = MenuGeneratorSettings::default().slots.into_iter().find(|slot| slot.meal_type == meal_type) {
+                s.slots.push(slot);
+            }
+        });
+    };
+
+    let run = move |seed: u64| {
+        settings.update(|s| s.seed = seed);
+        let current = settings.get_untracked();
+        spawn_local(async move {
+            set_loading.set(true);
+            match generate_menu(camp_id, current).await {
+                Ok(menu) => {
+                    if menu.days.is_empty() {
+                        toast_info(t!("menu-all-planned"));
+                    }
+                    preview.set(Some(menu));
+                },
+                Err(e) => toast_error(t!("error-generate-menu", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let remove_day = move |date: chrono::NaiveDate| {
+        preview.update(|menu| {
+            if let Some(menu) = menu {
+                menu.days.retain(|day| day.date != date);
+            }
+        });
+    };
+
+    let accept_day = move |date: chrono::NaiveDate| {
+        let Some(day) = preview.get_untracked().and_then(|menu| menu.days.into_iter().find(|day| day.date == date)) else {
+            return;
+        };
+        spawn_local(async move {
+            set_loading.set(true);
+            match accept_generated_day(camp_id, day).await {
+                Ok(added) => {
+                    toast_success(t!("meals-added-on", count = added, date = format_date(date, language)));
+                    remove_day(date);
+                    on_accept();
+                },
+                Err(e) => toast_error(t!("error-save-meals", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let accept_all = move |_| {
+        let days: Vec<_> = preview.get_untracked()
+            .map(|menu| menu.days)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|day| !day.meals.is_empty())
+            .collect();
+        spawn_local(async move {
+            set_loading.set(true);
+            let mut added = 0;
+            for day in days {
+                let date = day.date;
+                match accept_generated_day(camp_id, day).await {
+                    Ok(count) => {
+                        added += count;
+                        remove_day(date);
+                    },
+                    Err(e) => {
+                        toast_error(t!("error-save-meals-on", date = format_date(date, language), error = e.to_string()));
+                        break;
+                    },
+                }
+            }
+            if added > 0 {
+                toast_success(t!("meals-added", count = added));
+                on_accept();
+            }
+            set_loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="card border-2 border-indigo-200 space-y-4">
+            <h3 class="text-xl font-semibold flex items-center gap-2">
+                <span>"🪄"</span>
+                {t!("generate-menu")}
+            </h3>
+            <p class="text-sm text-slate-600">
+                {t!("generate-menu-help")}
+            </p>
+
+            <div class="space-y-2">
+                {MealType::all().into_iter().map(|meal_type| view! {
+                    <div class="grid gap-3 items-center" style="grid-template-columns: 10rem minmax(0,1fr) 6rem">
+                        <label class="flex items-center gap-2 text-sm font-medium">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || slot_of(meal_type).is_some()
+                                on:change=move |_| toggle_slot(meal_type)
+                            />
+                            {t!(meal_type.message_key())}
+                        </label>
+                        <input
+                            type="text"
+                            class="form-input text-sm"
+                            placeholder=t!("any-recipe")
+                            prop:value=move || slot_of(meal_type).map(|slot| slot.tags.join(", ")).unwrap_or_default()
+                            on:change=move |ev| {
+                                let tags = split_list(&event_target_value(&ev));
+                                update_slot(meal_type, &|slot| slot.tags = tags.clone());
+                            }
+                            disabled=move || slot_of(meal_type).is_none()
+                        />
+                        <label class="flex items-center gap-2 text-sm">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || slot_of(meal_type).is_some_and(|slot| slot.hot)
+                                on:change=move |ev| {
+                                    let hot = event_target_checked(&ev);
+                                    update_slot(meal_type, &|slot| slot.hot = hot);
+                                }
+                                disabled=move || slot_of(meal_type).is_none()
+                            />
+                            {t!("hot")}
+                        </label>
+                    </div>
+                }).collect_view()}
+            </div>
+
+            <div class="grid grid-cols-2 md:grid-cols-4 gap-4">
+                <div>
+                    <label class="form-label">{t!("no-repeat-days")}</label>
+                    <input
+                        type="number"
+                        min="0"
+                        class="form-input"
+                        prop:value=move || settings.get().no_repeat_days.to_string()
+                        on:change=move |ev| {
+                            let days = event_target_value(&ev).trim().parse::<u32>().unwrap_or(0);
+                            settings.update(|s| s.no_repeat_days = days);
+                        }
+                    />
+                </div>
+                <div>
+                    <label class="form-label">{t!("max-cost-per-day")}</label>
+                    <input
+                        type="number"
+                        min="0"
+                        step="any"
+                        class="form-input"
+                        prop:value=move || settings.get().max_cost_per_day.map(|cost| cost.to_string()).unwrap_or_default()
+                        on:change=move |ev| {
+                            let cost = event_target_value(&ev).trim().parse::<f64>().ok().filter(|cost| *cost >= 0.0);
+                            settings.update(|s| s.max_cost_per_day = cost);
+                        }
+                    />
+                </div>
+                <div>
+                    <label class="form-label">{t!("max-cook-intensive")}</label>
+                    <input
+                        type="number"
+                        min="0"
+                        class="form-input"
+                        placeholder=t!("no-limit")
+                        prop:value=move || settings.get().max_cook_intensive_per_day.map(|max| max.to_string()).unwrap_or_default()
+                        on:change=move |ev| {
+                            let max = event_target_value(&ev).trim().parse::<u32>().ok();
+                            settings.update(|s| s.max_cook_intensive_per_day = max);
+                        }
+                    />
+                </div>
+                <div>
+                    <label class="form-label">{t!("seed")}</label>
+                    <input
+                        type="number"
+                        min="0"
+                        class="form-input"
+                        prop:value=move || settings.get().seed.to_string()
+                        on:change=move |ev| {
+                            let seed = event_target_value(&ev).trim().parse::<u64>().unwrap_or(0);
+                            settings.update(|s| s.seed = seed);
+                        }
+                    />
+                </div>
+            </div>
+
+            <div>
+                <span class="form-label">{t!("exclude-allergens")}</span>
+                <div class="flex gap-2 flex-wrap">
+                    {ALLERGENS.iter().map(|allergen| {
+                        let name = allergen.to_string();
+                        let selected = {
+                            let name = name.clone();
+                            move || settings.get().excluded_allergens.contains(&name)
+                        };
+                        view! {
+                            <button
+                                type="button"
+                                class=move || if selected() { "btn btn-primary text-xs" } else { "btn btn-secondary text-xs" }
+                                on:click={
+                                    let name = name.clone();
+                                    move |_| settings.update(|s| {
+                                        if s.excluded_allergens.contains(&name) {
+                                            s.excluded_allergens.retain(|other| *other != name);
+                                        } else {
+                                            s.excluded_allergens.push(name.clone());
+                                        }
+                                    })
+                                }
+                            >
+                                {name.clone()}
+                            </button>
+                        }
+                    }).collect_view()}
+                </div>
+            </div>
+
+            <div class="flex gap-2">
+                <button
+                    type="button"
+                    class="btn btn-primary"
+                    on:click=move |_| run(settings.get_untracked().seed)
+                    disabled=move || loading.get()
+                >
+                    {t!("preview")}
+                </button>
+                <button
+                    type="button"
+                    class="btn btn-secondary"
+                    title=t!("next-seed")
+                    on:click=move |_| run(settings.get_untracked().seed.wrapping_add(1))
+                    disabled=move || loading.get()
+                >
+                    "🎲 " {t!("another-menu")}
+                </button>
+                {move || preview.get().is_some_and(|menu| menu.days.iter().any(|day| !day.meals.is_empty())).then(|| view! {
+                    <button
+                        type="button"
+                        class="btn btn-secondary"
+                        on:click=accept_all
+                        disabled=move || loading.get()
+                    >
+                        "✓ " {t!("accept-all")}
+                    </button>
+                })}
+            </div>
+
+            {move || preview.get().map(|menu| view! {
+                <div class="space-y-3">
+                    {menu.days.into_iter().map(|day| {
+                        let date = day.date;
+                        let has_meals = !day.meals.is_empty();
+                        view! {
+                            <div class="bg-slate-50 rounded-lg p-4 space-y-2">
+                                <div class="flex justify-between items-center gap-3">
+                                    <div>
+                                        <h4 class="font-bold text-indigo-700">{format_day(date, language)}</h4>
+                                        {day.cost_per_serving.map(|cost| view! {
+                                            <span class="text-xs text-slate-500">{t!("cost-per-person", cost = format_number(cost, 2, language))}</span>
+                                        })}
+                                    </div>
+                                    <div class="flex gap-2">
+                                        {has_meals.then(|| view! {
+                                            <button
+                                                type="button"
+                                                class="btn btn-primary text-xs px-3 py-1"
+                                                on:click=move |_| accept_day(date)
+                                                disabled=move || loading.get()
+                                            >
+                                                "✓ " {t!("accept")}
+                                            </button>
+                                        })}
+                                        <button
+                                            type="button"
+                                            class="btn btn-secondary text-xs px-3 py-1"
+                                            on:click=move |_| remove_day(date)
+                                            disabled=move || loading.get()
+                                        >
+                                            "✕ " {t!("reject")}
+                                        </button>
+                                    </div>
+                                </div>
+                                {day.meals.into_iter().map(|meal| view! {
+                                    <div class="flex justify-between text-sm">
+                                        <span>
+                                            <span class="font-semibold text-blue-600 mr-2">{t!(meal.meal_type.message_key())}</span>
+                                            {meal.recipe_name}
+                                        </span>
+                                        <span class="text-slate-500">
+                                            {meal.cost_per_serving.map(|cost| format_number(cost, 2, language))}
+                                        </span>
+                                    </div>
+                                }).collect_view()}
+                                {day.unfilled.into_iter().map(|slot| view! {
+                                    <div class="text-sm text-amber-700">
+                                        <span class="font-semibold mr-2">{t!(slot.meal_type.message_key())}</span>
+                                        {slot.reason.translate(language)}
+                                    </div>
+                                }).collect_view()}
+                            </div>
+                        }
+                    }).collect_view()}
+                </div>
+            })}
+        </div>
+    }
+}
@@ -7,6 +7,19 @@ pub mod camp_manager;
 pub mod meal_planner;
 pub mod report_generator;
 pub mod searchable_select;
+pub mod inventory_manager;
+pub mod supplier_manager;
+pub mod data_transfer;
+pub mod recipe_import;
+pub mod recipe_exchange;
+pub mod ingredient_merge;
+pub mod recipe_search;
+pub mod stock_finder;
+pub mod menu_generator;
+pub mod plan_check;
+pub mod api_token_manager;
+pub mod usage_modal;
+pub mod trash_view;
 
 pub use ui::*;
 pub use nav::*;
@@ -17,3 +30,16 @@ pub use camp_manager::*;
 pub use meal_planner::*;
 pub use report_generator::*;
 pub use searchable_select::*;
+pub use inventory_manager::*;
+pub use supplier_manager::*;
+pub use data_transfer::*;
+pub use recipe_import::*;
+pub use recipe_exchange::*;
+pub use ingredient_merge::*;
+pub use recipe_search::*;
+pub use stock_finder::*;
+pub use menu_generator::*;
+pub use plan_check::*;
+pub use api_token_manager::*;
+pub use usage_modal::*;
+pub use trash_view::*;
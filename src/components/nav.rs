@@ -1,13 +1,16 @@
 use leptos::prelude::*;
 use leptos_router::hooks::{use_location, use_navigate};
 
+use crate::i18n::{switch_language, use_language, Language};
 use crate::server_functions::auth::logout;
+use crate::t;
 
 #[component]
 pub fn NavBar() -> impl IntoView {
     let location = use_location();
     let pathname = move || location.pathname.get();
     let navigate = use_navigate();
+    let language = use_language();
 
     let logout_action = Action::new(|_: &()| async { logout().await });
 
@@ -30,7 +33,7 @@ pub fn NavBar() -> impl IntoView {
                     <div class="flex flex-col py-4">
                         <a href="/" class="text-2xl font-bold hover:underline transition-all duration-200 flex items-center gap-3 no-underline text-white">
                             <span class="text-3xl">"🏕️"</span>
-                            <span>"Boy Scout Meal Planner"</span>
+                            <span>{t!("app-title")}</span>
                         </a>
                         <span class="text-xs text-blue-100 ml-12">"Build: 2026-01-19 01:15:00 UTC - v1.0.0-WORKING!"</span>
                     </div>
@@ -45,7 +48,7 @@ pub fn NavBar() -> impl IntoView {
                             aria-current=move || if is_active("/camps") { Some("page") } else { None }
                         >
                             <span class="mr-2">"🏕️"</span>
-                            "Camps"
+                            {t!("nav-camps")}
                         </a>
                         <a
                             href="/planner"
@@ -57,7 +60,7 @@ pub fn NavBar() -> impl IntoView {
                             aria-current=move || if is_active("/planner") { Some("page") } else { None }
                         >
                             <span class="mr-2">"📅"</span>
-                            "Planner"
+                            {t!("nav-planner")}
                         </a>
                         <a
                             href="/recipes"
@@ -69,7 +72,7 @@ pub fn NavBar() -> impl IntoView {
                             aria-current=move || if is_active("/recipes") { Some("page") } else { None }
                         >
                             <span class="mr-2">"🍳"</span>
-                            "Recipes"
+                            {t!("nav-recipes")}
                         </a>
                         <a
                             href="/ingredients"
@@ -81,7 +84,19 @@ pub fn NavBar() -> impl IntoView {
                             aria-current=move || if is_active("/ingredients") { Some("page") } else { None }
                         >
                             <span class="mr-2">"🥕"</span>
-                            "Ingredients"
+                            {t!("nav-ingredients")}
+                        </a>
+                        <a
+                            href="/inventory"
+                            class=move || if is_active("/inventory") {
+                                "px-5 py-3 rounded-xl bg-white/20 backdrop-blur-sm transition-all duration-300 font-semibold text-white no-underline"
+                            } else {
+                                "px-5 py-3 rounded-xl hover:bg-white/10 backdrop-blur-sm transition-all duration-300 font-semibold text-white no-underline"
+                            }
+                            aria-current=move || if is_active("/inventory") { Some("page") } else { None }
+                        >
+                            <span class="mr-2">"📦"</span>
+                            {t!("nav-pantry")}
                         </a>
                         <a
                             href="/reports"
@@ -93,14 +108,41 @@ pub fn NavBar() -> impl IntoView {
                             aria-current=move || if is_active("/reports") { Some("page") } else { None }
                         >
                             <span class="mr-2">"📊"</span>
-                            "Reports"
+                            {t!("nav-reports")}
+                        </a>
+                        <a
+                            href="/admin"
+                            class=move || if is_active("/admin") {
+                                "px-5 py-3 rounded-xl bg-white/20 backdrop-blur-sm transition-all duration-300 font-semibold text-white no-underline"
+                            } else {
+                                "px-5 py-3 rounded-xl hover:bg-white/10 backdrop-blur-sm transition-all duration-300 font-semibold text-white no-underline"
+                            }
+                            aria-current=move || if is_active("/admin") { Some("page") } else { None }
+                        >
+                            <span class="mr-2">"⚙️"</span>
+                            {t!("nav-admin")}
                         </a>
+                        <select
+                            class="px-3 py-3 rounded-xl bg-white/10 hover:bg-white/20 transition-all duration-300 font-semibold text-white"
+                            aria-label=t!("language")
+                            on:change=move |ev| {
+                                if let Some(selected) = Language::from_code(&event_target_value(&ev)) {
+                                    switch_language(selected);
+                                }
+                            }
+                        >
+                            {Language::all().into_iter().map(|option| view! {
+                                <option value=option.code() selected=option == language class="text-slate-800">
+                                    {option.native_name()}
+                                </option>
+                            }).collect_view()}
+                        </select>
                         <button
                             class="px-5 py-3 rounded-xl hover:bg-white/10 backdrop-blur-sm transition-all duration-300 font-semibold text-white"
                             on:click=move |_| { logout_action.dispatch(()); }
                         >
                             <span class="mr-2">"🔒"</span>
-                            "Logout"
+                            {t!("logout")}
                         </button>
                     </div>
                 </div>
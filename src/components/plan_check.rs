@@ -0,0 +1,101 @@
+use crate::models::{PlanCheck, PlanWarningKind};
+use crate::server_functions::meal_plans::check_camp_plan;
+use crate::t;
+use crate::format::format_day;
+use crate::i18n::use_language;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+/// Variety and completeness warnings of a camp plan, each linking to its day
+#[component]
+pub fn PlanCheckPanel(camp_id: i64) -> impl IntoView {
+    let language = use_language();
+    let (check, set_check) = signal(None::<PlanCheck>);
+    let (error, set_error) = signal(None::<String>);
+    let (loading, set_loading) = signal(false);
+
+    let load = move || {
+        spawn_local(async move {
+            set_loading.set(true);
+            set_error.set(None);
+            match check_camp_plan(camp_id).await {
+                Ok(data) => set_check.set(Some(data)),
+                Err(e) => set_error.set(Some(t!("error-check-plans", error = e.to_string()))),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    Effect::new(move |_| load());
+
+    let icon = |kind: PlanWarningKind| match kind {
+        PlanWarningKind::RepeatedRecipe => "🔁",
+        PlanWarningKind::NoProduce => "🥦",
+        PlanWarningKind::MissingAttendance => "👥",
+        PlanWarningKind::EmptySlots => "🕳️",
+        PlanWarningKind::EmptyRecipe => "📭",
+    };
+
+    view! {
+        <div class="card border-2 border-amber-200 space-y-3">
+            <div class="flex justify-between items-center">
+                <h3 class="text-xl font-semibold flex items-center gap-2">
+                    <span>"🩺"</span>
+                    {t!("plan-check")}
+                </h3>
+                <button
+                    type="button"
+                    class="btn btn-secondary text-sm"
+                    on:click=move |_| load()
+                    disabled=move || loading.get()
+                >
+                    "↻ " {t!("check-again")}
+                </button>
+            </div>
+
+            {move || error.get().map(|err| view! {
+                <div class="alert-error">
+                    <span class="font-semibold mr-2">"⚠️ " {t!("error")} ":"</span>
+                    {err}
+                </div>
+            })}
+
+            {move || check.get().map(|check| if check.warnings.is_empty() {
+                view! {
+                    <p class="text-emerald-700 font-medium">"✅ " {t!("no-problems")}</p>
+                }.into_any()
+            } else {
+                view! {
+                    <div class="space-y-3">
+                        <div class="flex gap-2 flex-wrap">
+                            {check.summary().into_iter().map(|(kind, count)| view! {
+                                <span class="badge badge-secondary">{format!("{} {}: {}", icon(kind), t!(kind.message_key()), count)}</span>
+                            }).collect_view()}
+                        </div>
+                        <div class="divide-y divide-slate-100">
+                            {check.warnings.into_iter().map(|warning| {
+                                let date = warning.date.format("%Y-%m-%d").to_string();
+                                let day = format_day(warning.date, language);
+                                view! {
+                                    <div class="flex items-center gap-3 py-1.5 text-sm">
+                                        <span>{icon(warning.kind)}</span>
+                                        <a
+                                            href=format!("/planner/{}?date={}", camp_id, date)
+                                            class="font-semibold text-blue-600 hover:underline whitespace-nowrap"
+                                        >
+                                            {day}
+                                        </a>
+                                        {warning.meal_type.map(|meal_type| view! {
+                                            <span class="text-slate-500 whitespace-nowrap">{t!(meal_type.message_key())}</span>
+                                        })}
+                                        <span class="text-slate-700">{warning.message.translate(language)}</span>
+                                    </div>
+                                }
+                            }).collect_view()}
+                        </div>
+                    </div>
+                }.into_any()
+            })}
+        </div>
+    }
+}
@@ -1,7 +1,14 @@
-use crate::models::{CreateRecipeIngredient, Ingredient, Recipe};
+use crate::models::{CreateRecipe, CreateRecipeIngredient, Ingredient, Recipe, RecipeSearchPage, RecipeSearchQuery};
 use crate::server_functions::ingredients::get_ingredients;
-use crate::server_functions::recipes::{create_recipe, delete_recipe, get_recipes, get_recipe_with_ingredients, update_recipe};
-use crate::components::{SearchableSelect, ConfirmModal, toast_success, toast_error};
+use crate::server_functions::recipes::{
+    archive_recipe, create_recipe, delete_recipe, get_recipe_usage, get_recipes, get_recipe_with_ingredients, replace_recipe,
+    search_recipes, update_recipe,
+};
+use crate::components::{SearchableSelect, ConfirmModal, RecipeImportPanel, RecipeSearchFilters, FieldErrors, UsageModal, toast_success, toast_error, validate_form};
+use crate::format::{format_day, format_number, meal_type_str_label};
+use crate::i18n::use_language;
+use crate::t;
+use crate::error::FieldError;
 use leptos::ev::SubmitEvent;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
@@ -19,25 +26,34 @@ struct RecipeIngredientForm {
 
 #[component]
 pub fn RecipeEditor() -> impl IntoView {
+    let language = use_language();
     let (recipes, set_recipes) = signal(Vec::<Recipe>::new());
     let (ingredients, set_ingredients) = signal(Vec::<Ingredient>::new());
     let (show_form, set_show_form) = signal(false);
     let (editing_recipe_id, set_editing_recipe_id) = signal(None::<i64>);
     let (error, set_error) = signal(None::<String>);
+    // Field errors of the last save
+    let (field_errors, set_field_errors) = signal(Vec::<FieldError>::new());
     let (loading, set_loading) = signal(false);
 
     // Modal state
     let (show_delete_modal, set_show_delete_modal) = signal(false);
     let (delete_id, set_delete_id) = signal(0i64);
+    // Planned meals still serving the recipe picked for deleting
+    let (show_usage_modal, set_show_usage_modal) = signal(false);
+    let (usages, set_usages) = signal(Vec::<String>::new());
 
     // Form fields
     let (name, set_name) = signal(String::new());
     let (instructions, set_instructions) = signal(String::new());
     let (base_servings, set_base_servings) = signal(4);
+    let (tags, set_tags) = signal(String::new());
     let (recipe_ingredients, set_recipe_ingredients) = signal(Vec::<RecipeIngredientForm>::new());
 
     // Search
-    let (search_query, set_search_query) = signal(String::new());
+    let search_query = RwSignal::new(RecipeSearchQuery::default());
+    let (search_results, set_search_results) = signal(None::<RecipeSearchPage>);
+    let (show_import, set_show_import) = signal(false);
 
     // Load data on mount
     let load_data = move || {
@@ -45,14 +61,14 @@ pub fn RecipeEditor() -> impl IntoView {
             set_loading.set(true);
             set_error.set(None);
 
-            match get_recipes().await {
+            match get_recipes(false).await {
                 Ok(data) => set_recipes.set(data),
-                Err(e) => set_error.set(Some(format!("Failed to load recipes: {}", e))),
+                Err(e) => set_error.set(Some(t!("error-load-recipes", error = e.to_string()))),
             }
 
-            match get_ingredients().await {
+            match get_ingredients(false).await {
                 Ok(data) => set_ingredients.set(data),
-                Err(e) => set_error.set(Some(format!("Failed to load ingredients: {}", e))),
+                Err(e) => set_error.set(Some(t!("error-load-ingredients", error = e.to_string()))),
             }
 
             set_loading.set(false);
@@ -63,19 +79,36 @@ pub fn RecipeEditor() -> impl IntoView {
         load_data();
     });
 
+    // Search again whenever the filters change or the recipes were reloaded
+    Effect::new(move |_| {
+        let query = search_query.get();
+        recipes.track();
+        spawn_local(async move {
+            match search_recipes(query.clone()).await {
+                // Drop answers to searches that were overtaken by newer input
+                Ok(page) if search_query.get_untracked() == query => set_search_results.set(Some(page)),
+                Ok(_) => {}
+                Err(e) => set_error.set(Some(t!("error-search-recipes", error = e.to_string()))),
+            }
+        });
+    });
+
     let reset_form = move || {
         set_name.set(String::new());
         set_instructions.set(String::new());
         set_base_servings.set(4);
+        set_tags.set(String::new());
         set_recipe_ingredients.set(Vec::new());
         set_editing_recipe_id.set(None);
         set_error.set(None);
+        set_field_errors.set(Vec::new());
     };
 
     let cancel_form = move |_| {
         set_show_form.set(false);
         set_editing_recipe_id.set(None);
         set_error.set(None);
+        set_field_errors.set(Vec::new());
     };
 
     let add_ingredient = move |_| {
@@ -108,58 +141,22 @@ pub fn RecipeEditor() -> impl IntoView {
         let name_val = name.get();
         let instructions_val = instructions.get();
         let base_servings_val = base_servings.get();
+        let tags_val = tags.get();
         let recipe_ingredients_val = recipe_ingredients.get();
         let editing_id = editing_recipe_id.get();
 
-        if name_val.is_empty() {
-            toast_error("Please fill in recipe name");
-            return;
-        }
-
-        if recipe_ingredients_val.is_empty() {
-            toast_error("Please add at least one ingredient");
-            return;
-        }
-
-        // Validate base servings is positive
-        if base_servings_val <= 0 {
-            toast_error("Base servings must be greater than 0");
-            return;
-        }
-
-        // Validate ingredient quantities are positive
-        for ingredient in &recipe_ingredients_val {
-            if ingredient.base_quantity <= 0.0 {
-                toast_error("All ingredient quantities must be greater than 0");
-                return;
-            }
-
-            // Validate multipliers if they exist
-            if let Some(mult) = ingredient.child_multiplier {
-                if mult < 0.0 {
-                    toast_error("Multipliers cannot be negative");
-                    return;
-                }
-            }
-            if let Some(mult) = ingredient.teen_multiplier {
-                if mult < 0.0 {
-                    toast_error("Multipliers cannot be negative");
-                    return;
-                }
-            }
-            if let Some(mult) = ingredient.adult_multiplier {
-                if mult < 0.0 {
-                    toast_error("Multipliers cannot be negative");
-                    return;
-                }
-            }
-        }
-
-        spawn_local(async move {
-            set_loading.set(true);
-            set_error.set(None);
-
-            let ingredients_to_create = recipe_ingredients_val
+        let instructions_opt = if instructions_val.is_empty() {
+            None
+        } else {
+            Some(instructions_val)
+        };
+
+        let recipe = CreateRecipe {
+            name: name_val,
+            instructions: instructions_opt,
+            base_servings: base_servings_val,
+            tags: tags_val,
+            ingredients: recipe_ingredients_val
                 .into_iter()
                 .map(|ri| CreateRecipeIngredient {
                     ingredient_id: ri.ingredient_id,
@@ -170,47 +167,58 @@ pub fn RecipeEditor() -> impl IntoView {
                     adult_multiplier: ri.adult_multiplier,
                     notes: ri.notes,
                 })
-                .collect();
+                .collect(),
+        };
 
-            let instructions_opt = if instructions_val.is_empty() {
-                None
-            } else {
-                Some(instructions_val)
-            };
+        // Same rules as the server
+        if !validate_form(&recipe, set_field_errors) {
+            return;
+        }
+
+        spawn_local(async move {
+            set_loading.set(true);
+            set_error.set(None);
 
             let result = if let Some(id) = editing_id {
                 update_recipe(
                     id,
-                    name_val,
-                    instructions_opt,
-                    base_servings_val,
-                    ingredients_to_create,
+                    recipe.name,
+                    recipe.instructions,
+                    recipe.base_servings,
+                    recipe.tags,
+                    recipe.ingredients,
                 )
                 .await
-                .map_err(|e| format!("Failed to update recipe: {}", e))
             } else {
                 create_recipe(
-                    name_val,
-                    instructions_opt,
-                    base_servings_val,
-                    ingredients_to_create,
+                    recipe.name,
+                    recipe.instructions,
+                    recipe.base_servings,
+                    recipe.tags,
+                    recipe.ingredients,
                 )
                 .await
-                .map_err(|e| format!("Failed to create recipe: {}", e))
             };
 
             match result {
                 Ok(_) => {
                     if editing_id.is_some() {
-                        toast_success("Recipe updated successfully!");
+                        toast_success(t!("recipe-updated"));
                     } else {
-                        toast_success("Recipe created successfully!");
+                        toast_success(t!("recipe-created"));
                     }
                     reset_form();
                     set_show_form.set(false);
                     load_data();
                 }
-                Err(e) => toast_error(&e),
+                Err(e) => {
+                    set_field_errors.set(e.field_errors().to_vec());
+                    if editing_id.is_some() {
+                        toast_error(t!("error-update-recipe", error = e.to_string()));
+                    } else {
+                        toast_error(t!("error-create-recipe", error = e.to_string()));
+                    }
+                }
             }
 
             set_loading.set(false);
@@ -227,6 +235,7 @@ pub fn RecipeEditor() -> impl IntoView {
                     set_name.set(recipe_data.recipe.name.clone());
                     set_instructions.set(recipe_data.recipe.instructions.clone().unwrap_or_default());
                     set_base_servings.set(recipe_data.recipe.base_servings);
+                    set_tags.set(recipe_data.recipe.tags.clone());
                     
                     let form_ingredients = recipe_data
                         .ingredients
@@ -246,7 +255,7 @@ pub fn RecipeEditor() -> impl IntoView {
                     set_editing_recipe_id.set(Some(id));
                     set_show_form.set(true);
                 }
-                Err(e) => set_error.set(Some(format!("Failed to load recipe: {}", e))),
+                Err(e) => set_error.set(Some(t!("error-load-recipe", error = e.to_string()))),
             }
 
             set_loading.set(false);
@@ -255,7 +264,24 @@ pub fn RecipeEditor() -> impl IntoView {
 
     let handle_delete_click = move |id: i64| {
         set_delete_id.set(id);
-        set_show_delete_modal.set(true);
+
+        spawn_local(async move {
+            match get_recipe_usage(id).await {
+                Ok(report) if report.in_use() => {
+                    set_usages.set(report.meals.iter().map(|meal| {
+                        format!(
+                            "{} – {}, {}",
+                            meal.camp_name,
+                            format_day(meal.date, language),
+                            meal_type_str_label(&meal.meal_type, language),
+                        )
+                    }).collect());
+                    set_show_usage_modal.set(true);
+                }
+                Ok(_) => set_show_delete_modal.set(true),
+                Err(e) => toast_error(t!("error-load-usage", error = e.to_string())),
+            }
+        });
     };
 
     let confirm_delete = move || {
@@ -266,10 +292,10 @@ pub fn RecipeEditor() -> impl IntoView {
             set_loading.set(true);
             match delete_recipe(id).await {
                 Ok(_) => {
-                    toast_success("Recipe deleted successfully!");
+                    toast_success(t!("recipe-deleted"));
                     load_data();
                 },
-                Err(e) => toast_error(&format!("Failed to delete: {}", e)),
+                Err(e) => toast_error(t!("error-delete", error = e.to_string())),
             }
             set_loading.set(false);
         });
@@ -279,59 +305,109 @@ pub fn RecipeEditor() -> impl IntoView {
         set_show_delete_modal.set(false);
     };
 
+    let replace_and_delete = move |replacement_id: i64| {
+        let id = delete_id.get_untracked();
+        set_show_usage_modal.set(false);
+
+        spawn_local(async move {
+            set_loading.set(true);
+            let result = match replace_recipe(id, replacement_id).await {
+                Ok(_) => delete_recipe(id).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(_) => {
+                    toast_success(t!("recipe-replaced"));
+                    load_data();
+                }
+                Err(e) => toast_error(t!("error-replace", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let confirm_archive = move || {
+        let id = delete_id.get_untracked();
+        set_show_usage_modal.set(false);
+
+        spawn_local(async move {
+            match archive_recipe(id).await {
+                Ok(_) => {
+                    toast_success(t!("recipe-archived"));
+                    load_data();
+                }
+                Err(e) => toast_error(t!("error-archive", error = e.to_string())),
+            }
+        });
+    };
+
+    let cancel_usage = move || {
+        set_show_usage_modal.set(false);
+    };
+
     view! {
         <div class="space-y-6">
             <div class="flex justify-between items-center">
                 <h2 class="text-3xl font-bold text-gradient flex items-center gap-3">
                     <span class="text-4xl">"🍳"</span>
-                    "Recipes"
+                    {t!("recipes")}
                 </h2>
-                <button
-                    type="button"
-                    class="btn btn-primary"
-                    on:click=move |_| {
-                        reset_form();
-                        set_show_form.set(true);
-                    }
-                    disabled=move || loading.get()
-                >
-                    <span class="mr-1">"+"</span>
-                    " Add Recipe"
-                </button>
+                <div class="flex gap-2">
+                    <button
+                        type="button"
+                        class="btn btn-secondary"
+                        on:click=move |_| set_show_import.update(|show| *show = !*show)
+                        disabled=move || loading.get()
+                    >
+                        "📥 " {t!("import")}
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        on:click=move |_| {
+                            reset_form();
+                            set_show_form.set(true);
+                        }
+                        disabled=move || loading.get()
+                    >
+                        <span class="mr-1">"+"</span>
+                        " " {t!("add-recipe")}
+                    </button>
+                </div>
             </div>
 
             {move || error.get().map(|err| view! {
                 <div class="alert-error">
-                    <span class="font-semibold mr-2">"⚠️ Error:"</span>
+                    <span class="font-semibold mr-2">"⚠️ " {t!("error")} ":"</span>
                     {err}
                 </div>
             })}
 
-            // Search bar
-            {move || (!show_form.get() && !recipes.get().is_empty()).then(|| view! {
-                <div class="card bg-gradient-to-r from-indigo-50 to-purple-50 border-indigo-200">
-                    <div class="flex items-center gap-3">
-                        <span class="text-2xl">"🔍"</span>
-                        <input
-                            type="text"
-                            class="form-input flex-1"
-                            placeholder="Search recipes by name..."
-                            prop:value=move || search_query.get()
-                            on:input=move |ev| set_search_query.set(event_target_value(&ev))
-                        />
-                    </div>
-                </div>
+            {move || show_import.get().then(|| view! {
+                <RecipeImportPanel
+                    ingredients=ingredients
+                    on_imported=move || {
+                        set_show_import.set(false);
+                        load_data();
+                    }
+                    on_close=move || set_show_import.set(false)
+                />
             })}
 
+            // Search bar
+            <Show when=move || !show_form.get() && !recipes.get().is_empty()>
+                <RecipeSearchFilters query=search_query ingredients=ingredients/>
+            </Show>
+
             {move || show_form.get().then(|| view! {
                 <div class="card border-2 border-blue-200">
                     <h3 class="text-2xl font-bold mb-6 text-gradient flex items-center gap-2">
                         <span>{move || if editing_recipe_id.get().is_some() { "✏️" } else { "✨" }}</span>
-                        {move || if editing_recipe_id.get().is_some() { "Edit Recipe" } else { "New Recipe" }}
+                        {move || if editing_recipe_id.get().is_some() { t!("edit-recipe") } else { t!("new-recipe") }}
                     </h3>
                     <form on:submit=handle_submit class="space-y-4">
                         <div>
-                            <label class="form-label">"Recipe Name" <span class="text-red-500">"*"</span></label>
+                            <label class="form-label">{t!("recipe-name")} <span class="text-red-500">"*"</span></label>
                             <input
                                 type="text"
                                 class="form-input"
@@ -339,10 +415,11 @@ pub fn RecipeEditor() -> impl IntoView {
                                 on:input=move |ev| set_name.set(event_target_value(&ev))
                                 required
                             />
+                            <FieldErrors errors=field_errors field="name" />
                         </div>
 
                         <div>
-                            <label class="form-label">"Base Servings"</label>
+                            <label class="form-label">{t!("base-servings")}</label>
                             <input
                                 type="number"
                                 class="form-input"
@@ -354,10 +431,22 @@ pub fn RecipeEditor() -> impl IntoView {
                                 }
                                 min="1"
                             />
+                            <FieldErrors errors=field_errors field="base_servings" />
                         </div>
 
                         <div>
-                            <label class="form-label">"Instructions"</label>
+                            <label class="form-label">{t!("tags")}</label>
+                            <input
+                                type="text"
+                                class="form-input"
+                                placeholder=t!("tags-placeholder")
+                                prop:value=move || tags.get()
+                                on:input=move |ev| set_tags.set(event_target_value(&ev))
+                            />
+                        </div>
+
+                        <div>
+                            <label class="form-label">{t!("instructions")}</label>
                             <textarea
                                 class="form-input"
                                 rows="6"
@@ -370,7 +459,7 @@ pub fn RecipeEditor() -> impl IntoView {
                             <div class="flex justify-between items-center mb-4">
                                 <h4 class="text-lg font-bold text-slate-800 flex items-center gap-2">
                                     <span>"🥕"</span>
-                                    "Ingredients"
+                                    {t!("ingredients")}
                                     <span class="text-red-500">"*"</span>
                                 </h4>
                                 <button
@@ -379,19 +468,20 @@ pub fn RecipeEditor() -> impl IntoView {
                                     on:click=add_ingredient
                                 >
                                     <span class="mr-1">"+"</span>
-                                    " Add Ingredient"
+                                    " " {t!("add-ingredient-to-recipe")}
                                 </button>
                             </div>
+                            <FieldErrors errors=field_errors field="ingredients" />
 
                             {move || (!recipe_ingredients.get().is_empty()).then(|| view! {
                                 <div class="grid gap-2 px-2 mb-1 text-xs font-semibold text-slate-500 uppercase tracking-wide"
                                      style="grid-template-columns: minmax(0,3fr) 5rem 4.5rem 4rem 4rem 4rem 2rem">
-                                    <span>"Ingredient"</span>
-                                    <span>"Qty"</span>
-                                    <span>"Unit"</span>
-                                    <span class="text-center">"Child×"</span>
-                                    <span class="text-center">"Teen×"</span>
-                                    <span class="text-center">"Adult×"</span>
+                                    <span>{t!("ingredient")}</span>
+                                    <span>{t!("quantity-short")}</span>
+                                    <span>{t!("unit")}</span>
+                                    <span class="text-center">{t!("child-multiplier-short")}</span>
+                                    <span class="text-center">{t!("teen-multiplier-short")}</span>
+                                    <span class="text-center">{t!("adult-multiplier-short")}</span>
                                     <span></span>
                                 </div>
                             })}
@@ -417,7 +507,8 @@ pub fn RecipeEditor() -> impl IntoView {
                                                     }
                                                     get_id=|ingredient: &Ingredient| ingredient.id.to_string()
                                                     get_display=|ingredient: &Ingredient| ingredient.name.clone()
-                                                    placeholder="Search ingredients..."
+                                                    search_terms=|ingredient: Ingredient| ingredient.aliases
+                                                    placeholder=t!("search-ingredients")
                                                 />
                                                 <input
                                                     type="number"
@@ -513,16 +604,16 @@ pub fn RecipeEditor() -> impl IntoView {
                             <button type="submit" class="btn btn-primary" disabled=move || loading.get()>
                                 {move || {
                                     if loading.get() {
-                                        "Saving..."
+                                        t!("saving")
                                     } else if editing_recipe_id.get().is_some() {
-                                        "Update Recipe"
+                                        t!("update-recipe")
                                     } else {
-                                        "Save Recipe"
+                                        t!("save-recipe")
                                     }
                                 }}
                             </button>
                             <button type="button" class="btn btn-secondary" on:click=cancel_form disabled=move || loading.get()>
-                                "Cancel"
+                                {t!("cancel")}
                             </button>
                         </div>
                     </form>
@@ -533,37 +624,44 @@ pub fn RecipeEditor() -> impl IntoView {
                 view! {
                     <div class="card text-center py-12">
                         <div class="spinner mx-auto mb-4"></div>
-                        <p class="text-slate-600">"Loading recipes..."</p>
+                        <p class="text-slate-600">{t!("loading")}</p>
                     </div>
                 }.into_any()
             } else if recipes.get().is_empty() {
                 view! {
                     <div class="card text-center py-16 bg-gradient-to-br from-slate-50 to-blue-50 border-2 border-dashed border-slate-300">
                         <div class="text-7xl mb-6">"🍽️"</div>
-                        <h3 class="text-2xl font-bold text-slate-800 mb-3">"No recipes yet"</h3>
-                        <p class="text-lg text-slate-600 mb-8">"Get started by creating your first recipe"</p>
+                        <h3 class="text-2xl font-bold text-slate-800 mb-3">{t!("no-recipes")}</h3>
+                        <p class="text-lg text-slate-600 mb-8">{t!("no-recipes-text")}</p>
                     </div>
                 }.into_any()
             } else {
                 view! {
                     <div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-6">
                         <For
-                            each=move || {
-                                let query = search_query.get().to_lowercase();
-                                recipes.get()
-                                    .into_iter()
-                                    .filter(|r| query.is_empty() || r.name.to_lowercase().contains(&query))
-                                    .collect::<Vec<_>>()
-                            }
-                            key=|recipe| recipe.id
-                            let:recipe
+                            each=move || search_results.get().map(|page| page.hits).unwrap_or_default()
+                            key=|hit| (hit.recipe.id, hit.recipe.updated_at)
+                            let:hit
                         >
+                            {let recipe = hit.recipe.clone();
+                            view! {
                             <div class="card group">
                                 <div class="flex items-start justify-between mb-3">
                                     <div class="text-3xl group-hover:scale-110 transition-transform duration-200">"🍽️"</div>
-                                    <span class="badge badge-primary">{recipe.base_servings} " servings"</span>
+                                    <span class="badge badge-primary">{t!("servings-count", count = recipe.base_servings)}</span>
+                                </div>
+                                <h3 class="text-xl font-bold text-slate-800 mb-2">{recipe.name.clone()}</h3>
+                                <div class="flex gap-1 flex-wrap mb-4 text-xs">
+                                    {recipe.tag_list().into_iter().map(|tag| view! {
+                                        <span class="badge badge-secondary">{tag}</span>
+                                    }).collect_view()}
+                                    {hit.cost_per_serving.map(|cost| view! {
+                                        <span class="badge badge-success">{t!("cost-per-serving", cost = format_number(cost, 2, language))}</span>
+                                    })}
+                                    {(!hit.allergens.is_empty()).then(|| view! {
+                                        <span class="text-slate-500" title=t!("allergens")>{format!("⚠️ {}", hit.allergens.join(", "))}</span>
+                                    })}
                                 </div>
-                                <h3 class="text-xl font-bold text-slate-800 mb-4">{recipe.name.clone()}</h3>
                                 <div class="mt-auto flex gap-2">
                                     <button
                                         class="btn btn-secondary text-sm flex-1"
@@ -573,8 +671,24 @@ pub fn RecipeEditor() -> impl IntoView {
                                         }
                                         disabled=move || loading.get()
                                     >
-                                        "✏️ Edit"
+                                        "✏️ " {t!("edit")}
                                     </button>
+                                    <a
+                                        href=format!("/recipes/{}/export?format=jsonld", recipe.id)
+                                        class="btn btn-secondary text-sm"
+                                        rel="external"
+                                        title=t!("download-jsonld")
+                                    >
+                                        "JSON-LD"
+                                    </a>
+                                    <a
+                                        href=format!("/recipes/{}/export?format=cooklang", recipe.id)
+                                        class="btn btn-secondary text-sm"
+                                        rel="external"
+                                        title=t!("download-cooklang")
+                                    >
+                                        ".cook"
+                                    </a>
                                     <button
                                         class="btn btn-danger text-sm"
                                         on:click={
@@ -587,8 +701,41 @@ pub fn RecipeEditor() -> impl IntoView {
                                     </button>
                                 </div>
                             </div>
+                            }}
                         </For>
                     </div>
+                    {move || search_results.get().map(|page| {
+                        let page_count = page.page_count();
+                        let current = page.page;
+                        view! {
+                            <div class="flex justify-between items-center">
+                                <span class="text-sm text-slate-500">
+                                    {t!("recipes-found", count = page.total)}
+                                </span>
+                                {(page_count > 1).then(|| view! {
+                                    <div class="flex items-center gap-2">
+                                        <button
+                                            type="button"
+                                            class="btn btn-secondary text-sm"
+                                            disabled=current <= 1
+                                            on:click=move |_| search_query.update(|q| q.page = current - 1)
+                                        >
+                                            "‹ " {t!("previous-page")}
+                                        </button>
+                                        <span class="text-sm text-slate-600">{t!("page-of", page = current, pages = page_count)}</span>
+                                        <button
+                                            type="button"
+                                            class="btn btn-secondary text-sm"
+                                            disabled=current >= page_count
+                                            on:click=move |_| search_query.update(|q| q.page = current + 1)
+                                        >
+                                            {t!("next-page")} " ›"
+                                        </button>
+                                    </div>
+                                })}
+                            </div>
+                        }
+                    })}
                 }.into_any()
             }}
 
@@ -596,12 +743,29 @@ pub fn RecipeEditor() -> impl IntoView {
                 show=show_delete_modal.into()
                 on_confirm=confirm_delete
                 on_cancel=cancel_delete
-                title="Delete Recipe".to_string()
-                message="Are you sure? This action cannot be undone.".to_string()
-                confirm_text="Delete".to_string()
-                cancel_text="Cancel".to_string()
+                title=t!("delete-recipe")
+                message=t!("delete-recipe-confirm")
+                confirm_text=t!("delete")
+                cancel_text=t!("cancel")
                 variant="danger".to_string()
             />
+
+            <UsageModal
+                show=show_usage_modal.into()
+                title=t!("recipe-in-use")
+                usages=usages.into()
+                replacements=Signal::derive(move || {
+                    let id = delete_id.get();
+                    recipes.get()
+                        .into_iter()
+                        .filter(|recipe| recipe.id != id)
+                        .map(|recipe| (recipe.id, recipe.name))
+                        .collect()
+                })
+                on_replace=replace_and_delete
+                on_archive=confirm_archive
+                on_cancel=cancel_usage
+            />
         </div>
     }
 }
@@ -0,0 +1,245 @@
+use crate::models::{Ingredient, IngredientResolution, ParsedIngredient, RecipeFormat};
+use crate::server_functions::recipes::{import_recipe, preview_recipe_import};
+use crate::components::{read_file_text, selected_file, SearchableSelect, toast_success, toast_error};
+use crate::t;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+/// Import one recipe from schema.org JSON-LD or Cooklang, resolving each
+/// ingredient line to an existing ingredient, a new one, or skipping it
+#[component]
+pub fn RecipeImportPanel<F, C>(
+    ingredients: ReadSignal<Vec<Ingredient>>,
+    /// Called after the recipe was created
+    on_imported: F,
+    on_close: C,
+) -> impl IntoView
+where
+    F: Fn() + 'static + Clone + Send + Sync,
+    C: Fn() + 'static + Clone + Send + Sync,
+{
+    let (content, set_content) = signal(String::new());
+    let (format, set_format) = signal(RecipeFormat::JsonLd);
+    let (name, set_name) = signal(String::new());
+    let (servings, set_servings) = signal(0i32);
+    let (lines, set_lines) = signal(Vec::<(ParsedIngredient, RwSignal<IngredientResolution>)>::new());
+    let (previewed, set_previewed) = signal(false);
+    let (loading, set_loading) = signal(false);
+
+    let handle_file = move |ev: leptos::ev::Event| {
+        let Some(file) = selected_file(&ev) else {
+            return;
+        };
+        if file.name().ends_with(".cook") {
+            set_format.set(RecipeFormat::Cooklang);
+        } else {
+            set_format.set(RecipeFormat::JsonLd);
+        }
+        set_previewed.set(false);
+        spawn_local(async move {
+            if let Some(text) = read_file_text(file).await {
+                set_content.set(text);
+            }
+        });
+    };
+
+    let handle_preview = move |_| {
+        let text = content.get_untracked();
+        if text.trim().is_empty() {
+            toast_error(t!("recipe-import-empty"));
+            return;
+        }
+        let recipe_format = format.get_untracked();
+
+        spawn_local(async move {
+            set_loading.set(true);
+            match preview_recipe_import(text, recipe_format).await {
+                Ok(preview) => {
+                    set_name.set(preview.recipe.name.clone());
+                    set_servings.set(preview.recipe.servings);
+                    set_lines.set(
+                        preview.recipe.ingredients.into_iter()
+                            .zip(preview.matches)
+                            .map(|(line, matched)| {
+                                let resolution = match matched {
+                                    Some(id) => IngredientResolution::Existing(id),
+                                    None => IngredientResolution::Create,
+                                };
+                                (line, RwSignal::new(resolution))
+                            })
+                            .collect()
+                    );
+                    set_previewed.set(true);
+                }
+                Err(e) => toast_error(t!("error-read-recipe", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let handle_import = {
+        let on_imported = on_imported.clone();
+        move |_| {
+            let resolutions: Vec<IngredientResolution> = lines.get_untracked()
+                .iter()
+                .map(|(_, resolution)| resolution.get_untracked())
+                .collect();
+            let text = content.get_untracked();
+            let recipe_format = format.get_untracked();
+            let recipe_name = name.get_untracked();
+            let on_imported = on_imported.clone();
+
+            spawn_local(async move {
+                set_loading.set(true);
+                match import_recipe(text, recipe_format, recipe_name, resolutions).await {
+                    Ok(recipe) => {
+                        toast_success(t!("recipe-imported", name = recipe.recipe.name));
+                        set_previewed.set(false);
+                        set_content.set(String::new());
+                        on_imported();
+                    }
+                    Err(e) => toast_error(t!("error-import", error = e.to_string())),
+                }
+                set_loading.set(false);
+            });
+        }
+    };
+
+    view! {
+        <div class="card border-2 border-blue-200 space-y-4">
+            <div class="flex justify-between items-center">
+                <h3 class="text-2xl font-bold text-gradient">"📥 " {t!("recipe-import-title")}</h3>
+                <button type="button" class="btn btn-secondary" on:click=move |_| on_close()>{t!("close")}</button>
+            </div>
+
+            <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                <div>
+                    <label for="recipe-import-file" class="form-label">{t!("recipe-import-file")}</label>
+                    <input
+                        id="recipe-import-file"
+                        type="file"
+                        accept=".jsonld,.json,.cook,.txt"
+                        class="form-input"
+                        on:change=handle_file
+                    />
+                </div>
+                <div>
+                    <label for="recipe-import-format" class="form-label">{t!("recipe-import-format")}</label>
+                    <select
+                        id="recipe-import-format"
+                        class="form-input"
+                        prop:value=move || format.get().as_str()
+                        on:change=move |ev| {
+                            if let Ok(f) = event_target_value(&ev).parse::<RecipeFormat>() {
+                                set_format.set(f);
+                                set_previewed.set(false);
+                            }
+                        }
+                    >
+                        <option value="jsonld">"schema.org JSON-LD"</option>
+                        <option value="cooklang">"Cooklang"</option>
+                    </select>
+                </div>
+            </div>
+
+            <div>
+                <label for="recipe-import-content" class="form-label">{t!("recipe-import-paste")}</label>
+                <textarea
+                    id="recipe-import-content"
+                    class="form-input font-mono text-sm"
+                    rows="6"
+                    prop:value=move || content.get()
+                    on:input=move |ev| {
+                        set_content.set(event_target_value(&ev));
+                        set_previewed.set(false);
+                    }
+                ></textarea>
+            </div>
+
+            <button type="button" class="btn btn-secondary" on:click=handle_preview disabled=move || loading.get()>
+                {t!("recipe-import-read")}
+            </button>
+
+            {move || previewed.get().then(|| view! {
+                <div class="space-y-4">
+                    <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                        <div>
+                            <label for="recipe-import-name" class="form-label">{t!("recipe-name")}</label>
+                            <input
+                                id="recipe-import-name"
+                                type="text"
+                                class="form-input"
+                                prop:value=move || name.get()
+                                on:input=move |ev| set_name.set(event_target_value(&ev))
+                            />
+                        </div>
+                        <div>
+                            <span class="form-label">{t!("base-servings")}</span>
+                            <p class="py-2">{move || servings.get()}</p>
+                        </div>
+                    </div>
+
+                    <div class="space-y-2">
+                        <p class="font-semibold">{t!("ingredients")}</p>
+                        {lines.get().into_iter().map(|(line, resolution)| {
+                            let create_label = format!("➕ {}", t!("recipe-import-new-ingredient", name = line.name.clone()));
+                            view! {
+                                <div class="grid gap-2 items-center bg-slate-50 rounded-lg px-2 py-1.5"
+                                     style="grid-template-columns: minmax(0,2fr) minmax(0,3fr) auto auto">
+                                    <span class="text-sm">{line.raw.clone()}</span>
+                                    <SearchableSelect
+                                        options=ingredients.into()
+                                        selected_value=Signal::derive(move || match resolution.get() {
+                                            IngredientResolution::Existing(id) => id,
+                                            _ => 0,
+                                        })
+                                        on_change=move |id| resolution.set(IngredientResolution::Existing(id))
+                                        get_id=|ingredient: &Ingredient| ingredient.id.to_string()
+                                        get_display=|ingredient: &Ingredient| ingredient.name.clone()
+                                        search_terms=|ingredient: Ingredient| ingredient.aliases
+                                        placeholder=t!("recipe-import-match")
+                                    />
+                                    <button
+                                        type="button"
+                                        class=move || if resolution.get() == IngredientResolution::Create {
+                                            "btn btn-primary text-sm"
+                                        } else {
+                                            "btn btn-secondary text-sm"
+                                        }
+                                        on:click=move |_| resolution.set(IngredientResolution::Create)
+                                    >
+                                        {create_label}
+                                    </button>
+                                    <button
+                                        type="button"
+                                        class=move || if resolution.get() == IngredientResolution::Skip {
+                                            "btn btn-danger text-sm"
+                                        } else {
+                                            "btn btn-secondary text-sm"
+                                        }
+                                        on:click=move |_| resolution.set(IngredientResolution::Skip)
+                                    >
+                                        {t!("recipe-import-skip")}
+                                    </button>
+                                </div>
+                            }
+                        }).collect_view()}
+                    </div>
+
+                    <p class="text-sm text-slate-500">
+                        {t!("recipe-import-help")}
+                    </p>
+
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        on:click=handle_import.clone()
+                        disabled=move || loading.get() || name.get().trim().is_empty()
+                    >
+                        {t!("recipe-import-create")}
+                    </button>
+                </div>
+            })}
+        </div>
+    }
+}
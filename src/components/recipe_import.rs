@@ -0,0 +1,260 @@
+use crate::models::{ImportItem, ImportStatus, YamlImportOptions, YamlImportReport};
+use crate::server_functions::transfer::import_recipes_yaml;
+use crate::components::{read_file_text, selected_file, toast_success, toast_error};
+use crate::t;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+/// Upload `recipes.yaml` / `ingredients.yaml` in the `source_data` format,
+/// preview what changes and then import
+#[component]
+pub fn RecipeImport() -> impl IntoView {
+    let (ingredients_yaml, set_ingredients_yaml) = signal(None::<String>);
+    let (recipes_yaml, set_recipes_yaml) = signal(None::<String>);
+    let (mapping_yaml, set_mapping_yaml) = signal(None::<String>);
+    let (update_existing, set_update_existing) = signal(false);
+    let (auto_create, set_auto_create) = signal(true);
+    let (show_unchanged, set_show_unchanged) = signal(false);
+    let (report, set_report) = signal(None::<YamlImportReport>);
+    let (loading, set_loading) = signal(false);
+
+    // Any change to the input invalidates the preview
+    let file_handler = move |set_content: WriteSignal<Option<String>>| {
+        move |ev: leptos::ev::Event| {
+            set_report.set(None);
+            let file = selected_file(&ev);
+            spawn_local(async move {
+                let content = match file {
+                    Some(file) => read_file_text(file).await,
+                    None => None,
+                };
+                set_content.set(content);
+            });
+        }
+    };
+
+    let run_import = move |dry_run: bool| {
+        let ingredients = ingredients_yaml.get_untracked();
+        let recipes = recipes_yaml.get_untracked();
+        if ingredients.is_none() && recipes.is_none() {
+            toast_error(t!("yaml-import-choose-file"));
+            return;
+        }
+        let mapping = mapping_yaml.get_untracked();
+        let options = YamlImportOptions {
+            dry_run,
+            update_existing: update_existing.get_untracked(),
+            auto_create: auto_create.get_untracked(),
+        };
+
+        spawn_local(async move {
+            set_loading.set(true);
+            match import_recipes_yaml(ingredients, recipes, mapping, options).await {
+                Ok(result) => {
+                    if !dry_run {
+                        toast_success(t!("yaml-import-done"));
+                    }
+                    set_report.set(Some(result));
+                }
+                Err(e) => toast_error(t!("error-import", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="space-y-6">
+            <div class="card space-y-4">
+                <h3 class="text-xl font-bold">{t!("yaml-import-title")}</h3>
+                <p class="text-slate-600">
+                    {t!("yaml-import-help")}
+                </p>
+                <div class="grid grid-cols-1 md:grid-cols-3 gap-4">
+                    <div>
+                        <label for="yaml-ingredients" class="form-label">"ingredients.yaml"</label>
+                        <input
+                            id="yaml-ingredients"
+                            type="file"
+                            accept=".yaml,.yml"
+                            class="form-input"
+                            on:change=file_handler(set_ingredients_yaml)
+                        />
+                    </div>
+                    <div>
+                        <label for="yaml-recipes" class="form-label">"recipes.yaml"</label>
+                        <input
+                            id="yaml-recipes"
+                            type="file"
+                            accept=".yaml,.yml"
+                            class="form-input"
+                            on:change=file_handler(set_recipes_yaml)
+                        />
+                    </div>
+                    <div>
+                        <label for="yaml-mapping" class="form-label">{t!("yaml-import-mapping")}</label>
+                        <input
+                            id="yaml-mapping"
+                            type="file"
+                            accept=".yaml,.yml"
+                            class="form-input"
+                            on:change=file_handler(set_mapping_yaml)
+                        />
+                    </div>
+                </div>
+                <div class="flex flex-wrap gap-6">
+                    <label class="flex items-center gap-2">
+                        <input
+                            type="checkbox"
+                            class="form-checkbox"
+                            prop:checked=move || update_existing.get()
+                            on:change=move |ev| {
+                                set_update_existing.set(event_target_checked(&ev));
+                                set_report.set(None);
+                            }
+                        />
+                        {t!("yaml-import-update-existing")}
+                    </label>
+                    <label class="flex items-center gap-2">
+                        <input
+                            type="checkbox"
+                            class="form-checkbox"
+                            prop:checked=move || auto_create.get()
+                            on:change=move |ev| {
+                                set_auto_create.set(event_target_checked(&ev));
+                                set_report.set(None);
+                            }
+                        />
+                        {t!("yaml-import-auto-create")}
+                    </label>
+                </div>
+                <div class="flex gap-2">
+                    <button
+                        type="button"
+                        class="btn btn-secondary"
+                        on:click=move |_| run_import(true)
+                        disabled=move || loading.get() || (ingredients_yaml.get().is_none() && recipes_yaml.get().is_none())
+                    >
+                        {t!("preview")}
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        on:click=move |_| run_import(false)
+                        disabled=move || loading.get() || !report.get().is_some_and(|r| r.dry_run)
+                        title=t!("yaml-import-preview-first")
+                    >
+                        {t!("import")}
+                    </button>
+                </div>
+            </div>
+
+            {move || report.get().map(|report| view! {
+                <div class="card space-y-4">
+                    <div class="flex justify-between items-center">
+                        <h3 class="text-xl font-bold">
+                            {if report.dry_run { t!("preview") } else { t!("transfer-import-result") }}
+                        </h3>
+                        <label class="flex items-center gap-2 text-sm">
+                            <input
+                                type="checkbox"
+                                class="form-checkbox"
+                                prop:checked=move || show_unchanged.get()
+                                on:change=move |ev| set_show_unchanged.set(event_target_checked(&ev))
+                            />
+                            {t!("yaml-import-show-unchanged")}
+                        </label>
+                    </div>
+
+                    <ImportItemTable title=t!("ingredients") items=report.ingredients.clone() show_unchanged=show_unchanged/>
+                    <ImportItemTable title=t!("recipes") items=report.recipes.clone() show_unchanged=show_unchanged/>
+
+                    {(!report.auto_created.is_empty()).then(|| view! {
+                        <div>
+                            <p class="font-semibold">{t!("yaml-import-auto-created")}</p>
+                            <p class="text-slate-600">{report.auto_created.join(", ")}</p>
+                        </div>
+                    })}
+
+                    {(!report.unit_mismatches.is_empty()).then(|| view! {
+                        <div>
+                            <p class="font-semibold text-amber-700">{t!("yaml-import-unit-mismatches")}</p>
+                            <ul class="list-disc ml-6 text-amber-700">
+                                {report.unit_mismatches.iter().map(|m| view! {
+                                    <li>{t!("yaml-import-unit-mismatch", recipe = m.recipe.clone(), ingredient = m.ingredient.clone(), recipe_unit = m.recipe_unit.clone(), ingredient_unit = m.ingredient_unit.clone())}</li>
+                                }).collect_view()}
+                            </ul>
+                        </div>
+                    })}
+
+                    {(!report.skipped_lines.is_empty()).then(|| view! {
+                        <div>
+                            <p class="font-semibold text-amber-700">{t!("yaml-import-skipped-lines")}</p>
+                            <ul class="list-disc ml-6 text-amber-700">
+                                {report.skipped_lines.iter().map(|s| view! {
+                                    <li>{format!("{} [{}] '{}': {}", s.recipe, s.group, s.line, s.reason)}</li>
+                                }).collect_view()}
+                            </ul>
+                        </div>
+                    })}
+
+                    {(!report.unmapped_types.is_empty()).then(|| view! {
+                        <p class="text-amber-700">
+                            {t!("yaml-import-unmapped-types", types = report.unmapped_types.join(", "))}
+                        </p>
+                    })}
+
+                    {(!report.unknown_groups.is_empty()).then(|| view! {
+                        <p class="text-amber-700">
+                            {t!("yaml-import-unknown-groups", groups = report.unknown_groups.join(", "))}
+                        </p>
+                    })}
+                </div>
+            })}
+        </div>
+    }
+}
+
+#[component]
+fn ImportItemTable(
+    title: String,
+    items: Vec<ImportItem>,
+    show_unchanged: ReadSignal<bool>,
+) -> impl IntoView {
+    let summary = [ImportStatus::New, ImportStatus::Changed, ImportStatus::Conflict, ImportStatus::Unchanged]
+        .iter()
+        .map(|status| format!("{} {}", YamlImportReport::count(&items, *status), t!(status.message_key()).to_lowercase()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    view! {
+        <div>
+            <p class="font-semibold">{title}" – "{summary}</p>
+            <table class="w-full">
+                <tbody>
+                    {move || items.iter()
+                        .filter(|item| show_unchanged.get() || item.status != ImportStatus::Unchanged)
+                        .map(|item| {
+                            let badge = match item.status {
+                                ImportStatus::New => "badge badge-primary",
+                                ImportStatus::Conflict => "badge bg-red-100 text-red-700",
+                                _ => "badge",
+                            };
+                            view! {
+                                <tr>
+                                    <td>{item.name.clone()}</td>
+                                    <td>
+                                        <span class=badge>{t!(item.status.message_key())}</span>
+                                        {(item.status != ImportStatus::Unchanged && !item.applied).then(|| view! {
+                                            <span class="text-sm text-slate-500 ml-2">{t!("yaml-import-left-as-is")}</span>
+                                        })}
+                                    </td>
+                                    <td class="text-sm text-slate-600">{item.details.join("; ")}</td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()}
+                </tbody>
+            </table>
+        </div>
+    }
+}
@@ -0,0 +1,165 @@
+use crate::models::{Ingredient, RecipeSearchQuery, ALLERGENS};
+use crate::components::SearchableSelect;
+use crate::t;
+use leptos::prelude::*;
+
+/// Search text and filters for `search_recipes`. Any change goes back to page 1.
+#[component]
+pub fn RecipeSearchFilters(
+    query: RwSignal<RecipeSearchQuery>,
+    ingredients: ReadSignal<Vec<Ingredient>>,
+) -> impl IntoView {
+    let (show_filters, set_show_filters) = signal(false);
+
+    let update = move |change: &dyn Fn(&mut RecipeSearchQuery)| {
+        query.update(|q| {
+            change(q);
+            q.page = 1;
+        });
+    };
+
+    let ingredient_name = move |id: i64| {
+        ingredients.get()
+            .iter()
+            .find(|i| i.id == id)
+            .map(|i| i.name.clone())
+            .unwrap_or_default()
+    };
+
+    let active_filters = move || {
+        let q = query.get();
+        q.with_ingredients.len()
+            + q.without_ingredients.len()
+            + q.free_of_allergens.len()
+            + usize::from(q.max_cost_per_serving.is_some())
+    };
+
+    view! {
+        <div class="card bg-gradient-to-r from-indigo-50 to-purple-50 border-indigo-200 space-y-4">
+            <div class="flex items-center gap-3">
+                <span class="text-2xl">"🔍"</span>
+                <input
+                    type="text"
+                    class="form-input flex-1"
+                    placeholder=t!("recipe-search-placeholder")
+                    prop:value=move || query.get().text
+                    on:input=move |ev| {
+                        let text = event_target_value(&ev);
+                        update(&|q| q.text = text.clone());
+                    }
+                />
+                <button
+                    type="button"
+                    class="btn btn-secondary"
+                    on:click=move |_| set_show_filters.update(|show| *show = !*show)
+                >
+                    {t!("recipe-search-filters")}
+                    {move || (active_filters() > 0).then(|| view! {
+                        <span class="badge badge-primary ml-2">{active_filters()}</span>
+                    })}
+                </button>
+            </div>
+
+            {move || show_filters.get().then(|| view! {
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                    <div class="space-y-2">
+                        <SearchableSelect
+                            options=ingredients.into()
+                            selected_value=Signal::derive(|| 0)
+                            on_change=move |id| update(&|q| {
+                                if !q.with_ingredients.contains(&id) {
+                                    q.with_ingredients.push(id);
+                                }
+                                q.without_ingredients.retain(|other| *other != id);
+                            })
+                            get_id=|ingredient: &Ingredient| ingredient.id.to_string()
+                            get_display=|ingredient: &Ingredient| ingredient.name.clone()
+                            search_terms=|ingredient: Ingredient| ingredient.aliases
+                            placeholder=t!("recipe-search-add-ingredient")
+                            label=t!("recipe-search-contains")
+                        />
+                        <div class="flex gap-2 flex-wrap">
+                            {move || query.get().with_ingredients.into_iter().map(|id| view! {
+                                <span class="badge badge-primary">
+                                    {ingredient_name(id)}
+                                    <button type="button" class="ml-1" on:click=move |_| update(&|q| q.with_ingredients.retain(|other| *other != id))>"×"</button>
+                                </span>
+                            }).collect_view()}
+                        </div>
+                    </div>
+
+                    <div class="space-y-2">
+                        <SearchableSelect
+                            options=ingredients.into()
+                            selected_value=Signal::derive(|| 0)
+                            on_change=move |id| update(&|q| {
+                                if !q.without_ingredients.contains(&id) {
+                                    q.without_ingredients.push(id);
+                                }
+                                q.with_ingredients.retain(|other| *other != id);
+                            })
+                            get_id=|ingredient: &Ingredient| ingredient.id.to_string()
+                            get_display=|ingredient: &Ingredient| ingredient.name.clone()
+                            search_terms=|ingredient: Ingredient| ingredient.aliases
+                            placeholder=t!("recipe-search-add-ingredient")
+                            label=t!("recipe-search-without")
+                        />
+                        <div class="flex gap-2 flex-wrap">
+                            {move || query.get().without_ingredients.into_iter().map(|id| view! {
+                                <span class="badge badge-secondary">
+                                    {ingredient_name(id)}
+                                    <button type="button" class="ml-1" on:click=move |_| update(&|q| q.without_ingredients.retain(|other| *other != id))>"×"</button>
+                                </span>
+                            }).collect_view()}
+                        </div>
+                    </div>
+
+                    <div>
+                        <label for="recipe-search-max-cost" class="form-label">{t!("recipe-search-max-cost")}</label>
+                        <input
+                            id="recipe-search-max-cost"
+                            type="number"
+                            min="0"
+                            step="any"
+                            class="form-input"
+                            prop:value=move || query.get().max_cost_per_serving.map(|cost| cost.to_string()).unwrap_or_default()
+                            on:change=move |ev| {
+                                let cost = event_target_value(&ev).trim().parse::<f64>().ok().filter(|cost| *cost >= 0.0);
+                                update(&|q| q.max_cost_per_serving = cost);
+                            }
+                        />
+                    </div>
+
+                    <div>
+                        <span class="form-label">{t!("recipe-search-free-of")}</span>
+                        <div class="flex gap-2 flex-wrap">
+                            {ALLERGENS.iter().map(|allergen| {
+                                let allergen = allergen.to_string();
+                                let name = allergen.clone();
+                                let selected = move || query.get().free_of_allergens.contains(&allergen);
+                                view! {
+                                    <button
+                                        type="button"
+                                        class=move || if selected() { "btn btn-primary text-xs" } else { "btn btn-secondary text-xs" }
+                                        on:click={
+                                            let name = name.clone();
+                                            move |_| update(&|q| {
+                                                if q.free_of_allergens.contains(&name) {
+                                                    q.free_of_allergens.retain(|other| *other != name);
+                                                } else {
+                                                    q.free_of_allergens.push(name.clone());
+                                                }
+                                            })
+                                        }
+                                    >
+                                        {name.clone()}
+                                    </button>
+                                }
+                            }).collect_view()}
+                        </div>
+                    </div>
+                </div>
+            })}
+        </div>
+    }
+}
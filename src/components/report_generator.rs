@@ -1,16 +1,25 @@
 use leptos::prelude::*;
+use crate::t;
+use crate::i18n::{use_language, Language};
+use crate::format::{format_amount, format_date, format_day, meal_type_str_label};
 use leptos::task::spawn_local;
 use crate::components::{SearchableSelect, LoadingSpinner, toast_success, toast_error};
 use crate::server_functions::{
-    get_camps, get_camp, generate_shopping_list, generate_meal_schedule, generate_attendance_summary
+    get_camps, get_camp, generate_shopping_list, generate_meal_schedule, generate_attendance_summary,
+    generate_purchase_orders, generate_shopping_trips,
+};
+use crate::models::{
+    Camp, ShoppingListItem, MealScheduleItem, AttendanceSummary, PurchaseOrder,
+    ShoppingTripItem, ShoppingTripPlan, StorageType,
 };
-use crate::models::{Camp, MealType, ShoppingListItem, MealScheduleItem, AttendanceSummary};
 
 #[derive(Clone, Copy, PartialEq)]
 enum ReportType {
     ShoppingList,
     MealSchedule,
     AttendanceSummary,
+    PurchaseOrders,
+    ShoppingTrips,
 }
 
 
@@ -23,22 +32,27 @@ pub fn ReportGenerator() -> impl IntoView {
     let (report_type, set_report_type) = signal(ReportType::ShoppingList);
     let (start_date, set_start_date) = signal(String::new());
     let (end_date, set_end_date) = signal(String::new());
+    let (trip_dates, set_trip_dates) = signal(Vec::<String>::new());
+    let (new_trip_date, set_new_trip_date) = signal(String::new());
     let (is_loading, set_is_loading) = signal(false);
+    let (export_lang, set_export_lang) = signal(use_language().code().to_string());
 
     // Report data
     let (shopping_list, set_shopping_list) = signal(Vec::<ShoppingListItem>::new());
     let (meal_schedule, set_meal_schedule) = signal(Vec::<MealScheduleItem>::new());
     let (attendance_summary, set_attendance_summary) = signal(Vec::<AttendanceSummary>::new());
+    let (purchase_orders, set_purchase_orders) = signal(Vec::<PurchaseOrder>::new());
+    let (shopping_trips, set_shopping_trips) = signal(ShoppingTripPlan::default());
     let (report_generated, set_report_generated) = signal(false);
 
     // Load camps on mount
     let load_camps = Action::new(move |_: &()| async move {
-        match get_camps().await {
+        match get_camps(false).await {
             Ok(camps_list) => {
                 set_camps.set(camps_list);
             }
             Err(e) => {
-                toast_error(&format!("Failed to load camps: {}", e));
+                toast_error(&t!("error-load-camps", error = e.to_string()));
             }
         }
     });
@@ -55,10 +69,11 @@ pub fn ReportGenerator() -> impl IntoView {
                         // Set default date range to camp dates
                         set_start_date.set(camp.start_date.format("%Y-%m-%d").to_string());
                         set_end_date.set(camp.end_date.format("%Y-%m-%d").to_string());
+                        set_trip_dates.set(vec![camp.start_date.format("%Y-%m-%d").to_string()]);
                         set_selected_camp.set(Some(camp));
                     }
                     Err(e) => {
-                        toast_error(&format!("Failed to load camp details: {}", e));
+                        toast_error(&t!("error-load-camp", error = e.to_string()));
                     }
                 }
             });
@@ -66,12 +81,13 @@ pub fn ReportGenerator() -> impl IntoView {
             set_selected_camp.set(None);
             set_start_date.set(String::new());
             set_end_date.set(String::new());
+            set_trip_dates.set(Vec::new());
         }
     });
 
     let handle_generate = move |_| {
         if selected_camp_id.get() == 0 {
-            toast_error("Please select a camp");
+            toast_error(t!("select-camp-first"));
             return;
         }
 
@@ -82,12 +98,13 @@ pub fn ReportGenerator() -> impl IntoView {
         let current_report_type = report_type.get();
         let start = start_date.get();
         let end = end_date.get();
+        let trips = trip_dates.get();
 
         spawn_local(async move {
             match current_report_type {
                 ReportType::ShoppingList => {
                     if start.is_empty() || end.is_empty() {
-                        toast_error("Please select start and end dates");
+                        toast_error(t!("select-dates-first"));
                         set_is_loading.set(false);
                         return;
                     }
@@ -96,10 +113,10 @@ pub fn ReportGenerator() -> impl IntoView {
                         Ok(items) => {
                             set_shopping_list.set(items);
                             set_report_generated.set(true);
-                            toast_success("Shopping list generated successfully!");
+                            toast_success(t!("report-generated", report = t!("report-shopping-list")));
                         }
                         Err(e) => {
-                            toast_error(&format!("Failed to generate shopping list: {}", e));
+                            toast_error(&t!("error-generate-report", report = t!("report-shopping-list"), error = e.to_string()));
                         }
                     }
                 }
@@ -108,10 +125,10 @@ pub fn ReportGenerator() -> impl IntoView {
                         Ok(items) => {
                             set_meal_schedule.set(items);
                             set_report_generated.set(true);
-                            toast_success("Meal schedule generated successfully!");
+                            toast_success(t!("report-generated", report = t!("report-meal-schedule")));
                         }
                         Err(e) => {
-                            toast_error(&format!("Failed to generate meal schedule: {}", e));
+                            toast_error(&t!("error-generate-report", report = t!("report-meal-schedule"), error = e.to_string()));
                         }
                     }
                 }
@@ -120,10 +137,40 @@ pub fn ReportGenerator() -> impl IntoView {
                         Ok(items) => {
                             set_attendance_summary.set(items);
                             set_report_generated.set(true);
-                            toast_success("Attendance summary generated successfully!");
+                            toast_success(t!("report-generated", report = t!("report-attendance-summary")));
+                        }
+                        Err(e) => {
+                            toast_error(&t!("error-generate-report", report = t!("report-attendance-summary"), error = e.to_string()));
+                        }
+                    }
+                }
+                ReportType::PurchaseOrders => {
+                    match generate_purchase_orders(camp_id).await {
+                        Ok(orders) => {
+                            set_purchase_orders.set(orders);
+                            set_report_generated.set(true);
+                            toast_success(t!("report-generated", report = t!("report-purchase-orders")));
+                        }
+                        Err(e) => {
+                            toast_error(&t!("error-generate-report", report = t!("report-purchase-orders"), error = e.to_string()));
+                        }
+                    }
+                }
+                ReportType::ShoppingTrips => {
+                    if trips.is_empty() {
+                        toast_error(t!("add-trip-first"));
+                        set_is_loading.set(false);
+                        return;
+                    }
+
+                    match generate_shopping_trips(camp_id, trips).await {
+                        Ok(plan) => {
+                            set_shopping_trips.set(plan);
+                            set_report_generated.set(true);
+                            toast_success(t!("report-generated", report = t!("report-shopping-trips")));
                         }
                         Err(e) => {
-                            toast_error(&format!("Failed to generate attendance summary: {}", e));
+                            toast_error(&t!("error-generate-report", report = t!("report-shopping-trips"), error = e.to_string()));
                         }
                     }
                 }
@@ -136,15 +183,36 @@ pub fn ReportGenerator() -> impl IntoView {
         let _ = window().print();
     };
 
+    // Download URL for the current report, `None` for reports without spreadsheet export
+    let export_url = move |format: &str, semicolon: bool| -> Option<String> {
+        let report = match report_type.get() {
+            ReportType::ShoppingList => "shopping-list",
+            ReportType::MealSchedule => "meal-schedule",
+            ReportType::AttendanceSummary => "attendance",
+            _ => return None,
+        };
+        let mut url = format!(
+            "/camps/{}/reports/{}?format={}&lang={}",
+            selected_camp_id.get(), report, format, export_lang.get()
+        );
+        if semicolon {
+            url.push_str("&delimiter=semicolon");
+        }
+        if report_type.get() == ReportType::ShoppingList {
+            url.push_str(&format!("&start_date={}&end_date={}", start_date.get(), end_date.get()));
+        }
+        Some(url)
+    };
+
     view! {
         <div class="space-y-6">
             <div class="flex items-center justify-between">
-                <h2 class="text-3xl font-bold text-slate-800">"Reports"</h2>
+                <h2 class="text-3xl font-bold text-slate-800">{t!("reports")}</h2>
             </div>
 
             // Report Configuration Card
             <div class="card no-print">
-                <h3 class="text-xl font-bold text-slate-800 mb-6">"Generate Report"</h3>
+                <h3 class="text-xl font-bold text-slate-800 mb-6">{t!("generate-report")}</h3>
 
                 <div class="space-y-6">
                     // Camp Selector
@@ -155,13 +223,13 @@ pub fn ReportGenerator() -> impl IntoView {
                             on_change=move |id| set_selected_camp_id.set(id)
                             get_id=|camp: &Camp| camp.id.to_string()
                             get_display=|camp: &Camp| camp.name.clone()
-                            placeholder="Select a camp..."
+                            placeholder=t!("select-camp")
                         />
                     </div>
 
                     // Report Type Selector
                     <div>
-                        <label class="form-label">"Report Type" <span class="text-red-500">"*"</span></label>
+                        <label class="form-label">{t!("report-type")} <span class="text-red-500">"*"</span></label>
                         <select
                             class="form-input"
                             on:change=move |ev| {
@@ -169,14 +237,18 @@ pub fn ReportGenerator() -> impl IntoView {
                                 let new_type = match value.as_str() {
                                     "meal_schedule" => ReportType::MealSchedule,
                                     "attendance_summary" => ReportType::AttendanceSummary,
+                                    "purchase_orders" => ReportType::PurchaseOrders,
+                                    "shopping_trips" => ReportType::ShoppingTrips,
                                     _ => ReportType::ShoppingList,
                                 };
                                 set_report_type.set(new_type);
                             }
                         >
-                            <option value="shopping_list">"Shopping List"</option>
-                            <option value="meal_schedule">"Meal Schedule"</option>
-                            <option value="attendance_summary">"Attendance Summary"</option>
+                            <option value="shopping_list">{t!("report-shopping-list")}</option>
+                            <option value="meal_schedule">{t!("report-meal-schedule")}</option>
+                            <option value="attendance_summary">{t!("report-attendance-summary")}</option>
+                            <option value="purchase_orders">{t!("report-purchase-orders")}</option>
+                            <option value="shopping_trips">{t!("report-shopping-trips")}</option>
                         </select>
                     </div>
 
@@ -187,7 +259,7 @@ pub fn ReportGenerator() -> impl IntoView {
                     >
                         <div class="grid grid-cols-2 gap-4">
                             <div>
-                                <label class="form-label">"Start Date" <span class="text-red-500">"*"</span></label>
+                                <label class="form-label">{t!("start-date")} <span class="text-red-500">"*"</span></label>
                                 <input
                                     type="date"
                                     class="form-input"
@@ -196,7 +268,7 @@ pub fn ReportGenerator() -> impl IntoView {
                                 />
                             </div>
                             <div>
-                                <label class="form-label">"End Date" <span class="text-red-500">"*"</span></label>
+                                <label class="form-label">{t!("end-date")} <span class="text-red-500">"*"</span></label>
                                 <input
                                     type="date"
                                     class="form-input"
@@ -207,6 +279,57 @@ pub fn ReportGenerator() -> impl IntoView {
                         </div>
                     </Show>
 
+                    // Trip dates (only for shopping trips)
+                    <Show
+                        when=move || report_type.get() == ReportType::ShoppingTrips
+                        fallback=|| ()
+                    >
+                        <div>
+                            <label class="form-label">{t!("report-shopping-trips")} <span class="text-red-500">"*"</span></label>
+                            <div class="flex gap-2 flex-wrap mb-2">
+                                {move || trip_dates.get().into_iter().map(|date| {
+                                    let date_c = date.clone();
+                                    view! {
+                                        <span class="badge badge-primary">
+                                            {date}
+                                            <button
+                                                type="button"
+                                                class="ml-1"
+                                                on:click=move |_| set_trip_dates.update(|dates| dates.retain(|d| *d != date_c))
+                                            >"×"</button>
+                                        </span>
+                                    }
+                                }).collect_view()}
+                            </div>
+                            <div class="flex gap-2">
+                                <input
+                                    type="date"
+                                    class="form-input"
+                                    prop:value=move || new_trip_date.get()
+                                    on:input=move |ev| set_new_trip_date.set(event_target_value(&ev))
+                                />
+                                <button
+                                    type="button"
+                                    class="btn btn-secondary"
+                                    on:click=move |_| {
+                                        let date = new_trip_date.get();
+                                        if !date.is_empty() {
+                                            set_trip_dates.update(|dates| {
+                                                if !dates.contains(&date) {
+                                                    dates.push(date);
+                                                    dates.sort();
+                                                }
+                                            });
+                                            set_new_trip_date.set(String::new());
+                                        }
+                                    }
+                                >
+                                    "+ " {t!("add-trip")}
+                                </button>
+                            </div>
+                        </div>
+                    </Show>
+
                     // Generate Button
                     <div class="flex gap-3">
                         <button
@@ -214,7 +337,7 @@ pub fn ReportGenerator() -> impl IntoView {
                             on:click=handle_generate
                             disabled=move || is_loading.get() || selected_camp_id.get() == 0
                         >
-                            {move || if is_loading.get() { "Generating..." } else { "Generate Report" }}
+                            {move || if is_loading.get() { t!("generating") } else { t!("generate-report") }}
                         </button>
 
                         <Show
@@ -225,8 +348,22 @@ pub fn ReportGenerator() -> impl IntoView {
                                 class="btn btn-secondary"
                                 on:click=handle_print
                             >
-                                "🖨️ Print"
+                                "🖨️ " {t!("print")}
                             </button>
+                            {move || export_url("csv", false).map(|csv_url| view! {
+                                <a class="btn btn-secondary" href=csv_url download>"⬇️ CSV"</a>
+                                <a class="btn btn-secondary" href=export_url("csv", true) download>"⬇️ CSV (;)"</a>
+                                <a class="btn btn-secondary" href=export_url("xlsx", false) download>"⬇️ XLSX"</a>
+                                <select
+                                    class="form-input w-auto"
+                                    prop:value=move || export_lang.get()
+                                    on:change=move |ev| set_export_lang.set(event_target_value(&ev))
+                                >
+                                    {Language::all().into_iter().map(|language| view! {
+                                        <option value=language.code()>{language.native_name()}</option>
+                                    }).collect_view()}
+                                </select>
+                            })}
                         </Show>
                     </div>
                 </div>
@@ -262,6 +399,18 @@ pub fn ReportGenerator() -> impl IntoView {
                                 items=attendance_summary.get()
                             />
                         }.into_any(),
+                        ReportType::PurchaseOrders => view! {
+                            <PurchaseOrdersReport
+                                camp=selected_camp.get()
+                                orders=purchase_orders.get()
+                            />
+                        }.into_any(),
+                        ReportType::ShoppingTrips => view! {
+                            <ShoppingTripsReport
+                                camp=selected_camp.get()
+                                plan=shopping_trips.get()
+                            />
+                        }.into_any(),
                     }}
                 </div>
             </Show>
@@ -289,6 +438,7 @@ fn ShoppingListReport(
     start_date: String,
     end_date: String,
 ) -> impl IntoView {
+    let language = use_language();
     let camp_name = camp.as_ref().map(|c| c.name.clone()).unwrap_or_default();
 
     // Group items by category
@@ -313,9 +463,9 @@ fn ShoppingListReport(
 
     view! {
         <div>
-            <h2 class="text-2xl font-bold text-slate-800 mb-2">"Shopping List"</h2>
+            <h2 class="text-2xl font-bold text-slate-800 mb-2">{t!("report-shopping-list")}</h2>
             <p class="text-slate-600 mb-6">
-                {camp_name} " • " {start_date.clone()} " to " {end_date}
+                {camp_name} " • " {display_date(&start_date, language)} " – " {display_date(&end_date, language)}
             </p>
 
             {grouped_items.into_iter().map(|(category, items): (String, Vec<ShoppingListItem>)| {
@@ -327,17 +477,36 @@ fn ShoppingListReport(
                         <table class="w-full">
                             <thead>
                                 <tr class="bg-slate-100">
-                                    <th class="text-left p-3">"Ingredient"</th>
-                                    <th class="text-right p-3">"Quantity"</th>
-                                    <th class="text-left p-3">"Unit"</th>
+                                    <th class="text-left p-3">{t!("ingredient")}</th>
+                                    <th class="text-right p-3">{t!("report-needed")}</th>
+                                    <th class="text-right p-3">{t!("report-in-stock")}</th>
+                                    <th class="text-right p-3">{t!("report-to-buy")}</th>
+                                    <th class="text-left p-3">{t!("report-packs")}</th>
+                                    <th class="text-right p-3">{t!("report-leftover")}</th>
+                                    <th class="text-left p-3">{t!("unit")}</th>
                                 </tr>
                             </thead>
                             <tbody>
                                 {items.into_iter().map(|item| {
+                                    let packs = item.packs.iter()
+                                        .map(|pack| pack.describe(&item.unit))
+                                        .collect::<Vec<_>>()
+                                        .join(" + ");
                                     view! {
                                         <tr class="border-t border-slate-200">
                                             <td class="p-3">{item.ingredient_name}</td>
-                                            <td class="text-right p-3">{format!("{:.2}", item.total_quantity)}</td>
+                                            <td class="text-right p-3">
+                                                {format_amount(item.total_quantity, &item.unit, language)}
+                                                {(item.safety_margin > 0.0).then(|| view! {
+                                                    <span class="text-xs text-slate-500">{format!(" +{}", format_amount(item.safety_margin, &item.unit, language))}</span>
+                                                })}
+                                            </td>
+                                            <td class="text-right p-3 text-slate-500">{format_amount(item.in_stock, &item.unit, language)}</td>
+                                            <td class="text-right p-3 font-bold">{format_amount(item.to_buy, &item.unit, language)}</td>
+                                            <td class="p-3">{packs}</td>
+                                            <td class="text-right p-3 text-slate-500">
+                                                {if item.packs.is_empty() { String::new() } else { format_amount(item.leftover, &item.unit, language) }}
+                                            </td>
                                             <td class="p-3">{item.unit}</td>
                                         </tr>
                                     }
@@ -356,33 +525,32 @@ fn MealScheduleReport(
     camp: Option<Camp>,
     items: Vec<MealScheduleItem>,
 ) -> impl IntoView {
+    let language = use_language();
     let camp_name = camp.as_ref().map(|c| c.name.clone()).unwrap_or_default();
 
     view! {
         <div>
-            <h2 class="text-2xl font-bold text-slate-800 mb-2">"Meal Schedule"</h2>
+            <h2 class="text-2xl font-bold text-slate-800 mb-2">{t!("report-meal-schedule")}</h2>
             <p class="text-slate-600 mb-6">{camp_name}</p>
 
             <table class="w-full">
                 <thead>
                     <tr class="bg-slate-100">
-                        <th class="text-left p-3">"Date"</th>
-                        <th class="text-left p-3">"Meal Type"</th>
-                        <th class="text-left p-3">"Recipe"</th>
-                        <th class="text-right p-3">"Children"</th>
-                        <th class="text-right p-3">"Teens"</th>
-                        <th class="text-right p-3">"Adults"</th>
+                        <th class="text-left p-3">{t!("date")}</th>
+                        <th class="text-left p-3">{t!("meal-type")}</th>
+                        <th class="text-left p-3">{t!("recipe")}</th>
+                        <th class="text-right p-3">{t!("children")}</th>
+                        <th class="text-right p-3">{t!("teens")}</th>
+                        <th class="text-right p-3">{t!("adults")}</th>
                     </tr>
                 </thead>
                 <tbody>
                     {items.into_iter().map(|item| {
-                        let meal_type_display = MealType::from_str(&item.meal_type)
-                            .map(|mt| format_meal_type(&mt))
-                            .unwrap_or(item.meal_type.clone());
+                        let meal_type_display = meal_type_str_label(&item.meal_type, language);
 
                         view! {
                             <tr class="border-t border-slate-200">
-                                <td class="p-3">{item.date.format("%Y-%m-%d").to_string()}</td>
+                                <td class="p-3">{format_day(item.date, language)}</td>
                                 <td class="p-3">{meal_type_display}</td>
                                 <td class="p-3">{item.recipe_name}</td>
                                 <td class="text-right p-3">{item.children}</td>
@@ -402,33 +570,32 @@ fn AttendanceSummaryReport(
     camp: Option<Camp>,
     items: Vec<AttendanceSummary>,
 ) -> impl IntoView {
+    let language = use_language();
     let camp_name = camp.as_ref().map(|c| c.name.clone()).unwrap_or_default();
 
     view! {
         <div>
-            <h2 class="text-2xl font-bold text-slate-800 mb-2">"Attendance Summary"</h2>
+            <h2 class="text-2xl font-bold text-slate-800 mb-2">{t!("report-attendance-summary")}</h2>
             <p class="text-slate-600 mb-6">{camp_name}</p>
 
             <table class="w-full">
                 <thead>
                     <tr class="bg-slate-100">
-                        <th class="text-left p-3">"Date"</th>
-                        <th class="text-left p-3">"Meal Type"</th>
-                        <th class="text-right p-3">"Children"</th>
-                        <th class="text-right p-3">"Teens"</th>
-                        <th class="text-right p-3">"Adults"</th>
-                        <th class="text-right p-3">"Total"</th>
+                        <th class="text-left p-3">{t!("date")}</th>
+                        <th class="text-left p-3">{t!("meal-type")}</th>
+                        <th class="text-right p-3">{t!("children")}</th>
+                        <th class="text-right p-3">{t!("teens")}</th>
+                        <th class="text-right p-3">{t!("adults")}</th>
+                        <th class="text-right p-3">{t!("total")}</th>
                     </tr>
                 </thead>
                 <tbody>
                     {items.into_iter().map(|item| {
-                        let meal_type_display = MealType::from_str(&item.meal_type)
-                            .map(|mt| format_meal_type(&mt))
-                            .unwrap_or(item.meal_type.clone());
+                        let meal_type_display = meal_type_str_label(&item.meal_type, language);
 
                         view! {
                             <tr class="border-t border-slate-200">
-                                <td class="p-3">{item.date.format("%Y-%m-%d").to_string()}</td>
+                                <td class="p-3">{format_day(item.date, language)}</td>
                                 <td class="p-3">{meal_type_display}</td>
                                 <td class="text-right p-3">{item.children}</td>
                                 <td class="text-right p-3">{item.teens}</td>
@@ -443,13 +610,164 @@ fn AttendanceSummaryReport(
     }
 }
 
-// Helper function to format meal type
-fn format_meal_type(meal_type: &MealType) -> String {
-    match meal_type {
-        MealType::Breakfast => "Breakfast",
-        MealType::MorningSnack => "Morning Snack",
-        MealType::Lunch => "Lunch",
-        MealType::AfternoonSnack => "Afternoon Snack",
-        MealType::Dinner => "Dinner",
-    }.to_string()
+#[component]
+fn PurchaseOrdersReport(
+    camp: Option<Camp>,
+    orders: Vec<PurchaseOrder>,
+) -> impl IntoView {
+    let language = use_language();
+    let camp_name = camp.as_ref().map(|c| c.name.clone()).unwrap_or_default();
+
+    view! {
+        <div>
+            <h2 class="text-2xl font-bold text-slate-800 mb-2">{t!("report-purchase-orders")}</h2>
+            <p class="text-slate-600 mb-6">{camp_name}</p>
+
+            {orders.into_iter().map(|order| {
+                let supplier_name = if order.supplier_id.is_some() {
+                    order.supplier_name
+                } else {
+                    t!("no-supplier")
+                };
+
+                view! {
+                    <div class="mb-8">
+                        <h3 class="text-xl font-bold text-slate-700 mb-1 border-b-2 border-slate-300 pb-2">
+                            {supplier_name}
+                        </h3>
+                        <p class="text-slate-600 mb-4">
+                            {t!(
+                                "delivery-order-by",
+                                delivery = format_day(order.delivery_date, language),
+                                order_by = format_day(order.order_by, language)
+                            )}
+                        </p>
+                        <table class="w-full">
+                            <thead>
+                                <tr class="bg-slate-100">
+                                    <th class="text-left p-3">{t!("category")}</th>
+                                    <th class="text-left p-3">{t!("ingredient")}</th>
+                                    <th class="text-right p-3">{t!("quantity")}</th>
+                                    <th class="text-left p-3">{t!("unit")}</th>
+                                    <th class="text-left p-3">{t!("report-needed-on")}</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {order.lines.into_iter().map(|line| {
+                                    view! {
+                                        <tr class="border-t border-slate-200">
+                                            <td class="p-3 text-slate-500">{line.category_name}</td>
+                                            <td class="p-3">{line.ingredient_name}</td>
+                                            <td class="text-right p-3 font-bold">{format_amount(line.quantity, &line.unit, language)}</td>
+                                            <td class="p-3">{line.unit}</td>
+                                            <td class="p-3">{format_day(line.needed_on, language)}</td>
+                                        </tr>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </tbody>
+                        </table>
+                    </div>
+                }
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+}
+
+#[component]
+fn ShoppingTripsReport(
+    camp: Option<Camp>,
+    plan: ShoppingTripPlan,
+) -> impl IntoView {
+    let language = use_language();
+    let camp_name = camp.as_ref().map(|c| c.name.clone()).unwrap_or_default();
+    let unassigned = plan.unassigned;
+
+    view! {
+        <div>
+            <h2 class="text-2xl font-bold text-slate-800 mb-2">{t!("report-shopping-trips")}</h2>
+            <p class="text-slate-600 mb-6">{camp_name}</p>
+
+            {(!unassigned.is_empty()).then(|| view! {
+                <div class="alert-error mb-6">
+                    <span class="font-semibold mr-2">"⚠️"</span>
+                    {t!("needed-before-first-trip")}
+                    <ShoppingTripTable items=unassigned/>
+                </div>
+            })}
+
+            {plan.trips.into_iter().map(|trip| {
+                view! {
+                    <div class="mb-8">
+                        <h3 class="text-xl font-bold text-slate-700 mb-4 border-b-2 border-slate-300 pb-2">
+                            {t!("trip-on", date = format_day(trip.date, language))}
+                        </h3>
+                        {if trip.items.is_empty() {
+                            view! { <p class="text-slate-500">{t!("nothing-to-buy")}</p> }.into_any()
+                        } else {
+                            view! { <ShoppingTripTable items=trip.items/> }.into_any()
+                        }}
+                    </div>
+                }
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+}
+
+#[component]
+fn ShoppingTripTable(items: Vec<ShoppingTripItem>) -> impl IntoView {
+    let language = use_language();
+    view! {
+        <table class="w-full">
+            <thead>
+                <tr class="bg-slate-100">
+                    <th class="text-left p-3">{t!("storage")}</th>
+                    <th class="text-left p-3">{t!("ingredient")}</th>
+                    <th class="text-right p-3">{t!("quantity")}</th>
+                    <th class="text-left p-3">{t!("unit")}</th>
+                    <th class="text-left p-3">{t!("report-used")}</th>
+                    <th class="text-left p-3">{t!("report-keeps")}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {items.into_iter().map(|item| {
+                    let storage = item.storage_type.parse::<StorageType>()
+                        .map(|st| t!(st.message_key()))
+                        .unwrap_or(item.storage_type.clone());
+                    let used = if item.first_needed == item.last_needed {
+                        format_date(item.first_needed, language)
+                    } else {
+                        format!(
+                            "{} – {}",
+                            format_date(item.first_needed, language),
+                            format_date(item.last_needed, language)
+                        )
+                    };
+                    let keeps = item.shelf_life_days
+                        .map(|days| t!("days-count", count = days))
+                        .unwrap_or_default();
+
+                    view! {
+                        <tr class="border-t border-slate-200">
+                            <td class="p-3 text-slate-500">{storage}</td>
+                            <td class="p-3">{item.ingredient_name}</td>
+                            <td class="text-right p-3 font-bold">{format_amount(item.quantity, &item.unit, language)}</td>
+                            <td class="p-3">{item.unit}</td>
+                            <td class="p-3">{used}</td>
+                            <td class={if item.exceeds_shelf_life { "p-3 text-red-600 font-semibold" } else { "p-3" }}>
+                                {keeps}
+                                {item.exceeds_shelf_life.then_some(" ⚠️")}
+                            </td>
+                        </tr>
+                    }
+                }).collect::<Vec<_>>()}
+            </tbody>
+        </table>
+    }
+}
+
+/// A `YYYY-MM-DD` form value in the page language
+fn display_date(value: &str, language: Language) -> String {
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|date| format_date(date, language))
+        .unwrap_or_else(|_| value.to_string())
 }
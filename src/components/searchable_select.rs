@@ -1,5 +1,6 @@
 use leptos::ev::{FocusEvent, KeyboardEvent};
 use leptos::prelude::*;
+use crate::models::matches_search;
 
 #[component]
 pub fn SearchableSelect<T, F, G, H>(
@@ -13,12 +14,15 @@ pub fn SearchableSelect<T, F, G, H>(
     get_id: G,
     /// Function to extract the display text from an option
     get_display: H,
+    /// Extra texts an option is found by, e.g. ingredient aliases
+    #[prop(optional, into)]
+    search_terms: Option<Callback<T, Vec<String>>>,
     /// Placeholder text
-    #[prop(default = "Search...")]
-    placeholder: &'static str,
+    #[prop(into, default = "Search...".to_string())]
+    placeholder: String,
     /// Label for accessibility
-    #[prop(optional)]
-    label: Option<&'static str>,
+    #[prop(optional, into)]
+    label: Option<String>,
     /// Whether the field is required
     #[prop(default = false)]
     required: bool,
@@ -44,16 +48,22 @@ where
             .unwrap_or_default()
     });
 
-    // Filter options based on search query
+    // Filter options based on search query, ignoring case and diacritics
     let filtered_options = Memo::new(move |_| {
-        let query = search_query.get().to_lowercase();
-        if query.is_empty() {
+        let query = search_query.get();
+        if query.trim().is_empty() {
             options.get()
         } else {
             options
                 .get()
                 .into_iter()
-                .filter(|opt| get_display(opt).to_lowercase().contains(&query))
+                .filter(|opt| {
+                    let mut texts = vec![get_display(opt)];
+                    if let Some(terms) = search_terms {
+                        texts.extend(terms.run(opt.clone()));
+                    }
+                    matches_search(&query, texts.iter().map(String::as_str))
+                })
                 .collect()
         }
     });
@@ -142,7 +152,7 @@ where
                         if search_query.get().is_empty() && !is_open.get() {
                             selected_display.get()
                         } else {
-                            placeholder.to_string()
+                            placeholder.clone()
                         }
                     }
                     prop:value=move || search_query.get()
@@ -0,0 +1,351 @@
+use crate::models::{Camp, CreateAttendance, Ingredient, StockEntry, StockFinderRequest, StockMatch, StockSource};
+use crate::server_functions::recipes::find_recipes_from_stock;
+use crate::server_functions::ingredients::get_ingredients;
+use crate::server_functions::camps::get_camps;
+use crate::components::{SearchableSelect, toast_error, toast_info};
+use crate::format::{format_amount, format_quantity};
+use crate::i18n::use_language;
+use crate::t;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+/// Ranks recipes by how much of them the inventory or a list of leftovers covers
+#[component]
+pub fn StockRecipeFinder() -> impl IntoView {
+    let language = use_language();
+    let (ingredients, set_ingredients) = signal(Vec::<Ingredient>::new());
+    let (camps, set_camps) = signal(Vec::<Camp>::new());
+    let (matches, set_matches) = signal(None::<Vec<StockMatch>>);
+    let (error, set_error) = signal(None::<String>);
+    let (loading, set_loading) = signal(false);
+
+    // "inventory" or "list"
+    let (source, set_source) = signal("inventory");
+    // 0 = shared storeroom only
+    let (camp_id, set_camp_id) = signal(0i64);
+    let (children, set_children) = signal(String::from("0"));
+    let (teens, set_teens) = signal(String::from("0"));
+    let (adults, set_adults) = signal(String::from("10"));
+    let (min_coverage, set_min_coverage) = signal(String::from("50"));
+
+    // Leftovers entered by hand
+    let (leftovers, set_leftovers) = signal(Vec::<StockEntry>::new());
+    let (entry_ingredient, set_entry_ingredient) = signal(0i64);
+    let (entry_quantity, set_entry_quantity) = signal(String::new());
+    let (entry_unit, set_entry_unit) = signal(String::new());
+
+    Effect::new(move |_| {
+        spawn_local(async move {
+            match get_ingredients(false).await {
+                Ok(data) => set_ingredients.set(data),
+                Err(e) => set_error.set(Some(t!("error-load-ingredients", error = e.to_string()))),
+            }
+            match get_camps(false).await {
+                Ok(data) => set_camps.set(data),
+                Err(e) => set_error.set(Some(t!("error-load-camps", error = e.to_string()))),
+            }
+        });
+    });
+
+    // Default the headcount to the selected camp's usual attendance
+    Effect::new(move |_| {
+        let id = camp_id.get();
+        if let Some(camp) = camps.get_untracked().iter().find(|c| c.id == id) {
+            set_children.set(camp.default_children.to_string());
+            set_teens.set(camp.default_teens.to_string());
+            set_adults.set(camp.default_adults.to_string());
+        }
+    });
+
+    // Default the unit to the ingredient's primary unit
+    Effect::new(move |_| {
+        let id = entry_ingredient.get();
+        if let Some(ing) = ingredients.get_untracked().iter().find(|i| i.id == id) {
+            set_entry_unit.set(ing.primary_unit.clone());
+        }
+    });
+
+    let ingredient_name = move |id: i64| {
+        ingredients.get()
+            .iter()
+            .find(|i| i.id == id)
+            .map(|i| i.name.clone())
+            .unwrap_or_default()
+    };
+
+    let add_leftover = move |_| {
+        let ingredient_val = entry_ingredient.get();
+        let unit_val = entry_unit.get().trim().to_string();
+        if ingredient_val == 0 || unit_val.is_empty() {
+            toast_error(t!("validation-select-ingredient-unit"));
+            return;
+        }
+        let quantity_val = match entry_quantity.get().replace(',', ".").parse::<f64>() {
+            Ok(val) if val > 0.0 => val,
+            _ => {
+                toast_error(t!("validation-invalid-quantity"));
+                return;
+            }
+        };
+
+        set_leftovers.update(|list| list.push(StockEntry {
+            ingredient_id: ingredient_val,
+            quantity: quantity_val,
+            unit: unit_val,
+        }));
+        set_entry_ingredient.set(0);
+        set_entry_quantity.set(String::new());
+        set_entry_unit.set(String::new());
+    };
+
+    let parse_count = |value: String| value.trim().parse::<i32>().ok().filter(|count| *count >= 0);
+
+    let handle_search = move |_| {
+        let (Some(children_val), Some(teens_val), Some(adults_val)) =
+            (parse_count(children.get()), parse_count(teens.get()), parse_count(adults.get()))
+        else {
+            toast_error(t!("validation-invalid-headcount"));
+            return;
+        };
+        let min_coverage_val = min_coverage.get().trim().parse::<f64>().unwrap_or(0.0).clamp(0.0, 100.0) / 100.0;
+
+        let stock_source = if source.get() == "list" {
+            let items = leftovers.get();
+            if items.is_empty() {
+                toast_error(t!("validation-leftovers-empty"));
+                return;
+            }
+            StockSource::Items(items)
+        } else {
+            let camp = camp_id.get();
+            StockSource::Inventory { camp_id: if camp > 0 { Some(camp) } else { None } }
+        };
+
+        let request = StockFinderRequest {
+            source: stock_source,
+            headcount: CreateAttendance { children: children_val, teens: teens_val, adults: adults_val },
+            min_coverage: min_coverage_val,
+            limit: 0,
+        };
+
+        spawn_local(async move {
+            set_loading.set(true);
+            set_error.set(None);
+            match find_recipes_from_stock(request).await {
+                Ok(data) => {
+                    if data.is_empty() {
+                        toast_info(t!("stock-no-recipe-covered"));
+                    }
+                    set_matches.set(Some(data));
+                },
+                Err(e) => set_error.set(Some(t!("error-find-recipes", error = e.to_string()))),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="space-y-4">
+            <h2 class="text-3xl font-bold text-gradient flex items-center gap-3">
+                <span class="text-4xl">"🥫"</span>
+                {t!("cook-from-stock")}
+            </h2>
+
+            {move || error.get().map(|err| view! {
+                <div class="alert-error">
+                    <span class="font-semibold mr-2">"⚠️ " {t!("error")} ":"</span>
+                    {err}
+                </div>
+            })}
+
+            <div class="card space-y-4">
+                <div class="flex gap-2">
+                    <button
+                        type="button"
+                        class=move || if source.get() == "inventory" { "btn btn-primary" } else { "btn btn-secondary" }
+                        on:click=move |_| set_source.set("inventory")
+                    >
+                        "📦 " {t!("nav-pantry")}
+                    </button>
+                    <button
+                        type="button"
+                        class=move || if source.get() == "list" { "btn btn-primary" } else { "btn btn-secondary" }
+                        on:click=move |_| set_source.set("list")
+                    >
+                        "📝 " {t!("stock-leftovers-list")}
+                    </button>
+                </div>
+
+                {move || if source.get() == "inventory" {
+                    view! {
+                        <div class="md:w-1/3">
+                            <SearchableSelect
+                                options=camps.into()
+                                selected_value=camp_id.into()
+                                on_change=move |id| set_camp_id.set(id)
+                                get_id=|c: &Camp| c.id.to_string()
+                                get_display=|c: &Camp| c.name.clone()
+                                placeholder=t!("stock-shared-only")
+                                label=t!("stock-camp")
+                            />
+                        </div>
+                    }.into_any()
+                } else {
+                    view! {
+                        <div class="space-y-3">
+                            <div class="grid gap-3 items-end"
+                                 style="grid-template-columns: minmax(0,3fr) 6rem 6rem auto">
+                                <SearchableSelect
+                                    options=ingredients.into()
+                                    selected_value=entry_ingredient.into()
+                                    on_change=move |id| set_entry_ingredient.set(id)
+                                    get_id=|i: &Ingredient| i.id.to_string()
+                                    get_display=|i: &Ingredient| i.name.clone()
+                                    search_terms=|i: Ingredient| i.aliases
+                                    placeholder=t!("search-ingredients")
+                                    label=t!("ingredient")
+                                />
+                                <div>
+                                    <label class="form-label text-xs">{t!("quantity")}</label>
+                                    <input
+                                        type="text"
+                                        inputmode="decimal"
+                                        class="form-input text-sm"
+                                        prop:value=move || entry_quantity.get()
+                                        on:input=move |ev| set_entry_quantity.set(event_target_value(&ev))
+                                    />
+                                </div>
+                                <div>
+                                    <label class="form-label text-xs">{t!("unit")}</label>
+                                    <input
+                                        type="text"
+                                        class="form-input text-sm"
+                                        prop:value=move || entry_unit.get()
+                                        on:input=move |ev| set_entry_unit.set(event_target_value(&ev))
+                                    />
+                                </div>
+                                <button type="button" class="btn btn-secondary text-sm" on:click=add_leftover>
+                                    "+ " {t!("add")}
+                                </button>
+                            </div>
+                            <div class="flex gap-2 flex-wrap">
+                                {move || leftovers.get().into_iter().enumerate().map(|(index, entry)| view! {
+                                    <span class="badge badge-secondary">
+                                        {format!("{} {}", ingredient_name(entry.ingredient_id), format_quantity(entry.quantity, &entry.unit, language))}
+                                        <button
+                                            type="button"
+                                            class="ml-1"
+                                            on:click=move |_| set_leftovers.update(|list| { list.remove(index); })
+                                        >"×"</button>
+                                    </span>
+                                }).collect_view()}
+                            </div>
+                        </div>
+                    }.into_any()
+                }}
+
+                <div class="grid grid-cols-2 md:grid-cols-5 gap-4 items-end">
+                    <div>
+                        <label class="form-label">{t!("children")}</label>
+                        <input type="number" min="0" class="form-input"
+                            prop:value=move || children.get()
+                            on:input=move |ev| set_children.set(event_target_value(&ev))
+                        />
+                    </div>
+                    <div>
+                        <label class="form-label">{t!("teens")}</label>
+                        <input type="number" min="0" class="form-input"
+                            prop:value=move || teens.get()
+                            on:input=move |ev| set_teens.set(event_target_value(&ev))
+                        />
+                    </div>
+                    <div>
+                        <label class="form-label">{t!("adults")}</label>
+                        <input type="number" min="0" class="form-input"
+                            prop:value=move || adults.get()
+                            on:input=move |ev| set_adults.set(event_target_value(&ev))
+                        />
+                    </div>
+                    <div>
+                        <label class="form-label">{t!("stock-min-coverage")}</label>
+                        <input type="number" min="0" max="100" class="form-input"
+                            prop:value=move || min_coverage.get()
+                            on:input=move |ev| set_min_coverage.set(event_target_value(&ev))
+                        />
+                    </div>
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        on:click=handle_search
+                        disabled=move || loading.get()
+                    >
+                        "🔍 " {t!("stock-find-recipes")}
+                    </button>
+                </div>
+            </div>
+
+            {move || matches.get().map(|found| if found.is_empty() {
+                view! {
+                    <div class="card text-center py-16 bg-gradient-to-br from-slate-50 to-blue-50 border-2 border-dashed border-slate-300">
+                        <div class="text-7xl mb-6">"🥫"</div>
+                        <h3 class="text-2xl font-bold text-slate-800 mb-3">{t!("stock-nothing-to-cook")}</h3>
+                        <p class="text-lg text-slate-600">{t!("stock-nothing-to-cook-text")}</p>
+                    </div>
+                }.into_any()
+            } else {
+                view! {
+                    <div class="space-y-3">
+                        {found.into_iter().map(|found| {
+                            let percent = found.coverage_percent();
+                            let bar_class = if percent >= 100 {
+                                "h-2 rounded-full bg-emerald-500"
+                            } else if percent >= 50 {
+                                "h-2 rounded-full bg-amber-500"
+                            } else {
+                                "h-2 rounded-full bg-red-500"
+                            };
+                            view! {
+                                <div class="card space-y-3">
+                                    <div class="flex justify-between items-center gap-4">
+                                        <h3 class="text-lg font-bold text-slate-800">{found.recipe.name.clone()}</h3>
+                                        <span class="badge badge-primary">
+                                            {t!("stock-covered-lines", covered = found.covered_lines, total = found.total_lines)}
+                                        </span>
+                                    </div>
+                                    <div class="flex items-center gap-3">
+                                        <div class="flex-1 h-2 bg-slate-200 rounded-full overflow-hidden">
+                                            <div class=bar_class style=format!("width: {}%", percent.min(100))></div>
+                                        </div>
+                                        <span class="text-sm font-semibold text-slate-700 w-12 text-right">{format!("{}%", percent)}</span>
+                                    </div>
+                                    {(!found.missing.is_empty()).then(|| view! {
+                                        <div class="border-t border-slate-100 pt-2">
+                                            <div class="grid gap-3 text-xs font-semibold text-slate-500 uppercase tracking-wide py-1"
+                                                 style="grid-template-columns: minmax(0,3fr) 6rem 6rem 6rem 5rem">
+                                                <span>{t!("stock-missing")}</span>
+                                                <span class="text-right">{t!("report-needed")}</span>
+                                                <span class="text-right">{t!("report-in-stock")}</span>
+                                                <span class="text-right">{t!("report-to-buy")}</span>
+                                                <span>{t!("unit")}</span>
+                                            </div>
+                                            {found.missing.into_iter().map(|missing| view! {
+                                                <div class="grid gap-3 py-1 text-sm items-center"
+                                                     style="grid-template-columns: minmax(0,3fr) 6rem 6rem 6rem 5rem">
+                                                    <span class="text-slate-800 truncate">{missing.ingredient_name}</span>
+                                                    <span class="text-right">{format_amount(missing.needed, &missing.unit, language)}</span>
+                                                    <span class="text-right text-slate-500">{format_amount(missing.available, &missing.unit, language)}</span>
+                                                    <span class="text-right font-semibold text-red-600">{format_amount(missing.missing, &missing.unit, language)}</span>
+                                                    <span class="text-xs font-medium text-blue-700 bg-blue-100 px-2 py-0.5 rounded-full w-fit">{missing.unit}</span>
+                                                </div>
+                                            }).collect_view()}
+                                        </div>
+                                    })}
+                                </div>
+                            }
+                        }).collect_view()}
+                    </div>
+                }.into_any()
+            })}
+        </div>
+    }
+}
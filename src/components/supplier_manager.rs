@@ -0,0 +1,448 @@
+use crate::models::{
+    Category, CreateSupplier, Ingredient, SupplierWithAssignments, UpdateSupplier,
+    format_delivery_days,
+};
+use crate::server_functions::suppliers::{get_suppliers, create_supplier, update_supplier, delete_supplier};
+use crate::server_functions::categories::get_categories;
+use crate::server_functions::ingredients::get_ingredients;
+use crate::components::{SearchableSelect, ConfirmModal, FieldErrors, toast_success, toast_error, validate_form};
+use crate::error::FieldError;
+use crate::format::weekday_name;
+use crate::i18n::use_language;
+use crate::t;
+use chrono::Weekday;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+#[component]
+pub fn SupplierManager() -> impl IntoView {
+    let language = use_language();
+    let (suppliers, set_suppliers) = signal(Vec::<SupplierWithAssignments>::new());
+    let (categories, set_categories) = signal(Vec::<Category>::new());
+    let (ingredients, set_ingredients) = signal(Vec::<Ingredient>::new());
+    let (show_form, set_show_form) = signal(false);
+    let (editing_id, set_editing_id) = signal(None::<i64>);
+    let (error, set_error) = signal(None::<String>);
+    let (loading, set_loading) = signal(false);
+    let (field_errors, set_field_errors) = signal(Vec::<FieldError>::new());
+
+    // Modal state
+    let (show_delete_modal, set_show_delete_modal) = signal(false);
+    let (delete_id, set_delete_id) = signal(0i64);
+
+    // Form fields
+    let (name, set_name) = signal(String::new());
+    let (contact, set_contact) = signal(String::new());
+    let (lead_time, set_lead_time) = signal(String::from("0"));
+    let (delivery_days, set_delivery_days) = signal(Vec::<Weekday>::new());
+    let (deliver_upfront, set_deliver_upfront) = signal(false);
+    let (notes, set_notes) = signal(String::new());
+    let (category_ids, set_category_ids) = signal(Vec::<i64>::new());
+    let (ingredient_ids, set_ingredient_ids) = signal(Vec::<i64>::new());
+    let (ingredient_to_add, set_ingredient_to_add) = signal(0i64);
+
+    let load_data = move || {
+        spawn_local(async move {
+            set_loading.set(true);
+            set_error.set(None);
+
+            match get_suppliers().await {
+                Ok(data) => set_suppliers.set(data),
+                Err(e) => set_error.set(Some(t!("error-load-suppliers", error = e.to_string()))),
+            }
+
+            set_loading.set(false);
+        });
+    };
+
+    Effect::new(move |_| {
+        load_data();
+        spawn_local(async move {
+            match get_categories(false).await {
+                Ok(data) => set_categories.set(data),
+                Err(e) => set_error.set(Some(t!("error-load-categories", error = e.to_string()))),
+            }
+            match get_ingredients(false).await {
+                Ok(data) => set_ingredients.set(data),
+                Err(e) => set_error.set(Some(t!("error-load-ingredients", error = e.to_string()))),
+            }
+        });
+    });
+
+    let reset_form = move || {
+        set_name.set(String::new());
+        set_contact.set(String::new());
+        set_lead_time.set(String::from("0"));
+        set_delivery_days.set(Vec::new());
+        set_deliver_upfront.set(false);
+        set_notes.set(String::new());
+        set_category_ids.set(Vec::new());
+        set_ingredient_ids.set(Vec::new());
+        set_editing_id.set(None);
+        set_error.set(None);
+        set_field_errors.set(Vec::new());
+    };
+
+    let start_edit = move |supplier: SupplierWithAssignments| {
+        set_delivery_days.set(supplier.supplier.delivery_weekdays());
+        set_name.set(supplier.supplier.name);
+        set_contact.set(supplier.supplier.contact.unwrap_or_default());
+        set_lead_time.set(supplier.supplier.lead_time_days.to_string());
+        set_deliver_upfront.set(supplier.supplier.deliver_upfront);
+        set_notes.set(supplier.supplier.notes.unwrap_or_default());
+        set_category_ids.set(supplier.category_ids);
+        set_ingredient_ids.set(supplier.ingredient_ids);
+        set_editing_id.set(Some(supplier.supplier.id));
+        set_show_form.set(true);
+    };
+
+    Effect::new(move |_| {
+        let id = ingredient_to_add.get();
+        if id > 0 {
+            set_ingredient_ids.update(|ids| if !ids.contains(&id) { ids.push(id) });
+            set_ingredient_to_add.set(0);
+        }
+    });
+
+    let handle_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+
+        let Ok(lead_time_val) = lead_time.get().trim().parse::<i32>() else {
+            toast_error(t!("validation-lead-time-number"));
+            return;
+        };
+
+        let contact_val = contact.get();
+        let notes_val = notes.get();
+        let editing_id_val = editing_id.get();
+
+        let supplier = CreateSupplier {
+            name: name.get(),
+            contact: (!contact_val.is_empty()).then_some(contact_val),
+            lead_time_days: lead_time_val,
+            delivery_days: format_delivery_days(&delivery_days.get()),
+            deliver_upfront: deliver_upfront.get(),
+            notes: (!notes_val.is_empty()).then_some(notes_val),
+            category_ids: category_ids.get(),
+            ingredient_ids: ingredient_ids.get(),
+        };
+
+        // Same rules as the server
+        if !validate_form(&supplier, set_field_errors) {
+            return;
+        }
+
+        spawn_local(async move {
+            set_loading.set(true);
+
+            let result = if let Some(id) = editing_id_val {
+                update_supplier(id, UpdateSupplier {
+                    name: Some(supplier.name),
+                    contact: Some(supplier.contact.unwrap_or_default()),
+                    lead_time_days: Some(supplier.lead_time_days),
+                    delivery_days: Some(supplier.delivery_days),
+                    deliver_upfront: Some(supplier.deliver_upfront),
+                    notes: Some(supplier.notes.unwrap_or_default()),
+                    category_ids: Some(supplier.category_ids),
+                    ingredient_ids: Some(supplier.ingredient_ids),
+                }).await
+            } else {
+                create_supplier(supplier).await
+            };
+
+            match result {
+                Ok(_) => {
+                    toast_success(t!("supplier-saved"));
+                    reset_form();
+                    set_show_form.set(false);
+                    load_data();
+                }
+                Err(e) => {
+                    set_field_errors.set(e.field_errors().to_vec());
+                    toast_error(t!("error-save-supplier", error = e.to_string()));
+                }
+            }
+
+            set_loading.set(false);
+        });
+    };
+
+    let confirm_delete = move || {
+        let id = delete_id.get();
+        set_show_delete_modal.set(false);
+
+        spawn_local(async move {
+            set_loading.set(true);
+            match delete_supplier(id).await {
+                Ok(_) => {
+                    toast_success(t!("supplier-deleted"));
+                    load_data();
+                }
+                Err(e) => toast_error(t!("error-delete-supplier", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="space-y-4">
+            <div class="flex justify-between items-center">
+                <h3 class="text-2xl font-bold text-gradient flex items-center gap-2">
+                    <span class="text-3xl">"🚚"</span>
+                    {t!("suppliers")}
+                </h3>
+                <button
+                    type="button"
+                    class="btn btn-primary"
+                    on:click=move |_| {
+                        reset_form();
+                        set_show_form.set(true);
+                    }
+                    disabled=move || loading.get()
+                >
+                    <span class="mr-1">"+"</span>
+                    " " {t!("add-supplier")}
+                </button>
+            </div>
+
+            {move || error.get().map(|err| view! {
+                <div class="alert-error">
+                    <span class="font-semibold mr-2">"⚠️ " {t!("error")} ":"</span>
+                    {err}
+                </div>
+            })}
+
+            {move || show_form.get().then(|| view! {
+                <div class="card border-2 border-blue-200">
+                    <h4 class="text-lg font-bold mb-3 text-gradient flex items-center gap-2">
+                        <span>"✨"</span>
+                        {move || if editing_id.get().is_some() { t!("edit-supplier") } else { t!("new-supplier") }}
+                    </h4>
+                    <form on:submit=handle_submit class="space-y-4">
+                        <div class="grid grid-cols-1 md:grid-cols-3 gap-4">
+                            <div>
+                                <label class="form-label">{t!("supplier-name")} <span class="text-red-500">"*"</span></label>
+                                <input type="text" class="form-input"
+                                    prop:value=move || name.get()
+                                    on:input=move |ev| set_name.set(event_target_value(&ev))
+                                    placeholder=t!("supplier-name-placeholder")
+                                    required
+                                />
+                                <FieldErrors errors=field_errors field="name" />
+                            </div>
+                            <div>
+                                <label class="form-label">{t!("supplier-contact")}</label>
+                                <input type="text" class="form-input"
+                                    prop:value=move || contact.get()
+                                    on:input=move |ev| set_contact.set(event_target_value(&ev))
+                                    placeholder=t!("supplier-contact-placeholder")
+                                />
+                            </div>
+                            <div>
+                                <label class="form-label">{t!("supplier-lead-time")}</label>
+                                <input type="number" min="0" class="form-input"
+                                    prop:value=move || lead_time.get()
+                                    on:input=move |ev| set_lead_time.set(event_target_value(&ev))
+                                />
+                                <FieldErrors errors=field_errors field="lead_time_days" />
+                            </div>
+                        </div>
+
+                        <div>
+                            <label class="form-label">{t!("supplier-delivery-days")}</label>
+                            <div class="flex gap-3 flex-wrap">
+                                {WEEKDAYS.into_iter().map(|day| {
+                                    view! {
+                                        <label class="flex items-center gap-1 text-sm cursor-pointer">
+                                            <input type="checkbox" class="form-checkbox"
+                                                prop:checked=move || delivery_days.get().contains(&day)
+                                                on:change=move |ev| {
+                                                    let checked = event_target_checked(&ev);
+                                                    set_delivery_days.update(|days| {
+                                                        days.retain(|d| *d != day);
+                                                        if checked { days.push(day) }
+                                                    });
+                                                }
+                                            />
+                                            {weekday_name(day, language)}
+                                        </label>
+                                    }
+                                }).collect_view()}
+                            </div>
+                        </div>
+
+                        <label class="flex items-center gap-2 text-sm cursor-pointer">
+                            <input type="checkbox" class="form-checkbox"
+                                prop:checked=move || deliver_upfront.get()
+                                on:change=move |ev| set_deliver_upfront.set(event_target_checked(&ev))
+                            />
+                            {t!("supplier-deliver-upfront")}
+                        </label>
+
+                        <div>
+                            <label class="form-label">{t!("supplier-categories")}</label>
+                            <div class="flex gap-3 flex-wrap">
+                                {move || categories.get().into_iter().map(|category| {
+                                    let id = category.id;
+                                    view! {
+                                        <label class="flex items-center gap-1 text-sm cursor-pointer">
+                                            <input type="checkbox" class="form-checkbox"
+                                                prop:checked=move || category_ids.get().contains(&id)
+                                                on:change=move |ev| {
+                                                    let checked = event_target_checked(&ev);
+                                                    set_category_ids.update(|ids| {
+                                                        ids.retain(|c| *c != id);
+                                                        if checked { ids.push(id) }
+                                                    });
+                                                }
+                                            />
+                                            {category.name}
+                                        </label>
+                                    }
+                                }).collect_view()}
+                            </div>
+                        </div>
+
+                        <div>
+                            <SearchableSelect
+                                options=ingredients.into()
+                                selected_value=ingredient_to_add.into()
+                                on_change=move |id| set_ingredient_to_add.set(id)
+                                get_id=|i: &Ingredient| i.id.to_string()
+                                get_display=|i: &Ingredient| i.name.clone()
+                                search_terms=|i: Ingredient| i.aliases
+                                placeholder=t!("supplier-add-ingredient")
+                                label=t!("supplier-ingredients")
+                            />
+                            <div class="flex gap-2 flex-wrap mt-2">
+                                {move || ingredient_ids.get().into_iter().map(|id| {
+                                    let label = ingredients.get()
+                                        .iter()
+                                        .find(|i| i.id == id)
+                                        .map(|i| i.name.clone())
+                                        .unwrap_or_default();
+                                    view! {
+                                        <span class="badge badge-primary">
+                                            {label}
+                                            <button type="button" class="ml-1"
+                                                on:click=move |_| set_ingredient_ids.update(|ids| ids.retain(|i| *i != id))
+                                            >"×"</button>
+                                        </span>
+                                    }
+                                }).collect_view()}
+                            </div>
+                        </div>
+
+                        <div>
+                            <label class="form-label">{t!("notes")}</label>
+                            <textarea class="form-input" rows="2"
+                                prop:value=move || notes.get()
+                                on:input=move |ev| set_notes.set(event_target_value(&ev))
+                            />
+                        </div>
+
+                        <div class="flex gap-2">
+                            <button type="submit" class="btn btn-primary" disabled=move || loading.get()>
+                                {move || if loading.get() { t!("saving") } else { t!("save") }}
+                            </button>
+                            <button type="button" class="btn btn-secondary" on:click=move |_| set_show_form.set(false)>
+                                {t!("cancel")}
+                            </button>
+                        </div>
+                    </form>
+                </div>
+            })}
+
+            {move || if suppliers.get().is_empty() {
+                view! {
+                    <div class="card text-center py-16 bg-gradient-to-br from-slate-50 to-blue-50 border-2 border-dashed border-slate-300">
+                        <div class="text-7xl mb-6">"🚚"</div>
+                        <h3 class="text-2xl font-bold text-slate-800 mb-3">{t!("no-suppliers")}</h3>
+                        <p class="text-lg text-slate-600">{t!("no-suppliers-text")}</p>
+                    </div>
+                }.into_any()
+            } else {
+                view! {
+                    <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                        {suppliers.get().into_iter().map(|supplier| {
+                            let id = supplier.supplier.id;
+                            let category_names = categories.get()
+                                .iter()
+                                .filter(|c| supplier.category_ids.contains(&c.id))
+                                .map(|c| c.name.clone())
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            let weekdays = supplier.supplier.delivery_weekdays();
+                            let days = if weekdays.is_empty() {
+                                t!("supplier-every-day")
+                            } else {
+                                weekdays.into_iter().map(|day| weekday_name(day, language)).collect::<Vec<_>>().join(", ")
+                            };
+                            let supplier_c = supplier.clone();
+                            view! {
+                                <div class="card">
+                                    <div class="flex justify-between items-start">
+                                        <h4 class="text-lg font-bold text-slate-800">{supplier.supplier.name.clone()}</h4>
+                                        <div class="flex gap-1">
+                                            <button type="button"
+                                                class="text-slate-400 hover:text-blue-600 hover:bg-blue-50 rounded-lg p-1.5 transition-colors"
+                                                title=t!("edit")
+                                                on:click=move |_| start_edit(supplier_c.clone())
+                                            >"✏️"</button>
+                                            <button type="button"
+                                                class="text-slate-400 hover:text-red-600 hover:bg-red-50 rounded-lg p-1.5 transition-colors"
+                                                title=t!("delete")
+                                                on:click=move |_| {
+                                                    set_delete_id.set(id);
+                                                    set_show_delete_modal.set(true);
+                                                }
+                                            >"🗑️"</button>
+                                        </div>
+                                    </div>
+                                    {supplier.supplier.contact.clone().map(|c| view! {
+                                        <p class="text-sm text-slate-600">{c}</p>
+                                    })}
+                                    <div class="flex gap-2 flex-wrap mt-2 text-sm">
+                                        <span class="badge badge-primary">"🗓 " {days}</span>
+                                        <span class="badge badge-primary">"⏱ " {t!("supplier-lead-time-days", count = supplier.supplier.lead_time_days)}</span>
+                                        {supplier.supplier.deliver_upfront.then(|| view! {
+                                            <span class="badge badge-primary">"📦 " {t!("supplier-single-delivery")}</span>
+                                        })}
+                                    </div>
+                                    <p class="text-sm text-slate-600 mt-2">{category_names}</p>
+                                    {(!supplier.ingredient_ids.is_empty()).then(|| view! {
+                                        <p class="text-xs text-slate-500 mt-1">
+                                            {t!("supplier-ingredient-count", count = supplier.ingredient_ids.len())}
+                                        </p>
+                                    })}
+                                </div>
+                            }
+                        }).collect_view()}
+                    </div>
+                }.into_any()
+            }}
+
+            <ConfirmModal
+                show=show_delete_modal.into()
+                on_confirm=confirm_delete
+                on_cancel=move || set_show_delete_modal.set(false)
+                title=t!("delete-supplier")
+                message=t!("delete-supplier-confirm")
+                confirm_text=t!("delete")
+                cancel_text=t!("cancel")
+                variant="danger".to_string()
+            />
+        </div>
+    }
+}
@@ -0,0 +1,151 @@
+use crate::models::Trash;
+use crate::server_functions::{get_trash, restore_camp, restore_category, restore_ingredient, restore_recipe};
+use crate::components::{toast_success, toast_error};
+use crate::error::AppError;
+use crate::format::format_date;
+use crate::i18n::use_language;
+use crate::t;
+use chrono::{DateTime, Utc};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Camp,
+    Category,
+    Ingredient,
+    Recipe,
+}
+
+impl Kind {
+    fn message_key(&self) -> &'static str {
+        match self {
+            Kind::Camp => "trash-camp",
+            Kind::Category => "trash-category",
+            Kind::Ingredient => "trash-ingredient",
+            Kind::Recipe => "trash-recipe",
+        }
+    }
+
+    async fn restore(self, id: i64) -> Result<(), AppError> {
+        match self {
+            Kind::Camp => restore_camp(id).await,
+            Kind::Category => restore_category(id).await,
+            Kind::Ingredient => restore_ingredient(id).await,
+            Kind::Recipe => restore_recipe(id).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    kind: Kind,
+    id: i64,
+    name: String,
+    archived_at: Option<DateTime<Utc>>,
+}
+
+fn entries(trash: Trash) -> Vec<Entry> {
+    let entry = |kind, id, name, archived_at| Entry { kind, id, name, archived_at };
+
+    trash.camps.into_iter().map(|c| entry(Kind::Camp, c.id, c.name, c.archived_at))
+        .chain(trash.categories.into_iter().map(|c| entry(Kind::Category, c.id, c.name, c.archived_at)))
+        .chain(trash.ingredients.into_iter().map(|i| entry(Kind::Ingredient, i.id, i.name, i.archived_at)))
+        .chain(trash.recipes.into_iter().map(|r| entry(Kind::Recipe, r.id, r.name, r.archived_at)))
+        .collect()
+}
+
+/// Archived camps, categories, ingredients and recipes, with restore
+#[component]
+pub fn TrashView() -> impl IntoView {
+    let language = use_language();
+    let (items, set_items) = signal(Vec::<Entry>::new());
+    let (loading, set_loading) = signal(false);
+
+    let load_trash = move || {
+        spawn_local(async move {
+            match get_trash().await {
+                Ok(trash) => set_items.set(entries(trash)),
+                Err(e) => toast_error(t!("error-load-trash", error = e.to_string())),
+            }
+        });
+    };
+
+    Effect::new(move |_| {
+        load_trash();
+    });
+
+    let handle_restore = move |kind: Kind, id: i64| {
+        spawn_local(async move {
+            set_loading.set(true);
+            match kind.restore(id).await {
+                Ok(_) => {
+                    toast_success(t!("trash-restored"));
+                    load_trash();
+                }
+                Err(e) => toast_error(t!("error-restore", error = e.to_string())),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="space-y-6">
+            <h2 class="text-3xl font-bold text-gradient flex items-center gap-3">
+                <span class="text-4xl">"📦"</span>
+                {t!("trash")}
+            </h2>
+
+            {move || if items.get().is_empty() {
+                view! {
+                    <div class="card text-center py-8 text-slate-500">{t!("trash-empty")}</div>
+                }.into_any()
+            } else {
+                view! {
+                    <div class="card overflow-x-auto">
+                        <p class="text-slate-600 mb-4">{t!("trash-help")}</p>
+                        <table class="w-full text-left">
+                            <thead>
+                                <tr class="border-b">
+                                    <th class="py-2">{t!("trash-name")}</th>
+                                    <th class="py-2">{t!("trash-kind")}</th>
+                                    <th class="py-2">{t!("trash-archived-on")}</th>
+                                    <th class="py-2"></th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                <For
+                                    each=move || items.get()
+                                    key=|entry| (entry.kind.message_key(), entry.id)
+                                    let:entry
+                                >
+                                    <tr class="border-b last:border-0">
+                                        <td class="py-2 font-medium">{entry.name.clone()}</td>
+                                        <td class="py-2">
+                                            <span class="badge badge-secondary">{t!(entry.kind.message_key())}</span>
+                                        </td>
+                                        <td class="py-2 text-slate-600">
+                                            {entry.archived_at.map(|archived| format_date(archived.date_naive(), language))}
+                                        </td>
+                                        <td class="py-2 text-right">
+                                            <button
+                                                class="btn btn-secondary text-sm"
+                                                on:click={
+                                                    let (kind, id) = (entry.kind, entry.id);
+                                                    move |_| handle_restore(kind, id)
+                                                }
+                                                disabled=move || loading.get()
+                                            >
+                                                {t!("trash-restore")}
+                                            </button>
+                                        </td>
+                                    </tr>
+                                </For>
+                            </tbody>
+                        </table>
+                    </div>
+                }.into_any()
+            }}
+        </div>
+    }
+}
@@ -1,5 +1,10 @@
 use leptos::prelude::*;
 
+use super::toast::toast_error;
+use crate::error::{AppError, FieldError};
+use crate::i18n::use_language;
+use crate::models::Validate;
+
 #[component]
 pub fn FormField(
     /// Label text
@@ -13,6 +18,12 @@ pub fn FormField(
     /// Error message to display
     #[prop(optional)]
     error: Option<String>,
+    /// Field errors of the last save, see [`FieldErrors`]
+    #[prop(optional, into)]
+    errors: Option<Signal<Vec<FieldError>>>,
+    /// Payload field the `errors` are matched on, defaults to the input ID
+    #[prop(optional)]
+    name: Option<String>,
     /// Help text to display below the input
     #[prop(optional)]
     help_text: Option<String>,
@@ -27,6 +38,7 @@ pub fn FormField(
     });
     let error_id = format!("{}-error", field_id);
     let help_id = format!("{}-help", field_id);
+    let field_name = name.unwrap_or_else(|| field_id.clone());
 
     view! {
         <div class=format!("form-field {}", class)>
@@ -47,10 +59,37 @@ pub fn FormField(
                     {err}
                 </div>
             })}
+            {errors.map(|errors| view! { <FieldErrors errors=errors field=field_name /> })}
         </div>
     }
 }
 
+/// Localized messages of the [`FieldError`]s that belong to `field`
+#[component]
+pub fn FieldErrors(
+    /// Field errors of the last save
+    #[prop(into)]
+    errors: Signal<Vec<FieldError>>,
+    /// Payload field, e.g. `end_date`
+    #[prop(into)]
+    field: String,
+) -> impl IntoView {
+    let language = use_language();
+
+    view! {
+        {move || errors
+            .get()
+            .into_iter()
+            .filter(|error| error.field == field)
+            .map(|error| view! {
+                <div class="form-error text-red-600 text-sm mt-1" role="alert">
+                    {error.message.translate(language)}
+                </div>
+            })
+            .collect_view()}
+    }
+}
+
 #[component]
 pub fn Input(
     /// Input type (text, number, email, etc.)
@@ -251,6 +290,20 @@ pub fn Checkbox(
 }
 
 // Validation helpers
+
+/// Check a payload with the same rules as the server before sending it.
+/// Broken rules go to `set_errors` for [`FieldErrors`] and into a toast.
+pub fn validate_form(payload: &impl Validate, set_errors: WriteSignal<Vec<FieldError>>) -> bool {
+    let errors = payload.validation_errors();
+    set_errors.set(errors.clone());
+    if errors.is_empty() {
+        return true;
+    }
+
+    toast_error(AppError::Validation(errors).to_string());
+    false
+}
+
 pub fn validate_required(value: &str, field_name: &str) -> Result<(), String> {
     if value.trim().is_empty() {
         Err(format!("{} is required", field_name))
@@ -0,0 +1,111 @@
+use crate::t;
+use leptos::ev::KeyboardEvent;
+use leptos::prelude::*;
+
+/// Shown instead of the delete confirmation while something still uses the
+/// item: lists the uses and offers to move them to a replacement or to archive
+#[component]
+pub fn UsageModal(
+    /// Whether the modal is visible
+    show: Signal<bool>,
+    /// Modal title
+    title: String,
+    /// One line per use, e.g. a recipe or a planned meal
+    usages: Signal<Vec<String>>,
+    /// Items that can take over every use, as `(id, name)`
+    replacements: Signal<Vec<(i64, String)>>,
+    /// Called with the chosen replacement
+    on_replace: impl Fn(i64) + 'static + Clone + Send + Sync,
+    /// Callback when user archives instead
+    on_archive: impl Fn() + 'static + Clone + Send + Sync,
+    /// Callback when user cancels
+    on_cancel: impl Fn() + 'static + Clone + Send + Sync,
+) -> impl IntoView {
+    let on_replace = StoredValue::new(on_replace);
+    let on_archive = StoredValue::new(on_archive);
+    let on_cancel = StoredValue::new(on_cancel);
+    let (replacement, set_replacement) = signal(None::<i64>);
+
+    let handle_keydown = move |ev: KeyboardEvent| {
+        if ev.key() == "Escape" {
+            on_cancel.with_value(|f| f());
+        }
+    };
+
+    view! {
+        <Show
+            when=move || show.get()
+            fallback=|| ()
+        >
+            <div
+                class="modal-backdrop"
+                on:click=move |_| on_cancel.with_value(|f| f())
+                on:keydown=handle_keydown
+                role="dialog"
+                aria-modal="true"
+                aria-labelledby="usage-modal-title"
+                tabindex="-1"
+            >
+                <div class="modal-content" on:click=|ev| ev.stop_propagation()>
+                    <div class="modal-header">
+                        <h2 id="usage-modal-title" class="modal-title">{title.clone()}</h2>
+                    </div>
+                    <div class="modal-body space-y-4">
+                        <p>{t!("usage-still-used")}</p>
+                        <ul class="max-h-48 overflow-y-auto list-disc pl-5 text-sm text-slate-700">
+                            {move || usages.get().into_iter().map(|usage| view! { <li>{usage}</li> }).collect_view()}
+                        </ul>
+                        <div class="flex flex-wrap items-end gap-2">
+                            <div class="flex-1 min-w-48">
+                                <label for="usage-replacement" class="form-label">{t!("usage-replace-with")}</label>
+                                <select
+                                    id="usage-replacement"
+                                    class="form-input"
+                                    on:change=move |ev| set_replacement.set(event_target_value(&ev).parse().ok())
+                                >
+                                    <option value="" selected=move || replacement.get().is_none()>
+                                        {t!("usage-choose-replacement")}
+                                    </option>
+                                    {move || replacements.get().into_iter().map(|(id, name)| view! {
+                                        <option value=id.to_string() selected=move || replacement.get() == Some(id)>
+                                            {name}
+                                        </option>
+                                    }).collect_view()}
+                                </select>
+                            </div>
+                            <button
+                                class="btn btn-primary"
+                                type="button"
+                                disabled=move || replacement.get().is_none()
+                                on:click=move |_| {
+                                    if let Some(id) = replacement.get_untracked() {
+                                        on_replace.with_value(|f| f(id));
+                                    }
+                                }
+                            >
+                                {t!("usage-replace-and-delete")}
+                            </button>
+                        </div>
+                        <p class="text-sm text-slate-600">{t!("usage-archive-help")}</p>
+                    </div>
+                    <div class="modal-footer">
+                        <button
+                            class="btn btn-secondary"
+                            on:click=move |_| on_cancel.with_value(|f| f())
+                            type="button"
+                        >
+                            {t!("cancel")}
+                        </button>
+                        <button
+                            class="btn btn-primary"
+                            on:click=move |_| on_archive.with_value(|f| f())
+                            type="button"
+                        >
+                            {t!("usage-archive")}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
@@ -1,8 +1,17 @@
+use crate::migrate::{self, MigrateError};
 use sqlx::{sqlite::{SqliteConnectOptions, SqlitePoolOptions}, SqlitePool};
 use std::env;
 use std::str::FromStr;
 
-pub async fn init_db() -> Result<SqlitePool, sqlx::Error> {
+/// Open the database and apply pending migrations
+pub async fn init_db() -> Result<SqlitePool, MigrateError> {
+    let pool = connect().await?;
+    migrate::migrate_up(&pool, None).await?;
+    Ok(pool)
+}
+
+/// Open the database without touching its schema
+pub async fn connect() -> Result<SqlitePool, sqlx::Error> {
     let database_url = env::var("DATABASE_URL")
         .unwrap_or_else(|_| "sqlite://data/meal_planning.db".to_string());
 
@@ -17,57 +26,32 @@ pub async fn init_db() -> Result<SqlitePool, sqlx::Error> {
         })?;
     }
 
-    // Parse connection options and ensure create_if_missing is set
+    // Parse connection options and ensure create_if_missing is set. Foreign keys
+    // are switched on explicitly so ON DELETE RESTRICT/CASCADE always apply.
     let connect_options = SqliteConnectOptions::from_str(&database_url)?
-        .create_if_missing(true);
+        .create_if_missing(true)
+        .foreign_keys(true);
 
-    let pool = SqlitePoolOptions::new()
+    SqlitePoolOptions::new()
         .max_connections(5)
         .connect_with(connect_options)
-        .await?;
-
-    // Run migrations
-    run_migrations(&pool).await?;
-
-    Ok(pool)
+        .await
 }
 
-async fn run_migrations(pool: &SqlitePool) -> Result<(), sqlx::Error> {
-    // Ensure migration tracking table exists
-    sqlx::query(
-        "CREATE TABLE IF NOT EXISTS _migrations (
-            name TEXT PRIMARY KEY,
-            applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
-        )"
-    )
-    .execute(pool)
-    .await?;
-
-    let migrations: &[(&str, &str)] = &[
-        ("001_create_categories",          include_str!("../migrations/001_create_categories.sql")),
-        ("002_create_ingredients",         include_str!("../migrations/002_create_ingredients.sql")),
-        ("003_create_recipes",             include_str!("../migrations/003_create_recipes.sql")),
-        ("004_create_camps",               include_str!("../migrations/004_create_camps.sql")),
-        ("005_create_meal_plans",          include_str!("../migrations/005_create_meal_plans.sql")),
-        ("006_remove_planned_meals_unique", include_str!("../migrations/006_remove_planned_meals_unique.sql")),
-    ];
-
-    for (name, sql) in migrations {
-        let already_applied: bool = sqlx::query_scalar(
-            "SELECT EXISTS(SELECT 1 FROM _migrations WHERE name = ?)"
-        )
-        .bind(name)
-        .fetch_one(pool)
-        .await?;
-
-        if !already_applied {
-            sqlx::query(sql).execute(pool).await?;
-            sqlx::query("INSERT INTO _migrations (name) VALUES (?)")
-                .bind(name)
-                .execute(pool)
-                .await?;
-        }
-    }
-
-    Ok(())
+/// Empty in-memory database with all migrations applied
+#[cfg(test)]
+pub(crate) async fn test_pool() -> SqlitePool {
+    // Every connection to `:memory:` is its own database, so keep exactly one
+    let options = SqliteConnectOptions::from_str("sqlite::memory:")
+        .expect("valid url")
+        .foreign_keys(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect_with(options)
+        .await
+        .expect("in-memory database");
+    migrate::migrate_up(&pool, None).await.expect("migrations apply");
+    pool
 }
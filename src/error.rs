@@ -0,0 +1,156 @@
+//! Application error shared by the `api` functions, the server functions and
+//! the UI.
+//!
+//! Messages are Fluent keys with their variables rather than finished text, so
+//! the browser shows them in the page language. [`AppError`]'s `Display` does
+//! that translation, which keeps `format!("...: {}", e)` in toasts localized.
+
+use leptos::server_fn::codec::JsonEncoding;
+use leptos::server_fn::error::{FromServerFnError, ServerFnErrorErr};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::i18n::{translate, use_language, FluentArgs, Language};
+
+/// A message of `locales/*.ftl` with its variables
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    pub key: String,
+    #[serde(default)]
+    pub args: Vec<(String, String)>,
+}
+
+impl Message {
+    pub fn new(key: &str) -> Self {
+        Self { key: key.to_string(), args: Vec::new() }
+    }
+
+    pub fn with_arg(mut self, name: &str, value: impl ToString) -> Self {
+        self.args.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn translate(&self, language: Language) -> String {
+        if self.args.is_empty() {
+            return translate(language, &self.key, None);
+        }
+
+        let mut args = FluentArgs::new();
+        for (name, value) in &self.args {
+            // Numbers stay numbers so Fluent can pick the plural form
+            match value.parse::<f64>() {
+                Ok(number) => args.set(name.clone(), number),
+                Err(_) => args.set(name.clone(), value.clone()),
+            }
+        }
+        translate(language, &self.key, Some(&args))
+    }
+}
+
+/// A broken rule, tied to a field of the submitted payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldError {
+    /// Payload field, e.g. `end_date`; empty when the rule spans the whole form
+    pub field: String,
+    pub message: Message,
+}
+
+impl FieldError {
+    pub fn new(field: &str, key: &str) -> Self {
+        Self { field: field.to_string(), message: Message::new(key) }
+    }
+
+    pub fn with_arg(mut self, name: &str, value: impl ToString) -> Self {
+        self.message = self.message.with_arg(name, value);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AppError {
+    /// The record does not exist
+    NotFound,
+    /// The payload breaks one or more rules
+    Validation(Vec<FieldError>),
+    /// The change clashes with existing data: a duplicate, or a record still in use
+    Conflict(Message),
+    /// Not logged in
+    Unauthorized,
+    /// Database or transport failure with its technical detail
+    Internal(String),
+}
+
+pub type AppResult<T> = Result<T, AppError>;
+
+impl AppError {
+    /// A single field error
+    pub fn validation(field: &str, key: &str) -> Self {
+        AppError::Validation(vec![FieldError::new(field, key)])
+    }
+
+    /// An uploaded file that could not be parsed
+    pub fn invalid_file(field: &str, error: impl ToString) -> Self {
+        AppError::Validation(vec![FieldError::new(field, "validation-invalid-file").with_arg("error", error)])
+    }
+
+    pub fn conflict(key: &str) -> Self {
+        AppError::Conflict(Message::new(key))
+    }
+
+    /// Field errors of a validation error, none for the other kinds
+    pub fn field_errors(&self) -> &[FieldError] {
+        match self {
+            AppError::Validation(errors) => errors,
+            _ => &[],
+        }
+    }
+
+    pub fn message(&self, language: Language) -> String {
+        match self {
+            AppError::NotFound => translate(language, "error-not-found", None),
+            AppError::Validation(errors) => errors
+                .iter()
+                .map(|error| error.message.translate(language))
+                .collect::<Vec<_>>()
+                .join(", "),
+            AppError::Conflict(message) => message.translate(language),
+            AppError::Unauthorized => translate(language, "error-unauthorized", None),
+            AppError::Internal(detail) => {
+                let mut args = FluentArgs::new();
+                args.set("error", detail.clone());
+                translate(language, "error-internal", Some(&args))
+            }
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message(use_language()))
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl FromServerFnError for AppError {
+    type Encoder = JsonEncoding;
+
+    fn from_server_fn_error(value: ServerFnErrorErr) -> Self {
+        AppError::Internal(value.to_string())
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<sqlx::Error> for AppError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => AppError::NotFound,
+            sqlx::Error::Database(db) if db.is_unique_violation() => AppError::conflict("error-duplicate"),
+            sqlx::Error::Database(db) if db.is_foreign_key_violation() => AppError::conflict("error-reference"),
+            other => {
+                tracing::error!("Database error: {}", other);
+                AppError::Internal(other.to_string())
+            }
+        }
+    }
+}
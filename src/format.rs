@@ -0,0 +1,139 @@
+//! Locale formatting of numbers, quantities, dates and meal types.
+//!
+//! Used by the report pages, the PDF reports and the CSV/XLSX exports so a
+//! Czech user sees "3,25 kg" and "17. 7. 2026 (pá)" everywhere.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::i18n::{translate, Language};
+use crate::models::MealType;
+
+/// Units counted in whole pieces; quantities in them are rounded up
+const COUNT_UNITS: [&str; 10] = ["ks", "pc", "pcs", "piece", "pieces", "kus", "kusy", "pack", "packs", "balení"];
+
+/// Small units shown without decimals
+const WHOLE_UNITS: [&str; 3] = ["g", "ml", "mg"];
+
+pub fn decimal_separator(language: Language) -> char {
+    match language {
+        Language::En => '.',
+        Language::Cz => ',',
+    }
+}
+
+/// `value` with exactly `decimals` decimals and the decimal separator of `language`
+pub fn format_number(value: f64, decimals: usize, language: Language) -> String {
+    localize_separator(format!("{:.*}", decimals, value), language)
+}
+
+/// `value` rounded to what makes sense for `unit`: whole pieces (rounded up),
+/// whole grams and millilitres, and up to two decimals for everything else,
+/// fewer for larger amounts.
+pub fn round_quantity(value: f64, unit: &str) -> f64 {
+    let unit = unit.trim().to_lowercase();
+    if COUNT_UNITS.contains(&unit.as_str()) {
+        // A tiny remainder from the scaling arithmetic is not another piece,
+        // but a fraction of a single piece still needs one
+        let value = if value > 1.0 { value - 0.01 } else { value };
+        return value.ceil().max(0.0);
+    }
+    let factor = 10f64.powi(quantity_decimals(value, &unit) as i32);
+    (value * factor).round() / factor
+}
+
+fn quantity_decimals(value: f64, unit: &str) -> usize {
+    if COUNT_UNITS.contains(&unit) || WHOLE_UNITS.contains(&unit) || value.abs() >= 100.0 {
+        0
+    } else if value.abs() >= 10.0 {
+        1
+    } else {
+        2
+    }
+}
+
+/// Quantity without its unit, rounded for `unit` and without trailing zeros:
+/// `3,25`, `0,5`, `250`
+pub fn format_amount(value: f64, unit: &str, language: Language) -> String {
+    let rounded = round_quantity(value, unit);
+    let decimals = quantity_decimals(rounded, &unit.trim().to_lowercase());
+    let text = format!("{:.*}", decimals, rounded);
+    let text = if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    };
+    localize_separator(text, language)
+}
+
+/// Quantity with its unit: `3,25 kg`, `4 ks`
+pub fn format_quantity(value: f64, unit: &str, language: Language) -> String {
+    format!("{} {}", format_amount(value, unit, language), unit)
+}
+
+/// `17. 7. 2026` in Czech, `2026-07-17` in English
+pub fn format_date(date: NaiveDate, language: Language) -> String {
+    match language {
+        Language::En => date.format("%Y-%m-%d").to_string(),
+        Language::Cz => format!("{}. {}. {}", date.day(), date.month(), date.year()),
+    }
+}
+
+/// Date with its short weekday: `17. 7. 2026 (pá)`, `2026-07-17 (Fri)`
+pub fn format_day(date: NaiveDate, language: Language) -> String {
+    format!("{} ({})", format_date(date, language), weekday_name(date.weekday(), language))
+}
+
+/// Short weekday name
+pub fn weekday_name(weekday: Weekday, language: Language) -> &'static str {
+    let names = match language {
+        Language::En => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        Language::Cz => ["po", "út", "st", "čt", "pá", "so", "ne"],
+    };
+    names[weekday.num_days_from_monday() as usize]
+}
+
+pub fn meal_type_label(meal_type: MealType, language: Language) -> String {
+    translate(language, meal_type.message_key(), None)
+}
+
+/// Label of a stored meal type string, or the string itself when it is unknown
+pub fn meal_type_str_label(meal_type: &str, language: Language) -> String {
+    match MealType::from_str(meal_type) {
+        Some(meal_type) => meal_type_label(meal_type, language),
+        None => meal_type.to_string(),
+    }
+}
+
+fn localize_separator(text: String, language: Language) -> String {
+    match decimal_separator(language) {
+        '.' => text,
+        separator => text.replace('.', &separator.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pieces_round_up() {
+        assert_eq!(format_amount(2.3, "ks", Language::Cz), "3");
+        assert_eq!(format_amount(3.004, "pcs", Language::En), "3");
+        assert_eq!(format_amount(0.005, "ks", Language::Cz), "1");
+    }
+
+    #[test]
+    fn amounts_drop_trailing_zeros_and_use_the_decimal_separator() {
+        assert_eq!(format_amount(3.25, "kg", Language::Cz), "3,25");
+        assert_eq!(format_amount(0.5, "kg", Language::En), "0.5");
+        assert_eq!(format_amount(12.34, "l", Language::Cz), "12,3");
+        assert_eq!(format_amount(249.6, "g", Language::En), "250");
+    }
+
+    #[test]
+    fn days_carry_their_weekday() {
+        let date = NaiveDate::from_ymd_opt(2026, 7, 17).unwrap();
+        assert_eq!(format_day(date, Language::Cz), "17. 7. 2026 (pá)");
+        assert_eq!(format_day(date, Language::En), "2026-07-17 (Fri)");
+    }
+}
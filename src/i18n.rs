@@ -0,0 +1,181 @@
+//! Translations from the Fluent files in `locales/`.
+//!
+//! The language comes from the `lang` cookie: the server reads it from the
+//! request, the browser from `document.cookie`, so both render the same text.
+//! Components look strings up with the [`t!`](crate::t) macro.
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::FluentResource;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentArgs;
+
+/// Cookie holding the chosen language code
+pub const LANGUAGE_COOKIE: &str = "lang";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    #[default]
+    En,
+    Cz,
+}
+
+impl Language {
+    pub fn all() -> [Language; 2] {
+        [Language::En, Language::Cz]
+    }
+
+    /// Code used in the cookie, the `lang` query parameter and the locale file name
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Cz => "cz",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_lowercase().as_str() {
+            "en" => Some(Language::En),
+            "cz" | "cs" => Some(Language::Cz),
+            _ => None,
+        }
+    }
+
+    /// BCP 47 tag for the `lang` attribute
+    pub fn html_lang(&self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Cz => "cs",
+        }
+    }
+
+    /// Name of the language in that language, for the switcher
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::En => "English",
+            Language::Cz => "Čeština",
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Language::En => include_str!("../locales/en.ftl"),
+            Language::Cz => include_str!("../locales/cz.ftl"),
+        }
+    }
+}
+
+/// Language from a `Cookie` header value
+pub fn language_from_cookies(cookies: &str) -> Option<Language> {
+    cookies
+        .split(';')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(name, _)| name.trim() == LANGUAGE_COOKIE)
+        .and_then(|(_, value)| Language::from_code(value))
+}
+
+fn bundles() -> &'static [(Language, FluentBundle<FluentResource>)] {
+    static BUNDLES: OnceLock<Vec<(Language, FluentBundle<FluentResource>)>> = OnceLock::new();
+    BUNDLES.get_or_init(|| {
+        Language::all()
+            .into_iter()
+            .map(|language| {
+                let id: LanguageIdentifier = language.html_lang().parse().expect("valid language tag");
+                let mut bundle = FluentBundle::new_concurrent(vec![id]);
+                // Unicode isolation marks would show up in PDFs and exports
+                bundle.set_use_isolating(false);
+                let resource = FluentResource::try_new(language.source().to_string())
+                    .unwrap_or_else(|(resource, _)| resource);
+                bundle.add_resource_overriding(resource);
+                (language, bundle)
+            })
+            .collect()
+    })
+}
+
+/// Text of `key` in `language`, falling back to English and then to the key itself
+pub fn translate(language: Language, key: &str, args: Option<&FluentArgs>) -> String {
+    for candidate in [language, Language::En] {
+        let Some((_, bundle)) = bundles().iter().find(|(l, _)| *l == candidate) else {
+            continue;
+        };
+        if let Some(pattern) = bundle.get_message(key).and_then(|message| message.value()) {
+            let mut errors = Vec::new();
+            return bundle.format_pattern(pattern, args, &mut errors).into_owned();
+        }
+    }
+    key.to_string()
+}
+
+/// Provide the language of the current request or page. Called once in `App`.
+pub fn provide_language() {
+    provide_context(detect_language());
+}
+
+/// Language of the current page
+pub fn use_language() -> Language {
+    use_context::<Language>().unwrap_or_else(detect_language)
+}
+
+#[cfg(feature = "ssr")]
+fn detect_language() -> Language {
+    use_context::<axum::http::request::Parts>()
+        .and_then(|parts| {
+            parts.headers
+                .get_all(axum::http::header::COOKIE)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .find_map(language_from_cookies)
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn detect_language() -> Language {
+    use leptos::wasm_bindgen::JsCast;
+
+    document()
+        .dyn_into::<web_sys::HtmlDocument>()
+        .ok()
+        .and_then(|doc| doc.cookie().ok())
+        .and_then(|cookies| language_from_cookies(&cookies))
+        .unwrap_or_default()
+}
+
+/// Remember `language` in the cookie and reload the page in it
+pub fn switch_language(language: Language) {
+    #[cfg(not(feature = "ssr"))]
+    {
+        use leptos::wasm_bindgen::JsCast;
+
+        if let Ok(doc) = document().dyn_into::<web_sys::HtmlDocument>() {
+            let _ = doc.set_cookie(&format!(
+                "{}={}; path=/; max-age=31536000; samesite=strict",
+                LANGUAGE_COOKIE,
+                language.code()
+            ));
+        }
+        let _ = window().location().reload();
+    }
+    #[cfg(feature = "ssr")]
+    let _ = language;
+}
+
+/// Translate a message of `locales/*.ftl` into the page language.
+///
+/// `t!("nav-camps")`, or with Fluent variables `t!("camp-day", day = 5)`.
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::translate($crate::i18n::use_language(), $key, None)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::translate($crate::i18n::use_language(), $key, Some(&args))
+    }};
+}
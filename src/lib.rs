@@ -8,10 +8,17 @@ pub mod auth;
 pub mod components;
 #[cfg(feature = "ssr")]
 pub mod db;
+pub mod error;
+pub mod format;
+pub mod i18n;
+#[cfg(feature = "ssr")]
+pub mod migrate;
 pub mod models;
 pub mod pages;
 #[cfg(feature = "ssr")]
 pub mod reports;
+#[cfg(feature = "ssr")]
+pub mod rest;
 pub mod server_functions;
 
 pub use app::*;
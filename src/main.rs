@@ -47,6 +47,23 @@ async fn main() {
 
     // Build our application with routes
     let app = axum::Router::<AppState>::new()
+        .route(
+            "/camps/{id}/reports/{report}",
+            axum::routing::get(reports::export::export_report),
+        )
+        .route(
+            "/recipes/{id}/export",
+            axum::routing::get(reports::export::export_recipe),
+        )
+        .route(
+            "/admin/export",
+            axum::routing::get(reports::export::export_database),
+        )
+        .route(
+            "/camps/{id}/menu.ics",
+            axum::routing::get(reports::ical::camp_menu_ics),
+        )
+        .nest(rest::API_PREFIX, rest::router())
         .leptos_routes_with_context(
             &app_state,
             routes,
@@ -65,7 +82,7 @@ async fn main() {
             |opts| view! { <Shell options=opts/> },
         ))
         .layer(axum::Extension(pool))
-        .layer(axum::middleware::from_fn(auth::require_auth))
+        .layer(axum::middleware::from_fn_with_state(app_state.clone(), auth::require_auth))
         .layer(session_layer)
         .nest_service("/pkg", ServeDir::new("./target/site/pkg"))
         .nest_service("/style", ServeDir::new("./style"))
@@ -0,0 +1,350 @@
+//! Schema migrations.
+//!
+//! Every step is `migrations/<name>.sql`, optionally with a `<name>.down.sql`
+//! that undoes it. A step runs in one transaction together with its row in
+//! `_migrations`, which also keeps the SHA-256 of the file: editing a migration
+//! that was already applied is reported instead of silently ignored. Add new
+//! steps with a new file; never change an applied one.
+
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use sqlx::{Connection, SqlitePool};
+use std::collections::HashMap;
+
+pub struct Migration {
+    pub name: &'static str,
+    pub up: &'static str,
+    pub down: Option<&'static str>,
+}
+
+macro_rules! migration {
+    ($name:literal) => {
+        Migration {
+            name: $name,
+            up: include_str!(concat!("../migrations/", $name, ".sql")),
+            down: None,
+        }
+    };
+    ($name:literal, reversible) => {
+        Migration {
+            name: $name,
+            up: include_str!(concat!("../migrations/", $name, ".sql")),
+            down: Some(include_str!(concat!("../migrations/", $name, ".down.sql"))),
+        }
+    };
+}
+
+/// All migrations in the order they are applied
+pub const MIGRATIONS: &[Migration] = &[
+    migration!("001_create_categories"),
+    migration!("002_create_ingredients"),
+    migration!("003_create_recipes"),
+    migration!("004_create_camps"),
+    migration!("005_create_meal_plans"),
+    migration!("006_remove_planned_meals_unique"),
+    migration!("007_create_inventory", reversible),
+    migration!("008_create_suppliers", reversible),
+    migration!("009_add_pack_sizes", reversible),
+    migration!("010_add_shelf_life", reversible),
+    migration!("011_add_calendar_token", reversible),
+    migration!("012_add_ingredient_aliases", reversible),
+    migration!("013_add_recipe_search", reversible),
+    migration!("014_add_api_tokens", reversible),
+    migration!("015_add_archiving", reversible),
+    migration!("016_archive_camps_categories", reversible),
+];
+
+impl Migration {
+    /// SHA-256 of the up migration; line endings don't count so a checkout
+    /// with CRLF doesn't look edited
+    pub fn checksum(&self) -> String {
+        Sha256::digest(self.up.replace("\r\n", "\n").as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+fn find(name: &str) -> Option<&'static Migration> {
+    MIGRATIONS.iter().find(|migration| migration.name == name)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MigrateError {
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+    #[error("migration {name} was changed after it was applied (applied {applied}, file {current})")]
+    ChecksumMismatch { name: String, applied: String, current: String },
+    #[error("migration {0} is applied but unknown to this version")]
+    Unknown(String),
+    #[error("no migration named {0}")]
+    NotFound(String),
+    #[error("migration {0} has no down migration")]
+    Irreversible(&'static str),
+    #[error("migration {0} left rows pointing to missing rows in {1}")]
+    ForeignKeys(&'static str, String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    Pending,
+    Applied,
+    /// Applied, but the file has changed since
+    Modified,
+    /// Applied by another version of the application
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub name: String,
+    pub state: MigrationState,
+    pub applied_at: Option<DateTime<Utc>>,
+    pub reversible: bool,
+}
+
+#[derive(sqlx::FromRow)]
+struct AppliedMigration {
+    name: String,
+    applied_at: DateTime<Utc>,
+    checksum: Option<String>,
+}
+
+async fn ensure_table(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS _migrations (
+            name TEXT PRIMARY KEY,
+            applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            checksum TEXT
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    // Databases from before checksums were recorded
+    let has_checksum: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM pragma_table_info('_migrations') WHERE name = 'checksum')"
+    )
+    .fetch_one(pool)
+    .await?;
+    if !has_checksum {
+        sqlx::query("ALTER TABLE _migrations ADD COLUMN checksum TEXT").execute(pool).await?;
+    }
+
+    Ok(())
+}
+
+/// Applied migrations in the order they were applied
+async fn applied(pool: &SqlitePool) -> Result<Vec<AppliedMigration>, sqlx::Error> {
+    ensure_table(pool).await?;
+    sqlx::query_as::<_, AppliedMigration>("SELECT name, applied_at, checksum FROM _migrations ORDER BY rowid")
+        .fetch_all(pool)
+        .await
+}
+
+/// Check the applied migrations against the files and return their names in
+/// the order they were applied.
+///
+/// Migrations applied before checksums were recorded get the checksum of the
+/// current file.
+async fn verify(pool: &SqlitePool) -> Result<Vec<&'static str>, MigrateError> {
+    let mut names = Vec::new();
+    for row in applied(pool).await? {
+        let migration = find(&row.name).ok_or_else(|| MigrateError::Unknown(row.name.clone()))?;
+        let current = migration.checksum();
+
+        match row.checksum {
+            Some(checksum) if checksum != current => {
+                return Err(MigrateError::ChecksumMismatch { name: row.name, applied: checksum, current });
+            }
+            Some(_) => {}
+            None => {
+                sqlx::query("UPDATE _migrations SET checksum = ? WHERE name = ?")
+                    .bind(&current)
+                    .bind(&row.name)
+                    .execute(pool)
+                    .await?;
+            }
+        }
+        names.push(migration.name);
+    }
+    Ok(names)
+}
+
+/// Every known migration, then those applied by another version
+pub async fn status(pool: &SqlitePool) -> Result<Vec<MigrationStatus>, MigrateError> {
+    let mut applied: HashMap<String, AppliedMigration> =
+        applied(pool).await?.into_iter().map(|row| (row.name.clone(), row)).collect();
+
+    let mut statuses: Vec<MigrationStatus> = MIGRATIONS
+        .iter()
+        .map(|migration| {
+            let row = applied.remove(migration.name);
+            let state = match &row {
+                None => MigrationState::Pending,
+                Some(row) if row.checksum.as_ref().is_some_and(|c| *c != migration.checksum()) => {
+                    MigrationState::Modified
+                }
+                Some(_) => MigrationState::Applied,
+            };
+            MigrationStatus {
+                name: migration.name.to_string(),
+                state,
+                applied_at: row.map(|row| row.applied_at),
+                reversible: migration.down.is_some(),
+            }
+        })
+        .collect();
+
+    let mut unknown: Vec<AppliedMigration> = applied.into_values().collect();
+    unknown.sort_by_key(|row| row.applied_at);
+    statuses.extend(unknown.into_iter().map(|row| MigrationStatus {
+        name: row.name,
+        state: MigrationState::Unknown,
+        applied_at: Some(row.applied_at),
+        reversible: false,
+    }));
+
+    Ok(statuses)
+}
+
+/// Apply the pending migrations up to and including `target`, or all of them.
+/// Returns the names of the migrations applied.
+pub async fn migrate_up(pool: &SqlitePool, target: Option<&str>) -> Result<Vec<&'static str>, MigrateError> {
+    let applied = verify(pool).await?;
+    let last = match target {
+        Some(name) => MIGRATIONS
+            .iter()
+            .position(|migration| migration.name == name)
+            .ok_or_else(|| MigrateError::NotFound(name.to_string()))?,
+        None => MIGRATIONS.len() - 1,
+    };
+
+    let mut done = Vec::new();
+    for migration in &MIGRATIONS[..=last] {
+        if !applied.contains(&migration.name) {
+            run_step(pool, migration, migration.up, true).await?;
+            tracing::info!("Applied migration {}", migration.name);
+            done.push(migration.name);
+        }
+    }
+    Ok(done)
+}
+
+/// Roll back the last `steps` applied migrations, newest first. Nothing is
+/// rolled back when one of them has no down migration.
+pub async fn migrate_down(pool: &SqlitePool, steps: usize) -> Result<Vec<&'static str>, MigrateError> {
+    let applied = verify(pool).await?;
+    let mut plan: Vec<(&'static Migration, &'static str)> = Vec::new();
+    for name in applied.iter().rev().take(steps) {
+        let migration = find(name).ok_or_else(|| MigrateError::Unknown(name.to_string()))?;
+        let down = migration.down.ok_or(MigrateError::Irreversible(migration.name))?;
+        plan.push((migration, down));
+    }
+
+    let mut done = Vec::new();
+    for (migration, down) in plan {
+        run_step(pool, migration, down, false).await?;
+        tracing::info!("Rolled back migration {}", migration.name);
+        done.push(migration.name);
+    }
+    Ok(done)
+}
+
+/// Run `sql` and record (`up`) or forget (`!up`) the migration in one transaction
+async fn run_step(pool: &SqlitePool, migration: &'static Migration, sql: &str, up: bool) -> Result<(), MigrateError> {
+    let mut conn = pool.acquire().await?;
+
+    // Table rebuilds like 006 drop tables other tables point to, which would
+    // cascade with foreign keys on. The pragma has no effect inside a
+    // transaction, so it is switched off around it and the keys are checked
+    // before committing instead.
+    sqlx::query("PRAGMA foreign_keys = OFF").execute(&mut *conn).await?;
+
+    let result = async {
+        let mut tx = conn.begin().await?;
+        sqlx::query(sql).execute(&mut *tx).await?;
+
+        let broken: Option<String> = sqlx::query_scalar("PRAGMA foreign_key_check")
+            .fetch_optional(&mut *tx)
+            .await?;
+        if let Some(table) = broken {
+            return Err(MigrateError::ForeignKeys(migration.name, table));
+        }
+
+        if up {
+            sqlx::query("INSERT INTO _migrations (name, checksum) VALUES (?, ?)")
+                .bind(migration.name)
+                .bind(migration.checksum())
+                .execute(&mut *tx)
+                .await?;
+        } else {
+            sqlx::query("DELETE FROM _migrations WHERE name = ?")
+                .bind(migration.name)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok::<(), MigrateError>(())
+    }
+    .await;
+
+    sqlx::query("PRAGMA foreign_keys = ON").execute(&mut *conn).await?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    /// Every table, index and trigger of the database
+    async fn schema(pool: &SqlitePool) -> Vec<(String, Option<String>)> {
+        sqlx::query_as("SELECT name, sql FROM sqlite_master WHERE name NOT LIKE 'sqlite_%' ORDER BY type, name")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn reversible_migrations_roll_back_and_apply_again() {
+        let pool = test_pool().await;
+        let migrated = schema(&pool).await;
+
+        let first_reversible = MIGRATIONS.iter().position(|migration| migration.down.is_some()).unwrap();
+        assert_eq!(MIGRATIONS[first_reversible].name, "007_create_inventory");
+        let reversible: Vec<&str> = MIGRATIONS[first_reversible..].iter().map(|migration| migration.name).collect();
+
+        let rolled_back = migrate_down(&pool, reversible.len()).await.unwrap();
+        assert_eq!(rolled_back, reversible.iter().rev().copied().collect::<Vec<_>>());
+        assert!(status(&pool).await.unwrap()[first_reversible..]
+            .iter()
+            .all(|migration| migration.state == MigrationState::Pending));
+
+        // 006 has no down migration
+        assert!(matches!(migrate_down(&pool, 1).await, Err(MigrateError::Irreversible("006_remove_planned_meals_unique"))));
+
+        assert_eq!(migrate_up(&pool, None).await.unwrap(), reversible);
+        assert_eq!(schema(&pool).await, migrated);
+    }
+
+    #[tokio::test]
+    async fn an_edited_migration_is_reported() {
+        let pool = test_pool().await;
+        sqlx::query("UPDATE _migrations SET checksum = 'edited' WHERE name = '003_create_recipes'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        match migrate_up(&pool, None).await {
+            Err(MigrateError::ChecksumMismatch { name, applied, current }) => {
+                assert_eq!(name, "003_create_recipes");
+                assert_eq!(applied, "edited");
+                assert_eq!(current, find("003_create_recipes").unwrap().checksum());
+            }
+            other => panic!("expected a checksum mismatch, got {:?}", other),
+        }
+        assert_eq!(status(&pool).await.unwrap()[2].state, MigrationState::Modified);
+    }
+}
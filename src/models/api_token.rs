@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct ApiToken {
+    pub id: i64,
+    pub name: String,
+    /// One of `read`, `read_write`
+    pub scope: String,
+    /// First characters of the secret
+    pub prefix: String,
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub last_used_at: Option<DateTime<Utc>>,
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateApiToken {
+    pub name: String,
+    pub scope: ApiTokenScope,
+}
+
+/// A token just created; the secret is shown once and never stored
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NewApiToken {
+    pub token: ApiToken,
+    pub secret: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiTokenScope {
+    /// `GET` requests only
+    Read,
+    ReadWrite,
+}
+
+impl ApiTokenScope {
+    pub const ALL: [ApiTokenScope; 2] = [ApiTokenScope::Read, ApiTokenScope::ReadWrite];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiTokenScope::Read => "read",
+            ApiTokenScope::ReadWrite => "read_write",
+        }
+    }
+
+    /// Key of the label in `locales/*.ftl`
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            ApiTokenScope::Read => "api-token-scope-read",
+            ApiTokenScope::ReadWrite => "api-token-scope-read-write",
+        }
+    }
+
+    /// Whether the scope allows a request with this HTTP method
+    pub fn allows(&self, method: &str) -> bool {
+        match self {
+            ApiTokenScope::Read => matches!(method, "GET" | "HEAD" | "OPTIONS"),
+            ApiTokenScope::ReadWrite => true,
+        }
+    }
+}
+
+impl std::str::FromStr for ApiTokenScope {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read" => Ok(ApiTokenScope::Read),
+            "read_write" => Ok(ApiTokenScope::ReadWrite),
+            _ => Err(()),
+        }
+    }
+}
@@ -12,6 +12,10 @@ pub struct Camp {
     pub default_teens: i32,
     pub default_adults: i32,
     pub notes: Option<String>,
+    /// Set when the camp was archived; archived ones are left out of the lists
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub archived_at: Option<DateTime<Utc>>,
     #[cfg_attr(feature = "ssr", sqlx(default))]
     pub created_at: Option<DateTime<Utc>>,
     #[cfg_attr(feature = "ssr", sqlx(default))]
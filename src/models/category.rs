@@ -7,6 +7,10 @@ pub struct Category {
     pub id: i64,
     pub name: String,
     pub sort_order: i32,
+    /// Set when the category was archived; archived ones are left out of the lists
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub archived_at: Option<DateTime<Utc>>,
     #[cfg_attr(feature = "ssr", sqlx(default))]
     pub created_at: Option<DateTime<Utc>>,
     #[cfg_attr(feature = "ssr", sqlx(default))]
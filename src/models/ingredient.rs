@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use super::split_list;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
@@ -9,6 +10,29 @@ pub struct Ingredient {
     pub category_id: i64,
     pub primary_unit: String,
     pub secondary_unit: Option<String>,
+    /// Extra percentage bought on top of the calculated quantity
+    #[serde(default)]
+    pub safety_margin_percent: f64,
+    /// Days the ingredient keeps after buying; `None` keeps for the whole camp
+    #[serde(default)]
+    pub shelf_life_days: Option<i32>,
+    /// One of `fridge`, `freezer`, `dry`
+    #[serde(default = "default_storage_type")]
+    pub storage_type: String,
+    /// Price per primary unit; `None` when unknown
+    #[serde(default)]
+    pub price_per_unit: Option<f64>,
+    /// Comma-separated allergens, e.g. "gluten, milk"
+    #[serde(default)]
+    pub allergens: String,
+    /// Other names the ingredient is found by, loaded separately from `ingredient_aliases`
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", sqlx(skip))]
+    pub aliases: Vec<String>,
+    /// Set when the ingredient was archived instead of deleted; archived ones are left out of the lists
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub archived_at: Option<DateTime<Utc>>,
     #[cfg_attr(feature = "ssr", sqlx(default))]
     pub created_at: Option<DateTime<Utc>>,
     #[cfg_attr(feature = "ssr", sqlx(default))]
@@ -21,6 +45,16 @@ pub struct CreateIngredient {
     pub category_id: i64,
     pub primary_unit: String,
     pub secondary_unit: Option<String>,
+    #[serde(default)]
+    pub safety_margin_percent: f64,
+    #[serde(default)]
+    pub shelf_life_days: Option<i32>,
+    #[serde(default = "default_storage_type")]
+    pub storage_type: String,
+    #[serde(default)]
+    pub price_per_unit: Option<f64>,
+    #[serde(default)]
+    pub allergens: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,4 +63,314 @@ pub struct UpdateIngredient {
     pub category_id: Option<i64>,
     pub primary_unit: Option<String>,
     pub secondary_unit: Option<String>,
+    pub safety_margin_percent: Option<f64>,
+    pub shelf_life_days: Option<i32>,
+    /// Remove the shelf life, so the ingredient keeps for the whole camp again
+    #[serde(default)]
+    pub clear_shelf_life: bool,
+    pub storage_type: Option<String>,
+    pub price_per_unit: Option<f64>,
+    pub allergens: Option<String>,
+}
+
+impl Ingredient {
+    pub fn allergen_list(&self) -> Vec<String> {
+        split_list(&self.allergens)
+    }
+}
+
+/// The 14 allergens food must be labelled with in the EU
+pub const ALLERGENS: [&str; 14] = [
+    "gluten", "crustaceans", "eggs", "fish", "peanuts", "soy", "milk",
+    "nuts", "celery", "mustard", "sesame", "sulphites", "lupin", "molluscs",
+];
+
+fn default_storage_type() -> String {
+    StorageType::Dry.as_str().to_string()
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageType {
+    Fridge,
+    Freezer,
+    Dry,
+}
+
+impl StorageType {
+    pub const ALL: [StorageType; 3] = [StorageType::Fridge, StorageType::Freezer, StorageType::Dry];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageType::Fridge => "fridge",
+            StorageType::Freezer => "freezer",
+            StorageType::Dry => "dry",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StorageType::Fridge => "Fridge",
+            StorageType::Freezer => "Freezer",
+            StorageType::Dry => "Dry",
+        }
+    }
+
+    /// Key of the label in `locales/*.ftl`
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            StorageType::Fridge => "storage-fridge",
+            StorageType::Freezer => "storage-freezer",
+            StorageType::Dry => "storage-dry",
+        }
+    }
+}
+
+impl std::str::FromStr for StorageType {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fridge" => Ok(StorageType::Fridge),
+            "freezer" => Ok(StorageType::Freezer),
+            "dry" => Ok(StorageType::Dry),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct IngredientPack {
+    pub id: i64,
+    pub ingredient_id: i64,
+    pub unit: String,
+    pub size: f64,
+    /// Shop name of the pack, e.g. "1 kg bag"
+    pub label: Option<String>,
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateIngredientPack {
+    pub ingredient_id: i64,
+    pub unit: String,
+    pub size: f64,
+    pub label: Option<String>,
+}
+
+/// Number of packs of one size to buy
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PackCount {
+    pub size: f64,
+    pub label: Option<String>,
+    pub count: u32,
+}
+
+impl PackCount {
+    pub fn describe(&self, unit: &str) -> String {
+        match &self.label {
+            Some(label) => format!("{} × {}", self.count, label),
+            None => format!("{} × {} {}", self.count, self.size, unit),
+        }
+    }
+}
+
+/// Round `quantity` up to whole packs.
+///
+/// Picks the combination that buys the least over `quantity`, and of those the
+/// one with the fewest packs. Pack sizes too fine to compare step by step are
+/// rounded greedily, largest first. Returns an empty list when there are no packs.
+pub fn round_up_to_packs(quantity: f64, packs: &[IngredientPack]) -> Vec<PackCount> {
+    // Sizes are compared in thousandths of the unit, divided by their common divisor
+    const SCALE: f64 = 1000.0;
+    // Needs larger than this many steps are covered with the largest pack first,
+    // and packs whose common step is finer than the largest over this are rounded greedily
+    const MAX_STEPS: u64 = 100_000;
+
+    let mut sorted: Vec<&IngredientPack> = packs.iter().filter(|p| p.size > 0.0).collect();
+    sorted.sort_by(|a, b| b.size.total_cmp(&a.size));
+    sorted.dedup_by(|a, b| a.size == b.size);
+    if sorted.is_empty() {
+        return Vec::new();
+    }
+
+    let steps: Vec<u64> = sorted.iter().map(|p| ((p.size * SCALE).round() as u64).max(1)).collect();
+    let step = steps.iter().copied().fold(0, gcd);
+    let mut counts = vec![0u32; sorted.len()];
+    if steps[0] / step > MAX_STEPS {
+        let mut remaining = ((quantity * SCALE - 1e-6).ceil().max(0.0)) as u64;
+        for (count, size) in counts.iter_mut().zip(&steps) {
+            *count = (remaining / size) as u32;
+            remaining %= size;
+        }
+        if remaining > 0 {
+            counts[steps.len() - 1] += 1;
+        }
+        return pack_counts(sorted, counts);
+    }
+    let sizes: Vec<usize> = steps.iter().map(|s| (s / step) as usize).collect();
+    let largest = sizes[0];
+
+    let mut target = ((quantity * SCALE - 1e-6) / step as f64).ceil().max(0.0) as u64;
+    if target > MAX_STEPS {
+        let bulk = (target - MAX_STEPS).div_ceil(largest as u64);
+        counts[0] = bulk as u32;
+        target = target.saturating_sub(bulk * largest as u64);
+    }
+
+    if target > 0 {
+        // An optimum buys less than `target + largest`, or a pack could be left out.
+        // `best[total]` is the fewest packs making exactly `total`, and the last one used.
+        let len = target as usize + largest;
+        let mut best: Vec<Option<(u32, usize)>> = vec![None; len];
+        best[0] = Some((0, 0));
+        for total in 1..len {
+            best[total] = sizes
+                .iter()
+                .enumerate()
+                .filter(|(_, size)| **size <= total)
+                .filter_map(|(index, size)| best[total - size].map(|(count, _)| (count + 1, index)))
+                .min_by_key(|(count, _)| *count);
+        }
+
+        // Multiples of the largest pack are always reachable
+        let mut total = (target as usize..len)
+            .find(|total| best[*total].is_some())
+            .unwrap_or(len - largest);
+        while let Some((_, index)) = best[total].filter(|_| total > 0) {
+            counts[index] += 1;
+            total -= sizes[index];
+        }
+    }
+
+    pack_counts(sorted, counts)
+}
+
+fn pack_counts(sorted: Vec<&IngredientPack>, counts: Vec<u32>) -> Vec<PackCount> {
+    sorted
+        .into_iter()
+        .zip(counts)
+        .filter(|(_, count)| *count > 0)
+        .map(|(pack, count)| PackCount {
+            size: pack.size,
+            label: pack.label.clone(),
+            count,
+        })
+        .collect()
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Factor that turns a quantity in `from` into `to`, for units of the same
+/// kind (mass, volume or pieces). `None` when the units cannot be converted.
+pub fn unit_factor(from: &str, to: &str) -> Option<f64> {
+    fn to_base(unit: &str) -> Option<(&'static str, f64)> {
+        Some(match unit.trim().to_lowercase().as_str() {
+            "mg" => ("g", 0.001),
+            "g" => ("g", 1.0),
+            "dkg" => ("g", 10.0),
+            "kg" => ("g", 1000.0),
+            "ml" => ("ml", 1.0),
+            "cl" => ("ml", 10.0),
+            "dl" => ("ml", 100.0),
+            "l" => ("ml", 1000.0),
+            "ks" | "pc" | "pcs" => ("pcs", 1.0),
+            _ => return None,
+        })
+    }
+
+    if from == to {
+        return Some(1.0);
+    }
+    let (from_base, from_factor) = to_base(from)?;
+    let (to_base_unit, to_factor) = to_base(to)?;
+    (from_base == to_base_unit).then(|| from_factor / to_factor)
+}
+
+/// Another name an ingredient is known by, e.g. what the shop calls it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct IngredientAlias {
+    pub id: i64,
+    pub ingredient_id: i64,
+    pub alias: String,
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack(size: f64) -> IngredientPack {
+        IngredientPack {
+            id: 0,
+            ingredient_id: 1,
+            unit: "g".to_string(),
+            size,
+            label: None,
+            created_at: None,
+        }
+    }
+
+    fn counts(quantity: f64, sizes: &[f64]) -> Vec<(f64, u32)> {
+        let packs: Vec<IngredientPack> = sizes.iter().map(|size| pack(*size)).collect();
+        round_up_to_packs(quantity, &packs)
+            .into_iter()
+            .map(|p| (p.size, p.count))
+            .collect()
+    }
+
+    #[test]
+    fn one_large_pack_beats_many_small_ones() {
+        assert_eq!(counts(950.0, &[1000.0, 100.0]), vec![(1000.0, 1)]);
+    }
+
+    #[test]
+    fn least_overbuy_wins_over_greedy() {
+        assert_eq!(counts(7.0, &[10.0, 6.0]), vec![(10.0, 1)]);
+        assert_eq!(counts(12.0, &[10.0, 6.0]), vec![(6.0, 2)]);
+    }
+
+    #[test]
+    fn fewest_packs_for_the_same_quantity() {
+        assert_eq!(counts(1000.0, &[1000.0, 500.0, 100.0]), vec![(1000.0, 1)]);
+        assert_eq!(counts(1600.0, &[1000.0, 500.0, 100.0]), vec![(1000.0, 1), (500.0, 1), (100.0, 1)]);
+    }
+
+    #[test]
+    fn exact_and_fractional_sizes() {
+        assert_eq!(counts(2.0, &[0.5]), vec![(0.5, 4)]);
+        assert_eq!(counts(0.3, &[0.25, 0.1]), vec![(0.1, 3)]);
+    }
+
+    #[test]
+    fn large_quantities_use_the_largest_pack_first() {
+        assert_eq!(counts(250_000.0, &[1000.0, 100.0]), vec![(1000.0, 250)]);
+        assert_eq!(counts(250_050.0, &[1000.0, 100.0]), vec![(1000.0, 250), (100.0, 1)]);
+        assert_eq!(counts(1_000_001.0, &[10.0, 6.0]), vec![(10.0, 99_999), (6.0, 2)]);
+    }
+
+    #[test]
+    fn very_fine_pack_sizes_are_rounded_greedily() {
+        assert_eq!(counts(400_000.0, &[200_000.0, 0.003]), vec![(200_000.0, 2)]);
+        assert_eq!(counts(200_000.001, &[200_000.0, 0.003]), vec![(200_000.0, 1), (0.003, 1)]);
+    }
+
+    #[test]
+    fn nothing_to_buy_or_no_packs() {
+        assert!(counts(0.0, &[1000.0]).is_empty());
+        assert!(counts(500.0, &[]).is_empty());
+    }
+
+    #[test]
+    fn converts_between_units_of_one_kind() {
+        assert_eq!(unit_factor("kg", "g"), Some(1000.0));
+        assert_eq!(unit_factor("ml", "l"), Some(0.001));
+        assert_eq!(unit_factor("g", "ml"), None);
+    }
 }
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use super::Ingredient;
+
+/// Ingredient together with how much refers to it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IngredientUsage {
+    pub ingredient: Ingredient,
+    pub recipe_count: i64,
+    pub stock_count: i64,
+}
+
+/// Ingredients whose names suggest they are the same thing
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DuplicateGroup {
+    pub ingredients: Vec<IngredientUsage>,
+}
+
+/// A unit of a merged ingredient that has no known conversion to the target's unit
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RequiredConversion {
+    pub ingredient_id: i64,
+    pub ingredient_name: String,
+    pub unit: String,
+    pub target_unit: String,
+    /// Recipe lines and stock items using the unit
+    pub uses: i64,
+}
+
+/// `1 unit` of the source ingredient equals `factor` of the target's primary unit
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UnitFactor {
+    pub ingredient_id: i64,
+    pub unit: String,
+    pub factor: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct MergeResult {
+    pub merged: usize,
+    pub recipe_lines: u64,
+    pub stock_items: u64,
+    /// Names kept as aliases of the target
+    pub aliases: Vec<String>,
+}
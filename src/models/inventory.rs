@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct InventoryItem {
+    pub id: i64,
+    pub ingredient_id: i64,
+    /// Camp the stock belongs to; `None` means the shared storeroom
+    pub camp_id: Option<i64>,
+    pub storeroom: String,
+    pub quantity: f64,
+    pub unit: String,
+    pub notes: Option<String>,
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub created_at: Option<DateTime<Utc>>,
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct InventoryItemDetail {
+    #[serde(flatten)]
+    #[cfg_attr(feature = "ssr", sqlx(flatten))]
+    pub item: InventoryItem,
+    pub ingredient_name: String,
+    pub category_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateInventoryItem {
+    pub ingredient_id: i64,
+    pub camp_id: Option<i64>,
+    pub storeroom: Option<String>,
+    pub quantity: f64,
+    pub unit: String,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateInventoryItem {
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    pub storeroom: Option<String>,
+    /// `None` keeps the notes, an empty string clears them
+    pub notes: Option<String>,
+}
+
+/// One ingredient deducted from stock by a consume step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsumedItem {
+    pub ingredient_id: i64,
+    pub ingredient_name: String,
+    pub unit: String,
+    pub required: f64,
+    pub deducted: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsumptionResult {
+    pub camp_id: i64,
+    pub date: NaiveDate,
+    pub items: Vec<ConsumedItem>,
+}
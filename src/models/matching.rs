@@ -0,0 +1,92 @@
+/// Lowercase, strip diacritics and collapse whitespace, so that
+/// `Citróny`, `citrony` and ` citrony ` compare equal
+pub fn normalize_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.trim().chars().flat_map(char::to_lowercase) {
+        if c.is_whitespace() {
+            if !out.ends_with(' ') {
+                out.push(' ');
+            }
+            continue;
+        }
+        match strip_diacritic(c) {
+            Some(plain) => out.push_str(plain),
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+fn strip_diacritic(c: char) -> Option<&'static str> {
+    Some(match c {
+        'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' | 'ą' => "a",
+        'č' | 'ć' | 'ç' => "c",
+        'ď' => "d",
+        'é' | 'ě' | 'è' | 'ê' | 'ë' | 'ę' => "e",
+        'í' | 'ì' | 'î' | 'ï' => "i",
+        'ľ' | 'ĺ' | 'ł' => "l",
+        'ň' | 'ń' | 'ñ' => "n",
+        'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ő' | 'ø' => "o",
+        'ř' | 'ŕ' => "r",
+        'š' | 'ś' => "s",
+        'ť' => "t",
+        'ú' | 'ů' | 'ù' | 'û' | 'ü' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ž' | 'ź' | 'ż' => "z",
+        'ß' => "ss",
+        _ => return None,
+    })
+}
+
+/// Whether any of `texts` contains `query`, ignoring case and diacritics
+/// (`zeli` finds `Zelí čínské`)
+pub fn matches_search<'a>(query: &str, texts: impl IntoIterator<Item = &'a str>) -> bool {
+    let query = normalize_name(query);
+    query.is_empty() || texts.into_iter().any(|text| normalize_name(text).contains(&query))
+}
+
+/// Entries of a comma-separated list such as recipe tags or allergens:
+/// trimmed, lowercase, without empty or repeated entries
+pub fn split_list(text: &str) -> Vec<String> {
+    let mut entries: Vec<String> = Vec::new();
+    for entry in text.split(',').map(|e| e.trim().to_lowercase()) {
+        if !entry.is_empty() && !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+    entries
+}
+
+/// Levenshtein distance counted in characters
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Whether two ingredient names probably mean the same thing: equal after
+/// normalizing, or longer names a letter or two apart (`citron` / `citrony`)
+pub fn likely_same_name(a: &str, b: &str) -> bool {
+    let a = normalize_name(a);
+    let b = normalize_name(b);
+    if a == b {
+        return true;
+    }
+
+    let shorter = a.chars().count().min(b.chars().count());
+    if shorter < 4 || a.chars().next() != b.chars().next() {
+        return false;
+    }
+    edit_distance(&a, &b) <= (shorter / 6).max(1)
+}
@@ -56,7 +56,7 @@ pub struct CreatePlannedMeal {
     pub attendance: Option<CreateAttendance>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CreateAttendance {
     pub children: i32,
     pub teens: i32,
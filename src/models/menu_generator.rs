@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+use super::MealType;
+use crate::error::Message;
+
+/// Tag marking recipes served hot
+pub const HOT_TAG: &str = "hot";
+/// Tag marking recipes that keep the kitchen busy for a long time
+pub const COOK_INTENSIVE_TAG: &str = "cook-intensive";
+
+/// Which recipes may fill one meal slot
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MenuSlotRule {
+    pub meal_type: MealType,
+    /// A recipe needs at least one of these tags; empty allows any recipe
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Only recipes tagged `hot`
+    #[serde(default)]
+    pub hot: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MenuGeneratorSettings {
+    /// Meal slots to fill; slots that already have a meal are kept
+    pub slots: Vec<MenuSlotRule>,
+    /// A recipe is not used again within this many days
+    #[serde(default)]
+    pub no_repeat_days: u32,
+    /// Maximum cost of one adult serving of all meals of a day; recipes
+    /// without a complete price are left out when set
+    #[serde(default)]
+    pub max_cost_per_day: Option<f64>,
+    /// Allergens none of the recipes may contain
+    #[serde(default)]
+    pub excluded_allergens: Vec<String>,
+    /// Maximum number of recipes tagged `cook-intensive` per day
+    #[serde(default)]
+    pub max_cook_intensive_per_day: Option<u32>,
+    /// The same seed and data always give the same menu
+    #[serde(default)]
+    pub seed: u64,
+}
+
+impl Default for MenuGeneratorSettings {
+    fn default() -> Self {
+        let slot = |meal_type, tag: &str, hot| MenuSlotRule { meal_type, tags: vec![tag.to_string()], hot };
+        Self {
+            slots: vec![
+                slot(MealType::Breakfast, "breakfast", false),
+                slot(MealType::MorningSnack, "snack", false),
+                slot(MealType::Lunch, "lunch", true),
+                slot(MealType::AfternoonSnack, "snack", false),
+                slot(MealType::Dinner, "dinner", true),
+            ],
+            no_repeat_days: 3,
+            max_cost_per_day: None,
+            excluded_allergens: Vec::new(),
+            max_cook_intensive_per_day: Some(1),
+            seed: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GeneratedMeal {
+    pub meal_type: MealType,
+    pub recipe_id: i64,
+    pub recipe_name: String,
+    pub cost_per_serving: Option<f64>,
+}
+
+/// Slot the generator found no recipe for
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UnfilledSlot {
+    pub meal_type: MealType,
+    pub reason: Message,
+}
+
+/// Proposed meals for the empty slots of one day
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GeneratedDay {
+    pub date: NaiveDate,
+    pub meals: Vec<GeneratedMeal>,
+    pub unfilled: Vec<UnfilledSlot>,
+    /// Cost of one adult serving of all meals of the day, planned ones included;
+    /// `None` when a recipe has no price
+    pub cost_per_serving: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GeneratedMenu {
+    pub camp_id: i64,
+    pub seed: u64,
+    /// Days with at least one empty slot
+    pub days: Vec<GeneratedDay>,
+}
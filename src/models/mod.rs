@@ -4,6 +4,21 @@ pub mod recipe;
 pub mod camp;
 pub mod meal_plan;
 pub mod reports;
+pub mod inventory;
+pub mod supplier;
+pub mod transfer;
+pub mod yaml_import;
+pub mod recipe_formats;
+pub mod matching;
+pub mod ingredient_merge;
+pub mod recipe_search;
+pub mod stock_finder;
+pub mod menu_generator;
+pub mod plan_check;
+pub mod api_token;
+pub mod validation;
+pub mod usage;
+pub mod trash;
 
 pub use category::*;
 pub use ingredient::*;
@@ -11,6 +26,21 @@ pub use recipe::*;
 pub use camp::*;
 pub use meal_plan::*;
 pub use reports::*;
+pub use inventory::*;
+pub use supplier::*;
+pub use transfer::*;
+pub use yaml_import::*;
+pub use recipe_formats::*;
+pub use matching::*;
+pub use ingredient_merge::*;
+pub use recipe_search::*;
+pub use stock_finder::*;
+pub use menu_generator::*;
+pub use plan_check::*;
+pub use api_token::*;
+pub use validation::*;
+pub use usage::*;
+pub use trash::*;
 
 use serde::{Deserialize, Serialize};
 
@@ -46,6 +76,37 @@ impl MealType {
         }
     }
 
+    pub fn label(&self) -> &'static str {
+        match self {
+            MealType::Breakfast => "Breakfast",
+            MealType::MorningSnack => "Morning Snack",
+            MealType::Lunch => "Lunch",
+            MealType::AfternoonSnack => "Afternoon Snack",
+            MealType::Dinner => "Dinner",
+        }
+    }
+
+    /// Key of the label in `locales/*.ftl`
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            MealType::Breakfast => "meal-breakfast",
+            MealType::MorningSnack => "meal-morning-snack",
+            MealType::Lunch => "meal-lunch",
+            MealType::AfternoonSnack => "meal-afternoon-snack",
+            MealType::Dinner => "meal-dinner",
+        }
+    }
+
+    pub fn all() -> [MealType; 5] {
+        [
+            MealType::Breakfast,
+            MealType::MorningSnack,
+            MealType::Lunch,
+            MealType::AfternoonSnack,
+            MealType::Dinner,
+        ]
+    }
+
     pub fn sort_order(&self) -> u8 {
         match self {
             MealType::Breakfast => 1,
@@ -55,4 +116,22 @@ impl MealType {
             MealType::Dinner => 5,
         }
     }
+
+    /// Usual start of the meal slot as `(hour, minute)`
+    pub fn start_time(&self) -> (u32, u32) {
+        match self {
+            MealType::Breakfast => (8, 0),
+            MealType::MorningSnack => (10, 0),
+            MealType::Lunch => (12, 30),
+            MealType::AfternoonSnack => (15, 30),
+            MealType::Dinner => (18, 30),
+        }
+    }
+
+    pub fn duration_minutes(&self) -> i64 {
+        match self {
+            MealType::MorningSnack | MealType::AfternoonSnack => 30,
+            _ => 60,
+        }
+    }
 }
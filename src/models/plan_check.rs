@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+use super::MealType;
+use crate::error::Message;
+
+/// Words in a category name (lowercase, without diacritics) that mark it as
+/// vegetables or fruit
+pub const PRODUCE_CATEGORY_WORDS: [&str; 4] = ["vegetable", "fruit", "zelenin", "ovoc"];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanWarningKind {
+    /// The same recipe at two meals in a row
+    RepeatedRecipe,
+    /// No ingredient of the day is from a vegetable or fruit category
+    NoProduce,
+    /// A meal without its own attendance
+    MissingAttendance,
+    /// Meal slots of a camp day without a meal
+    EmptySlots,
+    /// A planned recipe without ingredients
+    EmptyRecipe,
+}
+
+impl PlanWarningKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PlanWarningKind::RepeatedRecipe => "Repeated recipe",
+            PlanWarningKind::NoProduce => "No vegetables or fruit",
+            PlanWarningKind::MissingAttendance => "Missing attendance",
+            PlanWarningKind::EmptySlots => "Empty slots",
+            PlanWarningKind::EmptyRecipe => "Recipe without ingredients",
+        }
+    }
+
+    /// Key of the label in `locales/*.ftl`
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            PlanWarningKind::RepeatedRecipe => "plan-warning-repeated-recipe",
+            PlanWarningKind::NoProduce => "plan-warning-no-produce",
+            PlanWarningKind::MissingAttendance => "plan-warning-missing-attendance",
+            PlanWarningKind::EmptySlots => "plan-warning-empty-slots",
+            PlanWarningKind::EmptyRecipe => "plan-warning-empty-recipe",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlanWarning {
+    pub kind: PlanWarningKind,
+    pub date: NaiveDate,
+    /// The meal the warning is about; `None` for the whole day
+    pub meal_type: Option<MealType>,
+    pub message: Message,
+}
+
+/// Result of checking the plan of one camp
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlanCheck {
+    pub camp_id: i64,
+    /// Ordered by date and meal
+    pub warnings: Vec<PlanWarning>,
+}
+
+impl PlanCheck {
+    pub fn count(&self, kind: PlanWarningKind) -> usize {
+        self.warnings.iter().filter(|warning| warning.kind == kind).count()
+    }
+
+    /// `(kind, count)` of each kind that occurs
+    pub fn summary(&self) -> Vec<(PlanWarningKind, usize)> {
+        [
+            PlanWarningKind::EmptySlots,
+            PlanWarningKind::RepeatedRecipe,
+            PlanWarningKind::NoProduce,
+            PlanWarningKind::MissingAttendance,
+            PlanWarningKind::EmptyRecipe,
+        ]
+        .into_iter()
+        .map(|kind| (kind, self.count(kind)))
+        .filter(|(_, count)| *count > 0)
+        .collect()
+    }
+}
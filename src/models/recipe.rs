@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use super::{split_list, CreateAttendance};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
@@ -8,12 +9,25 @@ pub struct Recipe {
     pub name: String,
     pub instructions: Option<String>,
     pub base_servings: i32,
+    /// Comma-separated tags, e.g. "hot, vegetarian"
+    #[serde(default)]
+    pub tags: String,
+    /// Set when the recipe was archived instead of deleted; archived ones are left out of the lists
+    #[serde(default)]
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub archived_at: Option<DateTime<Utc>>,
     #[cfg_attr(feature = "ssr", sqlx(default))]
     pub created_at: Option<DateTime<Utc>>,
     #[cfg_attr(feature = "ssr", sqlx(default))]
     pub updated_at: Option<DateTime<Utc>>,
 }
 
+impl Recipe {
+    pub fn tag_list(&self) -> Vec<String> {
+        split_list(&self.tags)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
 pub struct RecipeIngredient {
@@ -30,6 +44,17 @@ pub struct RecipeIngredient {
     pub created_at: Option<DateTime<Utc>>,
 }
 
+impl RecipeIngredient {
+    /// Quantity for the given headcount, scaled like the shopping reports:
+    /// each group counts with its multiplier, divided by the base servings
+    pub fn quantity_for(&self, headcount: &CreateAttendance, base_servings: i32) -> f64 {
+        let portions = self.child_multiplier.unwrap_or(1.0) * headcount.children as f64
+            + self.teen_multiplier.unwrap_or(1.0) * headcount.teens as f64
+            + self.adult_multiplier.unwrap_or(1.0) * headcount.adults as f64;
+        self.base_quantity * portions / base_servings.max(1) as f64
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecipeWithIngredients {
     #[serde(flatten)]
@@ -51,6 +76,8 @@ pub struct CreateRecipe {
     pub name: String,
     pub instructions: Option<String>,
     pub base_servings: i32,
+    #[serde(default)]
+    pub tags: String,
     pub ingredients: Vec<CreateRecipeIngredient>,
 }
 
@@ -70,5 +97,6 @@ pub struct UpdateRecipe {
     pub name: Option<String>,
     pub instructions: Option<String>,
     pub base_servings: Option<i32>,
+    pub tags: Option<String>,
     pub ingredients: Option<Vec<CreateRecipeIngredient>>,
 }
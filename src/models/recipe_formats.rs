@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+/// Recipe exchange formats used by other tools
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipeFormat {
+    /// schema.org `Recipe` as JSON-LD
+    JsonLd,
+    /// Cooklang `.cook` text
+    Cooklang,
+}
+
+impl RecipeFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecipeFormat::JsonLd => "jsonld",
+            RecipeFormat::Cooklang => "cooklang",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            RecipeFormat::JsonLd => "jsonld",
+            RecipeFormat::Cooklang => "cook",
+        }
+    }
+}
+
+impl std::str::FromStr for RecipeFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "jsonld" => Ok(RecipeFormat::JsonLd),
+            "cooklang" => Ok(RecipeFormat::Cooklang),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Recipe read from an exchange format, before ingredients are matched
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ParsedRecipe {
+    pub name: String,
+    pub servings: i32,
+    /// Comma-separated, from JSON-LD `keywords` or Cooklang `>> tags:`
+    #[serde(default)]
+    pub tags: String,
+    pub instructions: Option<String>,
+    pub ingredients: Vec<ParsedIngredient>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ParsedIngredient {
+    pub name: String,
+    pub quantity: Option<f64>,
+    pub unit: Option<String>,
+    /// The line as written in the source, shown when resolving
+    pub raw: String,
+}
+
+/// Parsed recipe with the existing ingredient each line matched, if any
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecipeImportPreview {
+    pub recipe: ParsedRecipe,
+    pub matches: Vec<Option<i64>>,
+}
+
+/// What to do with one ingredient line of an imported recipe
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IngredientResolution {
+    /// Use this existing ingredient
+    Existing(i64),
+    /// Create a new ingredient named as in the recipe
+    Create,
+    /// Leave the line out
+    Skip,
+}
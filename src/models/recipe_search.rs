@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use super::Recipe;
+
+pub const RECIPE_SEARCH_PAGE_SIZE: u32 = 24;
+/// Larger `per_page` values are cut down to this
+pub const RECIPE_SEARCH_MAX_PAGE_SIZE: u32 = 200;
+
+/// Text and filters of a recipe search
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RecipeSearchQuery {
+    /// Words looked up in name, instructions, tags and ingredient names,
+    /// ignoring case and diacritics; empty lists all recipes
+    #[serde(default)]
+    pub text: String,
+    /// Recipes must use all of these ingredients
+    #[serde(default)]
+    pub with_ingredients: Vec<i64>,
+    /// Recipes must use none of these ingredients
+    #[serde(default)]
+    pub without_ingredients: Vec<i64>,
+    /// Maximum cost of one adult serving; recipes without a complete price are left out
+    #[serde(default)]
+    pub max_cost_per_serving: Option<f64>,
+    /// Allergens none of the recipe's ingredients may contain
+    #[serde(default)]
+    pub free_of_allergens: Vec<String>,
+    /// Page number starting at 1
+    #[serde(default)]
+    pub page: u32,
+    /// Results per page; 0 uses `RECIPE_SEARCH_PAGE_SIZE`, at most `RECIPE_SEARCH_MAX_PAGE_SIZE`
+    #[serde(default)]
+    pub per_page: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecipeSearchHit {
+    pub recipe: Recipe,
+    /// Cost of one adult serving; `None` when an ingredient has no price
+    /// or its unit cannot be converted to the priced unit
+    pub cost_per_serving: Option<f64>,
+    pub allergens: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecipeSearchPage {
+    pub hits: Vec<RecipeSearchHit>,
+    /// Matching recipes on all pages
+    pub total: u32,
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl RecipeSearchPage {
+    pub fn page_count(&self) -> u32 {
+        self.total.div_ceil(self.per_page.max(1)).max(1)
+    }
+}
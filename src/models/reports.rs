@@ -1,5 +1,6 @@
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use super::{round_up_to_packs, IngredientPack, PackCount};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShoppingListItem {
@@ -8,6 +9,37 @@ pub struct ShoppingListItem {
     pub category_name: String,
     pub total_quantity: f64,
     pub unit: String,
+    /// Extra quantity from the ingredient's safety margin
+    #[serde(default)]
+    pub safety_margin: f64,
+    /// Quantity already on hand in the camp or shared storeroom
+    #[serde(default)]
+    pub in_stock: f64,
+    /// Quantity that still has to be bought (`total_quantity + safety_margin - in_stock`)
+    #[serde(default)]
+    pub to_buy: f64,
+    /// Whole packs covering `to_buy`; empty when the ingredient has no pack sizes
+    #[serde(default)]
+    pub packs: Vec<PackCount>,
+    /// Quantity actually bought after rounding up to packs
+    #[serde(default)]
+    pub purchase_quantity: f64,
+    /// Quantity left over after the camp (`purchase_quantity - to_buy`)
+    #[serde(default)]
+    pub leftover: f64,
+}
+
+impl ShoppingListItem {
+    /// Round `to_buy` up to the given pack sizes and fill in the purchase quantity
+    pub fn apply_packs(&mut self, packs: &[IngredientPack]) {
+        self.packs = round_up_to_packs(self.to_buy, packs);
+        self.purchase_quantity = if self.packs.is_empty() {
+            self.to_buy
+        } else {
+            self.packs.iter().map(|p| p.size * p.count as f64).sum()
+        };
+        self.leftover = (self.purchase_quantity - self.to_buy).max(0.0);
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,3 +61,32 @@ pub struct AttendanceSummary {
     pub adults: i32,
     pub total_people: i32,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShoppingTripItem {
+    pub ingredient_id: i64,
+    pub ingredient_name: String,
+    pub category_name: String,
+    pub storage_type: String,
+    pub quantity: f64,
+    pub unit: String,
+    /// First and last day the bought quantity is used
+    pub first_needed: NaiveDate,
+    pub last_needed: NaiveDate,
+    pub shelf_life_days: Option<i32>,
+    /// The last day of use is further from the trip than the shelf life allows
+    pub exceeds_shelf_life: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShoppingTrip {
+    pub date: NaiveDate,
+    pub items: Vec<ShoppingTripItem>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ShoppingTripPlan {
+    pub trips: Vec<ShoppingTrip>,
+    /// Needed before the first planned trip
+    pub unassigned: Vec<ShoppingTripItem>,
+}
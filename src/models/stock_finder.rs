@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use super::{CreateAttendance, Recipe};
+
+/// An amount of an ingredient we have left
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StockEntry {
+    pub ingredient_id: i64,
+    pub quantity: f64,
+    pub unit: String,
+}
+
+/// Where the available ingredients come from
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StockSource {
+    /// Stock in the inventory: the shared storeroom, plus the camp's own stock when given
+    Inventory { camp_id: Option<i64> },
+    /// A list entered by hand, e.g. what is left at the end of a camp
+    Items(Vec<StockEntry>),
+}
+
+/// Ingredient a recipe needs more of than is available
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissingIngredient {
+    pub ingredient_id: i64,
+    pub ingredient_name: String,
+    pub unit: String,
+    pub needed: f64,
+    pub available: f64,
+    pub missing: f64,
+}
+
+/// A recipe and how much of it the available stock covers
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StockMatch {
+    pub recipe: Recipe,
+    /// Average share of each ingredient line that is available, 0.0 to 1.0
+    pub coverage: f64,
+    pub covered_lines: usize,
+    pub total_lines: usize,
+    pub missing: Vec<MissingIngredient>,
+}
+
+impl StockMatch {
+    pub fn coverage_percent(&self) -> u32 {
+        (self.coverage * 100.0).floor() as u32
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StockFinderRequest {
+    pub source: StockSource,
+    /// Headcount the recipe quantities are scaled to
+    pub headcount: CreateAttendance,
+    /// Only recipes covering at least this share (0.0 to 1.0)
+    #[serde(default)]
+    pub min_coverage: f64,
+    /// Maximum number of recipes returned; 0 returns all
+    #[serde(default)]
+    pub limit: usize,
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Datelike, NaiveDate, Utc, Weekday};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct Supplier {
+    pub id: i64,
+    pub name: String,
+    pub contact: Option<String>,
+    pub lead_time_days: i32,
+    /// Comma-separated weekdays (`mon,wed,fri`); empty means every day
+    pub delivery_days: String,
+    pub deliver_upfront: bool,
+    pub notes: Option<String>,
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub created_at: Option<DateTime<Utc>>,
+    #[cfg_attr(feature = "ssr", sqlx(default))]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Supplier {
+    pub fn delivery_weekdays(&self) -> Vec<Weekday> {
+        parse_delivery_days(&self.delivery_days)
+    }
+
+    /// Latest day on or before `needed_on` the supplier can deliver
+    pub fn delivery_date_for(&self, needed_on: NaiveDate) -> NaiveDate {
+        let weekdays = self.delivery_weekdays();
+        if weekdays.is_empty() {
+            return needed_on;
+        }
+        (0..7)
+            .map(|offset| needed_on - chrono::Duration::days(offset))
+            .find(|date| weekdays.contains(&date.weekday()))
+            .unwrap_or(needed_on)
+    }
+
+    /// Last day the order has to be placed to arrive on `delivery_date`
+    pub fn order_by(&self, delivery_date: NaiveDate) -> NaiveDate {
+        delivery_date - chrono::Duration::days(self.lead_time_days.max(0) as i64)
+    }
+}
+
+pub fn parse_delivery_days(value: &str) -> Vec<Weekday> {
+    value
+        .split(',')
+        .filter_map(|day| day.trim().parse::<Weekday>().ok())
+        .collect()
+}
+
+pub fn format_delivery_days(days: &[Weekday]) -> String {
+    days.iter()
+        .map(|day| day.to_string().to_lowercase())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SupplierWithAssignments {
+    #[serde(flatten)]
+    pub supplier: Supplier,
+    pub category_ids: Vec<i64>,
+    pub ingredient_ids: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSupplier {
+    pub name: String,
+    pub contact: Option<String>,
+    pub lead_time_days: i32,
+    pub delivery_days: String,
+    pub deliver_upfront: bool,
+    pub notes: Option<String>,
+    pub category_ids: Vec<i64>,
+    pub ingredient_ids: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateSupplier {
+    pub name: Option<String>,
+    /// `None` keeps the contact, an empty string clears it
+    pub contact: Option<String>,
+    pub lead_time_days: Option<i32>,
+    pub delivery_days: Option<String>,
+    pub deliver_upfront: Option<bool>,
+    /// `None` keeps the notes, an empty string clears them
+    pub notes: Option<String>,
+    pub category_ids: Option<Vec<i64>>,
+    pub ingredient_ids: Option<Vec<i64>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PurchaseOrderLine {
+    pub ingredient_id: i64,
+    pub ingredient_name: String,
+    pub category_name: String,
+    pub quantity: f64,
+    pub unit: String,
+    /// First day the ingredient is used
+    pub needed_on: NaiveDate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PurchaseOrder {
+    /// `None` groups ingredients no supplier delivers
+    pub supplier_id: Option<i64>,
+    pub supplier_name: String,
+    pub delivery_date: NaiveDate,
+    pub order_by: NaiveDate,
+    pub lines: Vec<PurchaseOrderLine>,
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use super::{
+    Category, CreateAttendance, Ingredient, IngredientPack, Recipe, RecipeIngredient,
+    SupplierWithAssignments,
+};
+
+/// Bumped whenever the dump layout changes in a way older importers cannot read.
+///
+/// Version 2 added pack sizes, suppliers, stock and consumed days; version 1
+/// dumps still load with those empty.
+pub const DATA_EXPORT_VERSION: u32 = 2;
+
+/// Dump of all planning data, used to move data between instances.
+///
+/// IDs are the ones of the exporting instance; the importer remaps them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataExport {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub categories: Vec<Category>,
+    pub ingredients: Vec<Ingredient>,
+    pub recipes: Vec<ExportRecipe>,
+    pub camps: Vec<ExportCamp>,
+    #[serde(default)]
+    pub ingredient_packs: Vec<IngredientPack>,
+    #[serde(default)]
+    pub suppliers: Vec<SupplierWithAssignments>,
+    /// Stock in the shared storeroom; camp stock is exported with its camp
+    #[serde(default)]
+    pub inventory: Vec<ExportInventoryItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportRecipe {
+    #[serde(flatten)]
+    pub recipe: Recipe,
+    pub ingredients: Vec<RecipeIngredient>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportCamp {
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub default_children: i32,
+    pub default_teens: i32,
+    pub default_adults: i32,
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub archived_at: Option<DateTime<Utc>>,
+    pub meals: Vec<ExportPlannedMeal>,
+    #[serde(default)]
+    pub inventory: Vec<ExportInventoryItem>,
+    /// Days whose meals were already deducted from stock
+    #[serde(default)]
+    pub consumed_days: Vec<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportPlannedMeal {
+    pub date: NaiveDate,
+    pub meal_type: String,
+    pub recipe_id: i64,
+    pub attendance: Option<CreateAttendance>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct ExportInventoryItem {
+    pub ingredient_id: i64,
+    #[serde(default)]
+    pub storeroom: String,
+    pub quantity: f64,
+    pub unit: String,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportMode {
+    /// Keep existing data; reuse categories, ingredients, recipes and camps with the same name
+    Merge,
+    /// Delete all existing planning data, with its stock, before importing
+    Replace,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ImportReport {
+    /// Nothing was written; the counts show what an import would do
+    pub dry_run: bool,
+    pub categories_created: usize,
+    pub categories_matched: usize,
+    pub ingredients_created: usize,
+    pub ingredients_matched: usize,
+    pub recipes_created: usize,
+    pub recipes_matched: usize,
+    pub camps_created: usize,
+    pub camps_matched: usize,
+    pub meals_created: usize,
+    #[serde(default)]
+    pub suppliers_created: usize,
+    #[serde(default)]
+    pub suppliers_matched: usize,
+    /// Stock items and pack sizes are only imported for new ingredients and camps
+    #[serde(default)]
+    pub stock_items_created: usize,
+    #[serde(default)]
+    pub packs_created: usize,
+    pub warnings: Vec<String>,
+    /// Validation errors; when present nothing was imported
+    pub errors: Vec<String>,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use super::{Camp, Category, Ingredient, Recipe};
+
+/// Everything archived, shown in the trash where it can be restored
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Trash {
+    pub camps: Vec<Camp>,
+    pub categories: Vec<Category>,
+    pub ingredients: Vec<Ingredient>,
+    pub recipes: Vec<Recipe>,
+}
+
+impl Trash {
+    pub fn is_empty(&self) -> bool {
+        self.camps.is_empty() && self.categories.is_empty() && self.ingredients.is_empty() && self.recipes.is_empty()
+    }
+}
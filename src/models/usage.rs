@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+use super::{Ingredient, Recipe};
+
+/// Recipe line that uses an ingredient
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct RecipeLineUsage {
+    pub recipe_id: i64,
+    pub recipe_name: String,
+    pub base_quantity: f64,
+    pub unit: String,
+}
+
+/// Where an ingredient is used, shown before deleting it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IngredientUsageReport {
+    pub ingredient: Ingredient,
+    /// Recipe lines; these keep the ingredient from being deleted
+    pub recipes: Vec<RecipeLineUsage>,
+    /// Stock items, deleted together with the ingredient
+    pub stock_items: i64,
+}
+
+impl IngredientUsageReport {
+    pub fn in_use(&self) -> bool {
+        !self.recipes.is_empty()
+    }
+}
+
+/// Planned meal that serves a recipe
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct PlannedMealUsage {
+    pub planned_meal_id: i64,
+    pub camp_id: i64,
+    pub camp_name: String,
+    pub date: NaiveDate,
+    pub meal_type: String,
+}
+
+/// Where a recipe is used, shown before deleting it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecipeUsageReport {
+    pub recipe: Recipe,
+    /// Planned meals; these keep the recipe from being deleted
+    pub meals: Vec<PlannedMealUsage>,
+}
+
+impl RecipeUsageReport {
+    pub fn in_use(&self) -> bool {
+        !self.meals.is_empty()
+    }
+}
@@ -0,0 +1,417 @@
+//! Rules for the create/update payloads.
+//!
+//! The `api` functions check them before writing, and the forms run the same
+//! rules before sending (see `components/ui/form.rs`), so both sides report
+//! the same [`FieldError`]s.
+
+use chrono::NaiveDate;
+
+use super::{
+    Camp, CreateAttendance, CreateCamp, CreateCategory, CreateIngredient, CreateInventoryItem,
+    CreatePlannedMeal, CreateRecipe, CreateRecipeIngredient, CreateSupplier, StorageType,
+    UpdateCategory, UpdateIngredient, UpdateInventoryItem, UpdateRecipe, UpdateSupplier,
+};
+use crate::error::{AppError, FieldError};
+
+pub trait Validate {
+    /// Every broken rule, empty when the payload is valid
+    fn validation_errors(&self) -> Vec<FieldError>;
+
+    fn validate(&self) -> Result<(), AppError> {
+        check(self.validation_errors())
+    }
+}
+
+/// `Ok` without errors, otherwise a validation error carrying all of them
+pub fn check(errors: Vec<FieldError>) -> Result<(), AppError> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::Validation(errors))
+    }
+}
+
+pub fn require_name(field: &str, value: &str) -> Option<FieldError> {
+    value.trim().is_empty().then(|| FieldError::new(field, "validation-name-required"))
+}
+
+pub fn require_unit(field: &str, value: &str) -> Option<FieldError> {
+    value.trim().is_empty().then(|| FieldError::new(field, "validation-unit-required"))
+}
+
+pub fn require_non_negative<T: PartialOrd + Default>(field: &str, value: T, key: &str) -> Option<FieldError> {
+    (value < T::default()).then(|| FieldError::new(field, key))
+}
+
+pub fn require_positive<T: PartialOrd + Default>(field: &str, value: T, key: &str) -> Option<FieldError> {
+    (value <= T::default()).then(|| FieldError::new(field, key))
+}
+
+/// The end must come after the start; reported on `end_date`
+pub fn require_date_range(start_date: NaiveDate, end_date: NaiveDate) -> Option<FieldError> {
+    (start_date >= end_date).then(|| FieldError::new("end_date", "validation-end-before-start"))
+}
+
+/// Meals can only be planned between the first and the last day of the camp
+pub fn require_date_in_camp(field: &str, date: NaiveDate, camp: &Camp) -> Option<FieldError> {
+    (date < camp.start_date || date > camp.end_date).then(|| {
+        FieldError::new(field, "validation-date-outside-camp")
+            .with_arg("start", camp.start_date.format("%Y-%m-%d"))
+            .with_arg("end", camp.end_date.format("%Y-%m-%d"))
+    })
+}
+
+fn storage_errors(shelf_life_days: Option<i32>, storage_type: Option<&str>) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+    if shelf_life_days.is_some_and(|days| days < 0) {
+        errors.push(FieldError::new("shelf_life_days", "validation-shelf-life-negative"));
+    }
+    if let Some(storage_type) = storage_type
+        && storage_type.parse::<StorageType>().is_err()
+    {
+        errors.push(
+            FieldError::new("storage_type", "validation-storage-type-unknown").with_arg("value", storage_type),
+        );
+    }
+    errors
+}
+
+impl Validate for CreateCamp {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        [
+            require_name("name", &self.name),
+            require_date_range(self.start_date, self.end_date),
+            require_non_negative("default_children", self.default_children, "validation-children-negative"),
+            require_non_negative("default_teens", self.default_teens, "validation-teens-negative"),
+            require_non_negative("default_adults", self.default_adults, "validation-adults-negative"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Validate for CreateAttendance {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        [
+            require_non_negative("children", self.children, "validation-children-negative"),
+            require_non_negative("teens", self.teens, "validation-teens-negative"),
+            require_non_negative("adults", self.adults, "validation-adults-negative"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Checks the attendance only; the date is checked against the camp with
+/// [`require_date_in_camp`]
+impl Validate for CreatePlannedMeal {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        self.attendance.as_ref().map(Validate::validation_errors).unwrap_or_default()
+    }
+}
+
+impl Validate for CreateRecipeIngredient {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        let multipliers = [
+            ("child_multiplier", self.child_multiplier),
+            ("teen_multiplier", self.teen_multiplier),
+            ("adult_multiplier", self.adult_multiplier),
+        ];
+
+        std::iter::once(require_positive("base_quantity", self.base_quantity, "validation-quantity-positive"))
+            .chain(multipliers.into_iter().map(|(field, multiplier)| {
+                require_non_negative(field, multiplier.unwrap_or_default(), "validation-multiplier-negative")
+            }))
+            .flatten()
+            .collect()
+    }
+}
+
+/// Errors of the ingredient lines, reported once per rule on `ingredients`
+fn ingredient_errors(ingredients: &[CreateRecipeIngredient]) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+    for error in ingredients.iter().flat_map(Validate::validation_errors) {
+        let error = FieldError { field: "ingredients".to_string(), ..error };
+        if !errors.contains(&error) {
+            errors.push(error);
+        }
+    }
+    errors
+}
+
+impl Validate for CreateRecipe {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        [
+            require_name("name", &self.name),
+            require_positive("base_servings", self.base_servings, "validation-servings-positive"),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(ingredient_errors(&self.ingredients))
+        .collect()
+    }
+}
+
+impl Validate for UpdateRecipe {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        [
+            self.name.as_deref().and_then(|name| require_name("name", name)),
+            self.base_servings
+                .and_then(|servings| require_positive("base_servings", servings, "validation-servings-positive")),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.ingredients.as_deref().map(ingredient_errors).unwrap_or_default())
+        .collect()
+    }
+}
+
+impl Validate for CreateCategory {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        require_name("name", &self.name).into_iter().collect()
+    }
+}
+
+impl Validate for UpdateCategory {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        self.name.as_deref().and_then(|name| require_name("name", name)).into_iter().collect()
+    }
+}
+
+impl Validate for CreateIngredient {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        [
+            require_name("name", &self.name),
+            require_unit("primary_unit", &self.primary_unit),
+            require_non_negative("safety_margin_percent", self.safety_margin_percent, "validation-margin-negative"),
+            require_non_negative("price_per_unit", self.price_per_unit.unwrap_or_default(), "validation-price-negative"),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(storage_errors(self.shelf_life_days, Some(&self.storage_type)))
+        .collect()
+    }
+}
+
+impl Validate for UpdateIngredient {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        [
+            self.name.as_deref().and_then(|name| require_name("name", name)),
+            self.primary_unit.as_deref().and_then(|unit| require_unit("primary_unit", unit)),
+            self.safety_margin_percent
+                .and_then(|margin| require_non_negative("safety_margin_percent", margin, "validation-margin-negative")),
+            self.price_per_unit
+                .and_then(|price| require_non_negative("price_per_unit", price, "validation-price-negative")),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(storage_errors(self.shelf_life_days, self.storage_type.as_deref()))
+        .collect()
+    }
+}
+
+impl Validate for CreateSupplier {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        [
+            require_name("name", &self.name),
+            require_non_negative("lead_time_days", self.lead_time_days, "validation-lead-time-negative"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Validate for UpdateSupplier {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        [
+            self.name.as_deref().and_then(|name| require_name("name", name)),
+            self.lead_time_days
+                .and_then(|days| require_non_negative("lead_time_days", days, "validation-lead-time-negative")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Validate for CreateInventoryItem {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        [
+            require_positive("ingredient_id", self.ingredient_id, "validation-select-ingredient"),
+            require_non_negative("quantity", self.quantity, "validation-quantity-negative"),
+            require_unit("unit", &self.unit),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Validate for UpdateInventoryItem {
+    fn validation_errors(&self) -> Vec<FieldError> {
+        [
+            self.quantity
+                .and_then(|quantity| require_non_negative("quantity", quantity, "validation-quantity-negative")),
+            self.unit.as_deref().and_then(|unit| require_unit("unit", unit)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `(field, key)` of every broken rule
+    fn broken(payload: &impl Validate) -> Vec<(String, String)> {
+        payload
+            .validation_errors()
+            .into_iter()
+            .map(|error| (error.field, error.message.key))
+            .collect()
+    }
+
+    fn camp(start: u32, end: u32, children: i32) -> CreateCamp {
+        CreateCamp {
+            name: "Summer".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 7, start).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 7, end).unwrap(),
+            default_children: children,
+            default_teens: 0,
+            default_adults: 2,
+            notes: None,
+        }
+    }
+
+    fn line(base_quantity: f64, adult_multiplier: Option<f64>) -> CreateRecipeIngredient {
+        CreateRecipeIngredient {
+            ingredient_id: 1,
+            base_quantity,
+            unit: "g".to_string(),
+            child_multiplier: None,
+            teen_multiplier: None,
+            adult_multiplier,
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn a_valid_camp_passes() {
+        assert!(camp(1, 7, 10).validate().is_ok());
+    }
+
+    #[test]
+    fn camp_dates_and_headcounts_are_checked() {
+        assert_eq!(
+            broken(&camp(7, 7, -1)),
+            vec![
+                ("end_date".to_string(), "validation-end-before-start".to_string()),
+                ("default_children".to_string(), "validation-children-negative".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn blank_names_are_rejected() {
+        let category = CreateCategory { name: "  ".to_string(), sort_order: 0 };
+        assert_eq!(broken(&category), vec![("name".to_string(), "validation-name-required".to_string())]);
+
+        // Fields left out of an update are not checked
+        let update = UpdateCategory { name: None, sort_order: Some(3) };
+        assert!(broken(&update).is_empty());
+    }
+
+    #[test]
+    fn ingredient_lines_report_each_rule_once() {
+        let recipe = CreateRecipe {
+            name: "Goulash".to_string(),
+            instructions: None,
+            base_servings: 0,
+            tags: String::new(),
+            ingredients: vec![line(0.0, None), line(-1.0, Some(-0.5)), line(100.0, None)],
+        };
+        assert_eq!(
+            broken(&recipe),
+            vec![
+                ("base_servings".to_string(), "validation-servings-positive".to_string()),
+                ("ingredients".to_string(), "validation-quantity-positive".to_string()),
+                ("ingredients".to_string(), "validation-multiplier-negative".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn ingredients_need_a_unit_and_a_known_storage_type() {
+        let ingredient = CreateIngredient {
+            name: "Flour".to_string(),
+            category_id: 1,
+            primary_unit: String::new(),
+            secondary_unit: None,
+            safety_margin_percent: 5.0,
+            shelf_life_days: Some(-1),
+            storage_type: "cellar".to_string(),
+            price_per_unit: Some(-2.0),
+            allergens: String::new(),
+        };
+        assert_eq!(
+            broken(&ingredient),
+            vec![
+                ("primary_unit".to_string(), "validation-unit-required".to_string()),
+                ("price_per_unit".to_string(), "validation-price-negative".to_string()),
+                ("shelf_life_days".to_string(), "validation-shelf-life-negative".to_string()),
+                ("storage_type".to_string(), "validation-storage-type-unknown".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn stock_needs_an_ingredient_and_a_non_negative_quantity() {
+        let item = CreateInventoryItem {
+            ingredient_id: 0,
+            camp_id: None,
+            storeroom: None,
+            quantity: -1.0,
+            unit: "kg".to_string(),
+            notes: None,
+        };
+        assert_eq!(
+            broken(&item),
+            vec![
+                ("ingredient_id".to_string(), "validation-select-ingredient".to_string()),
+                ("quantity".to_string(), "validation-quantity-negative".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn planned_meal_dates_must_fall_within_the_camp() {
+        let camp = Camp {
+            id: 1,
+            name: "Summer".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 7, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 7, 7).unwrap(),
+            default_children: 0,
+            default_teens: 0,
+            default_adults: 0,
+            notes: None,
+            archived_at: None,
+            created_at: None,
+            updated_at: None,
+        };
+        let inside = NaiveDate::from_ymd_opt(2026, 7, 7).unwrap();
+        let outside = NaiveDate::from_ymd_opt(2026, 7, 8).unwrap();
+        assert!(require_date_in_camp("date", inside, &camp).is_none());
+
+        let error = require_date_in_camp("date", outside, &camp).unwrap();
+        assert_eq!(error.message.key, "validation-date-outside-camp");
+        assert_eq!(error.message.args, vec![
+            ("start".to_string(), "2026-07-01".to_string()),
+            ("end".to_string(), "2026-07-07".to_string()),
+        ]);
+    }
+}
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// How the source YAML files map onto this database.
+///
+/// The defaults match the Czech files in `source_data/`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportMapping {
+    /// Source ingredient `type` → category name
+    #[serde(default = "default_category_mapping")]
+    pub categories: HashMap<String, String>,
+    /// Category for unmapped types and auto-created ingredients
+    #[serde(default = "default_category")]
+    pub default_category: String,
+    #[serde(default)]
+    pub groups: GroupMapping,
+}
+
+/// Names of the portion groups in a recipe's `ingredients`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMapping {
+    /// Quantities for everyone
+    pub general: String,
+    pub child: String,
+    pub teen: String,
+    pub adult: String,
+}
+
+impl Default for ImportMapping {
+    fn default() -> Self {
+        Self {
+            categories: default_category_mapping(),
+            default_category: default_category(),
+            groups: GroupMapping::default(),
+        }
+    }
+}
+
+impl Default for GroupMapping {
+    fn default() -> Self {
+        Self {
+            general: "_general".to_string(),
+            child: "decko".to_string(),
+            teen: "pubos".to_string(),
+            adult: "dospelak".to_string(),
+        }
+    }
+}
+
+fn default_category_mapping() -> HashMap<String, String> {
+    [
+        ("ovoce zelenina", "Vegetables"),
+        ("pekarna", "Baking Goods"),
+        ("maso", "Meat & Fish"),
+    ]
+    .into_iter()
+    .map(|(kind, category)| (kind.to_string(), category.to_string()))
+    .collect()
+}
+
+fn default_category() -> String {
+    "Other".to_string()
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct YamlImportOptions {
+    /// Run everything but roll back at the end
+    pub dry_run: bool,
+    /// Overwrite existing ingredients and recipes that differ; otherwise they are left alone
+    pub update_existing: bool,
+    /// Create ingredients that recipes use but no file or database knows; otherwise skip those lines
+    pub auto_create: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStatus {
+    New,
+    Changed,
+    /// Differs in a way that affects existing data: an ingredient changing its unit
+    /// or a recipe that is already planned in a camp
+    Conflict,
+    Unchanged,
+}
+
+impl ImportStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ImportStatus::New => "New",
+            ImportStatus::Changed => "Changed",
+            ImportStatus::Conflict => "Conflict",
+            ImportStatus::Unchanged => "Unchanged",
+        }
+    }
+
+    /// Key of the label in `locales/*.ftl`
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            ImportStatus::New => "import-status-new",
+            ImportStatus::Changed => "import-status-changed",
+            ImportStatus::Conflict => "import-status-conflict",
+            ImportStatus::Unchanged => "import-status-unchanged",
+        }
+    }
+}
+
+/// What the import does (or would do) with one ingredient or recipe
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImportItem {
+    pub name: String,
+    pub status: ImportStatus,
+    /// Whether the change was applied; changed and conflicting items are only written with `update_existing`
+    pub applied: bool,
+    pub details: Vec<String>,
+}
+
+/// Ingredient line that could not be parsed as `<quantity> <unit>  <ingredient>`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SkippedLine {
+    pub recipe: String,
+    pub group: String,
+    pub line: String,
+    pub reason: String,
+}
+
+/// Recipe line using a unit the ingredient is not kept in
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UnitMismatch {
+    pub recipe: String,
+    pub ingredient: String,
+    pub recipe_unit: String,
+    pub ingredient_unit: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct YamlImportReport {
+    pub dry_run: bool,
+    pub ingredients: Vec<ImportItem>,
+    pub recipes: Vec<ImportItem>,
+    pub auto_created: Vec<String>,
+    pub skipped_lines: Vec<SkippedLine>,
+    pub unit_mismatches: Vec<UnitMismatch>,
+    /// Ingredient types without a category mapping, imported into the default category
+    pub unmapped_types: Vec<String>,
+    /// Recipe groups that are not in the group mapping and were ignored
+    pub unknown_groups: Vec<String>,
+}
+
+impl YamlImportReport {
+    pub fn count(items: &[ImportItem], status: ImportStatus) -> usize {
+        items.iter().filter(|item| item.status == status).count()
+    }
+}
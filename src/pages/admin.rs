@@ -0,0 +1,14 @@
+use leptos::prelude::*;
+use crate::components::{ApiTokenManager, DataTransfer, RecipeImport, TrashView};
+
+#[component]
+pub fn AdminPage() -> impl IntoView {
+    view! {
+        <div class="space-y-6">
+            <DataTransfer/>
+            <RecipeImport/>
+            <ApiTokenManager/>
+            <TrashView/>
+        </div>
+    }
+}
@@ -1,5 +1,6 @@
 use leptos::prelude::*;
-use crate::components::{IngredientManager, CategoryManager};
+use crate::t;
+use crate::components::{IngredientManager, CategoryManager, SupplierManager, IngredientMergeTool};
 
 #[component]
 pub fn IngredientsPage() -> impl IntoView {
@@ -18,7 +19,7 @@ pub fn IngredientsPage() -> impl IntoView {
                         on:click=move |_| set_active_tab.set("ingredients")
                     >
                         <span class="mr-2">"🥕"</span>
-                        "Ingredients"
+                        {t!("ingredients")}
                     </button>
                     <button
                         class=move || if active_tab.get() == "categories" {
@@ -29,13 +30,37 @@ pub fn IngredientsPage() -> impl IntoView {
                         on:click=move |_| set_active_tab.set("categories")
                     >
                         <span class="mr-2">"📁"</span>
-                        "Categories"
+                        {t!("categories")}
+                    </button>
+                    <button
+                        class=move || if active_tab.get() == "suppliers" {
+                            "flex-1 px-6 py-3 bg-white rounded-lg shadow-md border-2 border-blue-500 font-bold text-blue-700 transition-all duration-200"
+                        } else {
+                            "flex-1 px-6 py-3 text-slate-600 hover:bg-white/50 rounded-lg hover:shadow transition-all duration-200"
+                        }
+                        on:click=move |_| set_active_tab.set("suppliers")
+                    >
+                        <span class="mr-2">"🚚"</span>
+                        {t!("suppliers")}
+                    </button>
+                    <button
+                        class=move || if active_tab.get() == "duplicates" {
+                            "flex-1 px-6 py-3 bg-white rounded-lg shadow-md border-2 border-blue-500 font-bold text-blue-700 transition-all duration-200"
+                        } else {
+                            "flex-1 px-6 py-3 text-slate-600 hover:bg-white/50 rounded-lg hover:shadow transition-all duration-200"
+                        }
+                        on:click=move |_| set_active_tab.set("duplicates")
+                    >
+                        <span class="mr-2">"🔀"</span>
+                        {t!("duplicates")}
                     </button>
                 </div>
             </div>
 
             {move || match active_tab.get() {
                 "categories" => view! { <CategoryManager/> }.into_any(),
+                "suppliers" => view! { <SupplierManager/> }.into_any(),
+                "duplicates" => view! { <IngredientMergeTool/> }.into_any(),
                 _ => view! { <IngredientManager/> }.into_any(),
             }}
         </div>
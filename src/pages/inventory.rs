@@ -0,0 +1,9 @@
+use leptos::prelude::*;
+use crate::components::InventoryManager;
+
+#[component]
+pub fn InventoryPage() -> impl IntoView {
+    view! {
+        <InventoryManager/>
+    }
+}
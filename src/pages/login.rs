@@ -2,6 +2,7 @@ use leptos::prelude::*;
 use leptos_router::hooks::use_navigate;
 
 use crate::server_functions::auth::login;
+use crate::t;
 
 #[component]
 pub fn LoginPage() -> impl IntoView {
@@ -22,7 +23,7 @@ pub fn LoginPage() -> impl IntoView {
                     navigate("/", Default::default());
                 }
                 Ok(false) => {
-                    set_error.set(Some("Incorrect password.".to_string()));
+                    set_error.set(Some(t!("login-error")));
                 }
                 Err(e) => {
                     set_error.set(Some(e.to_string()));
@@ -36,19 +37,19 @@ pub fn LoginPage() -> impl IntoView {
             <div class="card w-full max-w-md">
                 <div class="text-center mb-8">
                     <div class="text-7xl mb-4">"🏕️"</div>
-                    <h1 class="text-3xl font-bold text-slate-800">"Boy Scout Meal Planner"</h1>
-                    <p class="text-slate-600 mt-2">"Sign in to continue"</p>
+                    <h1 class="text-3xl font-bold text-slate-800">{t!("app-title")}</h1>
+                    <p class="text-slate-600 mt-2">{t!("login-subtitle")}</p>
                 </div>
                 <form on:submit=move |ev| {
                     ev.prevent_default();
                     login_action.dispatch(password.get());
                 }>
                     <div class="mb-4">
-                        <label class="block text-sm font-medium text-slate-700 mb-2">"Password"</label>
+                        <label class="block text-sm font-medium text-slate-700 mb-2">{t!("password")}</label>
                         <input
                             type="password"
                             class="w-full px-4 py-2 border border-slate-300 rounded-lg focus:outline-none focus:ring-2 focus:ring-indigo-500"
-                            placeholder="Enter password"
+                            placeholder=t!("password-placeholder")
                             prop:value=password
                             on:input=move |ev| set_password.set(event_target_value(&ev))
                         />
@@ -61,7 +62,7 @@ pub fn LoginPage() -> impl IntoView {
                         class="w-full px-4 py-2 bg-indigo-600 text-white font-semibold rounded-lg hover:bg-indigo-700 transition-colors disabled:opacity-50"
                         disabled=move || login_action.pending().get()
                     >
-                        {move || if login_action.pending().get() { "Signing in…" } else { "Sign In" }}
+                        {move || if login_action.pending().get() { t!("signing-in") } else { t!("login-button") }}
                     </button>
                 </form>
             </div>
@@ -5,6 +5,8 @@ pub mod ingredients;
 pub mod login;
 pub mod meal_planner;
 pub mod reports;
+pub mod inventory;
+pub mod admin;
 
 pub use home::*;
 pub use camps::*;
@@ -13,3 +15,5 @@ pub use ingredients::*;
 pub use login::*;
 pub use meal_planner::*;
 pub use reports::*;
+pub use inventory::*;
+pub use admin::*;
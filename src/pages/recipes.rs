@@ -1,9 +1,44 @@
 use leptos::prelude::*;
-use crate::components::RecipeEditor;
+use crate::t;
+use crate::components::{RecipeEditor, StockRecipeFinder};
 
 #[component]
 pub fn RecipesPage() -> impl IntoView {
+    let (active_tab, set_active_tab) = signal("recipes");
+
     view! {
-        <RecipeEditor/>
+        <div class="space-y-6">
+            <div class="card p-0 overflow-hidden">
+                <div class="flex gap-1 bg-slate-50 p-2">
+                    <button
+                        class=move || if active_tab.get() == "recipes" {
+                            "flex-1 px-6 py-3 bg-white rounded-lg shadow-md border-2 border-blue-500 font-bold text-blue-700 transition-all duration-200"
+                        } else {
+                            "flex-1 px-6 py-3 text-slate-600 hover:bg-white/50 rounded-lg hover:shadow transition-all duration-200"
+                        }
+                        on:click=move |_| set_active_tab.set("recipes")
+                    >
+                        <span class="mr-2">"🍳"</span>
+                        {t!("recipes")}
+                    </button>
+                    <button
+                        class=move || if active_tab.get() == "stock" {
+                            "flex-1 px-6 py-3 bg-white rounded-lg shadow-md border-2 border-blue-500 font-bold text-blue-700 transition-all duration-200"
+                        } else {
+                            "flex-1 px-6 py-3 text-slate-600 hover:bg-white/50 rounded-lg hover:shadow transition-all duration-200"
+                        }
+                        on:click=move |_| set_active_tab.set("stock")
+                    >
+                        <span class="mr-2">"🥫"</span>
+                        {t!("cook-from-stock")}
+                    </button>
+                </div>
+            </div>
+
+            {move || match active_tab.get() {
+                "stock" => view! { <StockRecipeFinder/> }.into_any(),
+                _ => view! { <RecipeEditor/> }.into_any(),
+            }}
+        </div>
     }
 }
@@ -0,0 +1,367 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::NaiveDate;
+use rust_xlsxwriter::{Format, Workbook};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+
+use crate::api::camps::get_camp;
+use crate::api::reports;
+use crate::format::{format_date, format_number, meal_type_str_label, round_quantity};
+use crate::i18n::{language_from_cookies, translate, Language};
+use crate::models::{AttendanceSummary, MealScheduleItem, RecipeFormat, ShoppingListItem};
+
+#[derive(Debug, Clone)]
+pub enum Cell {
+    Text(String),
+    Number(f64),
+    /// Amount in `unit`, rounded for that unit when written
+    Quantity { value: f64, unit: String },
+    Integer(i64),
+    Date(NaiveDate),
+}
+
+/// A report flattened into header and rows, ready to be written as CSV or XLSX
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub title: String,
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<Cell>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportParams {
+    /// `csv` (default) or `xlsx`
+    pub format: Option<String>,
+    /// `en` or `cz`, defaults to the language of the `lang` cookie
+    pub lang: Option<String>,
+    /// `comma` (default) or `semicolon`; semicolon CSV also uses decimal commas for Czech Excel
+    pub delimiter: Option<String>,
+    /// Shopping list range, defaults to the whole camp
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
+/// `GET /camps/{id}/reports/{report}` – download a report as CSV or XLSX.
+///
+/// `report` is one of `shopping-list`, `meal-schedule` or `attendance`.
+pub async fn export_report(
+    State(pool): State<SqlitePool>,
+    Path((camp_id, report)): Path<(i64, String)>,
+    Query(params): Query<ExportParams>,
+    headers: HeaderMap,
+) -> Response {
+    let language = params.lang.as_deref()
+        .and_then(Language::from_code)
+        .or_else(|| {
+            headers.get_all(header::COOKIE)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .find_map(language_from_cookies)
+        })
+        .unwrap_or_default();
+
+    match build_export(&pool, camp_id, &report, &params, language).await {
+        Ok(response) => response,
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+async fn build_export(
+    pool: &SqlitePool,
+    camp_id: i64,
+    report: &str,
+    params: &ExportParams,
+    language: Language,
+) -> Result<Response, Box<dyn std::error::Error>> {
+    let camp = get_camp(pool, camp_id).await?;
+
+    let table = match report {
+        "shopping-list" => {
+            let start = params.start_date.unwrap_or(camp.start_date);
+            let end = params.end_date.unwrap_or(camp.end_date);
+            let items = reports::generate_shopping_list(pool, camp_id, start, end).await?;
+            shopping_list_table(&items, language)
+        }
+        "meal-schedule" => {
+            let items = reports::generate_meal_schedule(pool, camp_id).await?;
+            meal_schedule_table(&items, language)
+        }
+        "attendance" => {
+            let items = reports::generate_attendance_summary(pool, camp_id).await?;
+            attendance_table(&items, language)
+        }
+        _ => return Err(format!("Unknown report '{}'", report).into()),
+    };
+
+    let file_stem = format!("{}-{}", slugify(&camp.name), report);
+
+    match params.format.as_deref().unwrap_or("csv") {
+        "csv" => {
+            let semicolon = params.delimiter.as_deref() == Some("semicolon");
+            Ok(file_response(
+                to_csv(&table, language, semicolon),
+                "text/csv; charset=utf-8",
+                &format!("{}.csv", file_stem),
+            ))
+        }
+        "xlsx" => Ok(file_response(
+            to_xlsx(&table, language)?,
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            &format!("{}.xlsx", file_stem),
+        )),
+        other => Err(format!("Unknown export format '{}'", other).into()),
+    }
+}
+
+fn file_response(body: Vec<u8>, content_type: &str, filename: &str) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", filename)),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// Translated column headers
+fn translated_headers(language: Language, keys: &[&str]) -> Vec<String> {
+    keys.iter().map(|key| translate(language, key, None)).collect()
+}
+
+pub fn shopping_list_table(items: &[ShoppingListItem], language: Language) -> Table {
+    Table {
+        title: translate(language, "report-shopping-list", None),
+        headers: translated_headers(language, &[
+            "category", "ingredient", "report-needed", "report-margin", "report-in-stock",
+            "report-to-buy", "report-packs", "report-leftover", "unit",
+        ]),
+        rows: items
+            .iter()
+            .map(|item| {
+                let packs = item.packs.iter()
+                    .map(|pack| pack.describe(&item.unit))
+                    .collect::<Vec<_>>()
+                    .join(" + ");
+                vec![
+                    Cell::Text(item.category_name.clone()),
+                    Cell::Text(item.ingredient_name.clone()),
+                    quantity(item.total_quantity, &item.unit),
+                    quantity(item.safety_margin, &item.unit),
+                    quantity(item.in_stock, &item.unit),
+                    quantity(item.to_buy, &item.unit),
+                    Cell::Text(packs),
+                    quantity(item.leftover, &item.unit),
+                    Cell::Text(item.unit.clone()),
+                ]
+            })
+            .collect(),
+    }
+}
+
+pub fn meal_schedule_table(items: &[MealScheduleItem], language: Language) -> Table {
+    Table {
+        title: translate(language, "report-meal-schedule", None),
+        headers: translated_headers(language, &["date", "meal-type", "recipe", "children", "teens", "adults"]),
+        rows: items
+            .iter()
+            .map(|item| {
+                vec![
+                    Cell::Date(item.date),
+                    Cell::Text(meal_type_str_label(&item.meal_type, language)),
+                    Cell::Text(item.recipe_name.clone()),
+                    Cell::Integer(item.children as i64),
+                    Cell::Integer(item.teens as i64),
+                    Cell::Integer(item.adults as i64),
+                ]
+            })
+            .collect(),
+    }
+}
+
+pub fn attendance_table(items: &[AttendanceSummary], language: Language) -> Table {
+    Table {
+        title: translate(language, "report-attendance-summary", None),
+        headers: translated_headers(language, &["date", "meal-type", "children", "teens", "adults", "total"]),
+        rows: items
+            .iter()
+            .map(|item| {
+                vec![
+                    Cell::Date(item.date),
+                    Cell::Text(meal_type_str_label(&item.meal_type, language)),
+                    Cell::Integer(item.children as i64),
+                    Cell::Integer(item.teens as i64),
+                    Cell::Integer(item.adults as i64),
+                    Cell::Integer(item.total_people as i64),
+                ]
+            })
+            .collect(),
+    }
+}
+
+fn quantity(value: f64, unit: &str) -> Cell {
+    Cell::Quantity { value, unit: unit.to_string() }
+}
+
+/// UTF-8 CSV with a byte order mark so Excel detects the encoding
+pub fn to_csv(table: &Table, language: Language, semicolon: bool) -> Vec<u8> {
+    let delimiter = if semicolon { ";" } else { "," };
+    // A decimal comma only works where the comma is not the delimiter
+    let number_language = if semicolon { Language::Cz } else { Language::En };
+    let mut out = String::from("\u{feff}");
+
+    let header = table.headers.iter()
+        .map(|h| csv_escape(h, delimiter))
+        .collect::<Vec<_>>()
+        .join(delimiter);
+    out.push_str(&header);
+    out.push_str("\r\n");
+
+    for row in &table.rows {
+        let line = row.iter()
+            .map(|cell| {
+                let value = match cell {
+                    Cell::Text(text) => text.clone(),
+                    Cell::Number(n) => format_number(*n, 2, number_language),
+                    Cell::Quantity { value, unit } => {
+                        // Rounded like on screen, but without dropping decimals a spreadsheet could sum
+                        format_number(round_quantity(*value, unit), 2, number_language)
+                    }
+                    Cell::Integer(n) => n.to_string(),
+                    Cell::Date(date) => format_date(*date, language),
+                };
+                csv_escape(&value, delimiter)
+            })
+            .collect::<Vec<_>>()
+            .join(delimiter);
+        out.push_str(&line);
+        out.push_str("\r\n");
+    }
+
+    out.into_bytes()
+}
+
+fn csv_escape(value: &str, delimiter: &str) -> String {
+    if value.contains(delimiter) || value.contains('"') || value.contains('\n') || value.contains('\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn to_xlsx(table: &Table, language: Language) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut workbook = Workbook::new();
+    let bold = Format::new().set_bold();
+    let number = Format::new().set_num_format("0.00");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_name(&table.title)?;
+
+    for (col, header) in table.headers.iter().enumerate() {
+        worksheet.write_string_with_format(0, col as u16, header, &bold)?;
+    }
+
+    for (row_index, row) in table.rows.iter().enumerate() {
+        let row_number = row_index as u32 + 1;
+        for (col, cell) in row.iter().enumerate() {
+            let col = col as u16;
+            match cell {
+                Cell::Text(text) => { worksheet.write_string(row_number, col, text)?; }
+                Cell::Number(n) => { worksheet.write_number_with_format(row_number, col, *n, &number)?; }
+                Cell::Quantity { value, unit } => {
+                    worksheet.write_number_with_format(row_number, col, round_quantity(*value, unit), &number)?;
+                }
+                Cell::Integer(n) => { worksheet.write_number(row_number, col, *n as f64)?; }
+                Cell::Date(date) => { worksheet.write_string(row_number, col, format_date(*date, language))?; }
+            }
+        }
+    }
+
+    worksheet.autofit();
+
+    Ok(workbook.save_to_buffer()?)
+}
+
+fn slugify(name: &str) -> String {
+    let slug = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+        .collect::<String>();
+    slug.split('-').filter(|part| !part.is_empty()).collect::<Vec<_>>().join("-")
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DataExportParams {
+    /// `json` (default) or `yaml`
+    pub format: Option<String>,
+}
+
+/// `GET /admin/export` – download all planning data for moving it to another instance
+pub async fn export_database(
+    State(pool): State<SqlitePool>,
+    Query(params): Query<DataExportParams>,
+) -> Response {
+    let data = match crate::api::transfer::export_data(&pool).await {
+        Ok(data) => data,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+
+    let stamp = data.exported_at.format("%Y%m%d-%H%M%S");
+    let result = match params.format.as_deref().unwrap_or("json") {
+        "yaml" => serde_yaml::to_string(&data)
+            .map(|body| (body, "application/yaml", format!("meal-planning-{}.yaml", stamp)))
+            .map_err(|e| e.to_string()),
+        "json" => serde_json::to_string_pretty(&data)
+            .map(|body| (body, "application/json", format!("meal-planning-{}.json", stamp)))
+            .map_err(|e| e.to_string()),
+        other => Err(format!("Unknown export format '{}'", other)),
+    };
+
+    match result {
+        Ok((body, content_type, filename)) => file_response(body.into_bytes(), content_type, &filename),
+        Err(e) => (StatusCode::BAD_REQUEST, e).into_response(),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecipeExportParams {
+    /// `jsonld` (default) or `cooklang`
+    pub format: Option<String>,
+}
+
+/// `GET /recipes/{id}/export` – one recipe as schema.org JSON-LD or Cooklang
+pub async fn export_recipe(
+    State(pool): State<SqlitePool>,
+    Path(recipe_id): Path<i64>,
+    Query(params): Query<RecipeExportParams>,
+) -> Response {
+    use crate::api::recipe_formats;
+
+    let Ok(format) = params.format.as_deref().unwrap_or("jsonld").parse::<RecipeFormat>() else {
+        return (StatusCode::BAD_REQUEST, "Unknown recipe format").into_response();
+    };
+
+    let recipe = match crate::api::recipes::get_recipe_with_ingredients(&pool, recipe_id).await {
+        Ok(recipe) => recipe,
+        Err(crate::error::AppError::NotFound) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+
+    let (body, content_type) = match format {
+        RecipeFormat::JsonLd => (
+            serde_json::to_string_pretty(&recipe_formats::to_json_ld(&recipe)).unwrap_or_default(),
+            "application/ld+json",
+        ),
+        RecipeFormat::Cooklang => (recipe_formats::to_cooklang(&recipe), "text/plain; charset=utf-8"),
+    };
+
+    file_response(
+        body.into_bytes(),
+        content_type,
+        &format!("{}.{}", slugify(&recipe.recipe.name), format.extension()),
+    )
+}
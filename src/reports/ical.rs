@@ -0,0 +1,195 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::{NaiveDate, NaiveTime, Utc};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use tower_sessions::Session;
+
+use crate::api::camps::get_camp;
+use crate::error::AppError;
+use crate::format::meal_type_label;
+use crate::i18n::{language_from_cookies, translate, Language};
+use crate::models::MealType;
+
+#[derive(Debug, Deserialize)]
+pub struct CalendarParams {
+    pub token: Option<String>,
+    /// `en` or `cz`, defaults to the language of the `lang` cookie
+    pub lang: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct CalendarMeal {
+    id: i64,
+    date: NaiveDate,
+    meal_type: String,
+    recipe_name: String,
+    children: i32,
+    teens: i32,
+    adults: i32,
+}
+
+/// `GET /camps/{id}/menu.ics` – the camp's planned meals as an iCalendar feed.
+///
+/// The feed is built on every request, so subscribed calendars follow the plan.
+/// Calendar apps cannot log in, so besides a logged-in session the camp's
+/// `calendar_token` is accepted as the `token` query parameter.
+pub async fn camp_menu_ics(
+    State(pool): State<SqlitePool>,
+    Path(camp_id): Path<i64>,
+    Query(params): Query<CalendarParams>,
+    session: Session,
+    headers: HeaderMap,
+) -> Response {
+    let authenticated = session
+        .get::<bool>("authenticated")
+        .await
+        .unwrap_or(None)
+        == Some(true);
+
+    if !authenticated {
+        let token: Option<String> = sqlx::query_scalar("SELECT calendar_token FROM camps WHERE id = ?")
+            .bind(camp_id)
+            .fetch_optional(&pool)
+            .await
+            .ok()
+            .flatten()
+            .flatten();
+
+        if token.is_none() || token != params.token {
+            return StatusCode::UNAUTHORIZED.into_response();
+        }
+    }
+
+    let language = params.lang.as_deref()
+        .and_then(Language::from_code)
+        .or_else(|| {
+            headers.get_all(header::COOKIE)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .find_map(language_from_cookies)
+        })
+        .unwrap_or_default();
+
+    match generate_menu_calendar(&pool, camp_id, language).await {
+        Ok(body) => (
+            [
+                (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+                (header::CACHE_CONTROL, "no-cache"),
+            ],
+            body,
+        )
+            .into_response(),
+        Err(AppError::NotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+pub async fn generate_menu_calendar(pool: &SqlitePool, camp_id: i64, language: Language) -> Result<String, AppError> {
+    let camp = get_camp(pool, camp_id).await?;
+
+    let meals = sqlx::query_as::<_, CalendarMeal>(
+        r#"
+        SELECT
+            pm.id,
+            mp.date,
+            pm.meal_type,
+            r.name as recipe_name,
+            COALESCE(ma.children, camp.default_children) as children,
+            COALESCE(ma.teens, camp.default_teens) as teens,
+            COALESCE(ma.adults, camp.default_adults) as adults
+        FROM planned_meals pm
+        JOIN meal_plans mp ON pm.meal_plan_id = mp.id
+        JOIN recipes r ON pm.recipe_id = r.id
+        JOIN camps camp ON mp.camp_id = camp.id
+        LEFT JOIN meal_attendance ma ON pm.id = ma.planned_meal_id
+        WHERE mp.camp_id = ?
+        ORDER BY mp.date, pm.meal_type
+        "#
+    )
+    .bind(camp_id)
+    .fetch_all(pool)
+    .await?;
+
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//ai_meal_planning//Camp Menu//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(&format!("{} – {}", camp.name, translate(language, "calendar-menu", None)))),
+        "REFRESH-INTERVAL;VALUE=DURATION:PT1H".to_string(),
+        "X-PUBLISHED-TTL:PT1H".to_string(),
+    ];
+
+    for meal in meals {
+        let meal_type = MealType::from_str(&meal.meal_type);
+        let (hour, minute) = meal_type.map(|mt| mt.start_time()).unwrap_or((12, 0));
+        let duration = meal_type.map(|mt| mt.duration_minutes()).unwrap_or(60);
+        let start = meal.date.and_time(NaiveTime::from_hms_opt(hour, minute, 0).unwrap_or_default());
+        let end = start + chrono::Duration::minutes(duration);
+        let label = meal_type
+            .map(|mt| meal_type_label(mt, language))
+            .unwrap_or_else(|| meal.meal_type.clone());
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:planned-meal-{}@ai-meal-planning", meal.id));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        // Floating local times: meals happen at the same wall-clock time wherever the camp is
+        lines.push(format!("DTSTART:{}", start.format("%Y%m%dT%H%M%S")));
+        lines.push(format!("DTEND:{}", end.format("%Y%m%dT%H%M%S")));
+        lines.push(format!("SUMMARY:{}", escape_text(&format!("{}: {}", label, meal.recipe_name))));
+        lines.push(format!(
+            "DESCRIPTION:{}",
+            escape_text(&format!(
+                "{}: {}\n{}: {}\n{}: {}\n{}: {}",
+                translate(language, "children", None),
+                meal.children,
+                translate(language, "teens", None),
+                meal.teens,
+                translate(language, "adults", None),
+                meal.adults,
+                translate(language, "total", None),
+                meal.children + meal.teens + meal.adults
+            ))
+        ));
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    Ok(lines.iter().map(|line| fold_line(line)).collect::<Vec<_>>().join(""))
+}
+
+/// Escape a TEXT value (RFC 5545, section 3.3.11)
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line to at most 75 octets and terminate it with CRLF
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut width = 0;
+
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += len;
+    }
+
+    folded.push_str("\r\n");
+    folded
+}
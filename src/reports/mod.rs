@@ -1,3 +1,6 @@
+pub mod export;
+pub mod ical;
+
 use chrono::NaiveDate;
 use printpdf::*;
 use sqlx::SqlitePool;
@@ -7,6 +10,8 @@ use std::io::BufWriter;
 use crate::api::meal_plans::get_planned_meals_for_date;
 use crate::api::recipes::get_recipe_with_ingredients;
 use crate::api::camps::get_camp;
+use crate::format::{format_date, format_day, format_quantity};
+use crate::i18n::{translate, FluentArgs, Language};
 
 #[derive(Debug)]
 struct IngredientTotal {
@@ -20,7 +25,7 @@ pub async fn generate_daily_report(
     pool: &SqlitePool,
     camp_id: i64,
     date: NaiveDate,
-    language: &str,
+    language: Language,
 ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
     let camp = get_camp(pool, camp_id).await?;
     let planned_meals = get_planned_meals_for_date(pool, camp_id, date).await?;
@@ -86,7 +91,7 @@ pub async fn generate_daily_report(
 
     // Generate PDF
     let (doc, page1, layer1) = PdfDocument::new(
-        if language == "cz" { "Denní přehled surovin" } else { "Daily Ingredient Report" },
+        translate(language, "daily-report", None),
         Mm(210.0),
         Mm(297.0),
         "Layer 1"
@@ -100,11 +105,7 @@ pub async fn generate_daily_report(
 
     // Title
     current_layer.use_text(
-        if language == "cz" {
-            format!("Denní přehled surovin - {}", date.format("%d.%m.%Y"))
-        } else {
-            format!("Daily Ingredient Report - {}", date.format("%Y-%m-%d"))
-        },
+        format!("{} - {}", translate(language, "daily-report", None), format_day(date, language)),
         16.0,
         Mm(20.0),
         Mm(y_pos),
@@ -114,10 +115,10 @@ pub async fn generate_daily_report(
     y_pos -= 10.0;
 
     current_layer.use_text(
-        if language == "cz" {
-            format!("Tábor: {}", camp.name)
-        } else {
-            format!("Camp: {}", camp.name)
+        {
+            let mut args = FluentArgs::new();
+            args.set("name", camp.name.clone());
+            translate(language, "pdf-camp", Some(&args))
         },
         12.0,
         Mm(20.0),
@@ -158,7 +159,7 @@ pub async fn generate_daily_report(
         let ingredient_name = &total.name;
         
         for (unit, quantity) in total.quantities {
-            let line = format!("  {} {:.2} {}", ingredient_name, quantity, unit);
+            let line = format!("  {} {}", ingredient_name, format_quantity(quantity, &unit, language));
             current_layer.use_text(
                 &line,
                 10.0,
@@ -184,7 +185,7 @@ pub async fn generate_daily_report(
 pub async fn generate_camp_report(
     pool: &SqlitePool,
     camp_id: i64,
-    language: &str,
+    language: Language,
 ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
     let camp = get_camp(pool, camp_id).await?;
     
@@ -266,7 +267,7 @@ pub async fn generate_camp_report(
 
     // Generate PDF
     let (doc, page1, layer1) = PdfDocument::new(
-        if language == "cz" { "Nákupní seznam" } else { "Shopping List" },
+        translate(language, "report-shopping-list", None),
         Mm(210.0),
         Mm(297.0),
         "Layer 1"
@@ -280,11 +281,7 @@ pub async fn generate_camp_report(
 
     // Title
     current_layer.use_text(
-        if language == "cz" {
-            "Nákupní seznam pro celý tábor"
-        } else {
-            "Shopping List for Entire Camp"
-        },
+        translate(language, "camp-report", None),
         16.0,
         Mm(20.0),
         Mm(y_pos),
@@ -294,10 +291,10 @@ pub async fn generate_camp_report(
     y_pos -= 10.0;
 
     current_layer.use_text(
-        if language == "cz" {
-            format!("Tábor: {}", camp.name)
-        } else {
-            format!("Camp: {}", camp.name)
+        {
+            let mut args = FluentArgs::new();
+            args.set("name", camp.name.clone());
+            translate(language, "pdf-camp", Some(&args))
         },
         12.0,
         Mm(20.0),
@@ -306,10 +303,11 @@ pub async fn generate_camp_report(
     );
 
     current_layer.use_text(
-        if language == "cz" {
-            format!("Od {} do {}", camp.start_date.format("%d.%m.%Y"), camp.end_date.format("%d.%m.%Y"))
-        } else {
-            format!("From {} to {}", camp.start_date.format("%Y-%m-%d"), camp.end_date.format("%Y-%m-%d"))
+        {
+            let mut args = FluentArgs::new();
+            args.set("start", format_date(camp.start_date, language));
+            args.set("end", format_date(camp.end_date, language));
+            translate(language, "pdf-date-range", Some(&args))
         },
         12.0,
         Mm(20.0),
@@ -349,7 +347,7 @@ pub async fn generate_camp_report(
         let ingredient_name = &total.name;
         
         for (unit, quantity) in total.quantities {
-            let line = format!("  {} {:.2} {}", ingredient_name, quantity, unit);
+            let line = format!("  {} {}", ingredient_name, format_quantity(quantity, &unit, language));
             current_layer.use_text(
                 &line,
                 10.0,
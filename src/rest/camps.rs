@@ -0,0 +1,42 @@
+use axum::{extract::State, http::StatusCode, response::Response, Json};
+use sqlx::SqlitePool;
+
+use super::{created, ApiJson, ApiPath, ApiQuery, ApiResult, ArchiveFilter, Page, Pagination};
+use crate::api::camps;
+use crate::models::{Camp, CreateCamp, UpdateCamp};
+
+/// `GET /api/v1/camps` – newest camp first
+pub async fn list(
+    State(pool): State<SqlitePool>,
+    ApiQuery(pagination): ApiQuery<Pagination>,
+    ApiQuery(filter): ApiQuery<ArchiveFilter>,
+) -> ApiResult<Json<Page<Camp>>> {
+    let camps = camps::get_camps(&pool, filter.include_archived).await?;
+    Ok(Json(pagination.apply(camps)?))
+}
+
+/// `GET /api/v1/camps/{id}`
+pub async fn show(State(pool): State<SqlitePool>, ApiPath(id): ApiPath<i64>) -> ApiResult<Json<Camp>> {
+    Ok(Json(camps::get_camp(&pool, id).await?))
+}
+
+/// `POST /api/v1/camps`
+pub async fn create(State(pool): State<SqlitePool>, ApiJson(camp): ApiJson<CreateCamp>) -> ApiResult<Response> {
+    Ok(created(camps::create_camp(&pool, camp).await?))
+}
+
+/// `PATCH /api/v1/camps/{id}` – fields left out keep their value
+pub async fn update(
+    State(pool): State<SqlitePool>,
+    ApiPath(id): ApiPath<i64>,
+    ApiJson(camp): ApiJson<UpdateCamp>,
+) -> ApiResult<Json<Camp>> {
+    Ok(Json(camps::update_camp(&pool, id, camp).await?))
+}
+
+/// `DELETE /api/v1/camps/{id}` – also deletes the camp's meal plans
+pub async fn remove(State(pool): State<SqlitePool>, ApiPath(id): ApiPath<i64>) -> ApiResult<StatusCode> {
+    camps::get_camp(&pool, id).await?;
+    camps::delete_camp(&pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,45 @@
+use axum::{extract::State, http::StatusCode, response::Response, Json};
+use sqlx::SqlitePool;
+
+use super::{created, ApiJson, ApiPath, ApiQuery, ApiResult, ArchiveFilter, Page, Pagination};
+use crate::api::categories;
+use crate::models::{Category, CreateCategory, UpdateCategory};
+
+/// `GET /api/v1/categories` – in sort order
+pub async fn list(
+    State(pool): State<SqlitePool>,
+    ApiQuery(pagination): ApiQuery<Pagination>,
+    ApiQuery(filter): ApiQuery<ArchiveFilter>,
+) -> ApiResult<Json<Page<Category>>> {
+    let categories = categories::get_categories(&pool, filter.include_archived).await?;
+    Ok(Json(pagination.apply(categories)?))
+}
+
+/// `GET /api/v1/categories/{id}`
+pub async fn show(State(pool): State<SqlitePool>, ApiPath(id): ApiPath<i64>) -> ApiResult<Json<Category>> {
+    Ok(Json(categories::get_category(&pool, id).await?))
+}
+
+/// `POST /api/v1/categories`
+pub async fn create(
+    State(pool): State<SqlitePool>,
+    ApiJson(category): ApiJson<CreateCategory>,
+) -> ApiResult<Response> {
+    Ok(created(categories::create_category(&pool, category).await?))
+}
+
+/// `PATCH /api/v1/categories/{id}`
+pub async fn update(
+    State(pool): State<SqlitePool>,
+    ApiPath(id): ApiPath<i64>,
+    ApiJson(category): ApiJson<UpdateCategory>,
+) -> ApiResult<Json<Category>> {
+    Ok(Json(categories::update_category(&pool, id, category).await?))
+}
+
+/// `DELETE /api/v1/categories/{id}` – refused with 409 while ingredients use it
+pub async fn remove(State(pool): State<SqlitePool>, ApiPath(id): ApiPath<i64>) -> ApiResult<StatusCode> {
+    categories::get_category(&pool, id).await?;
+    categories::delete_category(&pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,63 @@
+use axum::{extract::State, http::StatusCode, response::Response, Json};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+
+use super::{created, ApiJson, ApiPath, ApiQuery, ApiResult, Page, Pagination};
+use crate::api::{ingredients, usage};
+use crate::models::{CreateIngredient, Ingredient, IngredientUsageReport, UpdateIngredient};
+
+#[derive(Debug, Deserialize)]
+pub struct IngredientFilter {
+    pub category_id: Option<i64>,
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
+/// `GET /api/v1/ingredients` – by name, optionally only one category; archived only on request
+pub async fn list(
+    State(pool): State<SqlitePool>,
+    ApiQuery(pagination): ApiQuery<Pagination>,
+    ApiQuery(filter): ApiQuery<IngredientFilter>,
+) -> ApiResult<Json<Page<Ingredient>>> {
+    let ingredients = match filter.category_id {
+        Some(category_id) => {
+            ingredients::get_ingredients_by_category(&pool, category_id, filter.include_archived).await?
+        }
+        None => ingredients::get_ingredients(&pool, filter.include_archived).await?,
+    };
+    Ok(Json(pagination.apply(ingredients)?))
+}
+
+/// `GET /api/v1/ingredients/{id}`
+pub async fn show(State(pool): State<SqlitePool>, ApiPath(id): ApiPath<i64>) -> ApiResult<Json<Ingredient>> {
+    Ok(Json(ingredients::get_ingredient(&pool, id).await?))
+}
+
+/// `POST /api/v1/ingredients`
+pub async fn create(
+    State(pool): State<SqlitePool>,
+    ApiJson(ingredient): ApiJson<CreateIngredient>,
+) -> ApiResult<Response> {
+    Ok(created(ingredients::create_ingredient(&pool, ingredient).await?))
+}
+
+/// `PATCH /api/v1/ingredients/{id}`
+pub async fn update(
+    State(pool): State<SqlitePool>,
+    ApiPath(id): ApiPath<i64>,
+    ApiJson(ingredient): ApiJson<UpdateIngredient>,
+) -> ApiResult<Json<Ingredient>> {
+    Ok(Json(ingredients::update_ingredient(&pool, id, ingredient).await?))
+}
+
+/// `DELETE /api/v1/ingredients/{id}` – refused with 409 while recipes use it
+pub async fn remove(State(pool): State<SqlitePool>, ApiPath(id): ApiPath<i64>) -> ApiResult<StatusCode> {
+    ingredients::get_ingredient(&pool, id).await?;
+    ingredients::delete_ingredient(&pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `GET /api/v1/ingredients/{id}/usage` – recipes and stock using the ingredient
+pub async fn usage(State(pool): State<SqlitePool>, ApiPath(id): ApiPath<i64>) -> ApiResult<Json<IngredientUsageReport>> {
+    Ok(Json(usage::get_ingredient_usage(&pool, id).await?))
+}
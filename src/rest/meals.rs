@@ -0,0 +1,71 @@
+use axum::{extract::State, http::StatusCode, response::Response, Json};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+use super::{created, ApiJson, ApiPath, ApiQuery, ApiResult, Page, Pagination};
+use crate::api::{camps, meal_plans};
+use crate::models::{CreatePlannedMeal, PlannedMealWithDetails, UpdatePlannedMeal};
+
+#[derive(Debug, Deserialize)]
+pub struct MealFilter {
+    /// Only this day of the camp
+    pub date: Option<NaiveDate>,
+}
+
+/// The meals planned for one day
+#[derive(Debug, Serialize)]
+pub struct PlannedDay {
+    pub date: NaiveDate,
+    pub meals: Vec<PlannedMealWithDetails>,
+}
+
+/// `GET /api/v1/camps/{id}/meals` – the camp's meal plan grouped by day
+pub async fn list_for_camp(
+    State(pool): State<SqlitePool>,
+    ApiPath(camp_id): ApiPath<i64>,
+    ApiQuery(pagination): ApiQuery<Pagination>,
+    ApiQuery(filter): ApiQuery<MealFilter>,
+) -> ApiResult<Json<Page<PlannedDay>>> {
+    camps::get_camp(&pool, camp_id).await?;
+
+    let days = match filter.date {
+        Some(date) => {
+            let meals = meal_plans::get_planned_meals_for_date(&pool, camp_id, date).await?;
+            vec![PlannedDay { date, meals }]
+        }
+        None => meal_plans::get_planned_meals_for_camp(&pool, camp_id)
+            .await?
+            .into_iter()
+            .map(|(date, meals)| PlannedDay { date, meals })
+            .collect(),
+    };
+    Ok(Json(pagination.apply(days)?))
+}
+
+/// `POST /api/v1/meals`
+pub async fn create(
+    State(pool): State<SqlitePool>,
+    ApiJson(meal): ApiJson<CreatePlannedMeal>,
+) -> ApiResult<Response> {
+    camps::get_camp(&pool, meal.camp_id).await?;
+    Ok(created(meal_plans::create_planned_meal(&pool, meal).await?))
+}
+
+/// `PATCH /api/v1/meals/{id}` – change the recipe and/or the attendance
+pub async fn update(
+    State(pool): State<SqlitePool>,
+    ApiPath(id): ApiPath<i64>,
+    ApiJson(meal): ApiJson<UpdatePlannedMeal>,
+) -> ApiResult<Json<PlannedMealWithDetails>> {
+    meal_plans::get_planned_meal(&pool, id).await?;
+    meal_plans::update_planned_meal(&pool, id, meal).await?;
+    Ok(Json(meal_plans::get_planned_meal(&pool, id).await?))
+}
+
+/// `DELETE /api/v1/meals/{id}`
+pub async fn remove(State(pool): State<SqlitePool>, ApiPath(id): ApiPath<i64>) -> ApiResult<StatusCode> {
+    meal_plans::get_planned_meal(&pool, id).await?;
+    meal_plans::delete_planned_meal(&pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,227 @@
+//! Versioned REST/JSON API under `/api/v1` for scripts and other tools.
+//!
+//! The handlers are thin wrappers around the `api::*` functions. Errors are
+//! returned as `{"error": {"code": "...", "message": "..."}}` with a matching
+//! HTTP status (validation errors add `fields`), and list endpoints are
+//! paginated with `page` and `per_page`.
+
+pub mod camps;
+pub mod categories;
+pub mod ingredients;
+pub mod meals;
+pub mod openapi;
+pub mod recipes;
+pub mod reports;
+
+use axum::{
+    extract::{
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+        FromRequest, FromRequestParts,
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::error::AppError;
+use crate::i18n::Language;
+use crate::AppState;
+
+pub const API_PREFIX: &str = "/api/v1";
+
+const DEFAULT_PER_PAGE: usize = 50;
+const MAX_PER_PAGE: usize = 200;
+
+/// Error response of the REST API
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    /// Stable machine readable code, e.g. `not_found`
+    pub code: &'static str,
+    pub message: String,
+    /// Broken rules as `(field, message)` of a `validation_failed` error
+    pub fields: Vec<(String, String)>,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, code: &'static str, message: impl Into<String>) -> Self {
+        Self { status, code, message: message.into(), fields: Vec::new() }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "bad_request", message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_FOUND, "not_found", message)
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, "unauthorized", message)
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::FORBIDDEN, "forbidden", message)
+    }
+}
+
+impl From<AppError> for ApiError {
+    fn from(error: AppError) -> Self {
+        let message = error.message(Language::En);
+        match error {
+            AppError::NotFound => Self::not_found(message),
+            AppError::Validation(errors) => Self {
+                fields: errors
+                    .iter()
+                    .map(|error| (error.field.clone(), error.message.translate(Language::En)))
+                    .collect(),
+                ..Self::new(StatusCode::UNPROCESSABLE_ENTITY, "validation_failed", message)
+            },
+            AppError::Conflict(_) => Self::new(StatusCode::CONFLICT, "conflict", message),
+            AppError::Unauthorized => Self::unauthorized(message),
+            AppError::Internal(_) => {
+                Self::new(StatusCode::INTERNAL_SERVER_ERROR, "internal_error", "Internal server error")
+            }
+        }
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::new(rejection.status(), "invalid_body", rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "invalid_query", rejection.body_text())
+    }
+}
+
+impl From<PathRejection> for ApiError {
+    fn from(rejection: PathRejection) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "invalid_path", rejection.body_text())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let mut error = json!({ "code": self.code, "message": self.message });
+        if !self.fields.is_empty() {
+            error["fields"] = self
+                .fields
+                .iter()
+                .map(|(field, message)| json!({ "field": field, "message": message }))
+                .collect();
+        }
+        let body = json!({ "error": error });
+        (self.status, Json(body)).into_response()
+    }
+}
+
+pub type ApiResult<T> = Result<T, ApiError>;
+
+/// JSON body whose parse errors are answered with an [`ApiError`]
+#[derive(FromRequest)]
+#[from_request(via(axum::Json), rejection(ApiError))]
+pub struct ApiJson<T>(pub T);
+
+/// Query string whose parse errors are answered with an [`ApiError`]
+#[derive(FromRequestParts)]
+#[from_request(via(axum::extract::Query), rejection(ApiError))]
+pub struct ApiQuery<T>(pub T);
+
+/// Path parameters whose parse errors are answered with an [`ApiError`]
+#[derive(FromRequestParts)]
+#[from_request(via(axum::extract::Path), rejection(ApiError))]
+pub struct ApiPath<T>(pub T);
+
+#[derive(Debug, Deserialize)]
+pub struct Pagination {
+    /// 1-based page number, default 1
+    pub page: Option<usize>,
+    /// Items per page, default 50, at most 200
+    pub per_page: Option<usize>,
+}
+
+/// `?include_archived=true` on the list endpoints
+#[derive(Debug, Deserialize)]
+pub struct ArchiveFilter {
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
+/// One page of a list
+#[derive(Debug, Serialize)]
+pub struct Page<T> {
+    pub data: Vec<T>,
+    pub page: usize,
+    pub per_page: usize,
+    pub total: usize,
+}
+
+impl Pagination {
+    /// Cut the page out of `items`
+    pub fn apply<T>(&self, items: Vec<T>) -> ApiResult<Page<T>> {
+        let page = self.page.unwrap_or(1);
+        let per_page = self.per_page.unwrap_or(DEFAULT_PER_PAGE);
+        if page == 0 {
+            return Err(ApiError::bad_request("page starts at 1"));
+        }
+        if per_page == 0 || per_page > MAX_PER_PAGE {
+            return Err(ApiError::bad_request(format!("per_page must be between 1 and {}", MAX_PER_PAGE)));
+        }
+
+        // A page far past the end is empty rather than an overflow
+        let total = items.len();
+        let data = items.into_iter().skip((page - 1).saturating_mul(per_page)).take(per_page).collect();
+        Ok(Page { data, page, per_page, total })
+    }
+}
+
+/// `201 Created` with the new resource
+pub fn created<T: Serialize>(value: T) -> Response {
+    (StatusCode::CREATED, Json(value)).into_response()
+}
+
+/// Routes of `/api/v1`, nested by `main`
+pub fn router() -> Router<AppState> {
+    use axum::routing::{patch, post};
+
+    Router::new()
+        .route("/openapi.json", get(openapi::openapi_json))
+        .route("/camps", get(camps::list).post(camps::create))
+        .route("/camps/{id}", get(camps::show).patch(camps::update).delete(camps::remove))
+        .route("/camps/{id}/meals", get(meals::list_for_camp))
+        .route("/camps/{id}/reports/shopping-list", get(reports::shopping_list))
+        .route("/camps/{id}/reports/shopping-trips", get(reports::shopping_trips))
+        .route("/camps/{id}/reports/meal-schedule", get(reports::meal_schedule))
+        .route("/camps/{id}/reports/attendance", get(reports::attendance))
+        .route("/categories", get(categories::list).post(categories::create))
+        .route("/categories/{id}", get(categories::show).patch(categories::update).delete(categories::remove))
+        .route("/ingredients", get(ingredients::list).post(ingredients::create))
+        .route("/ingredients/{id}", get(ingredients::show).patch(ingredients::update).delete(ingredients::remove))
+        .route("/ingredients/{id}/usage", get(ingredients::usage))
+        .route("/recipes", get(recipes::list).post(recipes::create))
+        .route("/recipes/{id}", get(recipes::show).patch(recipes::update).delete(recipes::remove))
+        .route("/recipes/{id}/usage", get(recipes::usage))
+        .route("/meals", post(meals::create))
+        .route("/meals/{id}", patch(meals::update).delete(meals::remove))
+        .fallback(|| async { ApiError::not_found("No such API endpoint") })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_past_the_end_are_empty() {
+        let pagination = Pagination { page: Some(usize::MAX), per_page: Some(MAX_PER_PAGE) };
+        let page = pagination.apply(vec![1, 2, 3]).unwrap();
+        assert!(page.data.is_empty());
+        assert_eq!(page.total, 3);
+    }
+}
@@ -0,0 +1,497 @@
+//! Hand-written OpenAPI 3 description of `/api/v1`. Keep it in step with
+//! [`super::router`] and the models when either changes.
+
+use axum::Json;
+use serde_json::{json, Value};
+
+use super::API_PREFIX;
+
+/// `GET /api/v1/openapi.json` – public, so tools can fetch it without logging in
+pub async fn openapi_json() -> Json<Value> {
+    Json(document())
+}
+
+fn document() -> Value {
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "AI Meal Planning API",
+            "version": "1.0.0",
+            "description": "Camps, recipes, ingredients, meal plans and reports. Authenticate with a personal API token from the Admin page (`Authorization: Bearer <token>`) or with the session cookie from `POST /api/login`. Read-only tokens get `403` for anything but `GET`. Errors are returned as `{\"error\": {\"code\": \"...\", \"message\": \"...\"}}`."
+        },
+        "servers": [{ "url": API_PREFIX }],
+        "security": [{ "bearerAuth": [] }, { "sessionCookie": [] }],
+        "paths": {
+            "/camps": {
+                "get": list_operation("Camps", "List camps, newest first", "Camp", &[include_archived_parameter()]),
+                "post": create_operation("Camps", "Create a camp", "CreateCamp", "Camp")
+            },
+            "/camps/{id}": {
+                "parameters": [id_parameter()],
+                "get": show_operation("Camps", "Get a camp", "Camp"),
+                "patch": update_operation("Camps", "Update a camp", "UpdateCamp", "Camp"),
+                "delete": delete_operation("Camps", "Delete a camp and its meal plan")
+            },
+            "/camps/{id}/meals": {
+                "parameters": [id_parameter()],
+                "get": with_response(
+                    list_operation("Meal plans", "Meal plan of a camp grouped by day", "PlannedDay", &[date_parameter("date", "Only this day")]),
+                    "404",
+                    error_response("Camp not found")
+                )
+            },
+            "/camps/{id}/reports/shopping-list": {
+                "parameters": [id_parameter()],
+                "get": {
+                    "tags": ["Reports"],
+                    "summary": "What still has to be bought for the date range",
+                    "parameters": [
+                        date_parameter("start_date", "Defaults to the first day of the camp"),
+                        date_parameter("end_date", "Defaults to the last day of the camp")
+                    ],
+                    "responses": {
+                        "200": json_response("Shopping list", json!({ "type": "array", "items": schema_ref("ShoppingListItem") })),
+                        "400": error_response("Invalid date range"),
+                        "404": error_response("Camp not found")
+                    }
+                }
+            },
+            "/camps/{id}/reports/shopping-trips": {
+                "parameters": [id_parameter()],
+                "get": {
+                    "tags": ["Reports"],
+                    "summary": "Shopping split over trip dates",
+                    "parameters": [{
+                        "name": "dates",
+                        "in": "query",
+                        "description": "Comma-separated trip dates (YYYY-MM-DD), defaults to the first day of the camp",
+                        "schema": { "type": "string", "example": "2026-07-10,2026-07-14" }
+                    }],
+                    "responses": {
+                        "200": json_response("Trips", schema_ref("ShoppingTripPlan")),
+                        "400": error_response("Invalid trip date"),
+                        "404": error_response("Camp not found")
+                    }
+                }
+            },
+            "/camps/{id}/reports/meal-schedule": {
+                "parameters": [id_parameter()],
+                "get": report_operation("Meal schedule with attendance", "MealScheduleItem")
+            },
+            "/camps/{id}/reports/attendance": {
+                "parameters": [id_parameter()],
+                "get": report_operation("Attendance per meal", "AttendanceSummary")
+            },
+            "/categories": {
+                "get": list_operation("Categories", "List categories in sort order", "Category", &[include_archived_parameter()]),
+                "post": create_operation("Categories", "Create a category", "CreateCategory", "Category")
+            },
+            "/categories/{id}": {
+                "parameters": [id_parameter()],
+                "get": show_operation("Categories", "Get a category", "Category"),
+                "patch": update_operation("Categories", "Update a category", "UpdateCategory", "Category"),
+                "delete": delete_operation("Categories", "Delete a category that no ingredient uses")
+            },
+            "/ingredients": {
+                "get": list_operation("Ingredients", "List ingredients by name", "Ingredient", &[
+                    json!({
+                        "name": "category_id",
+                        "in": "query",
+                        "description": "Only ingredients of this category",
+                        "schema": { "type": "integer", "format": "int64" }
+                    }),
+                    include_archived_parameter()
+                ]),
+                "post": create_operation("Ingredients", "Create an ingredient", "CreateIngredient", "Ingredient")
+            },
+            "/ingredients/{id}": {
+                "parameters": [id_parameter()],
+                "get": show_operation("Ingredients", "Get an ingredient", "Ingredient"),
+                "patch": update_operation("Ingredients", "Update an ingredient", "UpdateIngredient", "Ingredient"),
+                "delete": delete_operation("Ingredients", "Delete an ingredient that no recipe uses")
+            },
+            "/ingredients/{id}/usage": {
+                "parameters": [id_parameter()],
+                "get": show_operation("Ingredients", "Recipes and stock using an ingredient", "IngredientUsageReport")
+            },
+            "/recipes": {
+                "get": list_operation("Recipes", "List recipes by name, without ingredients", "Recipe", &[include_archived_parameter()]),
+                "post": create_operation("Recipes", "Create a recipe", "CreateRecipe", "RecipeWithIngredients")
+            },
+            "/recipes/{id}": {
+                "parameters": [id_parameter()],
+                "get": show_operation("Recipes", "Get a recipe with its ingredients", "RecipeWithIngredients"),
+                "patch": update_operation("Recipes", "Update a recipe; `ingredients` replaces the whole list", "UpdateRecipe", "RecipeWithIngredients"),
+                "delete": delete_operation("Recipes", "Delete a recipe that no meal plan uses")
+            },
+            "/recipes/{id}/usage": {
+                "parameters": [id_parameter()],
+                "get": show_operation("Recipes", "Planned meals serving a recipe", "RecipeUsageReport")
+            },
+            "/meals": {
+                "post": create_operation("Meal plans", "Plan a meal", "CreatePlannedMeal", "PlannedMeal")
+            },
+            "/meals/{id}": {
+                "parameters": [id_parameter()],
+                "patch": update_operation("Meal plans", "Change the recipe or attendance of a planned meal", "UpdatePlannedMeal", "PlannedMeal"),
+                "delete": delete_operation("Meal plans", "Remove a planned meal")
+            }
+        },
+        "components": {
+            "securitySchemes": {
+                "bearerAuth": { "type": "http", "scheme": "bearer", "description": "Personal API token" },
+                "sessionCookie": { "type": "apiKey", "in": "cookie", "name": "id" }
+            },
+            "schemas": schemas()
+        }
+    })
+}
+
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+fn id_parameter() -> Value {
+    json!({ "name": "id", "in": "path", "required": true, "schema": { "type": "integer", "format": "int64" } })
+}
+
+fn date_parameter(name: &str, description: &str) -> Value {
+    json!({ "name": name, "in": "query", "description": description, "schema": { "type": "string", "format": "date" } })
+}
+
+fn include_archived_parameter() -> Value {
+    json!({
+        "name": "include_archived",
+        "in": "query",
+        "description": "Also list archived items",
+        "schema": { "type": "boolean", "default": false }
+    })
+}
+
+fn json_response(description: &str, schema: Value) -> Value {
+    json!({ "description": description, "content": { "application/json": { "schema": schema } } })
+}
+
+fn error_response(description: &str) -> Value {
+    json_response(description, schema_ref("Error"))
+}
+
+fn json_body(schema: &str) -> Value {
+    json!({ "required": true, "content": { "application/json": { "schema": schema_ref(schema) } } })
+}
+
+fn list_operation(tag: &str, summary: &str, item: &str, extra_parameters: &[Value]) -> Value {
+    let mut parameters = vec![
+        json!({ "name": "page", "in": "query", "description": "1-based page", "schema": { "type": "integer", "minimum": 1, "default": 1 } }),
+        json!({ "name": "per_page", "in": "query", "schema": { "type": "integer", "minimum": 1, "maximum": 200, "default": 50 } }),
+    ];
+    parameters.extend_from_slice(extra_parameters);
+
+    json!({
+        "tags": [tag],
+        "summary": summary,
+        "parameters": parameters,
+        "responses": {
+            "200": json_response("One page", json!({
+                "type": "object",
+                "required": ["data", "page", "per_page", "total"],
+                "properties": {
+                    "data": { "type": "array", "items": schema_ref(item) },
+                    "page": { "type": "integer" },
+                    "per_page": { "type": "integer" },
+                    "total": { "type": "integer", "description": "Items on all pages" }
+                }
+            })),
+            "400": error_response("Invalid pagination")
+        }
+    })
+}
+
+fn with_response(mut operation: Value, status: &str, response: Value) -> Value {
+    operation["responses"][status] = response;
+    operation
+}
+
+fn show_operation(tag: &str, summary: &str, schema: &str) -> Value {
+    json!({
+        "tags": [tag],
+        "summary": summary,
+        "responses": {
+            "200": json_response("Found", schema_ref(schema)),
+            "404": error_response("Not found")
+        }
+    })
+}
+
+fn create_operation(tag: &str, summary: &str, body: &str, schema: &str) -> Value {
+    json!({
+        "tags": [tag],
+        "summary": summary,
+        "requestBody": json_body(body),
+        "responses": {
+            "201": json_response("Created", schema_ref(schema)),
+            "400": error_response("Malformed body"),
+            "409": error_response("Conflicts with existing data"),
+            "422": error_response("Validation failed")
+        }
+    })
+}
+
+fn update_operation(tag: &str, summary: &str, body: &str, schema: &str) -> Value {
+    json!({
+        "tags": [tag],
+        "summary": summary,
+        "description": "Fields left out keep their value.",
+        "requestBody": json_body(body),
+        "responses": {
+            "200": json_response("Updated", schema_ref(schema)),
+            "400": error_response("Malformed body"),
+            "404": error_response("Not found"),
+            "409": error_response("Conflicts with existing data"),
+            "422": error_response("Validation failed")
+        }
+    })
+}
+
+fn delete_operation(tag: &str, summary: &str) -> Value {
+    json!({
+        "tags": [tag],
+        "summary": summary,
+        "responses": {
+            "204": { "description": "Deleted" },
+            "404": error_response("Not found"),
+            "409": error_response("Still in use")
+        }
+    })
+}
+
+fn report_operation(summary: &str, item: &str) -> Value {
+    json!({
+        "tags": ["Reports"],
+        "summary": summary,
+        "responses": {
+            "200": json_response(summary, json!({ "type": "array", "items": schema_ref(item) })),
+            "404": error_response("Camp not found")
+        }
+    })
+}
+
+fn object(required: &[&str], properties: Value) -> Value {
+    json!({ "type": "object", "required": required, "properties": properties })
+}
+
+fn schemas() -> Value {
+    let integer = json!({ "type": "integer", "format": "int64" });
+    let count = json!({ "type": "integer", "format": "int32" });
+    let number = json!({ "type": "number" });
+    let string = json!({ "type": "string" });
+    let nullable_string = json!({ "type": "string", "nullable": true });
+    let date = json!({ "type": "string", "format": "date" });
+    let timestamp = json!({ "type": "string", "format": "date-time", "nullable": true });
+    let meal_type = json!({
+        "type": "string",
+        "enum": ["breakfast", "morning_snack", "lunch", "afternoon_snack", "dinner"]
+    });
+    let storage_type = json!({ "type": "string", "enum": ["fridge", "freezer", "dry"] });
+    let attendance = object(&["children", "teens", "adults"], json!({
+        "children": count, "teens": count, "adults": count
+    }));
+
+    json!({
+        "Error": object(&["error"], json!({
+            "error": object(&["code", "message"], json!({
+                "code": { "type": "string", "example": "not_found" },
+                "message": string,
+                "fields": {
+                    "type": "array",
+                    "description": "Broken rules of a `validation_failed` error",
+                    "items": object(&["field", "message"], json!({ "field": string, "message": string }))
+                }
+            }))
+        })),
+        "Camp": object(
+            &["id", "name", "start_date", "end_date", "default_children", "default_teens", "default_adults"],
+            json!({
+                "id": integer, "name": string, "start_date": date, "end_date": date,
+                "default_children": count, "default_teens": count, "default_adults": count,
+                "notes": nullable_string, "archived_at": timestamp, "created_at": timestamp, "updated_at": timestamp
+            })
+        ),
+        "CreateCamp": object(
+            &["name", "start_date", "end_date", "default_children", "default_teens", "default_adults"],
+            json!({
+                "name": string, "start_date": date, "end_date": date,
+                "default_children": count, "default_teens": count, "default_adults": count,
+                "notes": nullable_string
+            })
+        ),
+        "UpdateCamp": object(&[], json!({
+            "name": string, "start_date": date, "end_date": date,
+            "default_children": count, "default_teens": count, "default_adults": count,
+            "notes": nullable_string
+        })),
+        "Category": object(&["id", "name", "sort_order"], json!({
+            "id": integer, "name": string, "sort_order": count,
+            "archived_at": timestamp, "created_at": timestamp, "updated_at": timestamp
+        })),
+        "CreateCategory": object(&["name", "sort_order"], json!({ "name": string, "sort_order": count })),
+        "UpdateCategory": object(&[], json!({ "name": string, "sort_order": count })),
+        "Ingredient": object(
+            &["id", "name", "category_id", "primary_unit", "safety_margin_percent", "storage_type", "allergens", "aliases"],
+            json!({
+                "id": integer, "name": string, "category_id": integer,
+                "primary_unit": string, "secondary_unit": nullable_string,
+                "safety_margin_percent": number,
+                "shelf_life_days": { "type": "integer", "nullable": true },
+                "storage_type": storage_type,
+                "price_per_unit": { "type": "number", "nullable": true },
+                "allergens": { "type": "string", "description": "Comma-separated" },
+                "aliases": { "type": "array", "items": string },
+                "archived_at": timestamp, "created_at": timestamp, "updated_at": timestamp
+            })
+        ),
+        "IngredientUsageReport": object(&["ingredient", "recipes", "stock_items"], json!({
+            "ingredient": schema_ref("Ingredient"),
+            "recipes": {
+                "type": "array",
+                "items": object(&["recipe_id", "recipe_name", "base_quantity", "unit"], json!({
+                    "recipe_id": integer, "recipe_name": string, "base_quantity": number, "unit": string
+                }))
+            },
+            "stock_items": { "type": "integer", "description": "Deleted together with the ingredient" }
+        })),
+        "CreateIngredient": object(&["name", "category_id", "primary_unit"], json!({
+            "name": string, "category_id": integer,
+            "primary_unit": string, "secondary_unit": nullable_string,
+            "safety_margin_percent": number,
+            "shelf_life_days": { "type": "integer", "nullable": true },
+            "storage_type": storage_type,
+            "price_per_unit": { "type": "number", "nullable": true },
+            "allergens": string
+        })),
+        "UpdateIngredient": object(&[], json!({
+            "name": string, "category_id": integer,
+            "primary_unit": string, "secondary_unit": string,
+            "safety_margin_percent": number, "shelf_life_days": count,
+            "clear_shelf_life": { "type": "boolean", "description": "Remove the shelf life" },
+            "storage_type": storage_type, "price_per_unit": number, "allergens": string
+        })),
+        "Recipe": object(&["id", "name", "base_servings", "tags"], json!({
+            "id": integer, "name": string, "instructions": nullable_string,
+            "base_servings": count,
+            "tags": { "type": "string", "description": "Comma-separated" },
+            "archived_at": timestamp, "created_at": timestamp, "updated_at": timestamp
+        })),
+        "RecipeUsageReport": object(&["recipe", "meals"], json!({
+            "recipe": schema_ref("Recipe"),
+            "meals": {
+                "type": "array",
+                "items": object(&["planned_meal_id", "camp_id", "camp_name", "date", "meal_type"], json!({
+                    "planned_meal_id": integer, "camp_id": integer, "camp_name": string,
+                    "date": date, "meal_type": meal_type
+                }))
+            }
+        })),
+        "RecipeIngredient": object(
+            &["id", "recipe_id", "ingredient_id", "ingredient_name", "base_quantity", "unit"],
+            json!({
+                "id": integer, "recipe_id": integer, "ingredient_id": integer, "ingredient_name": string,
+                "base_quantity": number, "unit": string,
+                "child_multiplier": number, "teen_multiplier": number, "adult_multiplier": number,
+                "notes": nullable_string, "created_at": timestamp
+            })
+        ),
+        "RecipeWithIngredients": {
+            "allOf": [
+                schema_ref("Recipe"),
+                object(&["ingredients"], json!({
+                    "ingredients": { "type": "array", "items": schema_ref("RecipeIngredient") }
+                }))
+            ]
+        },
+        "CreateRecipeIngredient": object(&["ingredient_id", "base_quantity", "unit"], json!({
+            "ingredient_id": integer, "base_quantity": number, "unit": string,
+            "child_multiplier": { "type": "number", "default": 0.5 },
+            "teen_multiplier": { "type": "number", "default": 0.75 },
+            "adult_multiplier": { "type": "number", "default": 1.0 },
+            "notes": nullable_string
+        })),
+        "CreateRecipe": object(&["name", "base_servings", "ingredients"], json!({
+            "name": string, "instructions": nullable_string, "base_servings": count, "tags": string,
+            "ingredients": { "type": "array", "items": schema_ref("CreateRecipeIngredient") }
+        })),
+        "UpdateRecipe": object(&[], json!({
+            "name": string, "instructions": string, "base_servings": count, "tags": string,
+            "ingredients": { "type": "array", "items": schema_ref("CreateRecipeIngredient") }
+        })),
+        "Attendance": attendance,
+        "PlannedMeal": object(&["id", "meal_plan_id", "recipe_id", "meal_type", "recipe_name"], json!({
+            "id": integer, "meal_plan_id": integer, "recipe_id": integer,
+            "meal_type": meal_type, "recipe_name": string,
+            "attendance": {
+                "nullable": true,
+                "description": "Camp defaults apply when null",
+                "allOf": [schema_ref("Attendance")]
+            },
+            "created_at": timestamp
+        })),
+        "PlannedDay": object(&["date", "meals"], json!({
+            "date": date,
+            "meals": { "type": "array", "items": schema_ref("PlannedMeal") }
+        })),
+        "CreatePlannedMeal": object(&["camp_id", "date", "meal_type", "recipe_id"], json!({
+            "camp_id": integer, "date": date, "meal_type": meal_type, "recipe_id": integer,
+            "attendance": schema_ref("Attendance")
+        })),
+        "UpdatePlannedMeal": object(&[], json!({
+            "recipe_id": integer,
+            "attendance": schema_ref("Attendance")
+        })),
+        "ShoppingListItem": object(
+            &["ingredient_id", "ingredient_name", "category_name", "total_quantity", "unit", "to_buy"],
+            json!({
+                "ingredient_id": integer, "ingredient_name": string, "category_name": string,
+                "total_quantity": number, "unit": string,
+                "safety_margin": number, "in_stock": number, "to_buy": number,
+                "packs": {
+                    "type": "array",
+                    "items": object(&["size", "count"], json!({ "size": number, "label": nullable_string, "count": count }))
+                },
+                "purchase_quantity": number, "leftover": number
+            })
+        ),
+        "ShoppingTripPlan": object(&["trips", "unassigned"], json!({
+            "trips": {
+                "type": "array",
+                "items": object(&["date", "items"], json!({
+                    "date": date,
+                    "items": { "type": "array", "items": schema_ref("ShoppingTripItem") }
+                }))
+            },
+            "unassigned": {
+                "type": "array",
+                "description": "Needed before the first trip",
+                "items": schema_ref("ShoppingTripItem")
+            }
+        })),
+        "ShoppingTripItem": object(
+            &["ingredient_id", "ingredient_name", "category_name", "storage_type", "quantity", "unit",
+              "first_needed", "last_needed", "exceeds_shelf_life"],
+            json!({
+                "ingredient_id": integer, "ingredient_name": string, "category_name": string,
+                "storage_type": storage_type, "quantity": number, "unit": string,
+                "first_needed": date, "last_needed": date,
+                "shelf_life_days": { "type": "integer", "nullable": true },
+                "exceeds_shelf_life": { "type": "boolean" }
+            })
+        ),
+        "MealScheduleItem": object(&["date", "meal_type", "recipe_name", "children", "teens", "adults"], json!({
+            "date": date, "meal_type": meal_type, "recipe_name": string,
+            "children": count, "teens": count, "adults": count
+        })),
+        "AttendanceSummary": object(&["date", "meal_type", "children", "teens", "adults", "total_people"], json!({
+            "date": date, "meal_type": meal_type,
+            "children": count, "teens": count, "adults": count, "total_people": count
+        }))
+    })
+}
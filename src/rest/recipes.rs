@@ -0,0 +1,50 @@
+use axum::{extract::State, http::StatusCode, response::Response, Json};
+use sqlx::SqlitePool;
+
+use super::{created, ApiJson, ApiPath, ApiQuery, ApiResult, ArchiveFilter, Page, Pagination};
+use crate::api::{recipes, usage};
+use crate::models::{CreateRecipe, Recipe, RecipeUsageReport, RecipeWithIngredients, UpdateRecipe};
+
+/// `GET /api/v1/recipes` – by name, without ingredients
+pub async fn list(
+    State(pool): State<SqlitePool>,
+    ApiQuery(pagination): ApiQuery<Pagination>,
+    ApiQuery(filter): ApiQuery<ArchiveFilter>,
+) -> ApiResult<Json<Page<Recipe>>> {
+    let recipes = recipes::get_recipes(&pool, filter.include_archived).await?;
+    Ok(Json(pagination.apply(recipes)?))
+}
+
+/// `GET /api/v1/recipes/{id}` – with ingredients
+pub async fn show(
+    State(pool): State<SqlitePool>,
+    ApiPath(id): ApiPath<i64>,
+) -> ApiResult<Json<RecipeWithIngredients>> {
+    Ok(Json(recipes::get_recipe_with_ingredients(&pool, id).await?))
+}
+
+/// `POST /api/v1/recipes`
+pub async fn create(State(pool): State<SqlitePool>, ApiJson(recipe): ApiJson<CreateRecipe>) -> ApiResult<Response> {
+    Ok(created(recipes::create_recipe(&pool, recipe).await?))
+}
+
+/// `PATCH /api/v1/recipes/{id}` – `ingredients`, when given, replaces the whole list
+pub async fn update(
+    State(pool): State<SqlitePool>,
+    ApiPath(id): ApiPath<i64>,
+    ApiJson(recipe): ApiJson<UpdateRecipe>,
+) -> ApiResult<Json<RecipeWithIngredients>> {
+    Ok(Json(recipes::update_recipe(&pool, id, recipe).await?))
+}
+
+/// `DELETE /api/v1/recipes/{id}` – refused with 409 while meal plans use it
+pub async fn remove(State(pool): State<SqlitePool>, ApiPath(id): ApiPath<i64>) -> ApiResult<StatusCode> {
+    recipes::get_recipe(&pool, id).await?;
+    recipes::delete_recipe(&pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// `GET /api/v1/recipes/{id}/usage` – planned meals serving the recipe
+pub async fn usage(State(pool): State<SqlitePool>, ApiPath(id): ApiPath<i64>) -> ApiResult<Json<RecipeUsageReport>> {
+    Ok(Json(usage::get_recipe_usage(&pool, id).await?))
+}
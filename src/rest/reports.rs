@@ -0,0 +1,77 @@
+use axum::{extract::State, Json};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use sqlx::SqlitePool;
+
+use super::{ApiError, ApiPath, ApiQuery, ApiResult};
+use crate::api::{camps, reports};
+use crate::models::{AttendanceSummary, MealScheduleItem, ShoppingListItem, ShoppingTripPlan};
+
+#[derive(Debug, Deserialize)]
+pub struct DateRange {
+    /// Defaults to the first day of the camp
+    pub start_date: Option<NaiveDate>,
+    /// Defaults to the last day of the camp
+    pub end_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TripDates {
+    /// Comma-separated `YYYY-MM-DD` dates; defaults to the first day of the camp
+    pub dates: Option<String>,
+}
+
+/// `GET /api/v1/camps/{id}/reports/shopping-list`
+pub async fn shopping_list(
+    State(pool): State<SqlitePool>,
+    ApiPath(camp_id): ApiPath<i64>,
+    ApiQuery(range): ApiQuery<DateRange>,
+) -> ApiResult<Json<Vec<ShoppingListItem>>> {
+    let camp = camps::get_camp(&pool, camp_id).await?;
+    let start_date = range.start_date.unwrap_or(camp.start_date);
+    let end_date = range.end_date.unwrap_or(camp.end_date);
+    if end_date < start_date {
+        return Err(ApiError::bad_request("end_date is before start_date"));
+    }
+
+    Ok(Json(reports::generate_shopping_list(&pool, camp_id, start_date, end_date).await?))
+}
+
+/// `GET /api/v1/camps/{id}/reports/shopping-trips?dates=2026-07-10,2026-07-14`
+pub async fn shopping_trips(
+    State(pool): State<SqlitePool>,
+    ApiPath(camp_id): ApiPath<i64>,
+    ApiQuery(trips): ApiQuery<TripDates>,
+) -> ApiResult<Json<ShoppingTripPlan>> {
+    let camp = camps::get_camp(&pool, camp_id).await?;
+    let dates = match trips.dates.as_deref().map(str::trim).filter(|dates| !dates.is_empty()) {
+        Some(dates) => dates
+            .split(',')
+            .map(|date| {
+                NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+                    .map_err(|_| ApiError::bad_request(format!("Invalid trip date '{}'", date.trim())))
+            })
+            .collect::<ApiResult<Vec<_>>>()?,
+        None => vec![camp.start_date],
+    };
+
+    Ok(Json(reports::generate_shopping_trips(&pool, camp_id, dates).await?))
+}
+
+/// `GET /api/v1/camps/{id}/reports/meal-schedule`
+pub async fn meal_schedule(
+    State(pool): State<SqlitePool>,
+    ApiPath(camp_id): ApiPath<i64>,
+) -> ApiResult<Json<Vec<MealScheduleItem>>> {
+    camps::get_camp(&pool, camp_id).await?;
+    Ok(Json(reports::generate_meal_schedule(&pool, camp_id).await?))
+}
+
+/// `GET /api/v1/camps/{id}/reports/attendance`
+pub async fn attendance(
+    State(pool): State<SqlitePool>,
+    ApiPath(camp_id): ApiPath<i64>,
+) -> ApiResult<Json<Vec<AttendanceSummary>>> {
+    camps::get_camp(&pool, camp_id).await?;
+    Ok(Json(reports::generate_attendance_summary(&pool, camp_id).await?))
+}
@@ -0,0 +1,28 @@
+use leptos::prelude::*;
+use crate::error::AppError;
+use crate::models::{ApiToken, ApiTokenScope, NewApiToken};
+
+#[server(GetApiTokens, "/api")]
+pub async fn get_api_tokens() -> Result<Vec<ApiToken>, AppError> {
+    use crate::api::api_tokens;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    api_tokens::get_api_tokens(&pool).await
+}
+
+#[server(CreateApiTokenFn, "/api")]
+pub async fn create_api_token(name: String, scope: ApiTokenScope) -> Result<NewApiToken, AppError> {
+    use crate::api::api_tokens;
+    use crate::models::CreateApiToken;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    api_tokens::create_api_token(&pool, CreateApiToken { name, scope }).await
+}
+
+#[server(RevokeApiToken, "/api")]
+pub async fn revoke_api_token(id: i64) -> Result<(), AppError> {
+    use crate::api::api_tokens;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    api_tokens::revoke_api_token(&pool, id).await
+}
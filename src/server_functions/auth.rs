@@ -1,7 +1,8 @@
 use leptos::prelude::*;
+use crate::error::AppError;
 
 #[server(Login, "/api")]
-pub async fn login(password: String) -> Result<bool, ServerFnError<String>> {
+pub async fn login(password: String) -> Result<bool, AppError> {
     use tower_sessions::Session;
 
     let expected =
@@ -11,27 +12,27 @@ pub async fn login(password: String) -> Result<bool, ServerFnError<String>> {
     if matched {
         let session = leptos_axum::extract::<Session>()
             .await
-            .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+            .map_err(|e| AppError::Internal(e.to_string()))?;
         session
             .insert("authenticated", true)
             .await
-            .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+            .map_err(|e| AppError::Internal(e.to_string()))?;
     }
 
     Ok(matched)
 }
 
 #[server(Logout, "/api")]
-pub async fn logout() -> Result<(), ServerFnError<String>> {
+pub async fn logout() -> Result<(), AppError> {
     use tower_sessions::Session;
 
     let session = leptos_axum::extract::<Session>()
         .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+        .map_err(|e| AppError::Internal(e.to_string()))?;
     session
         .flush()
         .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+        .map_err(|e| AppError::Internal(e.to_string()))?;
 
     Ok(())
 }
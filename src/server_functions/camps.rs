@@ -1,24 +1,21 @@
 use crate::models::Camp;
 use leptos::prelude::*;
+use crate::error::AppError;
 
 #[server(GetCamps, "/api")]
-pub async fn get_camps() -> Result<Vec<Camp>, ServerFnError<String>> {
+pub async fn get_camps(include_archived: bool) -> Result<Vec<Camp>, AppError> {
     use crate::api::camps;
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    camps::get_camps(&pool)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+    camps::get_camps(&pool, include_archived).await
 }
 
 #[server(GetCamp, "/api")]
-pub async fn get_camp(id: i64) -> Result<Camp, ServerFnError<String>> {
+pub async fn get_camp(id: i64) -> Result<Camp, AppError> {
     use crate::api::camps;
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    camps::get_camp(&pool, id)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+    camps::get_camp(&pool, id).await
 }
 
 #[server(CreateCampFn, "/api")]
@@ -30,16 +27,13 @@ pub async fn create_camp(
     default_teens: i32,
     default_adults: i32,
     notes: Option<String>,
-) -> Result<Camp, ServerFnError<String>> {
+) -> Result<Camp, AppError> {
     use crate::api::camps;
     use crate::models::CreateCamp;
-    use chrono::NaiveDate;
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    let start = NaiveDate::parse_from_str(&start_date, "%Y-%m-%d")
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
-    let end = NaiveDate::parse_from_str(&end_date, "%Y-%m-%d")
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+    let start = super::parse_date(&start_date, "start_date")?;
+    let end = super::parse_date(&end_date, "end_date")?;
 
     let new_camp = CreateCamp {
         name,
@@ -51,17 +45,40 @@ pub async fn create_camp(
         notes,
     };
 
-    camps::create_camp(&pool, new_camp)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+    camps::create_camp(&pool, new_camp).await
 }
 
 #[server(DeleteCamp, "/api")]
-pub async fn delete_camp(id: i64) -> Result<(), ServerFnError<String>> {
+pub async fn delete_camp(id: i64) -> Result<(), AppError> {
+    use crate::api::camps;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    camps::delete_camp(&pool, id).await
+}
+
+#[server(ArchiveCamp, "/api")]
+pub async fn archive_camp(id: i64) -> Result<(), AppError> {
+    use crate::api::camps;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    camps::archive_camp(&pool, id).await
+}
+
+#[server(RestoreCamp, "/api")]
+pub async fn restore_camp(id: i64) -> Result<(), AppError> {
+    use crate::api::camps;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    camps::restore_camp(&pool, id).await
+}
+
+/// Path of the camp's subscribable menu calendar, including its access token
+#[server(GetCalendarUrl, "/api")]
+pub async fn get_calendar_url(camp_id: i64) -> Result<String, AppError> {
     use crate::api::camps;
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    camps::delete_camp(&pool, id)
+    camps::get_calendar_token(&pool, camp_id)
         .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+        .map(|token| format!("/camps/{}/menu.ics?token={}", camp_id, token))
 }
@@ -1,31 +1,28 @@
 use leptos::prelude::*;
+use crate::error::AppError;
 use crate::models::Category;
 
 #[server(GetCategories, "/api")]
-pub async fn get_categories() -> Result<Vec<Category>, ServerFnError> {
+pub async fn get_categories(include_archived: bool) -> Result<Vec<Category>, AppError> {
     use crate::api::categories;
     let pool = expect_context::<sqlx::SqlitePool>();
     
-    categories::get_categories(&pool)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    categories::get_categories(&pool, include_archived).await
 }
 
 #[server(GetCategory, "/api")]
-pub async fn get_category(id: i64) -> Result<Category, ServerFnError> {
+pub async fn get_category(id: i64) -> Result<Category, AppError> {
     use crate::api::categories;
     let pool = expect_context::<sqlx::SqlitePool>();
     
-    categories::get_category(&pool, id)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    categories::get_category(&pool, id).await
 }
 
 #[server(CreateCategoryFn, "/api")]
 pub async fn create_category(
     name: String,
     sort_order: i32,
-) -> Result<Category, ServerFnError> {
+) -> Result<Category, AppError> {
     use crate::api::categories;
     use crate::models::CreateCategory;
     let pool = expect_context::<sqlx::SqlitePool>();
@@ -35,9 +32,7 @@ pub async fn create_category(
         sort_order,
     };
     
-    categories::create_category(&pool, new_category)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    categories::create_category(&pool, new_category).await
 }
 
 #[server(UpdateCategoryFn, "/api")]
@@ -45,7 +40,7 @@ pub async fn update_category(
     id: i64,
     name: Option<String>,
     sort_order: Option<i32>,
-) -> Result<Category, ServerFnError> {
+) -> Result<Category, AppError> {
     use crate::api::categories;
     use crate::models::UpdateCategory;
     let pool = expect_context::<sqlx::SqlitePool>();
@@ -55,17 +50,29 @@ pub async fn update_category(
         sort_order,
     };
     
-    categories::update_category(&pool, id, update)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    categories::update_category(&pool, id, update).await
 }
 
 #[server(DeleteCategory, "/api")]
-pub async fn delete_category(id: i64) -> Result<(), ServerFnError> {
+pub async fn delete_category(id: i64) -> Result<(), AppError> {
     use crate::api::categories;
     let pool = expect_context::<sqlx::SqlitePool>();
     
-    categories::delete_category(&pool, id)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    categories::delete_category(&pool, id).await
+}
+
+#[server(ArchiveCategory, "/api")]
+pub async fn archive_category(id: i64) -> Result<(), AppError> {
+    use crate::api::categories;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    categories::archive_category(&pool, id).await
+}
+
+#[server(RestoreCategory, "/api")]
+pub async fn restore_category(id: i64) -> Result<(), AppError> {
+    use crate::api::categories;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    categories::restore_category(&pool, id).await
 }
@@ -1,79 +1,172 @@
 use leptos::prelude::*;
-use crate::models::Ingredient;
+use crate::error::AppError;
+use crate::models::{CreateIngredient, DuplicateGroup, Ingredient, IngredientAlias, IngredientUsageReport, IngredientPack, MergeResult, RequiredConversion, UnitFactor, UpdateIngredient};
 
 #[server(GetIngredients, "/api")]
-pub async fn get_ingredients() -> Result<Vec<Ingredient>, ServerFnError> {
+pub async fn get_ingredients(include_archived: bool) -> Result<Vec<Ingredient>, AppError> {
     use crate::api::ingredients;
     let pool = expect_context::<sqlx::SqlitePool>();
     
-    ingredients::get_ingredients(&pool)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    ingredients::get_ingredients(&pool, include_archived).await
 }
 
 #[server(GetIngredientsByCategory, "/api")]
-pub async fn get_ingredients_by_category(category_id: i64) -> Result<Vec<Ingredient>, ServerFnError> {
+pub async fn get_ingredients_by_category(category_id: i64, include_archived: bool) -> Result<Vec<Ingredient>, AppError> {
     use crate::api::ingredients;
     let pool = expect_context::<sqlx::SqlitePool>();
     
-    ingredients::get_ingredients_by_category(&pool, category_id)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    ingredients::get_ingredients_by_category(&pool, category_id, include_archived).await
 }
 
 #[server(CreateIngredientFn, "/api")]
 pub async fn create_ingredient(
-    name: String,
-    category_id: i64,
-    primary_unit: String,
-    secondary_unit: Option<String>,
-) -> Result<Ingredient, ServerFnError> {
+    ingredient: CreateIngredient,
+) -> Result<Ingredient, AppError> {
     use crate::api::ingredients;
-    use crate::models::CreateIngredient;
     let pool = expect_context::<sqlx::SqlitePool>();
-    
-    let new_ingredient = CreateIngredient {
-        name,
-        category_id,
-        primary_unit,
-        secondary_unit,
-    };
-    
-    ingredients::create_ingredient(&pool, new_ingredient)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+
+    ingredients::create_ingredient(&pool, ingredient).await
 }
 
 #[server(UpdateIngredientFn, "/api")]
 pub async fn update_ingredient(
     id: i64,
-    name: String,
-    category_id: i64,
-    primary_unit: String,
-    secondary_unit: Option<String>,
-) -> Result<Ingredient, ServerFnError> {
+    update: UpdateIngredient,
+) -> Result<Ingredient, AppError> {
     use crate::api::ingredients;
-    use crate::models::UpdateIngredient;
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    let update = UpdateIngredient {
-        name: Some(name),
-        category_id: Some(category_id),
-        primary_unit: Some(primary_unit),
-        secondary_unit,
-    };
-
-    ingredients::update_ingredient(&pool, id, update)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    ingredients::update_ingredient(&pool, id, update).await
 }
 
 #[server(DeleteIngredient, "/api")]
-pub async fn delete_ingredient(id: i64) -> Result<(), ServerFnError> {
+pub async fn delete_ingredient(id: i64) -> Result<(), AppError> {
+    use crate::api::ingredients;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    ingredients::delete_ingredient(&pool, id).await
+}
+
+#[server(GetIngredientUsage, "/api")]
+pub async fn get_ingredient_usage(id: i64) -> Result<IngredientUsageReport, AppError> {
+    use crate::api::usage;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    usage::get_ingredient_usage(&pool, id).await
+}
+
+#[server(ReplaceIngredient, "/api")]
+pub async fn replace_ingredient(id: i64, replacement_id: i64) -> Result<u64, AppError> {
+    use crate::api::usage;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    usage::replace_ingredient(&pool, id, replacement_id).await
+}
+
+#[server(ArchiveIngredient, "/api")]
+pub async fn archive_ingredient(id: i64) -> Result<(), AppError> {
     use crate::api::ingredients;
     let pool = expect_context::<sqlx::SqlitePool>();
     
-    ingredients::delete_ingredient(&pool, id)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    ingredients::archive_ingredient(&pool, id).await
+}
+
+#[server(RestoreIngredient, "/api")]
+pub async fn restore_ingredient(id: i64) -> Result<(), AppError> {
+    use crate::api::ingredients;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    ingredients::restore_ingredient(&pool, id).await
+}
+
+#[server(GetIngredientPacks, "/api")]
+pub async fn get_ingredient_packs(ingredient_id: i64) -> Result<Vec<IngredientPack>, AppError> {
+    use crate::api::ingredients;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    ingredients::get_ingredient_packs(&pool, ingredient_id).await
+}
+
+#[server(CreateIngredientPackFn, "/api")]
+pub async fn create_ingredient_pack(
+    ingredient_id: i64,
+    unit: String,
+    size: f64,
+    label: Option<String>,
+) -> Result<IngredientPack, AppError> {
+    use crate::api::ingredients;
+    use crate::models::CreateIngredientPack;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    let new_pack = CreateIngredientPack {
+        ingredient_id,
+        unit,
+        size,
+        label,
+    };
+
+    ingredients::create_ingredient_pack(&pool, new_pack).await
+}
+
+#[server(DeleteIngredientPack, "/api")]
+pub async fn delete_ingredient_pack(id: i64) -> Result<(), AppError> {
+    use crate::api::ingredients;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    ingredients::delete_ingredient_pack(&pool, id).await
+}
+
+#[server(GetIngredientAliases, "/api")]
+pub async fn get_ingredient_aliases(ingredient_id: i64) -> Result<Vec<IngredientAlias>, AppError> {
+    use crate::api::ingredients;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    ingredients::get_ingredient_aliases(&pool, ingredient_id).await
+}
+
+#[server(CreateIngredientAliasFn, "/api")]
+pub async fn create_ingredient_alias(ingredient_id: i64, alias: String) -> Result<IngredientAlias, AppError> {
+    use crate::api::ingredients;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    ingredients::create_ingredient_alias(&pool, ingredient_id, alias).await
+}
+
+#[server(DeleteIngredientAlias, "/api")]
+pub async fn delete_ingredient_alias(id: i64) -> Result<(), AppError> {
+    use crate::api::ingredients;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    ingredients::delete_ingredient_alias(&pool, id).await
+}
+
+#[server(FindDuplicateIngredients, "/api")]
+pub async fn find_duplicate_ingredients() -> Result<Vec<DuplicateGroup>, AppError> {
+    use crate::api::ingredient_merge;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    ingredient_merge::find_duplicates(&pool).await
+}
+
+#[server(GetMergeConversions, "/api")]
+pub async fn get_merge_conversions(
+    target_id: i64,
+    source_ids: Vec<i64>,
+) -> Result<Vec<RequiredConversion>, AppError> {
+    use crate::api::ingredient_merge;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    ingredient_merge::required_conversions(&pool, target_id, &source_ids).await
+}
+
+#[server(MergeIngredientsFn, "/api")]
+pub async fn merge_ingredients(
+    target_id: i64,
+    source_ids: Vec<i64>,
+    unit_factors: Vec<UnitFactor>,
+) -> Result<MergeResult, AppError> {
+    use crate::api::ingredient_merge;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    ingredient_merge::merge_ingredients(&pool, target_id, source_ids, unit_factors).await
 }
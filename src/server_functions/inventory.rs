@@ -0,0 +1,98 @@
+use crate::models::{ConsumptionResult, InventoryItem, InventoryItemDetail};
+use leptos::prelude::*;
+use crate::error::AppError;
+
+#[server(GetInventory, "/api")]
+pub async fn get_inventory(
+    camp_id: Option<i64>,
+) -> Result<Vec<InventoryItemDetail>, AppError> {
+    use crate::api::inventory;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    match camp_id {
+        Some(camp_id) => inventory::get_inventory_for_camp(&pool, camp_id).await,
+        None => inventory::get_inventory(&pool).await,
+    }
+}
+
+#[server(CreateInventoryItemFn, "/api")]
+pub async fn create_inventory_item(
+    ingredient_id: i64,
+    camp_id: Option<i64>,
+    storeroom: Option<String>,
+    quantity: f64,
+    unit: String,
+    notes: Option<String>,
+) -> Result<InventoryItem, AppError> {
+    use crate::api::inventory;
+    use crate::models::CreateInventoryItem;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    let new_item = CreateInventoryItem {
+        ingredient_id,
+        camp_id,
+        storeroom,
+        quantity,
+        unit,
+        notes,
+    };
+
+    inventory::create_inventory_item(&pool, new_item).await
+}
+
+#[server(UpdateInventoryItemFn, "/api")]
+pub async fn update_inventory_item(
+    id: i64,
+    quantity: f64,
+    unit: String,
+    storeroom: Option<String>,
+    notes: Option<String>,
+) -> Result<InventoryItem, AppError> {
+    use crate::api::inventory;
+    use crate::models::UpdateInventoryItem;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    let update = UpdateInventoryItem {
+        quantity: Some(quantity),
+        unit: Some(unit),
+        storeroom,
+        notes,
+    };
+
+    inventory::update_inventory_item(&pool, id, update).await
+}
+
+#[server(DeleteInventoryItem, "/api")]
+pub async fn delete_inventory_item(id: i64) -> Result<(), AppError> {
+    use crate::api::inventory;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    inventory::delete_inventory_item(&pool, id).await
+}
+
+#[server(GetConsumedDates, "/api")]
+pub async fn get_consumed_dates(camp_id: i64) -> Result<Vec<String>, AppError> {
+    use crate::api::inventory;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    inventory::get_consumed_dates(&pool, camp_id)
+        .await
+        .map(|dates| {
+            dates.into_iter()
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .collect()
+        })
+}
+
+#[server(ConsumeDay, "/api")]
+pub async fn consume_day(
+    camp_id: i64,
+    date: String,
+) -> Result<ConsumptionResult, AppError> {
+    use crate::api::inventory;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    let parsed_date = super::parse_date(&date, "date")?;
+
+    inventory::consume_day(&pool, camp_id, parsed_date).await
+}
@@ -1,21 +1,18 @@
-use crate::models::PlannedMealWithDetails;
+use crate::models::{GeneratedDay, GeneratedMenu, MenuGeneratorSettings, PlanCheck, PlannedMealWithDetails};
 use leptos::prelude::*;
+use crate::error::AppError;
 
 #[server(GetPlannedMealsForDate, "/api")]
 pub async fn get_planned_meals_for_date(
     camp_id: i64,
     date: String,
-) -> Result<Vec<PlannedMealWithDetails>, ServerFnError<String>> {
+) -> Result<Vec<PlannedMealWithDetails>, AppError> {
     use crate::api::meal_plans;
-    use chrono::NaiveDate;
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+    let parsed_date = super::parse_date(&date, "date")?;
 
-    meal_plans::get_planned_meals_for_date(&pool, camp_id, parsed_date)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+    meal_plans::get_planned_meals_for_date(&pool, camp_id, parsed_date).await
 }
 
 #[server(CreatePlannedMealFn, "/api")]
@@ -27,18 +24,15 @@ pub async fn create_planned_meal(
     children: Option<i32>,
     teens: Option<i32>,
     adults: Option<i32>,
-) -> Result<PlannedMealWithDetails, ServerFnError<String>> {
+) -> Result<PlannedMealWithDetails, AppError> {
     use crate::api::meal_plans;
     use crate::models::{CreateAttendance, CreatePlannedMeal, MealType};
-    use chrono::NaiveDate;
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    let parsed_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+    let parsed_date = super::parse_date(&date, "date")?;
 
-    let parsed_meal_type = MealType::from_str(&meal_type).ok_or_else(|| {
-        ServerFnError::<String>::ServerError("Invalid meal type".to_string())
-    })?;
+    let parsed_meal_type = MealType::from_str(&meal_type)
+        .ok_or_else(|| AppError::validation("meal_type", "validation-invalid-meal-type"))?;
 
     let attendance = if let (Some(c), Some(t), Some(a)) = (children, teens, adults) {
         Some(CreateAttendance {
@@ -58,9 +52,7 @@ pub async fn create_planned_meal(
         attendance,
     };
 
-    meal_plans::create_planned_meal(&pool, new_meal)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+    meal_plans::create_planned_meal(&pool, new_meal).await
 }
 
 #[server(UpdatePlannedMealFn, "/api")]
@@ -70,7 +62,7 @@ pub async fn update_planned_meal(
     children: Option<i32>,
     teens: Option<i32>,
     adults: Option<i32>,
-) -> Result<(), ServerFnError<String>> {
+) -> Result<(), AppError> {
     use crate::api::meal_plans;
     use crate::models::{CreateAttendance, UpdatePlannedMeal};
     let pool = expect_context::<sqlx::SqlitePool>();
@@ -90,25 +82,21 @@ pub async fn update_planned_meal(
         attendance,
     };
 
-    meal_plans::update_planned_meal(&pool, id, update)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+    meal_plans::update_planned_meal(&pool, id, update).await
 }
 
 #[server(DeletePlannedMeal, "/api")]
-pub async fn delete_planned_meal(id: i64) -> Result<(), ServerFnError<String>> {
+pub async fn delete_planned_meal(id: i64) -> Result<(), AppError> {
     use crate::api::meal_plans;
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    meal_plans::delete_planned_meal(&pool, id)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+    meal_plans::delete_planned_meal(&pool, id).await
 }
 
 #[server(GetPlannedMealsForCamp, "/api")]
 pub async fn get_planned_meals_for_camp(
     camp_id: i64,
-) -> Result<Vec<(String, Vec<PlannedMealWithDetails>)>, ServerFnError<String>> {
+) -> Result<Vec<(String, Vec<PlannedMealWithDetails>)>, AppError> {
     use crate::api::meal_plans;
     let pool = expect_context::<sqlx::SqlitePool>();
 
@@ -119,7 +107,6 @@ pub async fn get_planned_meals_for_camp(
                 .map(|(date, meals)| (date.format("%Y-%m-%d").to_string(), meals))
                 .collect()
         })
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
 }
 
 #[server(GetPlannedMealsForDateRange, "/api")]
@@ -127,20 +114,16 @@ pub async fn get_planned_meals_for_date_range(
     camp_id: i64,
     start_date: String,
     end_date: String,
-) -> Result<Vec<(String, Vec<PlannedMealWithDetails>)>, ServerFnError<String>> {
+) -> Result<Vec<(String, Vec<PlannedMealWithDetails>)>, AppError> {
     use crate::api::meal_plans;
-    use chrono::NaiveDate;
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    let start = NaiveDate::parse_from_str(&start_date, "%Y-%m-%d")
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
-    let end = NaiveDate::parse_from_str(&end_date, "%Y-%m-%d")
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+    let start = super::parse_date(&start_date, "start_date")?;
+    let end = super::parse_date(&end_date, "end_date")?;
 
     // Get all meals for camp and filter by date range
     let all_meals = meal_plans::get_planned_meals_for_camp(&pool, camp_id)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+        .await?;
 
     Ok(all_meals
         .into_iter()
@@ -148,3 +131,41 @@ pub async fn get_planned_meals_for_date_range(
         .map(|(date, meals)| (date.format("%Y-%m-%d").to_string(), meals))
         .collect())
 }
+
+#[server(GenerateMenu, "/api")]
+pub async fn generate_menu(
+    camp_id: i64,
+    settings: MenuGeneratorSettings,
+) -> Result<GeneratedMenu, AppError> {
+    use crate::api::menu_generator;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    menu_generator::generate_menu(&pool, camp_id, &settings).await
+}
+
+#[server(AcceptGeneratedDay, "/api")]
+pub async fn accept_generated_day(
+    camp_id: i64,
+    day: GeneratedDay,
+) -> Result<usize, AppError> {
+    use crate::api::menu_generator;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    menu_generator::accept_generated_day(&pool, camp_id, &day).await
+}
+
+#[server(CheckCampPlan, "/api")]
+pub async fn check_camp_plan(camp_id: i64) -> Result<PlanCheck, AppError> {
+    use crate::api::plan_check;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    plan_check::check_camp_plan(&pool, camp_id).await
+}
+
+#[server(CheckAllCampPlans, "/api")]
+pub async fn check_all_camp_plans() -> Result<Vec<PlanCheck>, AppError> {
+    use crate::api::plan_check;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    plan_check::check_all_camp_plans(&pool).await
+}
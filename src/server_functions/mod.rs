@@ -5,6 +5,11 @@ pub mod recipes;
 pub mod camps;
 pub mod meal_plans;
 pub mod reports;
+pub mod inventory;
+pub mod suppliers;
+pub mod transfer;
+pub mod api_tokens;
+pub mod trash;
 
 pub use auth::*;
 pub use categories::*;
@@ -13,3 +18,15 @@ pub use recipes::*;
 pub use camps::*;
 pub use meal_plans::*;
 pub use reports::*;
+pub use inventory::*;
+pub use suppliers::*;
+pub use transfer::*;
+pub use api_tokens::*;
+pub use trash::*;
+
+/// Date of a `YYYY-MM-DD` form field
+#[cfg(feature = "ssr")]
+pub(crate) fn parse_date(value: &str, field: &str) -> Result<chrono::NaiveDate, crate::error::AppError> {
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| crate::error::AppError::validation(field, "validation-invalid-date"))
+}
@@ -1,24 +1,21 @@
 use leptos::prelude::*;
-use crate::models::{Recipe, RecipeWithIngredients, CreateRecipeIngredient};
+use crate::error::AppError;
+use crate::models::{Recipe, RecipeSearchPage, RecipeSearchQuery, StockFinderRequest, StockMatch, RecipeWithIngredients, CreateRecipeIngredient, RecipeFormat, RecipeImportPreview, IngredientResolution, RecipeUsageReport};
 
 #[server(GetRecipes, "/api")]
-pub async fn get_recipes() -> Result<Vec<Recipe>, ServerFnError> {
+pub async fn get_recipes(include_archived: bool) -> Result<Vec<Recipe>, AppError> {
     use crate::api::recipes;
     let pool = expect_context::<sqlx::SqlitePool>();
     
-    recipes::get_recipes(&pool)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    recipes::get_recipes(&pool, include_archived).await
 }
 
 #[server(GetRecipeWithIngredients, "/api")]
-pub async fn get_recipe_with_ingredients(id: i64) -> Result<RecipeWithIngredients, ServerFnError> {
+pub async fn get_recipe_with_ingredients(id: i64) -> Result<RecipeWithIngredients, AppError> {
     use crate::api::recipes;
     let pool = expect_context::<sqlx::SqlitePool>();
     
-    recipes::get_recipe_with_ingredients(&pool, id)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    recipes::get_recipe_with_ingredients(&pool, id).await
 }
 
 #[server(CreateRecipeFn, "/api")]
@@ -26,8 +23,9 @@ pub async fn create_recipe(
     name: String,
     instructions: Option<String>,
     base_servings: i32,
+    tags: String,
     ingredients: Vec<CreateRecipeIngredient>,
-) -> Result<RecipeWithIngredients, ServerFnError> {
+) -> Result<RecipeWithIngredients, AppError> {
     use crate::api::recipes;
     use crate::models::CreateRecipe;
     let pool = expect_context::<sqlx::SqlitePool>();
@@ -36,12 +34,11 @@ pub async fn create_recipe(
         name,
         instructions,
         base_servings,
+        tags,
         ingredients,
     };
     
-    recipes::create_recipe(&pool, new_recipe)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    recipes::create_recipe(&pool, new_recipe).await
 }
 
 #[server(UpdateRecipeFn, "/api")]
@@ -50,8 +47,9 @@ pub async fn update_recipe(
     name: String,
     instructions: Option<String>,
     base_servings: i32,
+    tags: String,
     ingredients: Vec<CreateRecipeIngredient>,
-) -> Result<RecipeWithIngredients, ServerFnError> {
+) -> Result<RecipeWithIngredients, AppError> {
     use crate::api::recipes;
     use crate::models::UpdateRecipe;
     let pool = expect_context::<sqlx::SqlitePool>();
@@ -60,20 +58,89 @@ pub async fn update_recipe(
         name: Some(name),
         instructions,
         base_servings: Some(base_servings),
+        tags: Some(tags),
         ingredients: Some(ingredients),
     };
     
-    recipes::update_recipe(&pool, id, update_recipe)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    recipes::update_recipe(&pool, id, update_recipe).await
 }
 
 #[server(DeleteRecipe, "/api")]
-pub async fn delete_recipe(id: i64) -> Result<(), ServerFnError> {
+pub async fn delete_recipe(id: i64) -> Result<(), AppError> {
     use crate::api::recipes;
     let pool = expect_context::<sqlx::SqlitePool>();
     
-    recipes::delete_recipe(&pool, id)
-        .await
-        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+    recipes::delete_recipe(&pool, id).await
+}
+
+#[server(GetRecipeUsage, "/api")]
+pub async fn get_recipe_usage(id: i64) -> Result<RecipeUsageReport, AppError> {
+    use crate::api::usage;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    usage::get_recipe_usage(&pool, id).await
+}
+
+#[server(ReplaceRecipe, "/api")]
+pub async fn replace_recipe(id: i64, replacement_id: i64) -> Result<u64, AppError> {
+    use crate::api::usage;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    usage::replace_recipe(&pool, id, replacement_id).await
+}
+
+#[server(ArchiveRecipe, "/api")]
+pub async fn archive_recipe(id: i64) -> Result<(), AppError> {
+    use crate::api::recipes;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    recipes::archive_recipe(&pool, id).await
+}
+
+#[server(RestoreRecipe, "/api")]
+pub async fn restore_recipe(id: i64) -> Result<(), AppError> {
+    use crate::api::recipes;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    recipes::restore_recipe(&pool, id).await
+}
+
+#[server(SearchRecipes, "/api")]
+pub async fn search_recipes(query: RecipeSearchQuery) -> Result<RecipeSearchPage, AppError> {
+    use crate::api::recipe_search;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    recipe_search::search_recipes(&pool, &query).await
+}
+
+#[server(FindRecipesFromStock, "/api")]
+pub async fn find_recipes_from_stock(request: StockFinderRequest) -> Result<Vec<StockMatch>, AppError> {
+    use crate::api::stock_finder;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    stock_finder::find_recipes_from_stock(&pool, &request).await
+}
+
+#[server(PreviewRecipeImport, "/api")]
+pub async fn preview_recipe_import(
+    content: String,
+    format: RecipeFormat,
+) -> Result<RecipeImportPreview, AppError> {
+    use crate::api::recipe_formats;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    recipe_formats::preview_recipe_import(&pool, &content, format).await
+}
+
+#[server(ImportRecipeFn, "/api")]
+pub async fn import_recipe(
+    content: String,
+    format: RecipeFormat,
+    name: String,
+    resolutions: Vec<IngredientResolution>,
+) -> Result<RecipeWithIngredients, AppError> {
+    use crate::api::recipe_formats;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    recipe_formats::import_recipe(&pool, &content, format, name, resolutions).await
 }
@@ -1,51 +1,59 @@
 use leptos::prelude::*;
-use crate::models::{ShoppingListItem, MealScheduleItem, AttendanceSummary};
-#[cfg(feature = "ssr")]
-use chrono::NaiveDate;
+use crate::error::AppError;
+use crate::models::{ShoppingListItem, MealScheduleItem, AttendanceSummary, ShoppingTripPlan};
 
 #[server(GenerateShoppingList, "/api")]
 pub async fn generate_shopping_list(
     camp_id: i64,
     start_date: String,
     end_date: String,
-) -> Result<Vec<ShoppingListItem>, ServerFnError<String>> {
+) -> Result<Vec<ShoppingListItem>, AppError> {
     use crate::api::reports;
 
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    let start = NaiveDate::parse_from_str(&start_date, "%Y-%m-%d")
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+    let start = super::parse_date(&start_date, "start_date")?;
 
-    let end = NaiveDate::parse_from_str(&end_date, "%Y-%m-%d")
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))?;
+    let end = super::parse_date(&end_date, "end_date")?;
 
-    reports::generate_shopping_list(&pool, camp_id, start, end)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+    reports::generate_shopping_list(&pool, camp_id, start, end).await
 }
 
 #[server(GenerateMealSchedule, "/api")]
 pub async fn generate_meal_schedule(
     camp_id: i64,
-) -> Result<Vec<MealScheduleItem>, ServerFnError<String>> {
+) -> Result<Vec<MealScheduleItem>, AppError> {
     use crate::api::reports;
 
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    reports::generate_meal_schedule(&pool, camp_id)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+    reports::generate_meal_schedule(&pool, camp_id).await
 }
 
 #[server(GenerateAttendanceSummary, "/api")]
 pub async fn generate_attendance_summary(
     camp_id: i64,
-) -> Result<Vec<AttendanceSummary>, ServerFnError<String>> {
+) -> Result<Vec<AttendanceSummary>, AppError> {
     use crate::api::reports;
 
     let pool = expect_context::<sqlx::SqlitePool>();
 
-    reports::generate_attendance_summary(&pool, camp_id)
-        .await
-        .map_err(|e| ServerFnError::<String>::ServerError(e.to_string()))
+    reports::generate_attendance_summary(&pool, camp_id).await
+}
+
+#[server(GenerateShoppingTrips, "/api")]
+pub async fn generate_shopping_trips(
+    camp_id: i64,
+    trip_dates: Vec<String>,
+) -> Result<ShoppingTripPlan, AppError> {
+    use crate::api::reports;
+
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    let dates = trip_dates
+        .iter()
+        .map(|date| super::parse_date(date, "trip_dates"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    reports::generate_shopping_trips(&pool, camp_id, dates).await
 }
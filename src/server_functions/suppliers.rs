@@ -0,0 +1,50 @@
+use crate::models::{CreateSupplier, PurchaseOrder, SupplierWithAssignments, UpdateSupplier};
+use leptos::prelude::*;
+use crate::error::AppError;
+
+#[server(GetSuppliers, "/api")]
+pub async fn get_suppliers() -> Result<Vec<SupplierWithAssignments>, AppError> {
+    use crate::api::suppliers;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    suppliers::get_suppliers(&pool).await
+}
+
+#[server(CreateSupplierFn, "/api")]
+pub async fn create_supplier(
+    supplier: CreateSupplier,
+) -> Result<SupplierWithAssignments, AppError> {
+    use crate::api::suppliers;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    suppliers::create_supplier(&pool, supplier).await
+}
+
+#[server(UpdateSupplierFn, "/api")]
+pub async fn update_supplier(
+    id: i64,
+    update: UpdateSupplier,
+) -> Result<SupplierWithAssignments, AppError> {
+    use crate::api::suppliers;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    suppliers::update_supplier(&pool, id, update).await
+}
+
+#[server(DeleteSupplier, "/api")]
+pub async fn delete_supplier(id: i64) -> Result<(), AppError> {
+    use crate::api::suppliers;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    suppliers::delete_supplier(&pool, id).await
+}
+
+#[server(GeneratePurchaseOrders, "/api")]
+pub async fn generate_purchase_orders(
+    camp_id: i64,
+) -> Result<Vec<PurchaseOrder>, AppError> {
+    use crate::api::suppliers;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    suppliers::generate_purchase_orders(&pool, camp_id).await
+}
@@ -0,0 +1,53 @@
+use crate::models::{ImportMode, ImportReport, YamlImportOptions, YamlImportReport};
+use leptos::prelude::*;
+use crate::error::AppError;
+
+/// Import a JSON or YAML dump made by the export; `dry_run` only validates
+#[server(ImportDataFn, "/api")]
+pub async fn import_data(
+    content: String,
+    mode: ImportMode,
+    dry_run: bool,
+) -> Result<ImportReport, AppError> {
+    use crate::api::transfer;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    let data = transfer::parse_data(&content)
+        .map_err(|e| AppError::invalid_file("content", e))?;
+
+    transfer::import_data(&pool, data, mode, dry_run).await
+}
+
+/// Import recipes and ingredients in the `source_data` YAML format.
+///
+/// Either file may be left out; `mapping` is an optional mapping file, the
+/// defaults match the bundled data.
+#[server(ImportRecipesYaml, "/api")]
+pub async fn import_recipes_yaml(
+    ingredients_yaml: Option<String>,
+    recipes_yaml: Option<String>,
+    mapping_yaml: Option<String>,
+    options: YamlImportOptions,
+) -> Result<YamlImportReport, AppError> {
+    use crate::api::yaml_import;
+    use crate::models::ImportMapping;
+    let pool = expect_context::<sqlx::SqlitePool>();
+
+    let mapping: ImportMapping = match mapping_yaml {
+        Some(content) => serde_yaml::from_str(&content)
+            .map_err(|e| AppError::invalid_file("mapping_yaml", e))?,
+        None => ImportMapping::default(),
+    };
+    let ingredients = match ingredients_yaml {
+        Some(content) => yaml_import::parse_ingredients_yaml(&content)
+            .map_err(|e| AppError::invalid_file("ingredients_yaml", e))?,
+        None => Default::default(),
+    };
+    let recipes = match recipes_yaml {
+        Some(content) => yaml_import::parse_recipes_yaml(&content)
+            .map_err(|e| AppError::invalid_file("recipes_yaml", e))?,
+        None => Default::default(),
+    };
+
+    yaml_import::import_yaml(&pool, &ingredients, &recipes, &mapping, options).await
+}
@@ -0,0 +1,11 @@
+use leptos::prelude::*;
+use crate::error::AppError;
+use crate::models::Trash;
+
+#[server(GetTrash, "/api")]
+pub async fn get_trash() -> Result<Trash, AppError> {
+    use crate::api::trash;
+    let pool = expect_context::<sqlx::SqlitePool>();
+    
+    trash::get_trash(&pool).await
+}